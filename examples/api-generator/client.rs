@@ -156,28 +156,26 @@ impl ApiDetailedDescription {
         let json = args_or_not(&optional_args, "json");
         let form = args_or_not(&optional_args, "form");
         let multipart = args_or_not(&optional_args, "multipart");
-        let arg_values_list = vec![
-            quote! {#method.to_owned()},
-            quote!(endpoints),
-            quote! {Some(vec![#(#service_names),*])},
+        let call_options = vec![
+            quote!(service_names: Some(vec![#(#service_names),*])),
             quote!(use_https),
             quote!(version),
-            build_path,
+            quote!(path: #build_path),
             quote!(headers),
-            accept_json,
-            accept_application_octet_stream,
+            quote!(accept_json: #accept_json),
+            quote!(accept_application_octet_stream: #accept_application_octet_stream),
             quote!(query),
             quote!(query_pairs),
             quote!(appended_user_agent),
-            authorization,
-            quote! {Some(#idempotent)},
-            bytes,
-            body,
-            body_len,
-            content_type,
-            json,
-            form,
-            multipart,
+            quote!(authorization: #authorization),
+            quote!(idempotent: Some(#idempotent)),
+            quote!(bytes: #bytes),
+            quote!(body: #body),
+            quote!(body_len: #body_len),
+            quote!(content_type: #content_type),
+            quote!(json: #json),
+            quote!(form: #form),
+            quote!(multipart: #multipart),
             quote!(uploading_progress),
             quote!(receive_response_status),
             quote!(receive_response_header),
@@ -191,31 +189,48 @@ impl ApiDetailedDescription {
             quote!(response_error),
             quote!(before_backoff),
             quote!(after_backoff),
+            quote!(body_path: None),
+            quote!(max_retries: None),
+            quote!(max_redirects: None),
+            quote!(deadline: None),
         ];
-        let (call_sync_response_type, call_sync_response_code, call_async_response_code) = if matches!(
-            self.response.body,
-            Some(ResponseBody::Json(_))
-        ) {
-            (
-                quote!(crate::http_client::JsonResponse),
-                quote! {{
-                    let mut body = resp;
-                    let json = crate::http_client::JsonResponse::from(body.parse_json()?);
-                    Py::new(py, (json, parts))
-                }},
-                quote! {{
-                    let mut body = resp;
-                    let json = crate::http_client::JsonResponse::from(body._parse_json().await?);
-                    Python::with_gil(|py| Py::new(py, (json, parts)))
-                }},
-            )
-        } else {
-            (
-                quote!(crate::http::SyncHttpResponse),
-                quote!(Py::new(py, (resp, parts))),
-                quote!(Python::with_gil(|py| Py::new(py, (resp, parts)))),
-            )
-        };
+        let arg_values_list = vec![
+            quote! {#method.to_owned()},
+            quote!(endpoints),
+            quote! {crate::http_client::HttpCallOptions { #(#call_options),* }},
+        ];
+        let (call_sync_response_type, call_sync_response_code, call_async_response_code) =
+            if matches!(self.response.body, Some(ResponseBody::Json(_))) {
+                (
+                    quote!(crate::http_client::JsonResponse),
+                    quote! {{
+                        let mut body = resp;
+                        let json = if keep_raw.unwrap_or_default() {
+                            let (json, raw) = body.parse_json_with_raw()?;
+                            crate::http_client::JsonResponse::with_raw(json, raw)
+                        } else {
+                            crate::http_client::JsonResponse::from(body.parse_json()?)
+                        };
+                        Py::new(py, (json, parts))
+                    }},
+                    quote! {{
+                        let mut body = resp;
+                        let json = if keep_raw.unwrap_or_default() {
+                            let (json, raw) = body._parse_json_with_raw().await?;
+                            crate::http_client::JsonResponse::with_raw(json, raw)
+                        } else {
+                            crate::http_client::JsonResponse::from(body._parse_json().await?)
+                        };
+                        Python::with_gil(|py| Py::new(py, (json, parts)))
+                    }},
+                )
+            } else {
+                (
+                    quote!(crate::http::SyncHttpResponse),
+                    quote!(Py::new(py, (resp, parts))),
+                    quote!(Python::with_gil(|py| Py::new(py, (resp, parts)))),
+                )
+            };
         quote! {
             use pyo3::prelude::*;
             use crate::http_client::HttpClient;
@@ -229,7 +244,7 @@ impl ApiDetailedDescription {
             #[doc = #api_docs]
             #[pyclass(extends = HttpClient)]
             #[pyo3(
-                text_signature = "(/, http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None)"
+                text_signature = "(/, http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None, max_requests_per_sec = None, progress_interval_ms = None, on_request = None, on_response_log = None, on_retry = None, accept_encoding = None, extra_headers = None)"
             )]
             #[derive(Clone)]
             struct Client;
@@ -257,7 +272,14 @@ impl ApiDetailedDescription {
                     response_ok = "None",
                     response_error = "None",
                     before_backoff = "None",
-                    after_backoff = "None"
+                    after_backoff = "None",
+                    max_requests_per_sec = "None",
+                    progress_interval_ms = "None",
+                    on_request = "None",
+                    on_response_log = "None",
+                    on_retry = "None",
+                    accept_encoding = "None",
+                    extra_headers = "None"
                 )]
                 #[allow(clippy::too_many_arguments)]
                 pub(crate) fn new(
@@ -281,6 +303,13 @@ impl ApiDetailedDescription {
                     response_error: Option<PyObject>,
                     before_backoff: Option<PyObject>,
                     after_backoff: Option<PyObject>,
+                    max_requests_per_sec: Option<f64>,
+                    progress_interval_ms: Option<u64>,
+                    on_request: Option<PyObject>,
+                    on_response_log: Option<PyObject>,
+                    on_retry: Option<PyObject>,
+                    accept_encoding: Option<bool>,
+                    extra_headers: Option<std::collections::HashMap<String, String>>,
                 ) -> PyResult<(Self, HttpClient)> {
                     let client = HttpClient::new(
                         http_caller,
@@ -303,6 +332,13 @@ impl ApiDetailedDescription {
                         response_error,
                         before_backoff,
                         after_backoff,
+                        max_requests_per_sec,
+                        progress_interval_ms,
+                        on_request,
+                        on_response_log,
+                        on_retry,
+                        accept_encoding,
+                        extra_headers,
                     )?;
                     Ok((Self, client))
                 }
@@ -397,10 +433,7 @@ impl ApiDetailedDescription {
                     optional_args.insert("form".to_owned(), quote! {Vec<(String, Option<String>)>});
                 }
                 RequestBody::MultipartFormData(_) => {
-                    optional_args.insert(
-                        "multipart".to_owned(),
-                        quote! {std::collections::HashMap<String, PyObject>},
-                    );
+                    optional_args.insert("multipart".to_owned(), quote! {PyObject});
                 }
                 RequestBody::BinaryData => {
                     optional_args.insert("bytes".to_owned(), quote!(Vec<u8>));
@@ -432,6 +465,9 @@ impl ApiDetailedDescription {
         ] {
             optional_args.insert(arg_name.to_owned(), quote!(PyObject));
         }
+        if matches!(self.response.body, Some(ResponseBody::Json(_))) {
+            optional_args.insert("keep_raw".to_owned(), quote!(bool));
+        }
 
         (required_args, optional_args)
     }
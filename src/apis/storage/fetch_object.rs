@@ -10,7 +10,7 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
 #[doc = "从指定 URL 抓取指定名称的对象并存储到该空间中"]
 # [pyclass (extends = HttpClient)]
 #[pyo3(
-    text_signature = "(/, http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None)"
+    text_signature = "(/, http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None, max_requests_per_sec = None, progress_interval_ms = None, on_request = None, on_response_log = None, on_retry = None, accept_encoding = None, extra_headers = None)"
 )]
 #[derive(Clone)]
 struct Client;
@@ -37,7 +37,14 @@ impl Client {
         response_ok = "None",
         response_error = "None",
         before_backoff = "None",
-        after_backoff = "None"
+        after_backoff = "None",
+        max_requests_per_sec = "None",
+        progress_interval_ms = "None",
+        on_request = "None",
+        on_response_log = "None",
+        on_retry = "None",
+        accept_encoding = "None",
+        extra_headers = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
@@ -61,6 +68,13 @@ impl Client {
         response_error: Option<PyObject>,
         before_backoff: Option<PyObject>,
         after_backoff: Option<PyObject>,
+        max_requests_per_sec: Option<f64>,
+        progress_interval_ms: Option<u64>,
+        on_request: Option<PyObject>,
+        on_response_log: Option<PyObject>,
+        on_retry: Option<PyObject>,
+        accept_encoding: Option<bool>,
+        extra_headers: Option<std::collections::HashMap<String, String>>,
     ) -> PyResult<(Self, HttpClient)> {
         let client = HttpClient::new(
             http_caller,
@@ -83,12 +97,19 @@ impl Client {
             response_error,
             before_backoff,
             after_backoff,
+            max_requests_per_sec,
+            progress_interval_ms,
+            on_request,
+            on_response_log,
+            on_retry,
+            accept_encoding,
+            extra_headers,
         )?;
         Ok((Self, client))
     }
     #[doc = "发出阻塞请求"]
     #[pyo3(
-        text_signature = "(endpoints, credential, /, use_https = None, version = None, headers = None, query = None, query_pairs = None, appended_user_agent = None, from_url = None, to_entry = None, host = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None)"
+        text_signature = "(endpoints, credential, /, use_https = None, version = None, headers = None, query = None, query_pairs = None, appended_user_agent = None, from_url = None, to_entry = None, host = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None, keep_raw = None)"
     )]
     #[args(
         r#use_https = "None",
@@ -112,7 +133,8 @@ impl Client {
         r#response_ok = "None",
         r#response_error = "None",
         r#before_backoff = "None",
-        r#after_backoff = "None"
+        r#after_backoff = "None",
+        r#keep_raw = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     fn call(
@@ -141,81 +163,93 @@ impl Client {
         r#response_error: Option<PyObject>,
         r#before_backoff: Option<PyObject>,
         r#after_backoff: Option<PyObject>,
+        r#keep_raw: Option<bool>,
         py: Python<'_>,
     ) -> PyResult<Py<crate::http_client::JsonResponse>> {
         let super_ = self_.into_super();
         let (resp, parts) = super_._call(
             "POST".to_owned(),
             endpoints,
-            Some(vec![crate::http_client::ServiceName::r#Io]),
-            use_https,
-            version,
-            {
-                let mut segments = vec!["/fetch".to_owned()];
-                segments.extend({
-                    let mut segments = vec![];
-                    if let Some(r#from_url) = r#from_url {
-                        segments.push(qiniu_sdk::utils::base64::urlsafe(r#from_url.as_bytes()));
-                    }
-                    segments
-                });
-                segments.extend({
-                    let mut segments = vec!["to".to_owned()];
-                    if let Some(r#to_entry) = r#to_entry {
-                        segments.push(qiniu_sdk::utils::base64::urlsafe(r#to_entry.as_bytes()));
-                    }
-                    segments
-                });
-                segments.extend({
-                    let mut segments = vec!["host".to_owned()];
-                    if let Some(r#host) = r#host {
-                        segments.push(qiniu_sdk::utils::base64::urlsafe(r#host.as_bytes()));
-                    }
-                    segments
-                });
-                Some(segments.join("/"))
+            crate::http_client::HttpCallOptions {
+                service_names: Some(vec![crate::http_client::ServiceName::r#Io]),
+                use_https,
+                version,
+                path: {
+                    let mut segments = vec!["/fetch".to_owned()];
+                    segments.extend({
+                        let mut segments = vec![];
+                        if let Some(r#from_url) = r#from_url {
+                            segments.push(qiniu_sdk::utils::base64::urlsafe(r#from_url.as_bytes()));
+                        }
+                        segments
+                    });
+                    segments.extend({
+                        let mut segments = vec!["to".to_owned()];
+                        if let Some(r#to_entry) = r#to_entry {
+                            segments.push(qiniu_sdk::utils::base64::urlsafe(r#to_entry.as_bytes()));
+                        }
+                        segments
+                    });
+                    segments.extend({
+                        let mut segments = vec!["host".to_owned()];
+                        if let Some(r#host) = r#host {
+                            segments.push(qiniu_sdk::utils::base64::urlsafe(r#host.as_bytes()));
+                        }
+                        segments
+                    });
+                    Some(segments.join("/"))
+                },
+                headers,
+                accept_json: Some(true),
+                accept_application_octet_stream: None,
+                query,
+                query_pairs,
+                appended_user_agent,
+                authorization: Some(crate::http_client::Authorization::from(
+                    qiniu_sdk::http_client::Authorization::v2(credential),
+                )),
+                idempotent: Some(crate::http_client::Idempotent::r#Always),
+                bytes: None,
+                body: None,
+                body_len: None,
+                content_type: None,
+                json: None,
+                form: None,
+                multipart: None,
+                uploading_progress,
+                receive_response_status,
+                receive_response_header,
+                to_resolve_domain,
+                domain_resolved,
+                to_choose_ips,
+                ips_chosen,
+                before_request_signed,
+                after_request_signed,
+                response_ok,
+                response_error,
+                before_backoff,
+                after_backoff,
+                body_path: None,
+                max_retries: None,
+                max_redirects: None,
+                deadline: None,
             },
-            headers,
-            Some(true),
-            None,
-            query,
-            query_pairs,
-            appended_user_agent,
-            Some(crate::http_client::Authorization::from(
-                qiniu_sdk::http_client::Authorization::v2(credential),
-            )),
-            Some(crate::http_client::Idempotent::r#Always),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            uploading_progress,
-            receive_response_status,
-            receive_response_header,
-            to_resolve_domain,
-            domain_resolved,
-            to_choose_ips,
-            ips_chosen,
-            before_request_signed,
-            after_request_signed,
-            response_ok,
-            response_error,
-            before_backoff,
-            after_backoff,
             py,
         )?;
         {
             let mut body = resp;
-            let json = crate::http_client::JsonResponse::from(body.parse_json()?);
+            let json = if keep_raw.unwrap_or_default() {
+                let (json, raw) = body.parse_json_with_raw()?;
+                crate::http_client::JsonResponse::with_raw(json, raw)
+            } else {
+                crate::http_client::JsonResponse::from(body.parse_json()?)
+            };
             Py::new(py, (json, parts))
         }
     }
     #[doc = "发出异步请求"]
     #[pyo3(
-        text_signature = "(endpoints, credential, /, use_https = None, version = None, headers = None, query = None, query_pairs = None, appended_user_agent = None, from_url = None, to_entry = None, host = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None)"
+        text_signature = "(endpoints, credential, /, use_https = None, version = None, headers = None, query = None, query_pairs = None, appended_user_agent = None, from_url = None, to_entry = None, host = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None, keep_raw = None)"
     )]
     #[args(
         r#use_https = "None",
@@ -239,7 +273,8 @@ impl Client {
         r#response_ok = "None",
         r#response_error = "None",
         r#before_backoff = "None",
-        r#after_backoff = "None"
+        r#after_backoff = "None",
+        r#keep_raw = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     fn async_call<'p>(
@@ -268,6 +303,7 @@ impl Client {
         r#response_error: Option<PyObject>,
         r#before_backoff: Option<PyObject>,
         r#after_backoff: Option<PyObject>,
+        r#keep_raw: Option<bool>,
         py: Python<'p>,
     ) -> PyResult<&'p PyAny> {
         let http_client = self_.into_super().to_owned();
@@ -276,71 +312,85 @@ impl Client {
                 ._async_call(
                     "POST".to_owned(),
                     endpoints,
-                    Some(vec![crate::http_client::ServiceName::r#Io]),
-                    use_https,
-                    version,
-                    {
-                        let mut segments = vec!["/fetch".to_owned()];
-                        segments.extend({
-                            let mut segments = vec![];
-                            if let Some(r#from_url) = r#from_url {
+                    crate::http_client::HttpCallOptions {
+                        service_names: Some(vec![crate::http_client::ServiceName::r#Io]),
+                        use_https,
+                        version,
+                        path: {
+                            let mut segments = vec!["/fetch".to_owned()];
+                            segments.extend({
+                                let mut segments = vec![];
+                                if let Some(r#from_url) = r#from_url {
+                                    segments.push(qiniu_sdk::utils::base64::urlsafe(
+                                        r#from_url.as_bytes(),
+                                    ));
+                                }
+                                segments
+                            });
+                            segments.extend({
+                                let mut segments = vec!["to".to_owned()];
+                                if let Some(r#to_entry) = r#to_entry {
+                                    segments.push(qiniu_sdk::utils::base64::urlsafe(
+                                        r#to_entry.as_bytes(),
+                                    ));
+                                }
                                 segments
-                                    .push(qiniu_sdk::utils::base64::urlsafe(r#from_url.as_bytes()));
-                            }
-                            segments
-                        });
-                        segments.extend({
-                            let mut segments = vec!["to".to_owned()];
-                            if let Some(r#to_entry) = r#to_entry {
+                            });
+                            segments.extend({
+                                let mut segments = vec!["host".to_owned()];
+                                if let Some(r#host) = r#host {
+                                    segments
+                                        .push(qiniu_sdk::utils::base64::urlsafe(r#host.as_bytes()));
+                                }
                                 segments
-                                    .push(qiniu_sdk::utils::base64::urlsafe(r#to_entry.as_bytes()));
-                            }
-                            segments
-                        });
-                        segments.extend({
-                            let mut segments = vec!["host".to_owned()];
-                            if let Some(r#host) = r#host {
-                                segments.push(qiniu_sdk::utils::base64::urlsafe(r#host.as_bytes()));
-                            }
-                            segments
-                        });
-                        Some(segments.join("/"))
+                            });
+                            Some(segments.join("/"))
+                        },
+                        headers,
+                        accept_json: Some(true),
+                        accept_application_octet_stream: None,
+                        query,
+                        query_pairs,
+                        appended_user_agent,
+                        authorization: Some(crate::http_client::Authorization::from(
+                            qiniu_sdk::http_client::Authorization::v2(credential),
+                        )),
+                        idempotent: Some(crate::http_client::Idempotent::r#Always),
+                        bytes: None,
+                        body: None,
+                        body_len: None,
+                        content_type: None,
+                        json: None,
+                        form: None,
+                        multipart: None,
+                        uploading_progress,
+                        receive_response_status,
+                        receive_response_header,
+                        to_resolve_domain,
+                        domain_resolved,
+                        to_choose_ips,
+                        ips_chosen,
+                        before_request_signed,
+                        after_request_signed,
+                        response_ok,
+                        response_error,
+                        before_backoff,
+                        after_backoff,
+                        body_path: None,
+                        max_retries: None,
+                        max_redirects: None,
+                        deadline: None,
                     },
-                    headers,
-                    Some(true),
-                    None,
-                    query,
-                    query_pairs,
-                    appended_user_agent,
-                    Some(crate::http_client::Authorization::from(
-                        qiniu_sdk::http_client::Authorization::v2(credential),
-                    )),
-                    Some(crate::http_client::Idempotent::r#Always),
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    uploading_progress,
-                    receive_response_status,
-                    receive_response_header,
-                    to_resolve_domain,
-                    domain_resolved,
-                    to_choose_ips,
-                    ips_chosen,
-                    before_request_signed,
-                    after_request_signed,
-                    response_ok,
-                    response_error,
-                    before_backoff,
-                    after_backoff,
                 )
                 .await?;
             {
                 let mut body = resp;
-                let json = crate::http_client::JsonResponse::from(body._parse_json().await?);
+                let json = if keep_raw.unwrap_or_default() {
+                    let (json, raw) = body._parse_json_with_raw().await?;
+                    crate::http_client::JsonResponse::with_raw(json, raw)
+                } else {
+                    crate::http_client::JsonResponse::from(body._parse_json().await?)
+                };
                 Python::with_gil(|py| Py::new(py, (json, parts)))
             }
         })
@@ -10,7 +10,7 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
 #[doc = "修改已上传对象的生命周期"]
 # [pyclass (extends = HttpClient)]
 #[pyo3(
-    text_signature = "(/, http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None)"
+    text_signature = "(/, http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None, max_requests_per_sec = None, progress_interval_ms = None, on_request = None, on_response_log = None, on_retry = None, accept_encoding = None, extra_headers = None)"
 )]
 #[derive(Clone)]
 struct Client;
@@ -37,7 +37,14 @@ impl Client {
         response_ok = "None",
         response_error = "None",
         before_backoff = "None",
-        after_backoff = "None"
+        after_backoff = "None",
+        max_requests_per_sec = "None",
+        progress_interval_ms = "None",
+        on_request = "None",
+        on_response_log = "None",
+        on_retry = "None",
+        accept_encoding = "None",
+        extra_headers = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
@@ -61,6 +68,13 @@ impl Client {
         response_error: Option<PyObject>,
         before_backoff: Option<PyObject>,
         after_backoff: Option<PyObject>,
+        max_requests_per_sec: Option<f64>,
+        progress_interval_ms: Option<u64>,
+        on_request: Option<PyObject>,
+        on_response_log: Option<PyObject>,
+        on_retry: Option<PyObject>,
+        accept_encoding: Option<bool>,
+        extra_headers: Option<std::collections::HashMap<String, String>>,
     ) -> PyResult<(Self, HttpClient)> {
         let client = HttpClient::new(
             http_caller,
@@ -83,6 +97,13 @@ impl Client {
             response_error,
             before_backoff,
             after_backoff,
+            max_requests_per_sec,
+            progress_interval_ms,
+            on_request,
+            on_response_log,
+            on_retry,
+            accept_encoding,
+            extra_headers,
         )?;
         Ok((Self, client))
     }
@@ -151,78 +172,84 @@ impl Client {
         let (resp, parts) = super_._call(
             "POST".to_owned(),
             endpoints,
-            Some(vec![crate::http_client::ServiceName::r#Rs]),
-            use_https,
-            version,
-            {
-                let mut segments = vec!["/lifecycle".to_owned()];
-                segments.extend({
-                    let mut segments = vec![];
-                    if let Some(r#entry) = r#entry {
-                        segments.push(qiniu_sdk::utils::base64::urlsafe(r#entry.as_bytes()));
-                    }
-                    segments
-                });
-                segments.extend({
-                    let mut segments = vec!["toIAAfterDays".to_owned()];
-                    if let Some(r#to_ia_after_days) = r#to_ia_after_days {
-                        segments.push(r#to_ia_after_days.to_string());
-                    }
-                    segments
-                });
-                segments.extend({
-                    let mut segments = vec!["toArchiveAfterDays".to_owned()];
-                    if let Some(r#to_archive_after_days) = r#to_archive_after_days {
-                        segments.push(r#to_archive_after_days.to_string());
-                    }
-                    segments
-                });
-                segments.extend({
-                    let mut segments = vec!["toDeepArchiveAfterDays".to_owned()];
-                    if let Some(r#to_deep_archive_after_days) = r#to_deep_archive_after_days {
-                        segments.push(r#to_deep_archive_after_days.to_string());
-                    }
-                    segments
-                });
-                segments.extend({
-                    let mut segments = vec!["deleteAfterDays".to_owned()];
-                    if let Some(r#delete_after_days) = r#delete_after_days {
-                        segments.push(r#delete_after_days.to_string());
-                    }
-                    segments
-                });
-                Some(segments.join("/"))
+            crate::http_client::HttpCallOptions {
+                service_names: Some(vec![crate::http_client::ServiceName::r#Rs]),
+                use_https,
+                version,
+                path: {
+                    let mut segments = vec!["/lifecycle".to_owned()];
+                    segments.extend({
+                        let mut segments = vec![];
+                        if let Some(r#entry) = r#entry {
+                            segments.push(qiniu_sdk::utils::base64::urlsafe(r#entry.as_bytes()));
+                        }
+                        segments
+                    });
+                    segments.extend({
+                        let mut segments = vec!["toIAAfterDays".to_owned()];
+                        if let Some(r#to_ia_after_days) = r#to_ia_after_days {
+                            segments.push(r#to_ia_after_days.to_string());
+                        }
+                        segments
+                    });
+                    segments.extend({
+                        let mut segments = vec!["toArchiveAfterDays".to_owned()];
+                        if let Some(r#to_archive_after_days) = r#to_archive_after_days {
+                            segments.push(r#to_archive_after_days.to_string());
+                        }
+                        segments
+                    });
+                    segments.extend({
+                        let mut segments = vec!["toDeepArchiveAfterDays".to_owned()];
+                        if let Some(r#to_deep_archive_after_days) = r#to_deep_archive_after_days {
+                            segments.push(r#to_deep_archive_after_days.to_string());
+                        }
+                        segments
+                    });
+                    segments.extend({
+                        let mut segments = vec!["deleteAfterDays".to_owned()];
+                        if let Some(r#delete_after_days) = r#delete_after_days {
+                            segments.push(r#delete_after_days.to_string());
+                        }
+                        segments
+                    });
+                    Some(segments.join("/"))
+                },
+                headers,
+                accept_json: None,
+                accept_application_octet_stream: None,
+                query,
+                query_pairs,
+                appended_user_agent,
+                authorization: Some(crate::http_client::Authorization::from(
+                    qiniu_sdk::http_client::Authorization::v2(credential),
+                )),
+                idempotent: Some(crate::http_client::Idempotent::r#Always),
+                bytes: None,
+                body: None,
+                body_len: None,
+                content_type: None,
+                json: None,
+                form: None,
+                multipart: None,
+                uploading_progress,
+                receive_response_status,
+                receive_response_header,
+                to_resolve_domain,
+                domain_resolved,
+                to_choose_ips,
+                ips_chosen,
+                before_request_signed,
+                after_request_signed,
+                response_ok,
+                response_error,
+                before_backoff,
+                after_backoff,
+                body_path: None,
+                max_retries: None,
+                max_redirects: None,
+                deadline: None,
             },
-            headers,
-            None,
-            None,
-            query,
-            query_pairs,
-            appended_user_agent,
-            Some(crate::http_client::Authorization::from(
-                qiniu_sdk::http_client::Authorization::v2(credential),
-            )),
-            Some(crate::http_client::Idempotent::r#Always),
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            None,
-            uploading_progress,
-            receive_response_status,
-            receive_response_header,
-            to_resolve_domain,
-            domain_resolved,
-            to_choose_ips,
-            ips_chosen,
-            before_request_signed,
-            after_request_signed,
-            response_ok,
-            response_error,
-            before_backoff,
-            after_backoff,
             py,
         )?;
         Py::new(py, (resp, parts))
@@ -294,80 +321,88 @@ impl Client {
                 ._async_call(
                     "POST".to_owned(),
                     endpoints,
-                    Some(vec![crate::http_client::ServiceName::r#Rs]),
-                    use_https,
-                    version,
-                    {
-                        let mut segments = vec!["/lifecycle".to_owned()];
-                        segments.extend({
-                            let mut segments = vec![];
-                            if let Some(r#entry) = r#entry {
+                    crate::http_client::HttpCallOptions {
+                        service_names: Some(vec![crate::http_client::ServiceName::r#Rs]),
+                        use_https,
+                        version,
+                        path: {
+                            let mut segments = vec!["/lifecycle".to_owned()];
+                            segments.extend({
+                                let mut segments = vec![];
+                                if let Some(r#entry) = r#entry {
+                                    segments.push(qiniu_sdk::utils::base64::urlsafe(
+                                        r#entry.as_bytes(),
+                                    ));
+                                }
+                                segments
+                            });
+                            segments.extend({
+                                let mut segments = vec!["toIAAfterDays".to_owned()];
+                                if let Some(r#to_ia_after_days) = r#to_ia_after_days {
+                                    segments.push(r#to_ia_after_days.to_string());
+                                }
+                                segments
+                            });
+                            segments.extend({
+                                let mut segments = vec!["toArchiveAfterDays".to_owned()];
+                                if let Some(r#to_archive_after_days) = r#to_archive_after_days {
+                                    segments.push(r#to_archive_after_days.to_string());
+                                }
+                                segments
+                            });
+                            segments.extend({
+                                let mut segments = vec!["toDeepArchiveAfterDays".to_owned()];
+                                if let Some(r#to_deep_archive_after_days) =
+                                    r#to_deep_archive_after_days
+                                {
+                                    segments.push(r#to_deep_archive_after_days.to_string());
+                                }
+                                segments
+                            });
+                            segments.extend({
+                                let mut segments = vec!["deleteAfterDays".to_owned()];
+                                if let Some(r#delete_after_days) = r#delete_after_days {
+                                    segments.push(r#delete_after_days.to_string());
+                                }
                                 segments
-                                    .push(qiniu_sdk::utils::base64::urlsafe(r#entry.as_bytes()));
-                            }
-                            segments
-                        });
-                        segments.extend({
-                            let mut segments = vec!["toIAAfterDays".to_owned()];
-                            if let Some(r#to_ia_after_days) = r#to_ia_after_days {
-                                segments.push(r#to_ia_after_days.to_string());
-                            }
-                            segments
-                        });
-                        segments.extend({
-                            let mut segments = vec!["toArchiveAfterDays".to_owned()];
-                            if let Some(r#to_archive_after_days) = r#to_archive_after_days {
-                                segments.push(r#to_archive_after_days.to_string());
-                            }
-                            segments
-                        });
-                        segments.extend({
-                            let mut segments = vec!["toDeepArchiveAfterDays".to_owned()];
-                            if let Some(r#to_deep_archive_after_days) = r#to_deep_archive_after_days
-                            {
-                                segments.push(r#to_deep_archive_after_days.to_string());
-                            }
-                            segments
-                        });
-                        segments.extend({
-                            let mut segments = vec!["deleteAfterDays".to_owned()];
-                            if let Some(r#delete_after_days) = r#delete_after_days {
-                                segments.push(r#delete_after_days.to_string());
-                            }
-                            segments
-                        });
-                        Some(segments.join("/"))
+                            });
+                            Some(segments.join("/"))
+                        },
+                        headers,
+                        accept_json: None,
+                        accept_application_octet_stream: None,
+                        query,
+                        query_pairs,
+                        appended_user_agent,
+                        authorization: Some(crate::http_client::Authorization::from(
+                            qiniu_sdk::http_client::Authorization::v2(credential),
+                        )),
+                        idempotent: Some(crate::http_client::Idempotent::r#Always),
+                        bytes: None,
+                        body: None,
+                        body_len: None,
+                        content_type: None,
+                        json: None,
+                        form: None,
+                        multipart: None,
+                        uploading_progress,
+                        receive_response_status,
+                        receive_response_header,
+                        to_resolve_domain,
+                        domain_resolved,
+                        to_choose_ips,
+                        ips_chosen,
+                        before_request_signed,
+                        after_request_signed,
+                        response_ok,
+                        response_error,
+                        before_backoff,
+                        after_backoff,
+                        body_path: None,
+                        max_retries: None,
+                        max_redirects: None,
+                        deadline: None,
                     },
-                    headers,
-                    None,
-                    None,
-                    query,
-                    query_pairs,
-                    appended_user_agent,
-                    Some(crate::http_client::Authorization::from(
-                        qiniu_sdk::http_client::Authorization::v2(credential),
-                    )),
-                    Some(crate::http_client::Idempotent::r#Always),
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    uploading_progress,
-                    receive_response_status,
-                    receive_response_header,
-                    to_resolve_domain,
-                    domain_resolved,
-                    to_choose_ips,
-                    ips_chosen,
-                    before_request_signed,
-                    after_request_signed,
-                    response_ok,
-                    response_error,
-                    before_backoff,
-                    after_backoff,
                 )
                 .await?;
             Python::with_gil(|py| Py::new(py, (resp, parts)))
@@ -1,10 +1,20 @@
 use super::{
     exceptions::QiniuEmptyChainCredentialsProvider,
-    utils::{parse_header_value, parse_headers, parse_method, parse_uri, PythonIoBase},
+    utils::{
+        encode_query_component, parse_header_value, parse_headers, parse_method,
+        parse_ordered_query_pairs, parse_uri, unpicklable_error, PythonIoBase,
+    },
 };
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyType};
 use qiniu_sdk::credential::{QINIU_ACCESS_KEY_ENV_KEY, QINIU_SECRET_KEY_ENV_KEY};
-use std::{collections::HashMap, future::Future, io::Result as IoResult, pin::Pin, time::Duration};
+use std::{
+    collections::HashMap,
+    future::Future,
+    io::Result as IoResult,
+    pin::Pin,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "credential")?;
@@ -15,6 +25,7 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<GlobalCredentialProvider>()?;
     m.add_class::<EnvCredentialProvider>()?;
     m.add_class::<ChainCredentialsProvider>()?;
+    m.add_class::<MutableCredentialProvider>()?;
     m.add_class::<GetOptions>()?;
     Ok(m)
 }
@@ -98,14 +109,69 @@ impl Credential {
     }
 
     /// 对对象的下载 URL 签名，可以生成私有存储空间的下载地址
-    #[pyo3(text_signature = "($self, url, secs)")]
-    fn sign_download_url(self_: PyRef<'_, Self>, url: &str, secs: u64) -> PyResult<String> {
+    ///
+    /// 签名将在 `secs` 秒后过期（过期时间溢出时取最大值），URL 中已有的查询参数和片段都会被保留
+    ///
+    /// 可以通过 `extra_query` 传入额外的查询参数（例如 `attname`），它们将被编码后追加到 URL 中并被签名覆盖
+    #[pyo3(text_signature = "($self, url, secs, /, extra_query = None)")]
+    #[args(extra_query = "None")]
+    fn sign_download_url(
+        self_: PyRef<'_, Self>,
+        url: &str,
+        secs: u64,
+        extra_query: Option<&PyAny>,
+    ) -> PyResult<String> {
+        let deadline = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs())
+            .saturating_add(secs);
+        Self::sign_download_url_with_deadline(self_, url, deadline, extra_query)
+    }
+
+    /// 对对象的下载 URL 签名，签名将在指定的 UNIX 时间戳（单位为秒）过期
+    ///
+    /// URL 中已有的查询参数和片段都会被保留
+    ///
+    /// 可以通过 `extra_query` 传入额外的查询参数（例如 `attname`），它们将被编码后追加到 URL 中并被签名覆盖
+    #[pyo3(text_signature = "($self, url, deadline, /, extra_query = None)")]
+    #[args(extra_query = "None")]
+    fn sign_download_url_with_deadline(
+        self_: PyRef<'_, Self>,
+        url: &str,
+        deadline: u64,
+        extra_query: Option<&PyAny>,
+    ) -> PyResult<String> {
+        let extra_query = extra_query
+            .map(parse_ordered_query_pairs)
+            .transpose()?
+            .unwrap_or_default();
         let super_ = self_.as_ref();
-        Ok(super_
-            .0
-            .get(Default::default())?
-            .sign_download_url(parse_uri(url)?, Duration::from_secs(secs))
-            .to_string())
+        let credential = super_.0.get(Default::default())?;
+        let (url, fragment) = url
+            .split_once('#')
+            .map_or((url, None), |(url, fragment)| (url, Some(fragment)));
+        parse_uri(url)?;
+        let mut signed_url = url.to_owned();
+        if !signed_url.contains('?') {
+            signed_url.push('?');
+        } else if !signed_url.ends_with('?') && !signed_url.ends_with('&') {
+            signed_url.push('&');
+        }
+        for (key, value) in extra_query {
+            signed_url.push_str(&encode_query_component(&key));
+            signed_url.push('=');
+            signed_url.push_str(&encode_query_component(&value));
+            signed_url.push('&');
+        }
+        signed_url.push_str(&format!("e={}", deadline));
+        let token = credential.sign(signed_url.as_bytes());
+        signed_url.push_str("&token=");
+        signed_url.push_str(&token);
+        if let Some(fragment) = fragment {
+            signed_url.push('#');
+            signed_url.push_str(fragment);
+        }
+        Ok(signed_url)
     }
 
     /// 使用七牛签名算法 V1 对 HTTP 请求（请求体为内存数据）进行签名，返回 Authorization 的值
@@ -247,6 +313,23 @@ impl Credential {
             Ok(auth)
         })
     }
+
+    /// 支持通过 `pickle` 序列化认证信息，以便在多进程之间传递
+    ///
+    /// 注意序列化结果中包含明文的 SecretKey
+    fn __reduce__<'p>(
+        self_: PyRef<'p, Self>,
+        py: Python<'p>,
+    ) -> PyResult<(&'p PyType, (String, String))> {
+        let credential = self_.as_ref().0.get(Default::default())?;
+        Ok((
+            py.get_type::<Self>(),
+            (
+                credential.access_key().to_string(),
+                credential.secret_key().to_string(),
+            ),
+        ))
+    }
 }
 
 /// 认证信息获取接口
@@ -302,6 +385,10 @@ impl CredentialProvider {
     fn __str__(&self) -> String {
         self.__repr__()
     }
+
+    fn __reduce__(slf: &PyCell<Self>) -> PyResult<()> {
+        Err(unpicklable_error(slf))
+    }
 }
 
 impl qiniu_sdk::credential::CredentialProvider for CredentialProvider {
@@ -359,6 +446,10 @@ impl GlobalCredentialProvider {
 
 /// 环境变量认证信息提供者，可以将认证信息配置在环境变量中。
 ///
+/// 每次获取认证信息时都会重新读取环境变量 `QINIU_ACCESS_KEY` 和 `QINIU_SECRET_KEY`，
+/// 变量名称也可以通过 `credential.QINIU_ACCESS_KEY_ENV_KEY` 和 `credential.QINIU_SECRET_KEY_ENV_KEY` 获取。
+/// 如果任意一个环境变量未设置，获取认证信息时将抛出 `OSError` 异常。
+///
 /// 通过 `EnvCredentialProvider()` 创建
 #[pyclass(extends = CredentialProvider)]
 #[pyo3(text_signature = "()")]
@@ -428,6 +519,71 @@ impl ChainCredentialsProvider {
     }
 }
 
+/// 可变认证信息提供者
+///
+/// 可以在运行时通过 `set_credential()` 替换认证信息，已经使用该提供者创建的上传管理器或 HTTP 客户端等实例将立即使用新的认证信息，适用于需要定期轮换密钥的场景。
+///
+/// 通过 `MutableCredentialProvider(credential)` 创建
+#[pyclass(extends = CredentialProvider)]
+#[pyo3(text_signature = "(credential)")]
+struct MutableCredentialProvider(MutableCredential);
+
+#[pymethods]
+impl MutableCredentialProvider {
+    /// 创建可变认证信息提供者
+    #[new]
+    fn new(credential: PyRef<'_, Credential>) -> PyResult<(Self, CredentialProvider)> {
+        let credential = credential.into_super().0.get(Default::default())?.into();
+        let provider = MutableCredential(Arc::new(RwLock::new(credential)));
+        Ok((
+            Self(provider.to_owned()),
+            CredentialProvider(Box::new(provider)),
+        ))
+    }
+
+    /// 替换认证信息
+    #[pyo3(text_signature = "($self, credential)")]
+    fn set_credential(&self, credential: PyRef<'_, Credential>) -> PyResult<()> {
+        let credential = credential.into_super().0.get(Default::default())?.into();
+        *self.0.write() = credential;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MutableCredential(Arc<RwLock<qiniu_sdk::credential::Credential>>);
+
+impl MutableCredential {
+    fn read(&self) -> RwLockReadGuard<'_, qiniu_sdk::credential::Credential> {
+        self.0
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, qiniu_sdk::credential::Credential> {
+        self.0
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl qiniu_sdk::credential::CredentialProvider for MutableCredential {
+    fn get(
+        &self,
+        _opts: qiniu_sdk::credential::GetOptions,
+    ) -> IoResult<qiniu_sdk::credential::GotCredential> {
+        Ok(self.read().to_owned().into())
+    }
+
+    fn async_get<'a>(
+        &'a self,
+        opts: qiniu_sdk::credential::GetOptions,
+    ) -> Pin<Box<dyn Future<Output = IoResult<qiniu_sdk::credential::GotCredential>> + 'a + Send>>
+    {
+        Box::pin(async move { self.get(opts) })
+    }
+}
+
 /// 获取认证信息的选项
 ///
 /// 通过 `GetOptions()` 创建
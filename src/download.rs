@@ -1,16 +1,41 @@
 use super::{
     credential::CredentialProvider,
-    exceptions::{QiniuApiCallError, QiniuDownloadError, QiniuEmptyEndpoints},
+    exceptions::{
+        QiniuApiCallError, QiniuChunkedDownloadError, QiniuDownloadError, QiniuEmptyEndpoints,
+        QiniuInvalidConcurrency, QiniuInvalidPartSize, QiniuIoError,
+    },
     http::HttpResponsePartsMut,
-    http_client::{CallbackContextMut, EndpointsProvider, HttpClient, RequestBuilderPartsRef},
-    utils::{convert_api_call_error, extract_endpoints, parse_headers, PythonIoBase},
+    http_client::{
+        enabled_shared_http_client, new_extended_http_client, CallbackContextMut,
+        EndpointsProvider, FollowRedirectsExtension, HttpClient, RequestBuilderPartsRef,
+        DEFAULT_MAX_REDIRECTS,
+    },
+    utils::{
+        async_read_to_end_with_progress, convert_api_call_error, extract_endpoints, join_thread,
+        parse_headers, read_to_end_with_progress, AesCtrCipher, PythonIoBase,
+        PythonIoBaseAsyncRead,
+    },
 };
 use anyhow::Result as AnyResult;
-use futures::{lock::Mutex as AsyncMutex, AsyncReadExt};
+use async_std::fs::OpenOptions as AsyncOpenOptions;
+use futures::{
+    lock::Mutex as AsyncMutex, stream, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, StreamExt,
+};
 use maybe_owned::MaybeOwned;
 use pyo3::{exceptions::PyIOError, prelude::*, types::PyBytes};
 use std::{
-    collections::HashMap, io::Read, mem::transmute, num::NonZeroU64, sync::Arc, time::Duration,
+    collections::{HashMap, VecDeque},
+    fs::{self, File, OpenOptions},
+    io::{Error as IoError, ErrorKind as IoErrorKind, Read, Result as IoResult, Seek, SeekFrom},
+    mem::{take, transmute},
+    num::NonZeroU64,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    thread,
+    time::Duration,
 };
 
 pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
@@ -24,9 +49,12 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<UrlsSigner>()?;
     m.add_class::<StaticDomainsUrlsGenerator>()?;
     m.add_class::<EndpointsUrlGenerator>()?;
+    m.add_class::<DownloadUrlsBuilder>()?;
     m.add_class::<DownloadManager>()?;
     m.add_class::<DownloadingObjectReader>()?;
     m.add_class::<AsyncDownloadingObjectReader>()?;
+    m.add_class::<DecryptingReader>()?;
+    m.add_class::<AsyncDecryptingReader>()?;
     m.add_class::<DownloadingProgressInfo>()?;
     Ok(m)
 }
@@ -368,32 +396,189 @@ impl EndpointsUrlGenerator {
     }
 }
 
+/// 多域名下载 URL 列表构建器
+///
+/// 按照传入的顺序为指定对象生成各个域名的下载 URL，例如先 CDN 域名，再源站域名。
+/// 如果传入 `credential`，则对每个 URL 签名，`ttl_secs` 为签名的有效期。
+///
+/// 可以通过 `build()` 直接获取 URL 列表，也可以作为下载 URL 列表生成器传给 `DownloadManager`，下载时将按顺序尝试各个 URL。
+/// 作为下载 URL 列表生成器使用时同样使用 `ttl_secs` 作为签名的有效期，且只能用于下载 `object_name` 指定的对象，下载其他对象将抛出 `QiniuApiCallError`。
+///
+/// 通过 `DownloadUrlsBuilder(domains, object_name, use_https=None, credential=None, ttl_secs=None)` 创建多域名下载 URL 列表构建器
+#[derive(Debug, Clone)]
+#[pyclass(extends = DownloadUrlsGenerator)]
+#[pyo3(
+    text_signature = "(domains, object_name, /, use_https=None, credential=None, ttl_secs=None)"
+)]
+struct DownloadUrlsBuilder {
+    object_name: String,
+    ttl_secs: Option<u64>,
+}
+
+#[pymethods]
+impl DownloadUrlsBuilder {
+    #[new]
+    #[args(use_https = "None", credential = "None", ttl_secs = "None")]
+    fn new(
+        domains: Vec<&PyAny>,
+        object_name: String,
+        use_https: Option<bool>,
+        credential: Option<CredentialProvider>,
+        ttl_secs: Option<u64>,
+    ) -> PyResult<(Self, DownloadUrlsGenerator)> {
+        let (_, generator) = StaticDomainsUrlsGenerator::new(domains, use_https)?;
+        let generator = if let Some(credential) = credential {
+            UrlsSigner::new(credential, generator).1
+        } else {
+            generator
+        };
+        let generator = DownloadUrlsGenerator(Box::new(ObjectUrlsGenerator {
+            object_name: object_name.to_owned(),
+            ttl: ttl_secs.map(Duration::from_secs),
+            generator,
+        }));
+        Ok((
+            Self {
+                object_name,
+                ttl_secs,
+            },
+            generator,
+        ))
+    }
+
+    /// 获取对象名称
+    #[getter]
+    fn get_object_name(&self) -> &str {
+        &self.object_name
+    }
+
+    /// 按顺序生成下载 URL 列表
+    #[pyo3(text_signature = "($self)")]
+    fn build(self_: PyRef<'_, Self>) -> PyResult<Vec<String>> {
+        self_.as_ref().generate(&self_.object_name, self_.ttl_secs)
+    }
+
+    /// 异步按顺序生成下载 URL 列表
+    #[pyo3(text_signature = "($self)")]
+    fn async_build<'p>(self_: PyRef<'p, Self>, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let generator = self_.as_ref().0.to_owned();
+        let object_name = self_.object_name.to_owned();
+        let ttl_secs = self_.ttl_secs;
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let mut builder = qiniu_sdk::download::GeneratorOptions::builder();
+            if let Some(ttl_secs) = ttl_secs {
+                builder.ttl(Duration::from_secs(ttl_secs));
+            }
+            generator
+                .async_generate(&object_name, builder.build())
+                .await
+                .map(|urls| urls.iter().map(|url| url.to_string()).collect::<Vec<_>>())
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })
+    }
+}
+
+/// 只为指定对象生成下载 URL 列表的生成器，没有指定签名有效期时使用构建时传入的有效期
+#[derive(Debug, Clone)]
+struct ObjectUrlsGenerator {
+    object_name: String,
+    ttl: Option<Duration>,
+    generator: DownloadUrlsGenerator,
+}
+
+impl ObjectUrlsGenerator {
+    fn options<'a>(
+        &self,
+        object_name: &str,
+        options: qiniu_sdk::download::GeneratorOptions<'a>,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::download::GeneratorOptions<'a>> {
+        if object_name != self.object_name {
+            return Err(qiniu_sdk::http_client::ResponseError::new_with_msg(
+                qiniu_sdk::http::ResponseErrorKind::InvalidRequestResponse.into(),
+                format!(
+                    "DownloadUrlsBuilder only generates urls for object {:?}, but {:?} is requested",
+                    self.object_name, object_name
+                ),
+            ));
+        }
+        let mut builder = qiniu_sdk::download::GeneratorOptions::builder();
+        if let Some(ttl) = options.ttl().or(self.ttl) {
+            builder.ttl(ttl);
+        }
+        Ok(builder.build())
+    }
+}
+
+impl qiniu_sdk::download::DownloadUrlsGenerator for ObjectUrlsGenerator {
+    fn generate(
+        &self,
+        object_name: &str,
+        options: qiniu_sdk::download::GeneratorOptions<'_>,
+    ) -> qiniu_sdk::http_client::ApiResult<Vec<qiniu_sdk::http::Uri>> {
+        let options = self.options(object_name, options)?;
+        self.generator.0.generate(object_name, options)
+    }
+
+    fn async_generate<'a>(
+        &'a self,
+        object_name: &'a str,
+        options: qiniu_sdk::download::GeneratorOptions<'a>,
+    ) -> futures::future::BoxFuture<'a, qiniu_sdk::http_client::ApiResult<Vec<qiniu_sdk::http::Uri>>>
+    {
+        match self.options(object_name, options) {
+            Ok(options) => self.generator.0.async_generate(object_name, options),
+            Err(err) => Box::pin(async move { Err(err) }),
+        }
+    }
+}
+
 /// 下载管理器
 ///
-/// 通过 `DownloadManager(urls_generator, use_https = None, http_client = None)` 创建下载管理器
+/// 通过 `DownloadManager(urls_generator, use_https = None, http_client = None, follow_redirects = False, max_redirects = None)` 创建下载管理器
+///
+/// 如果传入 `follow_redirects = True`，下载地址返回 3xx 响应时将自动跟随 `Location` 头下载，最多跟随 `max_redirects` 次（默认为 10 次），
+/// 重定向经过的地址可以通过 `response_ok` 回调中的 `HttpResponseParts.redirect_history` 获取
 #[pyclass]
 #[derive(Debug, Clone)]
-#[pyo3(text_signature = "(urls_generator, /, use_https = None, http_client = None)")]
-struct DownloadManager(qiniu_sdk::download::DownloadManager);
+#[pyo3(
+    text_signature = "(urls_generator, /, use_https = None, http_client = None, follow_redirects = False, max_redirects = None)"
+)]
+struct DownloadManager(qiniu_sdk::download::DownloadManager, Option<usize>);
 
 #[pymethods]
 impl DownloadManager {
     /// 创建下载管理器
     #[new]
-    #[args(use_https = "None", http_client = "None")]
+    #[args(
+        use_https = "None",
+        http_client = "None",
+        follow_redirects = "false",
+        max_redirects = "None"
+    )]
     fn new(
         urls_generator: DownloadUrlsGenerator,
         use_https: Option<bool>,
         http_client: Option<HttpClient>,
-    ) -> Self {
+        follow_redirects: bool,
+        max_redirects: Option<usize>,
+        py: Python<'_>,
+    ) -> PyResult<Self> {
         let mut builder = qiniu_sdk::download::DownloadManager::builder(urls_generator);
         if let Some(use_https) = use_https {
             builder.use_https(use_https);
         }
         if let Some(http_client) = http_client {
             builder.http_client(http_client.into());
+        } else if let Some(http_client) =
+            enabled_shared_http_client(py).filter(|_| use_https.is_none() || follow_redirects)
+        {
+            builder.http_client(http_client.into());
+        } else if follow_redirects {
+            builder.http_client(new_extended_http_client()?.into());
         }
-        Self(builder.build())
+        let max_redirects =
+            follow_redirects.then(|| max_redirects.unwrap_or(DEFAULT_MAX_REDIRECTS));
+        Ok(Self(builder.build(), max_redirects))
     }
 
     /// 获取下载内容阅读器
@@ -439,10 +624,15 @@ impl DownloadManager {
 
     /// 将下载的对象内容写入指定的文件系统路径
     ///
+    /// 下载的内容会先写入 `{to_path}.downloading` 临时文件，下载完成并校验通过后再重命名为目标文件。
+    /// 如果 `resume` 为 `True` 且临时文件已经存在，则会通过范围下载从临时文件末尾继续下载。
+    /// 续传时如果对象的 Etag 与临时文件记录的不一致，将删除临时文件并抛出异常。
+    /// 下载失败时，如果 `resume` 为 `True`，将保留临时文件及其 Etag 记录以便下次续传，否则将删除临时文件。
+    ///
     /// 需要注意，如果文件已经存在，则会覆盖该文件，如果文件不存在，则会创建该文件。
     #[allow(clippy::too_many_arguments)]
     #[pyo3(
-        text_signature = "($self, object_name, to_path, /, range_from=None, range_to=None, retrier=None, headers=None, before_request=None, download_progress=None, response_ok=None, response_error=None)"
+        text_signature = "($self, object_name, to_path, /, range_from=None, range_to=None, retrier=None, headers=None, before_request=None, download_progress=None, response_ok=None, response_error=None, resume=True)"
     )]
     #[args(
         range_from = "None",
@@ -452,7 +642,8 @@ impl DownloadManager {
         before_request = "None",
         download_progress = "None",
         response_ok = "None",
-        response_error = "None"
+        response_error = "None",
+        resume = "true"
     )]
     fn download_to_path(
         &self,
@@ -466,10 +657,20 @@ impl DownloadManager {
         download_progress: Option<PyObject>,
         response_ok: Option<PyObject>,
         response_error: Option<PyObject>,
+        resume: bool,
+        py: Python<'_>,
     ) -> PyResult<()> {
-        let object = self.make_download_object(
+        let to_path = PathBuf::from(to_path);
+        let downloading_path = get_downloading_path(&to_path);
+        let (offset, expected_etag) = if resume {
+            get_resume_offset(&downloading_path)
+        } else {
+            (0, None)
+        };
+        let response_info = Arc::new(Mutex::new(DownloadedResponseInfo::default()));
+        let object = self.make_download_object_with_context(
             object_name,
-            range_from,
+            add_range_offset(range_from, offset),
             range_to,
             retrier,
             headers,
@@ -477,10 +678,226 @@ impl DownloadManager {
             download_progress,
             response_ok,
             response_error,
+            DownloadingContext {
+                progress_offset: offset,
+                response_info: Some(response_info.to_owned()),
+                expected_etag: expected_etag.to_owned(),
+                ..Default::default()
+            },
         )?;
-        object
-            .to_path(to_path)
-            .map_err(QiniuDownloadError::from_err)
+        let verify_etag = range_from.is_none() && range_to.is_none();
+        py.allow_threads(|| {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(offset > 0)
+                .truncate(offset == 0)
+                .open(&downloading_path)
+                .map_err(QiniuIoError::from_err)?;
+            let result = object
+                .to_writer(&mut file)
+                .map_err(QiniuDownloadError::from_err);
+            drop(file);
+            let response_info = lock_response_info(&response_info).to_owned();
+            complete_downloading(
+                &downloading_path,
+                &to_path,
+                offset,
+                expected_etag.as_deref(),
+                result,
+                response_info,
+                verify_etag,
+                resume,
+            )
+        })
+    }
+
+    /// 并发分片下载对象到指定的文件系统路径
+    ///
+    /// 对象将被按照 `chunk_size` 划分为多个范围，并发发起范围下载请求，每个范围的数据写入文件的对应偏移位置。
+    /// 如果服务器不支持范围下载，则退化为单个请求下载。
+    ///
+    /// 如果有范围下载失败，临时文件将被删除，并抛出 `QiniuChunkedDownloadError` 异常，异常信息中包含所有失败的范围。
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(
+        text_signature = "($self, object_name, to_path, /, chunk_size=4194304, concurrency=None, retrier=None, headers=None, download_progress=None)"
+    )]
+    #[args(
+        chunk_size = "4194304",
+        concurrency = "None",
+        retrier = "None",
+        headers = "None",
+        download_progress = "None"
+    )]
+    fn download_chunked(
+        &self,
+        object_name: &str,
+        to_path: &str,
+        chunk_size: u64,
+        concurrency: Option<usize>,
+        retrier: Option<DownloadRetrier>,
+        headers: Option<HashMap<String, String>>,
+        download_progress: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        let downloader = ChunkedDownloader::new(
+            self,
+            object_name,
+            chunk_size,
+            concurrency,
+            retrier,
+            headers,
+            download_progress,
+        )?;
+        let to_path = PathBuf::from(to_path);
+        let downloading_path = get_downloading_path(&to_path);
+        let response_info = Arc::new(Mutex::new(DownloadedResponseInfo::default()));
+        let first_chunk = downloader.make_first_chunk(response_info.to_owned())?;
+        py.allow_threads(|| {
+            let first_range = first_chunk.range();
+            let result = File::create(&downloading_path)
+                .map_err(QiniuIoError::from_err)
+                .and_then(|mut file| first_chunk.download_to(&mut file))
+                .and_then(|()| {
+                    downloader.make_remaining_chunks(&lock_response_info(&response_info))
+                });
+            let failures = match result {
+                Ok(chunks) => {
+                    let chunks = Arc::new(Mutex::new(VecDeque::from(chunks)));
+                    let failures = Arc::new(Mutex::new(Vec::new()));
+                    let handles = (0..downloader.concurrency)
+                        .map(|_| {
+                            let chunks = chunks.to_owned();
+                            let failures = failures.to_owned();
+                            let downloading_path = downloading_path.to_owned();
+                            thread::spawn(move || loop {
+                                let chunk = if let Some(chunk) = chunks.lock().unwrap().pop_front()
+                                {
+                                    chunk
+                                } else {
+                                    break;
+                                };
+                                let range = chunk.range();
+                                if let Err(err) = OpenOptions::new()
+                                    .write(true)
+                                    .open(&downloading_path)
+                                    .and_then(|mut file| {
+                                        file.seek(SeekFrom::Start(range.0))?;
+                                        Ok(file)
+                                    })
+                                    .map_err(QiniuIoError::from_err)
+                                    .and_then(|mut file| chunk.download_to(&mut file))
+                                {
+                                    failures.lock().unwrap().push((range, err));
+                                }
+                            })
+                        })
+                        .collect::<Vec<_>>();
+                    let panicked = handles
+                        .into_iter()
+                        .map(join_thread)
+                        .fold(None, |panicked, result| panicked.or(result.err()));
+                    if let Some(err) = panicked {
+                        fs::remove_file(&downloading_path).ok();
+                        return Err(err);
+                    }
+                    let failures = take(&mut *failures.lock().unwrap());
+                    failures
+                }
+                Err(err) => vec![(first_range, err)],
+            };
+            if !failures.is_empty() {
+                fs::remove_file(&downloading_path).ok();
+                return Err(make_chunked_download_error(failures));
+            }
+            fs::rename(&downloading_path, &to_path).map_err(QiniuIoError::from_err)
+        })
+    }
+
+    /// 异步并发分片下载对象到指定的文件系统路径
+    ///
+    /// 对象将被按照 `chunk_size` 划分为多个范围，并发发起范围下载请求，每个范围的数据写入文件的对应偏移位置。
+    /// 如果服务器不支持范围下载，则退化为单个请求下载。
+    ///
+    /// 如果有范围下载失败，临时文件将被删除，并抛出 `QiniuChunkedDownloadError` 异常，异常信息中包含所有失败的范围。
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(
+        text_signature = "($self, object_name, to_path, /, chunk_size=4194304, concurrency=None, retrier=None, headers=None, download_progress=None)"
+    )]
+    #[args(
+        chunk_size = "4194304",
+        concurrency = "None",
+        retrier = "None",
+        headers = "None",
+        download_progress = "None"
+    )]
+    fn async_download_chunked<'p>(
+        &self,
+        object_name: &str,
+        to_path: String,
+        chunk_size: u64,
+        concurrency: Option<usize>,
+        retrier: Option<DownloadRetrier>,
+        headers: Option<HashMap<String, String>>,
+        download_progress: Option<PyObject>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let downloader = ChunkedDownloader::new(
+            self,
+            object_name,
+            chunk_size,
+            concurrency,
+            retrier,
+            headers,
+            download_progress,
+        )?;
+        let to_path = PathBuf::from(to_path);
+        let downloading_path = get_downloading_path(&to_path);
+        let response_info = Arc::new(Mutex::new(DownloadedResponseInfo::default()));
+        let first_chunk = downloader.make_first_chunk(response_info.to_owned())?;
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let first_range = first_chunk.range();
+            let result = match async_std::fs::File::create(&downloading_path).await {
+                Ok(mut file) => first_chunk.async_download_to(&mut file).await,
+                Err(err) => Err(QiniuIoError::from_err(err)),
+            }
+            .and_then(|()| downloader.make_remaining_chunks(&lock_response_info(&response_info)));
+            let failures = match result {
+                Ok(chunks) => {
+                    stream::iter(chunks)
+                        .map(|chunk| {
+                            let downloading_path = downloading_path.to_owned();
+                            async move {
+                                let range = chunk.range();
+                                let mut file = AsyncOpenOptions::new()
+                                    .write(true)
+                                    .open(&downloading_path)
+                                    .await
+                                    .map_err(|err| (range, QiniuIoError::from_err(err)))?;
+                                file.seek(SeekFrom::Start(range.0))
+                                    .await
+                                    .map_err(|err| (range, QiniuIoError::from_err(err)))?;
+                                chunk
+                                    .async_download_to(&mut file)
+                                    .await
+                                    .map_err(|err| (range, err))
+                            }
+                        })
+                        .buffer_unordered(downloader.concurrency)
+                        .filter_map(|result| async move { result.err() })
+                        .collect::<Vec<_>>()
+                        .await
+                }
+                Err(err) => vec![(first_range, err)],
+            };
+            if !failures.is_empty() {
+                async_std::fs::remove_file(&downloading_path).await.ok();
+                return Err(make_chunked_download_error(failures));
+            }
+            async_std::fs::rename(&downloading_path, &to_path)
+                .await
+                .map_err(QiniuIoError::from_err)
+        })
     }
 
     /// 将下载的对象内容写入指定的输出流
@@ -572,10 +989,15 @@ impl DownloadManager {
 
     /// 将下载的对象内容异步写入指定的文件系统路径
     ///
+    /// 下载的内容会先写入 `{to_path}.downloading` 临时文件，下载完成并校验通过后再重命名为目标文件。
+    /// 如果 `resume` 为 `True` 且临时文件已经存在，则会通过范围下载从临时文件末尾继续下载。
+    /// 续传时如果对象的 Etag 与临时文件记录的不一致，将删除临时文件并抛出异常。
+    /// 下载失败时，如果 `resume` 为 `True`，将保留临时文件及其 Etag 记录以便下次续传，否则将删除临时文件。
+    ///
     /// 需要注意，如果文件已经存在，则会覆盖该文件，如果文件不存在，则会创建该文件。
     #[allow(clippy::too_many_arguments)]
     #[pyo3(
-        text_signature = "($self, object_name, to_path, /, range_from=None, range_to=None, retrier=None, headers=None, before_request=None, download_progress=None, response_ok=None, response_error=None)"
+        text_signature = "($self, object_name, to_path, /, range_from=None, range_to=None, retrier=None, headers=None, before_request=None, download_progress=None, response_ok=None, response_error=None, resume=True)"
     )]
     #[args(
         range_from = "None",
@@ -585,7 +1007,8 @@ impl DownloadManager {
         before_request = "None",
         download_progress = "None",
         response_ok = "None",
-        response_error = "None"
+        response_error = "None",
+        resume = "true"
     )]
     fn async_download_to_path<'p>(
         &'p self,
@@ -599,11 +1022,20 @@ impl DownloadManager {
         download_progress: Option<PyObject>,
         response_ok: Option<PyObject>,
         response_error: Option<PyObject>,
+        resume: bool,
         py: Python<'p>,
     ) -> PyResult<&'p PyAny> {
-        let object = self.make_download_object(
+        let to_path = PathBuf::from(to_path);
+        let downloading_path = get_downloading_path(&to_path);
+        let (offset, expected_etag) = if resume {
+            get_resume_offset(&downloading_path)
+        } else {
+            (0, None)
+        };
+        let response_info = Arc::new(Mutex::new(DownloadedResponseInfo::default()));
+        let object = self.make_download_object_with_context(
             object_name,
-            range_from,
+            add_range_offset(range_from, offset),
             range_to,
             retrier,
             headers,
@@ -611,12 +1043,44 @@ impl DownloadManager {
             download_progress,
             response_ok,
             response_error,
+            DownloadingContext {
+                progress_offset: offset,
+                response_info: Some(response_info.to_owned()),
+                expected_etag: expected_etag.to_owned(),
+                ..Default::default()
+            },
         )?;
+        let verify_etag = range_from.is_none() && range_to.is_none();
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            object
-                .async_to_path(to_path)
+            let mut file = AsyncOpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(offset > 0)
+                .truncate(offset == 0)
+                .open(&downloading_path)
                 .await
-                .map_err(QiniuDownloadError::from_err)
+                .map_err(QiniuIoError::from_err)?;
+            let result = object
+                .to_async_writer(&mut file)
+                .await
+                .map_err(QiniuDownloadError::from_err);
+            let flushed = file.flush().await.map_err(QiniuIoError::from_err);
+            drop(file);
+            let result = result.and(flushed);
+            let response_info = lock_response_info(&response_info).to_owned();
+            async_std::task::spawn_blocking(move || {
+                complete_downloading(
+                    &downloading_path,
+                    &to_path,
+                    offset,
+                    expected_etag.as_deref(),
+                    result,
+                    response_info,
+                    verify_etag,
+                    resume,
+                )
+            })
+            .await
         })
     }
 
@@ -704,9 +1168,18 @@ impl DownloadingObjectReader {
     }
 
     /// 读取所有下载的数据
-    #[pyo3(text_signature = "($self)")]
-    fn readall<'a>(&mut self, py: Python<'a>) -> PyResult<&'a PyBytes> {
-        self.read(-1, py)
+    ///
+    /// 如果传入 `progress` 回调函数，每读取一块数据后将以已读取的字节数调用该函数
+    #[pyo3(text_signature = "($self, /, progress = None)")]
+    #[args(progress = "None")]
+    fn readall<'a>(&mut self, progress: Option<PyObject>, py: Python<'a>) -> PyResult<&'a PyBytes> {
+        if let Some(progress) = progress {
+            let mut buf = Vec::new();
+            py.allow_threads(|| read_to_end_with_progress(&mut self.0, &mut buf, &progress))?;
+            Ok(PyBytes::new(py, &buf))
+        } else {
+            self.read(-1, py)
+        }
     }
 
     fn __repr__(&self) -> String {
@@ -749,6 +1222,151 @@ impl AsyncDownloadingObjectReader {
     }
 
     /// 异步所有读取下载的数据
+    ///
+    /// 如果传入 `progress` 回调函数，每读取一块数据后将以已读取的字节数调用该函数
+    #[pyo3(text_signature = "($self, /, progress = None)")]
+    #[args(progress = "None")]
+    fn readall<'a>(&mut self, progress: Option<PyObject>, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let progress = match progress {
+            Some(progress) => progress,
+            None => return self.read(-1, py),
+        };
+        let reader = self.0.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let mut reader = reader.lock().await;
+            let mut buf = Vec::new();
+            async_read_to_end_with_progress(&mut *reader, &mut buf, &progress).await?;
+            Python::with_gil(|py| Ok(PyBytes::new(py, &buf).to_object(py)))
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// AES-CTR 解密阅读器
+///
+/// 包装一个阅读器，例如 `download_manager.reader()` 返回的下载阅读器，将读取到的数据使用 AES-CTR 解密。
+///
+/// `key` 和 `nonce` 必须与上传时 `upload.EncryptingDataSource` 所用的一致，如果只下载了对象的一部分，需要通过 `offset` 指定其在对象中的起始偏移量。
+///
+/// 通过 `DecryptingReader(reader, key, nonce, offset = 0)` 创建 AES-CTR 解密阅读器
+#[pyclass]
+#[derive(Debug)]
+#[pyo3(text_signature = "(reader, key, nonce, /, offset = 0)")]
+struct DecryptingReader {
+    reader: PythonIoBase,
+    cipher: AesCtrCipher,
+    offset: u64,
+}
+
+#[pymethods]
+impl DecryptingReader {
+    /// 创建 AES-CTR 解密阅读器
+    #[new]
+    #[args(offset = "0")]
+    fn new(reader: PyObject, key: &[u8], nonce: &[u8], offset: u64) -> PyResult<Self> {
+        Ok(Self {
+            reader: PythonIoBase::new(reader),
+            cipher: AesCtrCipher::new(key, nonce)?,
+            offset,
+        })
+    }
+
+    /// 读取解密后的数据
+    #[pyo3(text_signature = "($self, size = -1, /)")]
+    #[args(size = "-1")]
+    fn read<'a>(&mut self, size: i64, py: Python<'a>) -> PyResult<&'a PyBytes> {
+        let mut buf = Vec::new();
+        if let Ok(size) = u64::try_from(size) {
+            buf.reserve(size as usize);
+            (&mut self.reader).take(size).read_to_end(&mut buf)
+        } else {
+            self.reader.read_to_end(&mut buf)
+        }
+        .map_err(PyIOError::new_err)?;
+        self.cipher.apply_keystream(self.offset, &mut buf);
+        self.offset += buf.len() as u64;
+        Ok(PyBytes::new(py, &buf))
+    }
+
+    /// 读取所有解密后的数据
+    #[pyo3(text_signature = "($self)")]
+    fn readall<'a>(&mut self, py: Python<'a>) -> PyResult<&'a PyBytes> {
+        self.read(-1, py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// AES-CTR 异步解密阅读器
+///
+/// 包装一个异步阅读器，例如 `download_manager.async_reader()` 返回的异步下载阅读器，将读取到的数据使用 AES-CTR 解密。
+///
+/// `key`、`nonce` 和 `offset` 的要求与 `DecryptingReader` 一致。
+///
+/// 通过 `AsyncDecryptingReader(reader, key, nonce, offset = 0)` 创建 AES-CTR 异步解密阅读器
+#[pyclass]
+#[derive(Debug)]
+#[pyo3(text_signature = "(reader, key, nonce, /, offset = 0)")]
+struct AsyncDecryptingReader(Arc<AsyncMutex<AsyncDecryptingReaderInner>>);
+
+#[derive(Debug)]
+struct AsyncDecryptingReaderInner {
+    reader: PythonIoBaseAsyncRead,
+    cipher: AesCtrCipher,
+    offset: u64,
+}
+
+#[pymethods]
+impl AsyncDecryptingReader {
+    /// 创建 AES-CTR 异步解密阅读器
+    #[new]
+    #[args(offset = "0")]
+    fn new(reader: PyObject, key: &[u8], nonce: &[u8], offset: u64) -> PyResult<Self> {
+        Ok(Self(Arc::new(AsyncMutex::new(
+            AsyncDecryptingReaderInner {
+                reader: PythonIoBase::new(reader).into_async_read(),
+                cipher: AesCtrCipher::new(key, nonce)?,
+                offset,
+            },
+        ))))
+    }
+
+    /// 异步读取解密后的数据
+    #[pyo3(text_signature = "($self, size = -1, /)")]
+    #[args(size = "-1")]
+    fn read<'a>(&mut self, size: i64, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let inner = self.0.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let mut inner = inner.lock().await;
+            let inner = &mut *inner;
+            let mut buf = Vec::new();
+            if let Ok(size) = u64::try_from(size) {
+                buf.reserve(size as usize);
+                (&mut inner.reader).take(size).read_to_end(&mut buf).await
+            } else {
+                inner.reader.read_to_end(&mut buf).await
+            }
+            .map_err(PyIOError::new_err)?;
+            inner.cipher.apply_keystream(inner.offset, &mut buf);
+            inner.offset += buf.len() as u64;
+            Python::with_gil(|py| Ok(PyBytes::new(py, &buf).to_object(py)))
+        })
+    }
+
+    /// 异步读取所有解密后的数据
     #[pyo3(text_signature = "($self)")]
     fn readall<'a>(&mut self, py: Python<'a>) -> PyResult<&'a PyAny> {
         self.read(-1, py)
@@ -776,6 +1394,34 @@ impl DownloadManager {
         download_progress: Option<PyObject>,
         response_ok: Option<PyObject>,
         response_error: Option<PyObject>,
+    ) -> PyResult<qiniu_sdk::download::DownloadingObject> {
+        self.make_download_object_with_context(
+            object_name,
+            range_from,
+            range_to,
+            retrier,
+            headers,
+            before_request,
+            download_progress,
+            response_ok,
+            response_error,
+            Default::default(),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn make_download_object_with_context(
+        &self,
+        object_name: &str,
+        range_from: Option<u64>,
+        range_to: Option<u64>,
+        retrier: Option<DownloadRetrier>,
+        headers: Option<HashMap<String, String>>,
+        before_request: Option<PyObject>,
+        download_progress: Option<PyObject>,
+        response_ok: Option<PyObject>,
+        response_error: Option<PyObject>,
+        context: DownloadingContext,
     ) -> PyResult<qiniu_sdk::download::DownloadingObject> {
         let mut object = self
             .0
@@ -800,11 +1446,32 @@ impl DownloadManager {
         if let Some(before_request) = before_request {
             object = object.on_before_request(on_before_request(before_request));
         }
+        if let Some(max_redirects) = self.1 {
+            object = object.on_before_request(
+                move |parts: &mut qiniu_sdk::http_client::RequestBuilderParts<'_>| {
+                    parts.add_extension(FollowRedirectsExtension::new(max_redirects, None));
+                    Ok(())
+                },
+            );
+        }
         if let Some(download_progress) = download_progress {
-            object = object.on_download_progress(on_download_progress(download_progress));
+            object = object.on_download_progress(on_download_progress(
+                download_progress,
+                context.progress_offset,
+            ));
+        }
+        if let Some(response_info) = &context.response_info {
+            object = object.on_before_request(record_response_headers(response_info.to_owned()));
         }
-        if let Some(response_ok) = response_ok {
-            object = object.on_response_ok(on_response(response_ok));
+        let partial_content_required =
+            context.partial_content_required || range_from.is_some() || range_to.is_some();
+        if partial_content_required || response_ok.is_some() || context.response_info.is_some() {
+            object = object.on_response_ok(on_response(
+                response_ok,
+                partial_content_required,
+                context.response_info,
+                context.expected_etag,
+            ));
         }
         if let Some(response_error) = response_error {
             object = object.on_response_error(on_error(response_error));
@@ -878,6 +1545,7 @@ fn on_before_request(
 
 fn on_download_progress(
     callback: PyObject,
+    offset: u64,
 ) -> impl Fn(qiniu_sdk::download::DownloadingProgressInfo) -> AnyResult<()> + Send + Sync + 'static
 {
     move |progress| {
@@ -885,8 +1553,8 @@ fn on_download_progress(
             callback.call1(
                 py,
                 (DownloadingProgressInfo::new(
-                    progress.transferred_bytes(),
-                    progress.total_bytes(),
+                    progress.transferred_bytes() + offset,
+                    progress.total_bytes().map(|total| total + offset),
                 ),),
             )
         })?;
@@ -894,12 +1562,38 @@ fn on_download_progress(
     }
 }
 
+// 指定了下载范围时，服务器必须返回 206 Partial Content，否则视为服务器不支持范围下载
 fn on_response(
-    callback: PyObject,
+    callback: Option<PyObject>,
+    partial_content_required: bool,
+    response_info: Option<Arc<Mutex<DownloadedResponseInfo>>>,
+    expected_etag: Option<String>,
 ) -> impl Fn(&mut qiniu_sdk::http::ResponseParts) -> AnyResult<()> + Send + Sync + 'static {
     move |parts| {
-        let parts = HttpResponsePartsMut::from(parts);
-        Python::with_gil(|py| callback.call1(py, (parts,)))?;
+        if partial_content_required && parts.status_code().as_u16() != 206 {
+            return Err(IoError::new(
+                IoErrorKind::InvalidData,
+                format!(
+                    "Range request is ignored by server, expected status code 206, but got {}",
+                    parts.status_code().as_u16()
+                ),
+            )
+            .into());
+        }
+        let downloaded_response_info = DownloadedResponseInfo::from_parts(parts);
+        if let Some(err) = expected_etag
+            .as_deref()
+            .and_then(|expected| downloaded_response_info.check_etag(expected).err())
+        {
+            return Err(err.into());
+        }
+        if let Some(response_info) = &response_info {
+            *lock_response_info(response_info) = downloaded_response_info;
+        }
+        if let Some(callback) = &callback {
+            let parts = HttpResponsePartsMut::from(parts);
+            Python::with_gil(|py| callback.call1(py, (parts,)))?;
+        }
         Ok(())
     }
 }
@@ -916,3 +1610,397 @@ fn on_error(
         Ok(())
     }
 }
+
+// 服务器返回错误时 SDK 不会调用响应回调，因此需要在收到响应头时就记录响应信息
+fn record_response_headers(
+    response_info: Arc<Mutex<DownloadedResponseInfo>>,
+) -> impl Fn(&mut qiniu_sdk::http_client::RequestBuilderParts<'_>) -> AnyResult<()> + Send + Sync + 'static
+{
+    move |parts| {
+        let status_response_info = response_info.to_owned();
+        parts.on_receive_response_status(move |_, status_code| {
+            *lock_response_info(&status_response_info) = DownloadedResponseInfo {
+                status_code: status_code.as_u16(),
+                ..Default::default()
+            };
+            Ok(())
+        });
+        let header_response_info = response_info.to_owned();
+        parts.on_receive_response_header(move |_, name, value| {
+            lock_response_info(&header_response_info).set_header(name, value);
+            Ok(())
+        });
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct DownloadingContext {
+    progress_offset: u64,
+    partial_content_required: bool,
+    response_info: Option<Arc<Mutex<DownloadedResponseInfo>>>,
+    expected_etag: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct DownloadedResponseInfo {
+    status_code: u16,
+    content_length: Option<u64>,
+    total_size: Option<u64>,
+    etag: Option<String>,
+}
+
+impl DownloadedResponseInfo {
+    fn from_parts(parts: &qiniu_sdk::http::ResponseParts) -> Self {
+        let mut info = Self {
+            status_code: parts.status_code().as_u16(),
+            ..Default::default()
+        };
+        for (name, value) in parts.headers() {
+            info.set_header(name, value);
+        }
+        info
+    }
+
+    fn set_header(
+        &mut self,
+        name: &qiniu_sdk::http::HeaderName,
+        value: &qiniu_sdk::http::HeaderValue,
+    ) {
+        let value = match value.to_str() {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+        if name == qiniu_sdk::http::header::CONTENT_LENGTH {
+            self.content_length = value.parse().ok();
+        } else if name == qiniu_sdk::http::header::CONTENT_RANGE {
+            self.total_size = value
+                .rsplit_once('/')
+                .and_then(|(_, total_size)| total_size.parse().ok());
+        } else if name == qiniu_sdk::http::header::ETAG {
+            self.etag = Some(value.trim_matches('"').to_owned());
+        }
+    }
+
+    // 续传时临时文件已经完整，服务器会返回 416，此时对象大小与临时文件大小一致
+    fn is_already_downloaded(&self, offset: u64) -> bool {
+        offset > 0 && self.status_code == 416 && self.total_size == Some(offset)
+    }
+
+    fn check_etag(&self, expected: &str) -> IoResult<()> {
+        match self.etag.as_deref() {
+            Some(etag) if etag != expected => Err(IoError::new(
+                IoErrorKind::InvalidData,
+                format!(
+                    "Object has been changed since the downloading file was created, expected etag {expected}, but got {etag}"
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn lock_response_info(
+    response_info: &Mutex<DownloadedResponseInfo>,
+) -> MutexGuard<'_, DownloadedResponseInfo> {
+    response_info
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn get_downloading_path(to_path: &Path) -> PathBuf {
+    let mut downloading_path = to_path.as_os_str().to_owned();
+    downloading_path.push(".downloading");
+    downloading_path.into()
+}
+
+fn get_downloading_etag_path(downloading_path: &Path) -> PathBuf {
+    let mut etag_path = downloading_path.as_os_str().to_owned();
+    etag_path.push(".etag");
+    etag_path.into()
+}
+
+// 只有记录了 Etag 的临时文件才能续传，否则无法判断对象是否已经发生变化
+fn get_resume_offset(downloading_path: &Path) -> (u64, Option<String>) {
+    let etag = fs::read_to_string(get_downloading_etag_path(downloading_path)).ok();
+    match (fs::metadata(downloading_path), etag) {
+        (Ok(metadata), Some(etag)) => (metadata.len(), Some(etag)),
+        _ => (0, None),
+    }
+}
+
+fn remove_file_if_exists(path: &Path) -> IoResult<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != IoErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+// 下载失败时仅在需要续传且可以续传时保留临时文件，其他错误都将删除临时文件
+#[allow(clippy::too_many_arguments)]
+fn complete_downloading(
+    downloading_path: &Path,
+    to_path: &Path,
+    offset: u64,
+    expected_etag: Option<&str>,
+    result: PyResult<()>,
+    mut response_info: DownloadedResponseInfo,
+    verify_etag: bool,
+    resume: bool,
+) -> PyResult<()> {
+    let etag_path = get_downloading_etag_path(downloading_path);
+    let remove_downloading_files = || {
+        remove_file_if_exists(downloading_path)
+            .and_then(|_| remove_file_if_exists(&etag_path))
+            .map_err(QiniuIoError::from_err)
+    };
+    if let Some(Err(err)) = expected_etag.map(|expected| response_info.check_etag(expected)) {
+        remove_downloading_files()?;
+        return Err(QiniuIoError::from_err(err));
+    }
+    if let Err(err) = result {
+        if !response_info.is_already_downloaded(offset) {
+            let resumable = resume
+                && fs::metadata(downloading_path).map_or(false, |metadata| metadata.len() > 0);
+            match &response_info.etag {
+                Some(etag) if resumable && fs::write(&etag_path, etag).is_ok() => {}
+                _ => remove_downloading_files()?,
+            }
+            return Err(err);
+        }
+        response_info.content_length = Some(0);
+    }
+    if let Err(err) = verify_downloaded_file(downloading_path, offset, &response_info, verify_etag)
+        .and_then(|_| fs::rename(downloading_path, to_path))
+    {
+        remove_downloading_files()?;
+        return Err(QiniuIoError::from_err(err));
+    }
+    remove_file_if_exists(&etag_path).map_err(QiniuIoError::from_err)
+}
+
+fn add_range_offset(range_from: Option<u64>, offset: u64) -> Option<u64> {
+    match (range_from, offset) {
+        (range_from, 0) => range_from,
+        (range_from, offset) => Some(range_from.unwrap_or(0) + offset),
+    }
+}
+
+fn verify_downloaded_file(
+    path: &Path,
+    offset: u64,
+    response_info: &DownloadedResponseInfo,
+    verify_etag: bool,
+) -> IoResult<()> {
+    if let Some(content_length) = response_info.content_length {
+        let file_size = fs::metadata(path)?.len();
+        if file_size != offset + content_length {
+            return Err(IoError::new(
+                IoErrorKind::InvalidData,
+                format!(
+                    "Downloaded file size mismatch, expected {}, but got {}",
+                    offset + content_length,
+                    file_size
+                ),
+            ));
+        }
+    }
+    // 只有 Etag V1 才能通过文件内容计算得出，其他类型的 Etag 无法校验
+    if let Some(etag) = response_info.etag.as_ref().filter(|etag| {
+        verify_etag
+            && etag.len() == qiniu_sdk::etag::ETAG_SIZE
+            && (etag.starts_with('F') || etag.starts_with('l'))
+    }) {
+        let actual = qiniu_sdk::etag::etag_of(File::open(path)?)?;
+        if &actual != etag {
+            return Err(IoError::new(
+                IoErrorKind::InvalidData,
+                format!("Downloaded file etag mismatch, expected {etag}, but got {actual}"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+struct ChunkedDownloader {
+    manager: DownloadManager,
+    object_name: String,
+    chunk_size: u64,
+    concurrency: usize,
+    retrier: Option<DownloadRetrier>,
+    headers: HashMap<String, String>,
+    progress: Arc<ChunkedDownloadingProgress>,
+}
+
+impl ChunkedDownloader {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        manager: &DownloadManager,
+        object_name: &str,
+        chunk_size: u64,
+        concurrency: Option<usize>,
+        retrier: Option<DownloadRetrier>,
+        headers: Option<HashMap<String, String>>,
+        download_progress: Option<PyObject>,
+    ) -> PyResult<Self> {
+        if chunk_size == 0 {
+            return Err(QiniuInvalidPartSize::new_err("Chunk size must not be zero"));
+        }
+        let concurrency = match concurrency {
+            Some(0) => return Err(QiniuInvalidConcurrency::new_err("Invalid concurrency")),
+            Some(concurrency) => concurrency,
+            None => thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+        };
+        Ok(Self {
+            manager: manager.to_owned(),
+            object_name: object_name.to_owned(),
+            chunk_size,
+            concurrency,
+            retrier,
+            headers: headers.unwrap_or_default(),
+            progress: Arc::new(ChunkedDownloadingProgress {
+                callback: download_progress,
+                transferred_bytes: AtomicU64::new(0),
+                total_bytes: AtomicU64::new(0),
+            }),
+        })
+    }
+
+    // 第一个范围不要求服务器返回 206，以便在服务器不支持范围下载时直接下载完整内容
+    fn make_first_chunk(
+        &self,
+        response_info: Arc<Mutex<DownloadedResponseInfo>>,
+    ) -> PyResult<DownloadingChunk> {
+        self.make_chunk(0, self.chunk_size - 1, false, Some(response_info))
+    }
+
+    fn make_remaining_chunks(
+        &self,
+        first_response_info: &DownloadedResponseInfo,
+    ) -> PyResult<Vec<DownloadingChunk>> {
+        if first_response_info.status_code != 206 {
+            return Ok(vec![]);
+        }
+        let total_size = first_response_info.total_size.ok_or_else(|| {
+            QiniuIoError::from_err(IoError::new(
+                IoErrorKind::InvalidData,
+                "Partial response does not contain the total size in Content-Range",
+            ))
+        })?;
+        self.progress
+            .total_bytes
+            .store(total_size, Ordering::SeqCst);
+        (1..)
+            .map(|index| index * self.chunk_size)
+            .take_while(|&from| from < total_size)
+            .map(|from| {
+                let to = (from + self.chunk_size).min(total_size) - 1;
+                self.make_chunk(from, to, true, None)
+            })
+            .collect()
+    }
+
+    fn make_chunk(
+        &self,
+        from: u64,
+        to: u64,
+        partial_content_required: bool,
+        response_info: Option<Arc<Mutex<DownloadedResponseInfo>>>,
+    ) -> PyResult<DownloadingChunk> {
+        let mut headers = self.headers.to_owned();
+        headers.insert("Range".to_owned(), format!("bytes={}-{}", from, to));
+        let object = self
+            .manager
+            .make_download_object_with_context(
+                &self.object_name,
+                None,
+                None,
+                self.retrier.to_owned(),
+                Some(headers),
+                None,
+                None,
+                None,
+                None,
+                DownloadingContext {
+                    partial_content_required,
+                    response_info,
+                    ..Default::default()
+                },
+            )?
+            .on_download_progress(on_chunk_download_progress(self.progress.to_owned()));
+        Ok(DownloadingChunk { from, to, object })
+    }
+}
+
+struct DownloadingChunk {
+    from: u64,
+    to: u64,
+    object: qiniu_sdk::download::DownloadingObject,
+}
+
+impl DownloadingChunk {
+    fn range(&self) -> (u64, u64) {
+        (self.from, self.to)
+    }
+
+    fn download_to(self, file: &mut File) -> PyResult<()> {
+        self.object
+            .to_writer(file)
+            .map_err(QiniuDownloadError::from_err)
+    }
+
+    async fn async_download_to(self, file: &mut async_std::fs::File) -> PyResult<()> {
+        self.object
+            .to_async_writer(file)
+            .await
+            .map_err(QiniuDownloadError::from_err)?;
+        file.flush().await.map_err(QiniuIoError::from_err)
+    }
+}
+
+struct ChunkedDownloadingProgress {
+    callback: Option<PyObject>,
+    transferred_bytes: AtomicU64,
+    total_bytes: AtomicU64,
+}
+
+fn on_chunk_download_progress(
+    progress: Arc<ChunkedDownloadingProgress>,
+) -> impl Fn(qiniu_sdk::download::DownloadingProgressInfo) -> AnyResult<()> + Send + Sync + 'static
+{
+    let last_transferred_bytes = AtomicU64::new(0);
+    move |info| {
+        let current = info.transferred_bytes();
+        let delta = current.saturating_sub(last_transferred_bytes.swap(current, Ordering::SeqCst));
+        let transferred_bytes = progress
+            .transferred_bytes
+            .fetch_add(delta, Ordering::SeqCst)
+            + delta;
+        if let Some(callback) = &progress.callback {
+            let total_bytes = NonZeroU64::new(progress.total_bytes.load(Ordering::SeqCst))
+                .map(|total_bytes| total_bytes.get());
+            Python::with_gil(|py| {
+                callback.call1(
+                    py,
+                    (DownloadingProgressInfo::new(transferred_bytes, total_bytes),),
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
+
+fn make_chunked_download_error(mut failures: Vec<((u64, u64), PyErr)>) -> PyErr {
+    failures.sort_by_key(|((from, _), _)| *from);
+    let ranges = failures
+        .iter()
+        .map(|((from, to), _)| format!("{}-{}", from, to))
+        .collect::<Vec<_>>()
+        .join(", ");
+    QiniuChunkedDownloadError::new_err(format!(
+        "Failed to download ranges [{}]: {}",
+        ranges, failures[0].1
+    ))
+}
@@ -1,6 +1,23 @@
-use super::utils::PythonIoBase;
+use super::{
+    exceptions::{QiniuInvalidPartSize, QiniuIoError},
+    utils::{get_concurrency, join_thread, ProgressThrottle, PythonIoBase},
+};
+use futures::{AsyncRead, StreamExt, TryStreamExt};
 use pyo3::prelude::*;
 use qiniu_sdk::etag::{FixedOutput, GenericArray, Reset, Update, ETAG_SIZE};
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::File,
+    io::{Cursor, Error as IoError, ErrorKind as IoErrorKind, Read, Result as IoResult},
+    path::PathBuf,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    thread,
+};
 
 pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "etag")?;
@@ -8,6 +25,12 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<EtagV1>()?;
     m.add_function(wrap_pyfunction!(etag_of, m)?)?;
     m.add_function(wrap_pyfunction!(async_etag_of, m)?)?;
+    m.add_function(wrap_pyfunction!(etag_of_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(etag_v2_of_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(etag_of_paths, m)?)?;
+    m.add_function(wrap_pyfunction!(async_etag_of_paths, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_etag, m)?)?;
+    m.add_function(wrap_pyfunction!(async_verify_etag, m)?)?;
     Ok(m)
 }
 
@@ -70,20 +93,388 @@ impl EtagV1 {
 }
 
 /// 读取 reader 中的数据并计算它的 Etag V1，生成结果
+///
+/// 如果传入 `progress` 回调，每读取一段数据都会以已经计算的字节数调用该回调，
+/// 如果同时传入 `progress_interval_ms`，则在该间隔内最多只会回调一次，但计算完成时总是会被回调
+#[pyfunction(progress = "None", progress_interval_ms = "None")]
+#[pyo3(text_signature = "(reader, /, progress = None, progress_interval_ms = None)")]
+fn etag_of(
+    reader: PyObject,
+    progress: Option<PyObject>,
+    progress_interval_ms: Option<u64>,
+) -> PyResult<String> {
+    if let Some(progress) = progress {
+        let progress = EtagProgress::new(progress, progress_interval_ms, None);
+        qiniu_sdk::etag::etag_of(ProgressReader::new(
+            PythonIoBase::new(reader),
+            progress.to_owned(),
+        ))
+        .map_err(|err| progress.convert_error(err))
+    } else {
+        let etag = qiniu_sdk::etag::etag_of(PythonIoBase::new(reader))?;
+        Ok(etag)
+    }
+}
+
+/// 异步读取 reader 中的数据并计算它的 Etag V1，生成结果
+///
+/// 如果传入 `progress` 回调，每读取一段数据都会以已经计算的字节数调用该回调，
+/// 如果同时传入 `progress_interval_ms`，则在该间隔内最多只会回调一次，但计算完成时总是会被回调
+#[pyfunction(progress = "None", progress_interval_ms = "None")]
+#[pyo3(text_signature = "(reader, /, progress = None, progress_interval_ms = None)")]
+fn async_etag_of(
+    reader: PyObject,
+    progress: Option<PyObject>,
+    progress_interval_ms: Option<u64>,
+    py: Python<'_>,
+) -> PyResult<&PyAny> {
+    pyo3_asyncio::async_std::future_into_py(py, async move {
+        let reader = PythonIoBase::new(reader).into_async_read();
+        if let Some(progress) = progress {
+            let progress = EtagProgress::new(progress, progress_interval_ms, None);
+            qiniu_sdk::etag::async_etag_of(ProgressReader::new(reader, progress.to_owned()))
+                .await
+                .map_err(|err| progress.convert_error(err))
+        } else {
+            let etag = qiniu_sdk::etag::async_etag_of(reader).await?;
+            Ok(etag)
+        }
+    })
+}
+
+/// 超过该大小的数据在计算 Etag 时将释放 GIL
+const RELEASE_GIL_THRESHOLD: usize = 1 << 20;
+
+/// 计算内存数据的 Etag V1
 #[pyfunction]
-#[pyo3(text_signature = "(reader)")]
-fn etag_of(reader: PyObject) -> PyResult<String> {
-    let etag = qiniu_sdk::etag::etag_of(PythonIoBase::new(reader))?;
+#[pyo3(text_signature = "(data)")]
+fn etag_of_bytes(data: &[u8], py: Python<'_>) -> PyResult<String> {
+    let etag = if data.len() > RELEASE_GIL_THRESHOLD {
+        py.allow_threads(|| qiniu_sdk::etag::etag_of(Cursor::new(data)))
+    } else {
+        qiniu_sdk::etag::etag_of(Cursor::new(data))
+    }
+    .map_err(QiniuIoError::from_err)?;
     Ok(etag)
 }
 
-/// 异步读取 reader 中的数据并计算它的 Etag V1，生成结果
+/// 按照指定的分片大小计算内存数据的 Etag V2
 #[pyfunction]
-#[pyo3(text_signature = "(reader)")]
-fn async_etag_of(reader: PyObject, py: Python<'_>) -> PyResult<&PyAny> {
+#[pyo3(text_signature = "(data, part_size)")]
+fn etag_v2_of_bytes(data: &[u8], part_size: usize, py: Python<'_>) -> PyResult<String> {
+    if part_size == 0 {
+        return Err(QiniuInvalidPartSize::new_err("Part size must not be zero"));
+    }
+    let parts = data
+        .chunks(part_size)
+        .map(|chunk| chunk.len())
+        .collect::<Vec<_>>();
+    let etag = if data.len() > RELEASE_GIL_THRESHOLD {
+        py.allow_threads(|| qiniu_sdk::etag::etag_with_parts(Cursor::new(data), &parts))
+    } else {
+        qiniu_sdk::etag::etag_with_parts(Cursor::new(data), &parts)
+    }
+    .map_err(QiniuIoError::from_err)?;
+    Ok(etag)
+}
+
+/// 并行读取多个文件并计算它们的 Etag V1，返回文件路径到 Etag 的字典
+///
+/// 如果不传入 `concurrency`，则使用 CPU 核心数作为并发数
+///
+/// 如果传入 `progress` 回调，每读取一段数据都会以所有文件累计已经计算的字节数调用该回调，
+/// 计算过程中不持有 GIL，仅在回调时重新获取 GIL，
+/// 如果同时传入 `progress_interval_ms`，则在该间隔内最多只会回调一次，但计算完成时总是会被回调
+#[pyfunction(concurrency = "None", progress = "None", progress_interval_ms = "None")]
+#[pyo3(
+    text_signature = "(paths, /, concurrency = None, progress = None, progress_interval_ms = None)"
+)]
+fn etag_of_paths(
+    paths: Vec<String>,
+    concurrency: Option<usize>,
+    progress: Option<PyObject>,
+    progress_interval_ms: Option<u64>,
+    py: Python<'_>,
+) -> PyResult<HashMap<String, String>> {
+    let concurrency = get_concurrency(concurrency)?;
+    py.allow_threads(|| {
+        let progress = progress
+            .map(|progress| {
+                let total_bytes = total_size_of_paths(&paths)?;
+                Ok::<_, PyErr>(EtagProgress::new(
+                    progress,
+                    progress_interval_ms,
+                    Some(total_bytes),
+                ))
+            })
+            .transpose()?;
+        let paths = Arc::new(Mutex::new(VecDeque::from(paths)));
+        let handles = (0..concurrency)
+            .map(|_| {
+                let paths = paths.to_owned();
+                let progress = progress.to_owned();
+                thread::spawn(move || {
+                    let mut etags = HashMap::new();
+                    loop {
+                        let path = if let Some(path) = paths.lock().unwrap().pop_front() {
+                            path
+                        } else {
+                            break;
+                        };
+                        let etag = File::open(&path)
+                            .and_then(|file| match &progress {
+                                Some(progress) => qiniu_sdk::etag::etag_of(ProgressReader::new(
+                                    file,
+                                    progress.to_owned(),
+                                )),
+                                None => qiniu_sdk::etag::etag_of(file),
+                            })
+                            .map_err(|err| match &progress {
+                                Some(progress) => progress.convert_error(err),
+                                None => QiniuIoError::from_err(err),
+                            })?;
+                        etags.insert(path, etag);
+                    }
+                    Ok::<_, PyErr>(etags)
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut etags = HashMap::new();
+        for handle in handles {
+            etags.extend(join_thread(handle)??);
+        }
+        Ok(etags)
+    })
+}
+
+/// 异步并行读取多个文件并计算它们的 Etag V1，返回文件路径到 Etag 的字典
+///
+/// 如果不传入 `concurrency`，则使用 CPU 核心数作为并发数
+///
+/// 如果传入 `progress` 回调，每读取一段数据都会以所有文件累计已经计算的字节数调用该回调，
+/// 如果同时传入 `progress_interval_ms`，则在该间隔内最多只会回调一次，但计算完成时总是会被回调
+#[pyfunction(concurrency = "None", progress = "None", progress_interval_ms = "None")]
+#[pyo3(
+    text_signature = "(paths, /, concurrency = None, progress = None, progress_interval_ms = None)"
+)]
+fn async_etag_of_paths(
+    paths: Vec<String>,
+    concurrency: Option<usize>,
+    progress: Option<PyObject>,
+    progress_interval_ms: Option<u64>,
+    py: Python<'_>,
+) -> PyResult<&PyAny> {
+    let concurrency = get_concurrency(concurrency)?;
     pyo3_asyncio::async_std::future_into_py(py, async move {
-        let etag =
-            qiniu_sdk::etag::async_etag_of(PythonIoBase::new(reader).into_async_read()).await?;
-        Ok(etag)
+        let progress = progress
+            .map(|progress| {
+                let total_bytes = total_size_of_paths(&paths)?;
+                Ok::<_, PyErr>(EtagProgress::new(
+                    progress,
+                    progress_interval_ms,
+                    Some(total_bytes),
+                ))
+            })
+            .transpose()?;
+        let etags = futures::stream::iter(paths)
+            .map(|path| {
+                let progress = progress.to_owned();
+                async_std::task::spawn(async move {
+                    let file = async_std::fs::File::open(&path).await?;
+                    let etag = match progress {
+                        Some(progress) => {
+                            qiniu_sdk::etag::async_etag_of(ProgressReader::new(file, progress))
+                                .await?
+                        }
+                        None => qiniu_sdk::etag::async_etag_of(file).await?,
+                    };
+                    Ok::<_, std::io::Error>((path, etag))
+                })
+            })
+            .buffer_unordered(concurrency)
+            .map_err(|err| match &progress {
+                Some(progress) => progress.convert_error(err),
+                None => QiniuIoError::from_err(err),
+            })
+            .try_collect::<HashMap<_, _>>()
+            .await?;
+        Ok(etags)
+    })
+}
+
+fn total_size_of_paths(paths: &[String]) -> PyResult<u64> {
+    paths.iter().try_fold(0, |total, path| {
+        std::fs::metadata(path)
+            .map(|metadata| total + metadata.len())
+            .map_err(QiniuIoError::from_err)
+    })
+}
+
+/// 计算 Etag 时的进度回调
+///
+/// 计算过程中不持有 GIL，仅在需要回调时重新获取 GIL
+#[derive(Debug)]
+struct EtagProgress {
+    callback: PyObject,
+    throttle: Option<ProgressThrottle>,
+    hashed_bytes: AtomicU64,
+    total_bytes: Option<u64>,
+    error: Mutex<Option<PyErr>>,
+}
+
+impl EtagProgress {
+    fn new(
+        callback: PyObject,
+        progress_interval_ms: Option<u64>,
+        total_bytes: Option<u64>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            callback,
+            throttle: progress_interval_ms.map(ProgressThrottle::new),
+            hashed_bytes: Default::default(),
+            total_bytes,
+            error: Default::default(),
+        })
+    }
+
+    fn advance(&self, bytes: u64, eof: bool) -> IoResult<()> {
+        let hashed_bytes = self.hashed_bytes.fetch_add(bytes, Ordering::SeqCst) + bytes;
+        let finished = eof && self.total_bytes.is_none();
+        if bytes == 0 && !finished {
+            return Ok(());
+        }
+        if !finished {
+            if let Some(throttle) = &self.throttle {
+                if !throttle.should_emit(hashed_bytes, self.total_bytes) {
+                    return Ok(());
+                }
+            }
+        }
+        Python::with_gil(|py| self.callback.call1(py, (hashed_bytes,))).map_err(|err| {
+            *self.error.lock().unwrap() = Some(err);
+            IoError::new(IoErrorKind::Other, "etag progress callback failed")
+        })?;
+        Ok(())
+    }
+
+    /// 如果是回调函数抛出的异常，则原样返回该异常
+    fn convert_error(&self, err: IoError) -> PyErr {
+        self.error
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or_else(|| QiniuIoError::from_err(err))
+    }
+}
+
+/// 读取数据时汇报 Etag 计算进度
+#[derive(Debug)]
+struct ProgressReader<R> {
+    inner: R,
+    progress: Arc<EtagProgress>,
+}
+
+impl<R> ProgressReader<R> {
+    fn new(inner: R, progress: Arc<EtagProgress>) -> Self {
+        Self { inner, progress }
+    }
+}
+
+impl<R: Read> Read for ProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let have_read = self.inner.read(buf)?;
+        self.progress
+            .advance(have_read as u64, have_read == 0 && !buf.is_empty())?;
+        Ok(have_read)
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ProgressReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<IoResult<usize>> {
+        let have_read = futures::ready!(Pin::new(&mut self.inner).poll_read(cx, buf))?;
+        self.progress
+            .advance(have_read as u64, have_read == 0 && !buf.is_empty())?;
+        Poll::Ready(Ok(have_read))
+    }
+}
+
+/// 计算文件或 reader 中数据的 Etag，并与期望的 Etag 比较，返回是否一致
+///
+/// 根据期望的 Etag 格式自动选择 Etag V1 或 Etag V2 算法，Etag V2 需要传入上传时的分片大小列表 `parts`，否则抛出 `QiniuInvalidPartSize`
+#[pyfunction(parts = "None")]
+#[pyo3(text_signature = "(path_or_reader, expected, /, parts = None)")]
+fn verify_etag(
+    path_or_reader: &PyAny,
+    expected: &str,
+    parts: Option<Vec<usize>>,
+    py: Python<'_>,
+) -> PyResult<bool> {
+    let parts = etag_parts(expected, parts)?;
+    let etag = if let Ok(path) = path_or_reader.extract::<PathBuf>() {
+        py.allow_threads(|| {
+            let file = File::open(path)?;
+            match parts {
+                Some(parts) => qiniu_sdk::etag::etag_with_parts(file, &parts),
+                None => qiniu_sdk::etag::etag_of(file),
+            }
+        })
+    } else {
+        let reader = PythonIoBase::new(path_or_reader.into());
+        match parts {
+            Some(parts) => qiniu_sdk::etag::etag_with_parts(reader, &parts),
+            None => qiniu_sdk::etag::etag_of(reader),
+        }
+    }
+    .map_err(QiniuIoError::from_err)?;
+    Ok(etag == expected)
+}
+
+/// 异步计算文件或 reader 中数据的 Etag，并与期望的 Etag 比较，返回是否一致
+///
+/// 根据期望的 Etag 格式自动选择 Etag V1 或 Etag V2 算法，Etag V2 需要传入上传时的分片大小列表 `parts`，否则抛出 `QiniuInvalidPartSize`
+#[pyfunction(parts = "None")]
+#[pyo3(text_signature = "(path_or_reader, expected, /, parts = None)")]
+fn async_verify_etag<'p>(
+    path_or_reader: &'p PyAny,
+    expected: String,
+    parts: Option<Vec<usize>>,
+    py: Python<'p>,
+) -> PyResult<&'p PyAny> {
+    let parts = etag_parts(&expected, parts)?;
+    let path = path_or_reader.extract::<PathBuf>().ok();
+    let reader: PyObject = path_or_reader.into();
+    pyo3_asyncio::async_std::future_into_py(py, async move {
+        let etag = if let Some(path) = path {
+            let file = async_std::fs::File::open(path)
+                .await
+                .map_err(QiniuIoError::from_err)?;
+            match parts {
+                Some(parts) => qiniu_sdk::etag::async_etag_with_parts(file, &parts).await,
+                None => qiniu_sdk::etag::async_etag_of(file).await,
+            }
+        } else {
+            let reader = PythonIoBase::new(reader).into_async_read();
+            match parts {
+                Some(parts) => qiniu_sdk::etag::async_etag_with_parts(reader, &parts).await,
+                None => qiniu_sdk::etag::async_etag_of(reader).await,
+            }
+        }
+        .map_err(QiniuIoError::from_err)?;
+        Ok(etag == expected)
     })
 }
+
+/// Etag V2 以 `n` 开头，仅在此时使用分片大小列表计算，未传入分片大小列表时抛出 `QiniuInvalidPartSize`
+fn etag_parts(expected: &str, parts: Option<Vec<usize>>) -> PyResult<Option<Vec<usize>>> {
+    if !expected.starts_with('n') {
+        Ok(None)
+    } else if parts.is_some() {
+        Ok(parts)
+    } else {
+        Err(QiniuInvalidPartSize::new_err("Etag V2 requires parts"))
+    }
+}
@@ -2,11 +2,19 @@ use super::http::Metrics;
 use maybe_owned::MaybeOwned;
 use pyo3::{
     create_exception,
-    exceptions::{PyIOError, PyRuntimeError, PyTypeError, PyValueError},
+    exceptions::{
+        PyIOError, PyNotImplementedError, PyRuntimeError, PyTimeoutError, PyTypeError, PyValueError,
+    },
     prelude::*,
-    types::PyBytes,
+    types::{PyBytes, PyDict, PyType},
+    wrap_pyfunction, PyTypeInfo,
+};
+use std::{
+    collections::HashMap,
+    io::Error as IoError,
+    net::{IpAddr, SocketAddr},
+    time::SystemTimeError,
 };
-use std::{io::Error as IoError, time::SystemTimeError};
 
 pub(super) fn register(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add(
@@ -38,6 +46,10 @@ pub(super) fn register(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         "QiniuBodySizeMissingError",
         py.get_type::<QiniuBodySizeMissingError>(),
     )?;
+    m.add(
+        "QiniuUnpicklableError",
+        py.get_type::<QiniuUnpicklableError>(),
+    )?;
     m.add(
         "QiniuInvalidConcurrency",
         py.get_type::<QiniuInvalidConcurrency>(),
@@ -62,8 +74,64 @@ pub(super) fn register(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         "QiniuInvalidSourceKeyLengthError",
         py.get_type::<QiniuInvalidSourceKeyLengthError>(),
     )?;
+    m.add(
+        "QiniuInvalidCompressionLevel",
+        py.get_type::<QiniuInvalidCompressionLevel>(),
+    )?;
+    m.add(
+        "QiniuInvalidChunkSize",
+        py.get_type::<QiniuInvalidChunkSize>(),
+    )?;
+    m.add(
+        "QiniuInvalidEncryptionKey",
+        py.get_type::<QiniuInvalidEncryptionKey>(),
+    )?;
+    m.add(
+        "QiniuChunkedDownloadError",
+        py.get_type::<QiniuChunkedDownloadError>(),
+    )?;
+    m.add("QiniuTimeoutError", py.get_type::<QiniuTimeoutError>())?;
+    m.add(
+        "QiniuInvalidRateLimit",
+        py.get_type::<QiniuInvalidRateLimit>(),
+    )?;
+    m.add(
+        "QiniuInvalidStorageType",
+        py.get_type::<QiniuInvalidStorageType>(),
+    )?;
+    m.add(
+        "QiniuInvalidBackoffJitter",
+        py.get_type::<QiniuInvalidBackoffJitter>(),
+    )?;
+    m.add("QiniuInvalidRatio", py.get_type::<QiniuInvalidRatio>())?;
+    m.add(
+        "QiniuEtagMismatchError",
+        py.get_type::<QiniuEtagMismatchError>(),
+    )?;
+    m.add(
+        "QiniuRunningEventLoopError",
+        py.get_type::<QiniuRunningEventLoopError>(),
+    )?;
+    m.add(
+        "QiniuResponseTooLargeError",
+        py.get_type::<QiniuResponseTooLargeError>(),
+    )?;
+    m.add(
+        "QiniuUploadManagerClosedError",
+        py.get_type::<QiniuUploadManagerClosedError>(),
+    )?;
+    m.add("QiniuInvalidSaveKey", py.get_type::<QiniuInvalidSaveKey>())?;
+    m.add(
+        "QiniuInvalidCallback",
+        py.get_type::<QiniuInvalidCallback>(),
+    )?;
+    m.add(
+        "QiniuUnsupportedRecorderOperation",
+        py.get_type::<QiniuUnsupportedRecorderOperation>(),
+    )?;
     m.add_class::<QiniuHttpCallErrorKind>()?;
     m.add_class::<QiniuApiCallErrorKind>()?;
+    m.add_class::<QiniuErrorKind>()?;
 
     QiniuInvalidURLError::register(py, m)?;
     QiniuInvalidStatusCodeError::register(py, m)?;
@@ -86,12 +154,104 @@ pub(super) fn register(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     QiniuInvalidDomainWithPortError::register(py, m)?;
     QiniuInvalidIpAddrWithPortError::register(py, m)?;
     QiniuApiCallError::register(py, m)?;
+    add_info_attributes::<QiniuApiCallError>(
+        py,
+        &[
+            "status_code",
+            "response_headers",
+            "is_retryable",
+            "error_kind",
+            "response_body",
+        ],
+    )?;
     QiniuDownloadError::register(py, m)?;
     QiniuAuthorizationError::register(py, m)?;
     QiniuInvalidPrefixLengthError::register(py, m)?;
+    add_to_dict_methods(py, m)?;
+    Ok(())
+}
+
+/// 为模块中所有的七牛异常类型添加 `to_dict()` 方法
+fn add_to_dict_methods(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    let to_dict = py
+        .import("functools")?
+        .getattr("partialmethod")?
+        .call1((wrap_pyfunction!(exception_to_dict, m)?,))?;
+    for (name, value) in m.dict() {
+        if !name.extract::<&str>()?.starts_with("Qiniu") {
+            continue;
+        }
+        if let Ok(ty) = value.downcast::<PyType>() {
+            if ty.is_subclass_of::<pyo3::exceptions::PyBaseException>()? {
+                ty.setattr("to_dict", to_dict)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 将异常转换为可以被 JSON 序列化的字典
+///
+/// 包含异常类型 `type` 和错误消息 `message`，如果异常信息（即 `args[0]`）提供了 `to_dict()` 方法，则合并其返回的字段
+#[pyfunction]
+fn exception_to_dict<'p>(exception: &'p PyAny, py: Python<'p>) -> PyResult<&'p PyDict> {
+    let dict = PyDict::new(py);
+    dict.set_item("type", exception.get_type().name()?)?;
+    dict.set_item("message", exception.str()?)?;
+    if let Ok(info) = exception.getattr("args")?.get_item(0i32) {
+        if info.hasattr("to_dict")? {
+            let info_dict: &PyDict = info.call_method0("to_dict")?.downcast()?;
+            for (key, value) in info_dict.iter() {
+                dict.set_item(key, value)?;
+            }
+        }
+    }
+    Ok(dict)
+}
+
+fn format_endpoint(ip: Option<IpAddr>, port: Option<u16>) -> Option<String> {
+    match (ip, port) {
+        (Some(ip), Some(port)) => Some(SocketAddr::new(ip, port).to_string()),
+        (Some(ip), None) => Some(ip.to_string()),
+        _ => None,
+    }
+}
+
+/// 在异常类型上添加属性，访问时转发到异常信息（即 `args[0]`）的同名属性
+fn add_info_attributes<T: PyTypeInfo>(py: Python<'_>, names: &[&'static str]) -> PyResult<()> {
+    let ty = py.get_type::<T>();
+    for &name in names {
+        ty.setattr(name, Py::new(py, ExceptionInfoAttribute(name))?)?;
+    }
     Ok(())
 }
 
+/// 异常属性描述符
+///
+/// 异常信息不存在或没有该属性时返回 `None`
+#[pyclass]
+struct ExceptionInfoAttribute(&'static str);
+
+#[pymethods]
+impl ExceptionInfoAttribute {
+    fn __get__(
+        slf: PyRef<'_, Self>,
+        instance: Option<&PyAny>,
+        _owner: Option<&PyAny>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let instance = match instance {
+            Some(instance) if !instance.is_none() => instance,
+            _ => return Ok(slf.into_py(py)),
+        };
+        let name = slf.0;
+        match instance.getattr("args")?.get_item(0i32) {
+            Ok(info) if info.hasattr(name)? => Ok(info.getattr(name)?.into_py(py)),
+            _ => Ok(py.None()),
+        }
+    }
+}
+
 macro_rules! create_exception_with_info {
     ($module: ident, $name: ident, $name_str: literal, $base: ty, $inner_name: ident, $inner_type:ty, $doc: expr) => {
         create_exception!($module, $name, $base, $doc);
@@ -156,6 +316,12 @@ create_exception!(
     PyTypeError,
     "七牛缺少 body_len 参数错误"
 );
+create_exception!(
+    qiniu_bindings,
+    QiniuUnpicklableError,
+    PyTypeError,
+    "七牛对象无法序列化错误"
+);
 create_exception!(
     qiniu_bindings,
     QiniuEmptyChainCredentialsProvider,
@@ -222,6 +388,102 @@ create_exception!(
     PyValueError,
     "七牛数据源 KEY 长度错误"
 );
+create_exception!(
+    qiniu_bindings,
+    QiniuInvalidCompressionLevel,
+    PyValueError,
+    "七牛压缩级别错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuInvalidChunkSize,
+    PyValueError,
+    "七牛数据块大小错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuInvalidEncryptionKey,
+    PyValueError,
+    "七牛加密密钥或随机数长度错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuChunkedDownloadError,
+    PyIOError,
+    "七牛分片下载错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuTimeoutError,
+    PyTimeoutError,
+    "七牛操作超时错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuInvalidRateLimit,
+    PyValueError,
+    "七牛请求速率限制错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuInvalidStorageType,
+    PyValueError,
+    "七牛存储类型错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuInvalidBackoffJitter,
+    PyValueError,
+    "七牛退避时长抖动比率错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuInvalidRatio,
+    PyValueError,
+    "七牛比率错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuEtagMismatchError,
+    PyValueError,
+    "七牛 Etag 校验不一致错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuRunningEventLoopError,
+    PyRuntimeError,
+    "七牛事件循环正在运行错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuResponseTooLargeError,
+    PyIOError,
+    "七牛响应体过大错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuUploadManagerClosedError,
+    PyRuntimeError,
+    "七牛上传管理器已关闭错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuInvalidSaveKey,
+    PyValueError,
+    "七牛自定义对象名称错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuInvalidCallback,
+    PyValueError,
+    "七牛上传回调设置错误"
+);
+create_exception!(
+    qiniu_bindings,
+    QiniuUnsupportedRecorderOperation,
+    PyNotImplementedError,
+    "七牛断点恢复记录器不支持的操作错误"
+);
 create_exception_with_info!(
     qiniu_bindings,
     QiniuCallbackError,
@@ -577,6 +839,19 @@ impl QiniuHttpCallErrorInfo {
     fn get_metrics(&self) -> Option<Metrics> {
         self.0.metrics().cloned().map(Metrics::from)
     }
+
+    /// 转换为可以被 JSON 序列化的字典
+    #[pyo3(text_signature = "($self)")]
+    fn to_dict<'p>(&self, py: Python<'p>) -> PyResult<&'p PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item("kind", format!("{:?}", self.get_kind()))?;
+        dict.set_item("message", self.0.to_string())?;
+        dict.set_item(
+            "endpoint",
+            format_endpoint(self.0.server_ip(), self.get_server_port()),
+        )?;
+        Ok(dict)
+    }
 }
 
 /// 七牛 API 响应错误类型
@@ -625,8 +900,156 @@ impl From<qiniu_sdk::http_client::ResponseErrorKind> for QiniuApiCallErrorKind {
     }
 }
 
+/// 七牛 API 调用错误分类
+///
+/// 用于在捕获 `QiniuApiCallError` 后判断错误原因，无需解析错误消息
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QiniuErrorKind {
+    /// 超时
+    Timeout = 1,
+
+    /// 域名解析失败
+    Dns = 2,
+
+    /// 网络连接或传输失败
+    Connect = 3,
+
+    /// 服务器返回了错误的状态码
+    Status = 4,
+
+    /// 协议错误，例如非法的请求或响应
+    Protocol = 5,
+
+    /// 其他错误
+    Other = 6,
+}
+
+impl From<qiniu_sdk::http_client::ResponseErrorKind> for QiniuErrorKind {
+    fn from(kind: qiniu_sdk::http_client::ResponseErrorKind) -> Self {
+        use qiniu_sdk::{
+            http::ResponseErrorKind as HttpErrorKind,
+            http_client::ResponseErrorKind as CallErrorKind,
+        };
+        match kind {
+            CallErrorKind::HttpError(HttpErrorKind::TimeoutError) => Self::Timeout,
+            CallErrorKind::HttpError(
+                HttpErrorKind::DnsServerError | HttpErrorKind::UnknownHostError,
+            ) => Self::Dns,
+            CallErrorKind::HttpError(
+                HttpErrorKind::ConnectError
+                | HttpErrorKind::ProxyError
+                | HttpErrorKind::SendError
+                | HttpErrorKind::ReceiveError
+                | HttpErrorKind::ClientCertError
+                | HttpErrorKind::ServerCertError
+                | HttpErrorKind::SslError,
+            ) => Self::Connect,
+            CallErrorKind::HttpError(
+                HttpErrorKind::ProtocolError
+                | HttpErrorKind::InvalidRequestResponse
+                | HttpErrorKind::InvalidUrl
+                | HttpErrorKind::InvalidHeader
+                | HttpErrorKind::TooManyRedirect,
+            ) => Self::Protocol,
+            CallErrorKind::StatusCodeError(_) | CallErrorKind::UnexpectedStatusCode(_) => {
+                Self::Status
+            }
+            CallErrorKind::ParseResponseError
+            | CallErrorKind::UnexpectedEof
+            | CallErrorKind::MaliciousResponse => Self::Protocol,
+            _ => Self::Other,
+        }
+    }
+}
+
 #[pymethods]
 impl QiniuApiCallErrorInfo {
+    /// 获取 API 调用错误分类
+    #[getter]
+    fn get_error_kind(&self) -> QiniuErrorKind {
+        self.0.kind().into()
+    }
+
+    /// 判断该错误是否值得重试
+    ///
+    /// 超时、域名解析失败、网络连接失败、响应体提前结束，以及 406、429 和除 501、579 以外的 5xx 状态码被认为是可以重试的
+    #[getter]
+    fn get_is_retryable(&self) -> bool {
+        match self.get_error_kind() {
+            QiniuErrorKind::Timeout | QiniuErrorKind::Dns | QiniuErrorKind::Connect => true,
+            QiniuErrorKind::Status => self.get_status_code().map_or(false, |code| {
+                matches!(code, 406 | 429)
+                    || (500..600).contains(&code) && !matches!(code, 501 | 579)
+            }),
+            _ => matches!(
+                self.0.kind(),
+                qiniu_sdk::http_client::ResponseErrorKind::UnexpectedEof
+            ),
+        }
+    }
+
+    /// 获取 HTTP 响应头
+    ///
+    /// 错误中只保留了 `X-ReqId` 和 `X-Log` 响应头
+    #[getter]
+    fn get_response_headers(&self) -> PyResult<HashMap<String, String>> {
+        let mut headers = HashMap::new();
+        if let Some(x_reqid) = self.get_x_reqid()? {
+            headers.insert("X-ReqId".to_owned(), x_reqid);
+        }
+        if let Some(x_log) = self.get_x_log()? {
+            headers.insert("X-Log".to_owned(), x_log);
+        }
+        Ok(headers)
+    }
+
+    /// 转换为可以被 JSON 序列化的字典
+    ///
+    /// 包含错误分类 `kind`、API 调用错误类型 `api_kind`、状态码 `status`、错误消息 `message`、服务器地址 `endpoint`、
+    /// `x_reqid`、`is_retryable` 和重试统计信息 `retried`（仅当错误由本库构建的 `HttpClient` 返回时才有，否则为 `None`）
+    #[pyo3(text_signature = "($self)")]
+    fn to_dict<'p>(&self, py: Python<'p>) -> PyResult<&'p PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item("kind", format!("{:?}", self.get_error_kind()))?;
+        dict.set_item("api_kind", format!("{:?}", self.get_kind()))?;
+        dict.set_item("status", self.get_status_code())?;
+        dict.set_item("message", self.get_message())?;
+        dict.set_item(
+            "endpoint",
+            format_endpoint(self.0.server_ip(), self.get_server_port()),
+        )?;
+        dict.set_item("x_reqid", self.get_x_reqid()?)?;
+        dict.set_item("is_retryable", self.get_is_retryable())?;
+        let retried = self
+            .0
+            .extensions()
+            .get::<qiniu_sdk::http_client::RetriedStatsInfo>()
+            .map(|retried| {
+                let retried_dict = PyDict::new(py);
+                retried_dict.set_item("retried_total", retried.retried_total())?;
+                retried_dict.set_item(
+                    "retried_on_current_endpoint",
+                    retried.retried_on_current_endpoint(),
+                )?;
+                retried_dict
+                    .set_item("retried_on_current_ips", retried.retried_on_current_ips())?;
+                retried_dict.set_item("abandoned_endpoints", retried.abandoned_endpoints())?;
+                Ok::<_, PyErr>(retried_dict)
+            })
+            .transpose()?;
+        dict.set_item("retried", retried)?;
+        Ok(dict)
+    }
+
+    /// 获取响应体
+    ///
+    /// 错误中只保留了响应体样本，与 `response_body_sample` 相同
+    #[getter]
+    fn get_response_body<'p>(&self, py: Python<'p>) -> &'p PyBytes {
+        self.get_response_body_sample(py)
+    }
+
     /// 获取 API 调用错误消息
     #[getter]
     fn get_message(&self) -> Option<String> {
@@ -2,31 +2,41 @@ use super::{
     exceptions::{
         QiniuHeaderValueEncodingError, QiniuHttpCallError, QiniuInvalidIpAddrError,
         QiniuInvalidMethodError, QiniuInvalidURLError, QiniuIsahcError, QiniuJsonError,
+        QiniuResponseTooLargeError,
     },
     utils::{
-        convert_headers_to_hashmap, convert_json_value_to_py_object, extract_async_request_body,
-        extract_async_response_body, extract_sync_request_body, extract_sync_response_body,
-        parse_headers, parse_ip_addr, parse_ip_addrs, parse_method, parse_port, parse_status_code,
-        parse_uri, RemotePyCallLocalAgent,
+        async_read_to_end_with_progress, convert_body_read_error, convert_headers_to_hashmap,
+        convert_json_value_to_py_object, extract_async_request_body, extract_async_response_body,
+        extract_sync_request_body, extract_sync_response_body, parse_headers, parse_ip_addr,
+        parse_ip_addrs, parse_method, parse_port, parse_status_code, parse_uri,
+        read_to_end_with_progress, PythonIoBase, RemotePyCallLocalAgent, ResponseBodyTooLarge,
     },
 };
 use futures::AsyncReadExt;
-use futures::{future::BoxFuture, lock::Mutex as AsyncMutex};
+use futures::{future::BoxFuture, lock::Mutex as AsyncMutex, ready, AsyncRead};
 use pyo3::{
-    exceptions::{PyIOError, PyNotImplementedError},
+    exceptions::{PyIOError, PyNotImplementedError, PyValueError},
     prelude::*,
+    pyclass::CompareOp,
     types::PyBytes,
 };
 use qiniu_sdk::http::{Method, Uri};
 use std::{
     borrow::Cow,
-    collections::HashMap,
-    io::Read,
-    mem::{take, transmute},
+    collections::{hash_map::DefaultHasher, HashMap},
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    io::{
+        Cursor, Error as IoError, ErrorKind as IoErrorKind, Read, Result as IoResult, Seek,
+        SeekFrom,
+    },
+    mem::{replace, take, transmute},
     net::IpAddr,
     num::NonZeroU16,
     ops::{Deref, DerefMut},
-    sync::Arc,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
     time::Duration,
 };
 
@@ -34,6 +44,8 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "http")?;
     m.add_class::<HttpCaller>()?;
     m.add_class::<IsahcHttpCaller>()?;
+    m.add_class::<CallbackHttpCaller>()?;
+    m.add_class::<TransferProgressInfo>()?;
     m.add_class::<HttpRequestParts>()?;
     m.add_class::<SyncHttpRequest>()?;
     m.add_class::<AsyncHttpRequest>()?;
@@ -72,6 +84,7 @@ impl HttpCaller {
         py: Python<'_>,
     ) -> PyResult<Py<SyncHttpResponse>> {
         let response = SyncHttpRequest::with_request_from_ref_mut(request, |request| {
+            disable_automatic_decompression(request.extensions_mut());
             py.allow_threads(|| self.0.call(request).map_err(QiniuHttpCallError::from_err))
         })?;
         let (parts, body) = response.into_parts_and_body();
@@ -85,6 +98,7 @@ impl HttpCaller {
         pyo3_asyncio::async_std::future_into_py(py, async move {
             let response =
                 AsyncHttpRequest::with_request_from_ref_mut(request, move |request, agent| {
+                    disable_automatic_decompression(request.extensions_mut());
                     Box::pin(async move {
                         if let Some(agent) = agent {
                             agent.run(http_caller.async_call(request)).await?
@@ -156,6 +170,119 @@ impl IsahcHttpCaller {
     }
 }
 
+/// 基于回调函数的 HTTP 客户端实现
+///
+/// 回调函数接受 `SyncHttpRequest` 并返回 `SyncHttpResponse`，异步请求也将阻塞调用该回调函数，因此通常仅用于测试。
+/// 传给回调函数的请求不包含请求的回调函数
+///
+/// 通过 `CallbackHttpCaller(callback)` 创建基于回调函数的 HTTP 客户端
+#[pyclass(extends = HttpCaller)]
+#[pyo3(text_signature = "(callback)")]
+#[derive(Clone)]
+struct CallbackHttpCaller;
+
+#[pymethods]
+impl CallbackHttpCaller {
+    #[new]
+    fn new(callback: PyObject) -> (Self, HttpCaller) {
+        (
+            CallbackHttpCaller,
+            HttpCaller::new(CallbackHttpCallerImpl(callback)),
+        )
+    }
+}
+
+#[derive(Debug)]
+struct CallbackHttpCallerImpl(PyObject);
+
+impl CallbackHttpCallerImpl {
+    fn call_with_body(
+        &self,
+        parts: &qiniu_sdk::http::RequestParts<'_>,
+        body: Vec<u8>,
+    ) -> PyResult<(qiniu_sdk::http::ResponseParts, Vec<u8>)> {
+        let mut builder = qiniu_sdk::http::RequestParts::builder();
+        builder
+            .url(parts.url().to_owned())
+            .method(parts.method().to_owned())
+            .version(parts.version())
+            .headers(parts.headers().to_owned())
+            .appended_user_agent(parts.appended_user_agent().to_owned());
+        if let Some(resolved_ip_addrs) = parts.resolved_ip_addrs() {
+            builder.resolved_ip_addrs(resolved_ip_addrs.to_owned());
+        }
+        Python::with_gil(|py| {
+            let request = Py::new(
+                py,
+                (
+                    SyncHttpRequest(qiniu_sdk::http::SyncRequestBody::from(body)),
+                    HttpRequestParts(builder.build()),
+                ),
+            )?;
+            let response = self.0.call1(py, (request,))?;
+            let mut response = response
+                .as_ref(py)
+                .extract::<PyRefMut<SyncHttpResponse>>()?;
+            let mut body = Vec::new();
+            response
+                .0
+                .read_to_end(&mut body)
+                .map_err(PyIOError::new_err)?;
+            Ok((take(&mut response.as_mut().0), body))
+        })
+    }
+}
+
+impl qiniu_sdk::http::HttpCaller for CallbackHttpCallerImpl {
+    fn call(
+        &self,
+        request: &mut qiniu_sdk::http::SyncRequest<'_>,
+    ) -> qiniu_sdk::http::SyncResponseResult {
+        let mut body = Vec::new();
+        request
+            .body_mut()
+            .read_to_end(&mut body)
+            .map_err(|err| convert_callback_error(request.url(), err))?;
+        let (parts, body) = self
+            .call_with_body(request.parts(), body)
+            .map_err(|err| convert_callback_error(request.url(), err))?;
+        Ok(qiniu_sdk::http::Response::from_parts_and_body(
+            parts,
+            qiniu_sdk::http::SyncResponseBody::from_bytes(body),
+        ))
+    }
+
+    fn async_call<'a>(
+        &'a self,
+        request: &'a mut qiniu_sdk::http::AsyncRequest<'_>,
+    ) -> BoxFuture<'a, qiniu_sdk::http::AsyncResponseResult> {
+        Box::pin(async move {
+            let mut body = Vec::new();
+            request
+                .body_mut()
+                .read_to_end(&mut body)
+                .await
+                .map_err(|err| convert_callback_error(request.url(), err))?;
+            let (parts, body) = self
+                .call_with_body(request.parts(), body)
+                .map_err(|err| convert_callback_error(request.url(), err))?;
+            Ok(qiniu_sdk::http::Response::from_parts_and_body(
+                parts,
+                qiniu_sdk::http::AsyncResponseBody::from_bytes(body),
+            ))
+        })
+    }
+}
+
+fn convert_callback_error(
+    url: &Uri,
+    err: impl Into<anyhow::Error>,
+) -> qiniu_sdk::http::ResponseError {
+    qiniu_sdk::http::ResponseError::builder(qiniu_sdk::http::ResponseErrorKind::UnknownError, err)
+        .uri(url)
+        .build()
+}
+
 /// 数据传输进度信息
 ///
 /// 通过 `TransferProgressInfo(transferred_bytes, total_bytes)` 创建数据传输进度信息
@@ -193,6 +320,14 @@ impl TransferProgressInfo {
         self.total_bytes
     }
 
+    /// 获取传输进度百分比
+    ///
+    /// 范围为 `0` 到 `100`，如果总共需要传输的数据量为 `0`，则返回 `None`
+    #[getter]
+    fn get_percentage(&self) -> Option<f64> {
+        calculate_percentage(self.transferred_bytes, self.total_bytes)
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self)
     }
@@ -208,6 +343,14 @@ impl ToPyObject for TransferProgressInfo {
     }
 }
 
+pub(super) fn calculate_percentage(transferred_bytes: u64, total_bytes: u64) -> Option<f64> {
+    if total_bytes == 0 {
+        None
+    } else {
+        Some((transferred_bytes as f64 / total_bytes as f64 * 100.0).clamp(0.0, 100.0))
+    }
+}
+
 /// HTTP 请求信息
 ///
 /// 不包含请求体信息
@@ -437,6 +580,15 @@ impl HttpRequestParts {
         Ok(())
     }
 
+    /// 生成等价的 curl 命令行
+    ///
+    /// 默认隐去 `Authorization` 等敏感 HTTP 头的值，可以通过 `redact=False` 保留原值
+    #[pyo3(text_signature = "($self, /, redact = True)")]
+    #[args(redact = "true")]
+    fn to_curl(&self, redact: bool) -> String {
+        make_curl_command(&self.0, None, redact)
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -446,6 +598,69 @@ impl HttpRequestParts {
     }
 }
 
+const SENSITIVE_HEADER_NAMES: [&str; 3] = ["authorization", "proxy-authorization", "cookie"];
+
+fn make_curl_command(
+    parts: &qiniu_sdk::http::RequestParts<'_>,
+    body_size: Option<u64>,
+    redact: bool,
+) -> String {
+    let mut args = vec![
+        "curl".to_owned(),
+        "-X".to_owned(),
+        parts.method().to_string(),
+        quote_shell_arg(&parts.url().to_string()),
+    ];
+    if !parts.headers().contains_key("user-agent") {
+        args.push("-H".to_owned());
+        args.push(quote_shell_arg(&format!(
+            "User-Agent: {}",
+            parts.user_agent()
+        )));
+    }
+    for (name, value) in parts.headers() {
+        let value = if redact && SENSITIVE_HEADER_NAMES.contains(&name.as_str()) {
+            Cow::Borrowed("<redacted>")
+        } else {
+            String::from_utf8_lossy(value.as_bytes())
+        };
+        args.push("-H".to_owned());
+        args.push(quote_shell_arg(&format!("{}: {}", name, value)));
+    }
+    if let (Some(ip_addrs), Some(host)) = (parts.resolved_ip_addrs(), parts.url().host()) {
+        let port = parts.url().port_u16().unwrap_or_else(|| {
+            if parts.url().scheme_str() == Some("https") {
+                443
+            } else {
+                80
+            }
+        });
+        for ip_addr in ip_addrs.iter() {
+            let ip = match ip_addr {
+                IpAddr::V4(ip) => ip.to_string(),
+                IpAddr::V6(ip) => format!("[{}]", ip),
+            };
+            args.push("--resolve".to_owned());
+            args.push(quote_shell_arg(&format!("{}:{}:{}", host, port, ip)));
+        }
+    }
+    let mut command = args.join(" ");
+    match body_size {
+        Some(0) | None => {}
+        Some(body_size) => {
+            command.push_str(&format!(
+                " --data-binary @- # request body of {} bytes is omitted",
+                body_size
+            ));
+        }
+    }
+    command
+}
+
+fn quote_shell_arg(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
 impl Deref for HttpRequestParts {
     type Target = qiniu_sdk::http::RequestParts<'static>;
 
@@ -526,11 +741,32 @@ impl SyncHttpRequest {
         Ok((SyncHttpRequest(body), parts))
     }
 
+    /// 获取请求体
+    ///
+    /// 读取后请求体将被重置，因此可以重复获取
+    #[getter]
+    fn get_body<'p>(&mut self, py: Python<'p>) -> PyResult<&'p PyBytes> {
+        let mut body = Vec::new();
+        self.0.read_to_end(&mut body).map_err(PyIOError::new_err)?;
+        qiniu_sdk::prelude::Reset::reset(&mut self.0).map_err(PyIOError::new_err)?;
+        Ok(PyBytes::new(py, &body))
+    }
+
     /// 设置请求体
     #[setter]
     fn set_body(&mut self, body: Vec<u8>) {
         self.0 = qiniu_sdk::http::SyncRequestBody::from(body);
     }
+
+    /// 生成等价的 curl 命令行
+    ///
+    /// 请求体不会包含在命令行中，仅注明其长度。
+    /// 默认隐去 `Authorization` 等敏感 HTTP 头的值，可以通过 `redact=False` 保留原值
+    #[pyo3(text_signature = "($self, /, redact = True)")]
+    #[args(redact = "true")]
+    fn to_curl(self_: PyRef<'_, Self>, redact: bool) -> String {
+        make_curl_command(&self_.as_ref().0, Some(self_.0.size()), redact)
+    }
 }
 
 impl SyncHttpRequest {
@@ -668,8 +904,10 @@ impl AsyncHttpRequest {
 }
 
 /// HTTP 版本
+///
+/// 支持比较大小，顺序与 HTTP 协议版本号一致，例如 `Version.HTTP_2 >= Version.HTTP_11`
 #[pyclass]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 #[allow(non_camel_case_types)]
 pub(super) enum Version {
     /// HTTP 0.9
@@ -693,6 +931,22 @@ impl Version {
     fn __str__(&self) -> String {
         self.__repr__()
     }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        let (this, other) = (*self as u8, *other as u8);
+        match op {
+            CompareOp::Lt => this < other,
+            CompareOp::Le => this <= other,
+            CompareOp::Eq => this == other,
+            CompareOp::Ne => this != other,
+            CompareOp::Gt => this > other,
+            CompareOp::Ge => this >= other,
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        *self as u64
+    }
 }
 
 impl From<qiniu_sdk::http::Version> for Version {
@@ -854,6 +1108,15 @@ impl Metrics {
         *self.0.transfer_duration_mut() = Some(Duration::from_nanos(duration_ns));
     }
 
+    /// 转换为字典，所有耗时均以纳秒为单位，未设置的耗时为 `None`
+    #[pyo3(text_signature = "($self)")]
+    fn to_dict(&self) -> HashMap<&'static str, Option<u128>> {
+        Self::FIELD_NAMES
+            .into_iter()
+            .zip(self.durations())
+            .collect()
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -861,6 +1124,42 @@ impl Metrics {
     fn __str__(&self) -> String {
         self.__repr__()
     }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
+        match op {
+            CompareOp::Eq => (self.durations() == other.durations()).to_object(py),
+            CompareOp::Ne => (self.durations() != other.durations()).to_object(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.durations().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl Metrics {
+    const FIELD_NAMES: [&'static str; 6] = [
+        "total_duration",
+        "name_lookup_duration",
+        "connect_duration",
+        "secure_connect_duration",
+        "redirect_duration",
+        "transfer_duration",
+    ];
+
+    fn durations(&self) -> [Option<u128>; 6] {
+        [
+            self.get_total_duration(),
+            self.get_name_lookup_duration(),
+            self.get_connect_duration(),
+            self.get_secure_connect_duration(),
+            self.get_redirect_duration(),
+            self.get_transfer_duration(),
+        ]
+    }
 }
 
 impl From<qiniu_sdk::http::Metrics> for Metrics {
@@ -875,6 +1174,14 @@ impl AsRef<qiniu_sdk::http::Metrics> for Metrics {
     }
 }
 
+/// 跟随重定向时经过的地址列表，由跟随重定向的 HTTP 客户端写入响应扩展
+#[derive(Debug, Clone, Default)]
+pub(super) struct RedirectHistory(pub(super) Vec<String>);
+
+/// 请求解析得到的 IP 地址列表，由 HTTP 客户端写入响应扩展
+#[derive(Debug, Clone, Default)]
+pub(super) struct ResolvedIpAddrs(pub(super) Vec<IpAddr>);
+
 macro_rules! impl_http_response_parts_ref {
     ($name:ident) => {
         #[pymethods]
@@ -914,6 +1221,50 @@ macro_rules! impl_http_response_parts_ref {
             fn get_metrics(&self) -> Option<Metrics> {
                 self.0.metrics().cloned().map(Metrics)
             }
+
+            /// 获取 HTTP 响应体长度
+            #[getter]
+            fn get_content_length(&self) -> Option<u64> {
+                self.0
+                    .headers()
+                    .get(qiniu_sdk::http::header::CONTENT_LENGTH)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.parse().ok())
+            }
+
+            /// 获取跟随重定向时经过的地址列表，按照跟随的顺序排列
+            ///
+            /// 没有跟随重定向时返回空列表
+            #[getter]
+            fn get_redirect_history(&self) -> Vec<String> {
+                self.0
+                    .extensions()
+                    .get::<RedirectHistory>()
+                    .map(|history| history.0.to_owned())
+                    .unwrap_or_default()
+            }
+
+            /// 获取该请求最终解析得到的 IP 地址列表
+            ///
+            /// 终端地址为 IP 地址或者 HTTP 客户端没有解析域名时返回空列表，实际连接的 IP 地址可以通过 `server_ip` 获取
+            #[getter]
+            fn get_last_resolved_ips(&self) -> Vec<String> {
+                self.0
+                    .extensions()
+                    .get::<ResolvedIpAddrs>()
+                    .map(|ips| ips.0.iter().map(|ip| ip.to_string()).collect())
+                    .unwrap_or_default()
+            }
+
+            /// 获取 HTTP 响应的 Content-Range
+            #[getter]
+            fn get_content_range(&self) -> Option<String> {
+                self.0
+                    .headers()
+                    .get(qiniu_sdk::http::header::CONTENT_RANGE)
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| value.to_owned())
+            }
         }
     };
 }
@@ -1061,24 +1412,6 @@ macro_rules! impl_response_body {
                 Ok(true)
             }
 
-            #[pyo3(text_signature = "($self, offset, whence = 0)")]
-            #[args(whence = "0")]
-            pub fn seek(&self, offset: i64, whence: i64) -> PyResult<bool> {
-                let _offset = offset;
-                let _whence = whence;
-                Err(PyNotImplementedError::new_err("seek"))
-            }
-
-            #[pyo3(text_signature = "($self)")]
-            pub fn seekable(&self) -> PyResult<bool> {
-                Ok(false)
-            }
-
-            #[pyo3(text_signature = "($self)")]
-            pub fn tell(&self) -> PyResult<bool> {
-                Err(PyNotImplementedError::new_err("tell"))
-            }
-
             #[pyo3(text_signature = "($self, size = None)")]
             #[args(size = "None")]
             pub fn truncate(&self, size: Option<u64>) -> PyResult<()> {
@@ -1105,11 +1438,16 @@ macro_rules! impl_response_body {
 /// 封装 HTTP 响应相关字段
 ///
 /// 通过 `SyncHttpResponse(status_code = None, headers = None, version = None, server_ip = None, server_port = None, body = None, metrics = None)` 创建阻塞 HTTP 响应
+///
+/// 如果 `body` 为字符串、字节数组或可寻址的文件对象，则响应体支持 `seek()` 和 `tell()`，但在解压响应体后将不再支持
 #[pyclass(extends = HttpResponseParts)]
 #[pyo3(
     text_signature = "(/, status_code = None, headers = None, version = None, server_ip = None, server_port = None, body = None, metrics = None)"
 )]
-pub(super) struct SyncHttpResponse(qiniu_sdk::http::SyncResponseBody);
+pub(super) struct SyncHttpResponse(
+    qiniu_sdk::http::SyncResponseBody,
+    Option<SeekableResponseBody>,
+);
 
 #[pymethods]
 impl SyncHttpResponse {
@@ -1150,35 +1488,70 @@ impl SyncHttpResponse {
         if let Some(server_port) = server_port {
             builder.server_port(parse_port(server_port)?);
         }
+        let mut seekable = None;
         if let Some(body) = body {
-            builder.body(extract_sync_response_body(body, py));
+            if let Some(body) = SeekableResponseBody::extract(&body, py) {
+                builder.body(qiniu_sdk::http::SyncResponseBody::from_reader(
+                    body.to_owned(),
+                ));
+                seekable = Some(body);
+            } else {
+                builder.body(extract_sync_response_body(body, py));
+            }
         }
         if let Some(metrics) = metrics {
             builder.metrics(metrics.0);
         }
         let (parts, body) = builder.build().into_parts_and_body();
-        Ok((Self(body), HttpResponseParts(parts)))
+        Ok((Self(body, seekable), HttpResponseParts(parts)))
     }
 
     /// 读取响应体数据
-    #[pyo3(text_signature = "($self, size = -1, /)")]
-    #[args(size = "-1")]
-    fn read<'a>(&mut self, size: i64, py: Python<'a>) -> PyResult<&'a PyBytes> {
+    ///
+    /// 默认根据 `Content-Encoding` 头自动解压 gzip 或 deflate 压缩的响应体，解压后将移除该头。
+    /// 是否解压在首次读取压缩过的响应体时确定，之后传入不同的 `decode_content` 将抛出 `ValueError`
+    #[pyo3(text_signature = "($self, size = -1, /, decode_content = True)")]
+    #[args(size = "-1", decode_content = "true")]
+    fn read<'a>(
+        mut self_: PyRefMut<'_, Self>,
+        size: i64,
+        decode_content: bool,
+        py: Python<'a>,
+    ) -> PyResult<&'a PyBytes> {
+        Self::decode_content(&mut self_, decode_content)?;
         let mut buf = Vec::new();
         if let Ok(size) = u64::try_from(size) {
             buf.reserve(size as usize);
-            (&mut self.0).take(size).read_to_end(&mut buf)
+            (&mut self_.0).take(size).read_to_end(&mut buf)
         } else {
-            self.0.read_to_end(&mut buf)
+            self_.0.read_to_end(&mut buf)
         }
-        .map_err(PyIOError::new_err)?;
+        .map_err(convert_body_read_error)?;
         Ok(PyBytes::new(py, &buf))
     }
 
     /// 读取所有响应体数据
-    #[pyo3(text_signature = "($self)")]
-    fn readall<'a>(&mut self, py: Python<'a>) -> PyResult<&'a PyBytes> {
-        self.read(-1, py)
+    ///
+    /// 默认根据 `Content-Encoding` 头自动解压 gzip 或 deflate 压缩的响应体，解压后将移除该头。
+    /// 是否解压在首次读取压缩过的响应体时确定，之后传入不同的 `decode_content` 将抛出 `ValueError`
+    ///
+    /// 如果传入 `progress` 回调函数，每读取一块数据后将以已读取的字节数调用该函数
+    #[pyo3(text_signature = "($self, /, decode_content = True, progress = None)")]
+    #[args(decode_content = "true", progress = "None")]
+    fn readall<'a>(
+        mut self_: PyRefMut<'_, Self>,
+        decode_content: bool,
+        progress: Option<PyObject>,
+        py: Python<'a>,
+    ) -> PyResult<&'a PyBytes> {
+        if let Some(progress) = progress {
+            Self::decode_content(&mut self_, decode_content)?;
+            let mut buf = Vec::new();
+            read_to_end_with_progress(&mut self_.0, &mut buf, &progress)?;
+            Ok(PyBytes::new(py, &buf))
+        } else {
+            Self::read(self_, -1, decode_content, py)
+        }
     }
 
     #[pyo3(text_signature = "($self, b)")]
@@ -1187,6 +1560,63 @@ impl SyncHttpResponse {
         Err(PyNotImplementedError::new_err("write"))
     }
 
+    /// 移动响应体的读取位置，返回新的位置
+    ///
+    /// 仅当响应体可寻址时可用，否则抛出 `NotImplementedError`
+    #[pyo3(text_signature = "($self, offset, whence = 0)")]
+    #[args(whence = "0")]
+    fn seek(&mut self, offset: i64, whence: i64, py: Python<'_>) -> PyResult<u64> {
+        let body = self
+            .1
+            .as_ref()
+            .ok_or_else(|| PyNotImplementedError::new_err("seek"))?;
+        let pos = match whence {
+            0 => SeekFrom::Start(
+                u64::try_from(offset)
+                    .map_err(|_| PyValueError::new_err("negative seek position"))?,
+            ),
+            1 => SeekFrom::Current(offset),
+            2 => SeekFrom::End(offset),
+            _ => return Err(PyValueError::new_err("invalid whence")),
+        };
+        py.allow_threads(|| body.seek(pos))
+            .map_err(PyIOError::new_err)
+    }
+
+    /// 响应体是否可寻址
+    #[pyo3(text_signature = "($self)")]
+    fn seekable(&self) -> bool {
+        self.1.is_some()
+    }
+
+    /// 获取响应体当前的读取位置
+    ///
+    /// 仅当响应体可寻址时可用，否则抛出 `NotImplementedError`
+    #[pyo3(text_signature = "($self)")]
+    fn tell(&mut self, py: Python<'_>) -> PyResult<u64> {
+        self.seek(0, 1, py)
+    }
+
+    /// 读取所有响应体数据并解码为字符串
+    ///
+    /// 如果没有指定编码，则使用 `Content-Type` 头中的 `charset`，默认为 UTF-8
+    #[pyo3(text_signature = "($self, encoding = None, /)")]
+    #[args(encoding = "None")]
+    fn text(
+        mut self_: PyRefMut<'_, Self>,
+        encoding: Option<String>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let encoding = encoding.unwrap_or_else(|| get_charset(self_.as_ref().0.headers()));
+        Self::decode_content(&mut self_, true)?;
+        let mut buf = Vec::new();
+        self_
+            .0
+            .read_to_end(&mut buf)
+            .map_err(convert_body_read_error)?;
+        decode_text(&buf, &encoding, py)
+    }
+
     /// 解析 JSON 响应体
     #[pyo3(text_signature = "($self)")]
     pub(super) fn parse_json(&mut self) -> PyResult<PyObject> {
@@ -1196,11 +1626,123 @@ impl SyncHttpResponse {
     }
 }
 
+impl SyncHttpResponse {
+    pub(super) fn parse_json_with_raw(&mut self) -> PyResult<(PyObject, Vec<u8>)> {
+        let mut buf = Vec::new();
+        self.0
+            .read_to_end(&mut buf)
+            .map_err(convert_body_read_error)?;
+        let value: serde_json::Value =
+            serde_json::from_slice(&buf).map_err(QiniuJsonError::from_err)?;
+        Ok((convert_json_value_to_py_object(&value)?, buf))
+    }
+
+    pub(super) fn notify_body_complete(&mut self, parts: &HttpResponseParts, callback: PyObject) {
+        self.1 = None;
+        let body = replace(
+            &mut self.0,
+            qiniu_sdk::http::SyncResponseBody::from_bytes(Vec::new()),
+        );
+        self.0 = qiniu_sdk::http::SyncResponseBody::from_reader(BodyCompleteNotifier::new(
+            body,
+            clone_response_parts(&parts.0),
+            callback,
+        ));
+    }
+
+    pub(super) fn limit_body_size(
+        &mut self,
+        parts: &HttpResponseParts,
+        max_body_size: u64,
+    ) -> PyResult<()> {
+        check_content_length(parts, max_body_size)?;
+        self.1 = None;
+        let body = replace(
+            &mut self.0,
+            qiniu_sdk::http::SyncResponseBody::from_bytes(Vec::new()),
+        );
+        self.0 = qiniu_sdk::http::SyncResponseBody::from_reader(SizeLimitedBody::new(
+            body,
+            max_body_size,
+        ));
+        Ok(())
+    }
+
+    fn decode_content(self_: &mut PyRefMut<'_, Self>, decode_content: bool) -> PyResult<()> {
+        let parts: &mut HttpResponseParts = self_.as_mut();
+        if let Some(encoding) = ContentEncoding::select(&mut parts.0, decode_content)? {
+            let body = replace(
+                &mut self_.0,
+                qiniu_sdk::http::SyncResponseBody::from_bytes(Vec::new()),
+            );
+            self_.0 = encoding.decode_sync_body(body);
+            self_.1 = None;
+        }
+        Ok(())
+    }
+
+    pub(super) fn decode_content_encoding(&mut self, parts: &mut HttpResponseParts) {
+        if let Some(encoding) = ContentEncoding::take_from(&mut parts.0) {
+            let body = replace(
+                &mut self.0,
+                qiniu_sdk::http::SyncResponseBody::from_bytes(Vec::new()),
+            );
+            self.0 = encoding.decode_sync_body(body);
+            self.1 = None;
+        }
+    }
+}
+
 impl_response_body!(SyncHttpResponse);
 
 impl From<qiniu_sdk::http::SyncResponseBody> for SyncHttpResponse {
     fn from(body: qiniu_sdk::http::SyncResponseBody) -> Self {
-        Self(body)
+        Self(body, None)
+    }
+}
+
+trait ReadSeek: Read + Seek + Debug + Send {}
+impl<T: Read + Seek + Debug + Send> ReadSeek for T {}
+
+/// 可寻址的响应体，读取和寻址共享同一个读取位置
+#[derive(Clone)]
+struct SeekableResponseBody(Arc<Mutex<Box<dyn ReadSeek>>>);
+
+impl SeekableResponseBody {
+    fn new(body: impl ReadSeek + 'static) -> Self {
+        Self(Arc::new(Mutex::new(Box::new(body))))
+    }
+
+    fn extract(body: &PyObject, py: Python<'_>) -> Option<Self> {
+        if let Ok(body) = body.extract::<String>(py) {
+            Some(Self::new(Cursor::new(body.into_bytes())))
+        } else if let Ok(body) = body.extract::<Vec<u8>>(py) {
+            Some(Self::new(Cursor::new(body)))
+        } else if body
+            .call_method0(py, "seekable")
+            .and_then(|seekable| seekable.extract::<bool>(py))
+            .unwrap_or(false)
+        {
+            Some(Self::new(PythonIoBase::new(body.clone_ref(py))))
+        } else {
+            None
+        }
+    }
+
+    fn seek(&self, pos: SeekFrom) -> IoResult<u64> {
+        self.0.lock().unwrap().seek(pos)
+    }
+}
+
+impl Read for SeekableResponseBody {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        self.0.lock().unwrap().read(buf)
+    }
+}
+
+impl Debug for SeekableResponseBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SeekableResponseBody").finish()
     }
 }
 
@@ -1266,12 +1808,24 @@ impl AsyncHttpResponse {
     }
 
     /// 异步读取响应体数据
-    #[pyo3(text_signature = "($self, size = -1, /)")]
-    #[args(size = "-1")]
-    fn read<'a>(&mut self, size: i64, py: Python<'a>) -> PyResult<&'a PyAny> {
-        let reader = self.0.to_owned();
+    ///
+    /// 默认根据 `Content-Encoding` 头自动解压 gzip 或 deflate 压缩的响应体，解压后将移除该头。
+    /// 是否解压在首次读取压缩过的响应体时确定，之后传入不同的 `decode_content` 将抛出 `ValueError`
+    #[pyo3(text_signature = "($self, size = -1, /, decode_content = True)")]
+    #[args(size = "-1", decode_content = "true")]
+    fn read<'a>(
+        mut self_: PyRefMut<'_, Self>,
+        size: i64,
+        decode_content: bool,
+        py: Python<'a>,
+    ) -> PyResult<&'a PyAny> {
+        let encoding = ContentEncoding::select(&mut self_.as_mut().0, decode_content)?;
+        let reader = self_.0.to_owned();
         pyo3_asyncio::async_std::future_into_py(py, async move {
             let mut reader = reader.lock().await;
+            if let Some(encoding) = encoding {
+                encoding.decode_async_body(&mut reader);
+            }
             let mut buf = Vec::new();
             if let Ok(size) = u64::try_from(size) {
                 buf.reserve(size as usize);
@@ -1279,15 +1833,40 @@ impl AsyncHttpResponse {
             } else {
                 reader.read_to_end(&mut buf).await
             }
-            .map_err(PyIOError::new_err)?;
+            .map_err(convert_body_read_error)?;
             Python::with_gil(|py| Ok(PyBytes::new(py, &buf).to_object(py)))
         })
     }
 
     /// 异步所有读取响应体数据
-    #[pyo3(text_signature = "($self)")]
-    fn readall<'a>(&mut self, py: Python<'a>) -> PyResult<&'a PyAny> {
-        self.read(-1, py)
+    ///
+    /// 默认根据 `Content-Encoding` 头自动解压 gzip 或 deflate 压缩的响应体，解压后将移除该头。
+    /// 是否解压在首次读取压缩过的响应体时确定，之后传入不同的 `decode_content` 将抛出 `ValueError`
+    ///
+    /// 如果传入 `progress` 回调函数，每读取一块数据后将以已读取的字节数调用该函数
+    #[pyo3(text_signature = "($self, /, decode_content = True, progress = None)")]
+    #[args(decode_content = "true", progress = "None")]
+    fn readall<'a>(
+        mut self_: PyRefMut<'_, Self>,
+        decode_content: bool,
+        progress: Option<PyObject>,
+        py: Python<'a>,
+    ) -> PyResult<&'a PyAny> {
+        let progress = match progress {
+            Some(progress) => progress,
+            None => return Self::read(self_, -1, decode_content, py),
+        };
+        let encoding = ContentEncoding::select(&mut self_.as_mut().0, decode_content)?;
+        let reader = self_.0.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let mut reader = reader.lock().await;
+            if let Some(encoding) = encoding {
+                encoding.decode_async_body(&mut reader);
+            }
+            let mut buf = Vec::new();
+            async_read_to_end_with_progress(&mut *reader, &mut buf, &progress).await?;
+            Python::with_gil(|py| Ok(PyBytes::new(py, &buf).to_object(py)))
+        })
     }
 
     #[pyo3(text_signature = "($self, b)")]
@@ -1296,6 +1875,51 @@ impl AsyncHttpResponse {
         Err(PyNotImplementedError::new_err("write"))
     }
 
+    #[pyo3(text_signature = "($self, offset, whence = 0)")]
+    #[args(whence = "0")]
+    fn seek(&self, offset: i64, whence: i64) -> PyResult<u64> {
+        let _offset = offset;
+        let _whence = whence;
+        Err(PyNotImplementedError::new_err("seek"))
+    }
+
+    #[pyo3(text_signature = "($self)")]
+    fn seekable(&self) -> bool {
+        false
+    }
+
+    #[pyo3(text_signature = "($self)")]
+    fn tell(&self) -> PyResult<u64> {
+        Err(PyNotImplementedError::new_err("tell"))
+    }
+
+    /// 异步读取所有响应体数据并解码为字符串
+    ///
+    /// 如果没有指定编码，则使用 `Content-Type` 头中的 `charset`，默认为 UTF-8
+    #[pyo3(text_signature = "($self, encoding = None, /)")]
+    #[args(encoding = "None")]
+    fn text<'a>(
+        mut self_: PyRefMut<'_, Self>,
+        encoding: Option<String>,
+        py: Python<'a>,
+    ) -> PyResult<&'a PyAny> {
+        let encoding = encoding.unwrap_or_else(|| get_charset(self_.as_ref().0.headers()));
+        let content_encoding = ContentEncoding::select(&mut self_.as_mut().0, true)?;
+        let reader = self_.0.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let mut reader = reader.lock().await;
+            if let Some(content_encoding) = content_encoding {
+                content_encoding.decode_async_body(&mut reader);
+            }
+            let mut buf = Vec::new();
+            reader
+                .read_to_end(&mut buf)
+                .await
+                .map_err(convert_body_read_error)?;
+            Python::with_gil(|py| decode_text(&buf, &encoding, py))
+        })
+    }
+
     /// 异步解析 JSON 响应体
     #[pyo3(text_signature = "($self)")]
     fn parse_json<'a>(&mut self, py: Python<'a>) -> PyResult<&'a PyAny> {
@@ -1305,16 +1929,57 @@ impl AsyncHttpResponse {
 }
 
 impl AsyncHttpResponse {
+    pub(super) async fn notify_body_complete(&self, parts: &HttpResponseParts, callback: PyObject) {
+        let mut body = self.0.lock().await;
+        let inner = replace(
+            &mut *body,
+            qiniu_sdk::http::AsyncResponseBody::from_bytes(Vec::new()),
+        );
+        *body = qiniu_sdk::http::AsyncResponseBody::from_reader(BodyCompleteNotifier::new(
+            inner,
+            clone_response_parts(&parts.0),
+            callback,
+        ));
+    }
+
+    pub(super) async fn decode_content_encoding(&self, parts: &mut HttpResponseParts) {
+        if let Some(encoding) = ContentEncoding::take_from(&mut parts.0) {
+            encoding.decode_async_body(&mut *self.0.lock().await);
+        }
+    }
+
+    pub(super) async fn limit_body_size(
+        &self,
+        parts: &HttpResponseParts,
+        max_body_size: u64,
+    ) -> PyResult<()> {
+        check_content_length(parts, max_body_size)?;
+        let mut body = self.0.lock().await;
+        let inner = replace(
+            &mut *body,
+            qiniu_sdk::http::AsyncResponseBody::from_bytes(Vec::new()),
+        );
+        *body = qiniu_sdk::http::AsyncResponseBody::from_reader(SizeLimitedBody::new(
+            inner,
+            max_body_size,
+        ));
+        Ok(())
+    }
+
     pub(super) async fn _parse_json(&mut self) -> PyResult<PyObject> {
+        Ok(self._parse_json_with_raw().await?.0)
+    }
+
+    pub(super) async fn _parse_json_with_raw(&mut self) -> PyResult<(PyObject, Vec<u8>)> {
         let mut reader = self.0.lock().await;
         let mut buf = Vec::new();
         reader
             .read_to_end(&mut buf)
             .await
-            .map_err(PyIOError::new_err)?;
+            .map_err(convert_body_read_error)?;
         let value: serde_json::Value =
             serde_json::from_slice(&buf).map_err(QiniuJsonError::from_err)?;
-        convert_json_value_to_py_object(&value)
+        Ok((convert_json_value_to_py_object(&value)?, buf))
     }
 }
 
@@ -1326,6 +1991,247 @@ impl From<qiniu_sdk::http::AsyncResponseBody> for AsyncHttpResponse {
     }
 }
 
+/// 复制响应元信息
+///
+/// `ResponseParts` 由于包含扩展而无法直接克隆，这里只复制状态码、HTTP 头、版本、服务器地址和统计信息
+fn clone_response_parts(parts: &qiniu_sdk::http::ResponseParts) -> qiniu_sdk::http::ResponseParts {
+    let (mut cloned, _) = qiniu_sdk::http::Response::<()>::default().into_parts_and_body();
+    *cloned.status_code_mut() = parts.status_code();
+    *cloned.headers_mut() = parts.headers().to_owned();
+    *cloned.version_mut() = parts.version();
+    *cloned.server_ip_mut() = parts.server_ip();
+    *cloned.server_port_mut() = parts.server_port();
+    *cloned.metrics_mut() = parts.metrics().cloned();
+    cloned
+}
+
+#[derive(Debug)]
+struct BodyCompleteNotifier<B> {
+    body: B,
+    parts: Option<qiniu_sdk::http::ResponseParts>,
+    callback: PyObject,
+}
+
+impl<B> BodyCompleteNotifier<B> {
+    fn new(body: B, parts: qiniu_sdk::http::ResponseParts, callback: PyObject) -> Self {
+        Self {
+            body,
+            parts: Some(parts),
+            callback,
+        }
+    }
+
+    fn notify(&mut self) -> IoResult<()> {
+        if let Some(parts) = self.parts.take() {
+            Python::with_gil(|py| self.callback.call1(py, (HttpResponseParts(parts),)))
+                .map_err(|err| IoError::new(IoErrorKind::Other, err))?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: Read> Read for BodyCompleteNotifier<B> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let have_read = self.body.read(buf)?;
+        if have_read == 0 && !buf.is_empty() {
+            self.notify()?;
+        }
+        Ok(have_read)
+    }
+}
+
+impl<B: AsyncRead + Unpin> AsyncRead for BodyCompleteNotifier<B> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<IoResult<usize>> {
+        let have_read = ready!(Pin::new(&mut self.body).poll_read(cx, buf))?;
+        if have_read == 0 && !buf.is_empty() {
+            self.notify()?;
+        }
+        Poll::Ready(Ok(have_read))
+    }
+}
+
+fn check_content_length(parts: &HttpResponseParts, max_body_size: u64) -> PyResult<()> {
+    let content_length = parts
+        .0
+        .headers()
+        .get(qiniu_sdk::http::header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+    match content_length {
+        Some(content_length) if content_length > max_body_size => Err(
+            QiniuResponseTooLargeError::new_err(ResponseBodyTooLarge { max_body_size }.to_string()),
+        ),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug)]
+struct SizeLimitedBody<B> {
+    body: B,
+    max_body_size: u64,
+    have_read: u64,
+}
+
+impl<B> SizeLimitedBody<B> {
+    fn new(body: B, max_body_size: u64) -> Self {
+        Self {
+            body,
+            max_body_size,
+            have_read: 0,
+        }
+    }
+
+    fn check(&mut self, have_read: usize) -> IoResult<()> {
+        self.have_read += have_read as u64;
+        if self.have_read > self.max_body_size {
+            return Err(IoError::new(
+                IoErrorKind::Other,
+                ResponseBodyTooLarge {
+                    max_body_size: self.max_body_size,
+                },
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<B: Read> Read for SizeLimitedBody<B> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let have_read = self.body.read(buf)?;
+        self.check(have_read)?;
+        Ok(have_read)
+    }
+}
+
+impl<B: AsyncRead + Unpin> AsyncRead for SizeLimitedBody<B> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<IoResult<usize>> {
+        let have_read = ready!(Pin::new(&mut self.body).poll_read(cx, buf))?;
+        self.check(have_read)?;
+        Poll::Ready(Ok(have_read))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ContentEncoding {
+    Gzip,
+    Deflate,
+}
+
+/// 记录压缩过的响应体是否被解压，确定后不再改变
+#[derive(Debug, Clone, Copy)]
+struct DecodeContentMode(bool);
+
+impl ContentEncoding {
+    fn parse(parts: &qiniu_sdk::http::ResponseParts) -> Option<Self> {
+        let encoding = parts
+            .headers()
+            .get(qiniu_sdk::http::header::CONTENT_ENCODING)?
+            .to_str()
+            .ok()?
+            .trim()
+            .to_ascii_lowercase();
+        let encoding = match encoding.as_str() {
+            "gzip" | "x-gzip" => Self::Gzip,
+            "deflate" => Self::Deflate,
+            _ => return None,
+        };
+        Some(encoding)
+    }
+
+    fn take_from(parts: &mut qiniu_sdk::http::ResponseParts) -> Option<Self> {
+        let encoding = Self::parse(parts)?;
+        parts
+            .headers_mut()
+            .remove(qiniu_sdk::http::header::CONTENT_ENCODING);
+        parts.extensions_mut().insert(DecodeContentMode(true));
+        Some(encoding)
+    }
+
+    fn select(
+        parts: &mut qiniu_sdk::http::ResponseParts,
+        decode_content: bool,
+    ) -> PyResult<Option<Self>> {
+        if let Some(&DecodeContentMode(mode)) = parts.extensions().get::<DecodeContentMode>() {
+            return if mode == decode_content {
+                Ok(None)
+            } else {
+                Err(PyValueError::new_err(
+                    "decode_content can not be changed after the response body has been read",
+                ))
+            };
+        }
+        if decode_content {
+            Ok(Self::take_from(parts))
+        } else {
+            if Self::parse(parts).is_some() {
+                parts.extensions_mut().insert(DecodeContentMode(false));
+            }
+            Ok(None)
+        }
+    }
+
+    fn decode_sync_body(
+        self,
+        body: qiniu_sdk::http::SyncResponseBody,
+    ) -> qiniu_sdk::http::SyncResponseBody {
+        match self {
+            Self::Gzip => {
+                qiniu_sdk::http::SyncResponseBody::from_reader(flate2::read::GzDecoder::new(body))
+            }
+            Self::Deflate => {
+                qiniu_sdk::http::SyncResponseBody::from_reader(flate2::read::ZlibDecoder::new(body))
+            }
+        }
+    }
+
+    fn decode_async_body(self, body: &mut qiniu_sdk::http::AsyncResponseBody) {
+        let reader = futures::io::BufReader::new(replace(
+            body,
+            qiniu_sdk::http::AsyncResponseBody::from_bytes(Vec::new()),
+        ));
+        *body = match self {
+            Self::Gzip => qiniu_sdk::http::AsyncResponseBody::from_reader(
+                async_compression::futures::bufread::GzipDecoder::new(reader),
+            ),
+            Self::Deflate => qiniu_sdk::http::AsyncResponseBody::from_reader(
+                async_compression::futures::bufread::ZlibDecoder::new(reader),
+            ),
+        };
+    }
+}
+
+/// 由本库负责解压响应体，因此关闭 isahc 的自动解压，避免同一个响应体被解压两次
+pub(super) fn disable_automatic_decompression(extensions: &mut qiniu_sdk::http::Extensions) {
+    extensions.insert(qiniu_sdk::isahc::AutomaticDecompressionRequestExtension::new(false));
+}
+
+fn get_charset(headers: &qiniu_sdk::http::HeaderMap) -> String {
+    headers
+        .get(qiniu_sdk::http::header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(|content_type| content_type.parse::<mime::Mime>().ok())
+        .and_then(|content_type| {
+            content_type
+                .get_param(mime::CHARSET)
+                .map(|charset| charset.as_str().to_owned())
+        })
+        .unwrap_or_else(|| "utf-8".to_owned())
+}
+
+fn decode_text(buf: &[u8], encoding: &str, py: Python<'_>) -> PyResult<PyObject> {
+    Ok(PyBytes::new(py, buf)
+        .call_method1("decode", (encoding,))?
+        .to_object(py))
+}
+
 fn on_uploading_progress(callback: PyObject) -> qiniu_sdk::http::OnProgressCallback<'static> {
     qiniu_sdk::http::OnProgressCallback::new(move |progress| {
         Python::with_gil(|py| {
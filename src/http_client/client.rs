@@ -1,32 +1,52 @@
-use super::region::ServiceName;
+use super::region::{IpAddrWithPort, ServiceName};
 use crate::{
     credential::CredentialProvider,
     exceptions::{
         QiniuApiCallError, QiniuApiCallErrorInfo, QiniuAuthorizationError,
         QiniuBodySizeMissingError, QiniuEmptyChainedResolver, QiniuHeaderValueEncodingError,
-        QiniuInvalidPrefixLengthError, QiniuIoError, QiniuIsahcError, QiniuJsonError,
+        QiniuInvalidBackoffJitter, QiniuInvalidPrefixLengthError, QiniuInvalidRateLimit,
+        QiniuInvalidRatio, QiniuIoError, QiniuIsahcError, QiniuJsonError, QiniuTimeoutError,
         QiniuTrustDNSError,
     },
     http::{
         AsyncHttpRequest, AsyncHttpResponse, HttpCaller, HttpRequestParts, HttpResponseParts,
-        HttpResponsePartsRef, Metrics, SyncHttpRequest, SyncHttpResponse, TransferProgressInfo,
-        Version,
+        HttpResponsePartsRef, Metrics, RedirectHistory, ResolvedIpAddrs, SyncHttpRequest,
+        SyncHttpResponse, TransferProgressInfo, Version,
     },
     upload_token::UploadTokenProvider,
     utils::{
         convert_api_call_error, convert_headers_to_hashmap, convert_py_any_to_json_value,
-        extract_async_multipart, extract_endpoints_provider, extract_ip_addrs_with_port,
-        extract_sync_multipart, parse_domain_with_port, parse_header_name, parse_header_value,
-        parse_headers, parse_ip_addr_with_port, parse_ip_addrs, parse_method, parse_mime,
-        parse_query_pairs, PythonIoBase,
+        extract_async_multipart, extract_endpoints_provider, extract_sync_multipart, get_body_len,
+        instant_after, parse_domain_with_port, parse_header_name, parse_header_value,
+        parse_headers, parse_ip_addr, parse_ip_addr_with_port, parse_ip_addrs, parse_method,
+        parse_mime, parse_query_pairs, parse_uri, unpicklable_error, ProgressThrottle,
+        PythonIoBase,
     },
 };
 use anyhow::Result as AnyResult;
+use futures::AsyncReadExt;
 use maybe_owned::MaybeOwned;
+use mime::Mime;
 use num_integer::Integer;
-use pyo3::{prelude::*, types::PyIterator};
+use pyo3::{
+    once_cell::GILOnceCell,
+    prelude::*,
+    types::{PyBytes, PyDict, PyFloat, PyIterator},
+};
 use qiniu_sdk::prelude::AuthorizationProvider;
-use std::{borrow::Cow, collections::HashMap, mem::transmute, path::PathBuf, time::Duration};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fs,
+    future::Future,
+    io::{Error as IoError, ErrorKind as IoErrorKind, Read, Result as IoResult, Write},
+    mem::transmute,
+    net::IpAddr,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard, RwLock},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<Authorization>()?;
@@ -38,6 +58,7 @@ pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<CachedResolver>()?;
     m.add_class::<ChainedResolver>()?;
     m.add_class::<TrustDnsResolver>()?;
+    m.add_class::<DohResolver>()?;
     m.add_class::<Chooser>()?;
     m.add_class::<DirectChooser>()?;
     m.add_class::<IpChooser>()?;
@@ -55,12 +76,16 @@ pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<RandomizedBackoff>()?;
     m.add_class::<ExponentialBackoff>()?;
     m.add_class::<LimitedBackoff>()?;
+    m.add_class::<JitteredExponentialBackoff>()?;
     m.add_class::<HttpClient>()?;
     m.add_class::<SimplifiedCallbackContext>()?;
     m.add_class::<CallbackContextMut>()?;
     m.add_class::<ExtendedCallbackContextRef>()?;
     m.add_class::<RequestBuilderPartsRef>()?;
     m.add_class::<JsonResponse>()?;
+    m.add_class::<MultipartForm>()?;
+    m.add_function(wrap_pyfunction!(default_shared_http_client, m)?)?;
+    m.add_function(wrap_pyfunction!(set_default_shared_http_client, m)?)?;
 
     Ok(())
 }
@@ -131,6 +156,51 @@ impl Authorization {
         )
     }
 
+    /// 计算 HTTP 请求的 Authorization 头的值，无需构建 HTTP 请求
+    ///
+    /// 适用于使用其他 HTTP 库发送请求的场景，如果签名算法不会设置 Authorization 头（例如下载凭证签名算法），则返回 `None`
+    #[pyo3(text_signature = "($self, method, url, /, headers = None, body = None)")]
+    #[args(headers = "None", body = "None")]
+    fn authorization_header_for(
+        &self,
+        method: &str,
+        url: &str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        py: Python<'_>,
+    ) -> PyResult<Option<String>> {
+        let mut request = Self::make_request(method, url, headers, body)?;
+        py.allow_threads(|| {
+            self.0
+                .sign(&mut request)
+                .map_err(QiniuAuthorizationError::from_err)
+        })?;
+        Self::get_authorization_header(&request)
+    }
+
+    /// 异步计算 HTTP 请求的 Authorization 头的值，无需构建 HTTP 请求
+    ///
+    /// 适用于使用其他 HTTP 库发送请求的场景，如果签名算法不会设置 Authorization 头（例如下载凭证签名算法），则返回 `None`
+    #[pyo3(text_signature = "($self, method, url, /, headers = None, body = None)")]
+    #[args(headers = "None", body = "None")]
+    fn async_authorization_header_for<'p>(
+        &self,
+        method: &str,
+        url: &str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let auth = self.0.to_owned();
+        let mut request = Self::make_request(method, url, headers, body)?;
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            auth.async_sign(&mut request)
+                .await
+                .map_err(QiniuAuthorizationError::from_err)?;
+            Self::get_authorization_header(&request)
+        })
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -140,6 +210,40 @@ impl Authorization {
     }
 }
 
+impl Authorization {
+    fn make_request<B: From<Vec<u8>>>(
+        method: &str,
+        url: &str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+    ) -> PyResult<qiniu_sdk::http::Request<'static, B>> {
+        let mut builder = qiniu_sdk::http::RequestParts::builder();
+        builder.method(parse_method(method)?).url(parse_uri(url)?);
+        if let Some(headers) = headers {
+            builder.headers(parse_headers(headers)?);
+        }
+        Ok(qiniu_sdk::http::Request::from_parts_and_body(
+            builder.build(),
+            B::from(body.unwrap_or_default()),
+        ))
+    }
+
+    fn get_authorization_header<B>(
+        request: &qiniu_sdk::http::Request<'_, B>,
+    ) -> PyResult<Option<String>> {
+        request
+            .headers()
+            .get(qiniu_sdk::http::header::AUTHORIZATION)
+            .map(|value| {
+                value
+                    .to_str()
+                    .map(|value| value.to_owned())
+                    .map_err(QiniuHeaderValueEncodingError::from_err)
+            })
+            .transpose()
+    }
+}
+
 impl From<qiniu_sdk::http_client::Authorization<'static>> for Authorization {
     fn from(authorization: qiniu_sdk::http_client::Authorization<'static>) -> Self {
         Self(authorization)
@@ -155,52 +259,60 @@ impl From<Authorization> for qiniu_sdk::http_client::Authorization<'static> {
 /// 重试统计信息
 ///
 /// 通过 `RetriedStatsInfo()` 创建重试统计信息
+///
+/// 创建时即记录首次尝试的时间，之后每次记录重试或切换地址时都会更新最近一次尝试的时间
 #[pyclass]
 #[pyo3(text_signature = "()")]
 #[derive(Clone)]
-struct RetriedStatsInfo(qiniu_sdk::http_client::RetriedStatsInfo);
+struct RetriedStatsInfo(qiniu_sdk::http_client::RetriedStatsInfo, AttemptTimestamps);
 
 #[pymethods]
 impl RetriedStatsInfo {
     #[new]
     fn new() -> Self {
-        RetriedStatsInfo(Default::default())
+        RetriedStatsInfo(Default::default(), AttemptTimestamps::now())
     }
 
     /// 提升当前终端地址的重试次数
     #[pyo3(text_signature = "($self)")]
     fn increase_current_endpoint(&mut self) {
         self.0.increase_current_endpoint();
+        self.1.record();
     }
 
     /// 提升放弃的终端地址的数量
     #[pyo3(text_signature = "($self)")]
     fn increase_abandoned_endpoints(&mut self) {
         self.0.increase_abandoned_endpoints();
+        self.1.record();
     }
 
     /// 提升放弃的终端的 IP 地址的数量
     #[pyo3(text_signature = "($self)")]
     fn increase_abandoned_ips_of_current_endpoint(&mut self) {
-        self.0.increase_abandoned_ips_of_current_endpoint()
+        self.0.increase_abandoned_ips_of_current_endpoint();
+        self.1.record();
     }
 
     /// 切换到备选终端地址
     #[pyo3(text_signature = "($self)")]
     fn switch_to_alternative_endpoints(&mut self) {
         self.0.switch_to_alternative_endpoints();
+        self.1.record();
     }
 
     /// 切换终端地址
     #[pyo3(text_signature = "($self)")]
     fn switch_endpoint(&mut self) {
         self.0.switch_endpoint();
+        self.1.record();
     }
 
     /// 切换当前 IP 地址
     #[pyo3(text_signature = "($self)")]
     fn switch_ips(&mut self) {
         self.0.switch_ips();
+        self.1.record();
     }
 
     /// 获取总共重试的次数
@@ -239,6 +351,24 @@ impl RetriedStatsInfo {
         self.0.switched_to_alternative_endpoints()
     }
 
+    /// 获取首次尝试的时间戳，单位为纳秒
+    #[getter]
+    fn get_first_attempt_at_ns(&self) -> Option<u128> {
+        self.1.first.map(unix_timestamp_ns)
+    }
+
+    /// 获取最近一次尝试的时间戳，单位为纳秒
+    #[getter]
+    fn get_last_attempt_at_ns(&self) -> Option<u128> {
+        self.1.last.map(unix_timestamp_ns)
+    }
+
+    /// 获取从首次尝试到最近一次尝试总共经过的时间，单位为纳秒
+    #[getter]
+    fn get_elapsed_ns(&self) -> Option<u128> {
+        self.1.elapsed().map(|elapsed| elapsed.as_nanos())
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -248,6 +378,42 @@ impl RetriedStatsInfo {
     }
 }
 
+/// 尝试时间记录
+///
+/// SDK 内部的重试统计信息并不记录时间，因此由 HTTP 客户端在每次尝试发出请求前将其记录在请求扩展中
+#[derive(Clone, Copy, Debug, Default)]
+struct AttemptTimestamps {
+    first: Option<SystemTime>,
+    last: Option<SystemTime>,
+}
+
+impl AttemptTimestamps {
+    fn now() -> Self {
+        let now = SystemTime::now();
+        Self {
+            first: Some(now),
+            last: Some(now),
+        }
+    }
+
+    fn record(&mut self) {
+        let now = SystemTime::now();
+        self.first.get_or_insert(now);
+        self.last = Some(now);
+    }
+
+    fn elapsed(&self) -> Option<Duration> {
+        let (first, last) = self.first.zip(self.last)?;
+        Some(last.duration_since(first).unwrap_or_default())
+    }
+}
+
+fn unix_timestamp_ns(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+}
+
 impl AsRef<qiniu_sdk::http_client::RetriedStatsInfo> for RetriedStatsInfo {
     fn as_ref(&self) -> &qiniu_sdk::http_client::RetriedStatsInfo {
         &self.0
@@ -421,8 +587,8 @@ impl ShuffledResolver {
 #[pyo3(
     text_signature = "(resolver, /, auto_persistent = None, cache_lifetime_secs = None, shrink_interval_secs = None)"
 )]
-#[derive(Clone, Copy)]
-struct CachedResolver;
+#[derive(Clone, Debug)]
+struct CachedResolver(CachedResolverImpl);
 
 #[pymethods]
 impl CachedResolver {
@@ -439,12 +605,11 @@ impl CachedResolver {
         cache_lifetime_secs: Option<u64>,
         shrink_interval_secs: Option<u64>,
     ) -> (Self, Resolver) {
-        (
-            Self,
-            Resolver(Box::new(
-                Self::new_builder(resolver, cache_lifetime_secs, shrink_interval_secs)
-                    .default_load_or_create_from(auto_persistent),
-            )),
+        Self::new_with(
+            resolver,
+            CachedResolverPersistence::Default { auto_persistent },
+            cache_lifetime_secs,
+            shrink_interval_secs,
         )
     }
 
@@ -471,12 +636,14 @@ impl CachedResolver {
     ) -> PyResult<Py<Self>> {
         Py::new(
             py,
-            (
-                Self,
-                Resolver(Box::new(
-                    Self::new_builder(resolver, cache_lifetime_secs, shrink_interval_secs)
-                        .load_or_create_from(path, auto_persistent),
-                )),
+            Self::new_with(
+                resolver,
+                CachedResolverPersistence::Path {
+                    path,
+                    auto_persistent,
+                },
+                cache_lifetime_secs,
+                shrink_interval_secs,
             ),
         )
     }
@@ -498,31 +665,369 @@ impl CachedResolver {
     ) -> PyResult<Py<Self>> {
         Py::new(
             py,
-            (
-                Self,
-                Resolver(Box::new(
-                    Self::new_builder(resolver, cache_lifetime_secs, shrink_interval_secs)
-                        .in_memory(),
-                )),
+            Self::new_with(
+                resolver,
+                CachedResolverPersistence::InMemory,
+                cache_lifetime_secs,
+                shrink_interval_secs,
             ),
         )
     }
+
+    /// 将尚未写入的缓存立即同步写入持久化文件，返回时写入已经完成
+    ///
+    /// 无论是否启用自动持久化缓存都会写入，对于不启用文件系统持久化缓存的域名解析缓存器，该方法不做任何事
+    #[pyo3(text_signature = "($self)")]
+    fn persist(&self, py: Python<'_>) -> PyResult<()> {
+        py.allow_threads(|| self.0.persist())
+            .map_err(QiniuIoError::from_err)
+    }
+
+    /// 清空所有缓存的域名解析结果
+    ///
+    /// 对于启用文件系统持久化缓存的域名解析缓存器，将同时删除持久化的缓存文件，之后的解析不会再加载被清空的缓存项
+    #[pyo3(text_signature = "($self)")]
+    fn clear(&self, py: Python<'_>) -> PyResult<()> {
+        py.allow_threads(|| self.0.clear())
+            .map_err(QiniuIoError::from_err)
+    }
+
+    /// 获取尚未过期的缓存项数量，包括从持久化文件中加载的缓存项
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
 }
 
 impl CachedResolver {
-    fn new_builder(
+    fn new_with(
         resolver: Resolver,
+        persistence: CachedResolverPersistence,
         cache_lifetime_secs: Option<u64>,
         shrink_interval_secs: Option<u64>,
-    ) -> qiniu_sdk::http_client::CachedResolverBuilder<Resolver> {
-        let mut builder = qiniu_sdk::http_client::CachedResolverBuilder::new(resolver);
-        if let Some(cache_lifetime) = cache_lifetime_secs {
-            builder = builder.cache_lifetime(Duration::from_secs(cache_lifetime));
+    ) -> (Self, Resolver) {
+        let cached_resolver = CachedResolverImpl::new(
+            resolver,
+            persistence,
+            Duration::from_secs(cache_lifetime_secs.unwrap_or(120)),
+            Duration::from_secs(shrink_interval_secs.unwrap_or(120)),
+        );
+        (
+            Self(cached_resolver.to_owned()),
+            Resolver(Box::new(cached_resolver)),
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct CachedResolverImpl(Arc<CachedResolverInner>);
+
+/// SDK 的域名解析缓存器仅用于查询缓存，其自动持久化功能总是关闭，持久化由 `resolved` 记录的缓存项同步写入，
+/// 写入格式与 SDK 的持久化文件保持一致
+#[derive(Debug)]
+struct CachedResolverInner {
+    resolver: Resolver,
+    persistence: CachedResolverPersistence,
+    path: Option<PathBuf>,
+    cache_lifetime: Duration,
+    shrink_interval: Duration,
+    cached: RwLock<Arc<qiniu_sdk::http_client::CachedResolver<Resolver>>>,
+    resolved: Mutex<ResolvedEntries>,
+    persisting: Mutex<()>,
+}
+
+#[derive(Debug, Default)]
+struct ResolvedEntries {
+    entries: HashMap<String, ResolvedEntry>,
+    unpersisted: HashSet<String>,
+}
+
+#[derive(Debug)]
+struct ResolvedEntry {
+    answers: qiniu_sdk::http_client::ResolveAnswers,
+    cached_at: SystemTime,
+}
+
+#[derive(Debug, Clone)]
+enum CachedResolverPersistence {
+    Default {
+        auto_persistent: bool,
+    },
+    Path {
+        path: PathBuf,
+        auto_persistent: bool,
+    },
+    InMemory,
+}
+
+impl CachedResolverPersistence {
+    fn auto_persistent(&self) -> bool {
+        match self {
+            Self::Default { auto_persistent }
+            | Self::Path {
+                auto_persistent, ..
+            } => *auto_persistent,
+            Self::InMemory => false,
+        }
+    }
+}
+
+impl CachedResolverImpl {
+    fn new(
+        resolver: Resolver,
+        persistence: CachedResolverPersistence,
+        cache_lifetime: Duration,
+        shrink_interval: Duration,
+    ) -> Self {
+        let cached = Self::build(&resolver, &persistence, cache_lifetime, shrink_interval);
+        let path = cached.persistent_path().map(|path| path.to_owned());
+        let entries = path
+            .as_deref()
+            .map(|path| load_resolved_entries(path, cache_lifetime))
+            .unwrap_or_default();
+        Self(Arc::new(CachedResolverInner {
+            resolver,
+            persistence,
+            path,
+            cache_lifetime,
+            shrink_interval,
+            cached: RwLock::new(Arc::new(cached)),
+            resolved: Mutex::new(ResolvedEntries {
+                entries,
+                unpersisted: Default::default(),
+            }),
+            persisting: Default::default(),
+        }))
+    }
+
+    fn build(
+        resolver: &Resolver,
+        persistence: &CachedResolverPersistence,
+        cache_lifetime: Duration,
+        shrink_interval: Duration,
+    ) -> qiniu_sdk::http_client::CachedResolver<Resolver> {
+        let builder = qiniu_sdk::http_client::CachedResolverBuilder::new(resolver.to_owned())
+            .cache_lifetime(cache_lifetime)
+            .shrink_interval(shrink_interval);
+        match persistence {
+            CachedResolverPersistence::Default { .. } => builder.default_load_or_create_from(false),
+            CachedResolverPersistence::Path { path, .. } => {
+                builder.load_or_create_from(path, false)
+            }
+            CachedResolverPersistence::InMemory => builder.in_memory(),
+        }
+    }
+
+    fn cached(&self) -> Arc<qiniu_sdk::http_client::CachedResolver<Resolver>> {
+        self.0
+            .cached
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .to_owned()
+    }
+
+    fn record_resolved(&self, domain: &str, answers: &qiniu_sdk::http_client::ResolveAnswers) {
+        let mut resolved = self.0.lock_resolved();
+        if resolved
+            .entries
+            .get(domain)
+            .map_or(false, |entry| self.0.is_valid(entry))
+        {
+            return;
         }
-        if let Some(shrink_interval) = shrink_interval_secs {
-            builder = builder.shrink_interval(Duration::from_secs(shrink_interval));
+        resolved.entries.insert(
+            domain.to_owned(),
+            ResolvedEntry {
+                answers: answers.to_owned(),
+                cached_at: SystemTime::now(),
+            },
+        );
+        if self.0.path.is_some() {
+            resolved.unpersisted.insert(domain.to_owned());
         }
-        builder
+        drop(resolved);
+        if self.0.persistence.auto_persistent() {
+            let cached_resolver = self.to_owned();
+            thread::spawn(move || cached_resolver.0.persist().ok());
+        }
+    }
+
+    fn len(&self) -> usize {
+        let mut resolved = self.0.lock_resolved();
+        let ResolvedEntries {
+            entries,
+            unpersisted,
+        } = &mut *resolved;
+        entries.retain(|_, entry| self.0.is_valid(entry));
+        unpersisted.retain(|domain| entries.contains_key(domain));
+        entries.len()
+    }
+
+    fn persist(&self) -> IoResult<()> {
+        self.0.persist()
+    }
+
+    fn clear(&self) -> IoResult<()> {
+        let _persisting = self
+            .0
+            .persisting
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *self.0.lock_resolved() = Default::default();
+        let mut cached = self
+            .0
+            .cached
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(path) = &self.0.path {
+            match fs::remove_file(path) {
+                Err(err) if err.kind() != IoErrorKind::NotFound => return Err(err),
+                _ => {}
+            }
+        }
+        // 新的缓存器在首次解析时才会加载持久化文件，因此必须在删除文件后再替换
+        *cached = Arc::new(Self::build(
+            &self.0.resolver,
+            &self.0.persistence,
+            self.0.cache_lifetime,
+            self.0.shrink_interval,
+        ));
+        Ok(())
+    }
+}
+
+impl CachedResolverInner {
+    fn lock_resolved(&self) -> MutexGuard<'_, ResolvedEntries> {
+        self.resolved
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn is_valid(&self, entry: &ResolvedEntry) -> bool {
+        entry
+            .cached_at
+            .elapsed()
+            .map_or(false, |elapsed| elapsed < self.cache_lifetime)
+    }
+
+    /// 将尚未写入的缓存项同步追加到持久化文件，写入失败的缓存项将在下次持久化时重新写入
+    fn persist(&self) -> IoResult<()> {
+        let path = if let Some(path) = &self.path {
+            path
+        } else {
+            return Ok(());
+        };
+        let _persisting = self
+            .persisting
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let (domains, lines) = {
+            let mut resolved = self.lock_resolved();
+            let domains = resolved.unpersisted.drain().collect::<Vec<_>>();
+            let mut lines = Vec::with_capacity(domains.len());
+            for domain in domains.iter() {
+                if let Some(entry) = resolved.entries.get(domain) {
+                    lines.push(serde_json::to_string(&serde_json::json!({
+                        "key": domain,
+                        "value": {
+                            "value": entry.answers,
+                            "cached_at": entry.cached_at,
+                        },
+                    }))?);
+                }
+            }
+            (domains, lines)
+        };
+        if lines.is_empty() {
+            return Ok(());
+        }
+        append_lines(path, &lines).map_err(|err| {
+            self.lock_resolved().unpersisted.extend(domains);
+            err
+        })
+    }
+}
+
+impl Drop for CachedResolverInner {
+    fn drop(&mut self) {
+        if self.persistence.auto_persistent() {
+            self.persist().ok();
+        }
+    }
+}
+
+fn load_resolved_entries(path: &Path, cache_lifetime: Duration) -> HashMap<String, ResolvedEntry> {
+    let mut entries = HashMap::new();
+    let content = if let Ok(content) = fs::read_to_string(path) {
+        content
+    } else {
+        return entries;
+    };
+    for line in content.lines() {
+        let mut entry = if let Ok(entry) = serde_json::from_str::<serde_json::Value>(line) {
+            entry
+        } else {
+            continue;
+        };
+        let domain = if let Some(domain) = entry["key"].as_str() {
+            domain.to_owned()
+        } else {
+            continue;
+        };
+        let value = entry["value"].take();
+        if value.is_null() {
+            entries.remove(&domain);
+            continue;
+        }
+        if let (Ok(answers), Ok(cached_at)) = (
+            serde_json::from_value(value["value"].to_owned()),
+            serde_json::from_value::<SystemTime>(value["cached_at"].to_owned()),
+        ) {
+            if cached_at
+                .elapsed()
+                .map_or(false, |elapsed| elapsed < cache_lifetime)
+            {
+                entries.insert(domain, ResolvedEntry { answers, cached_at });
+            }
+        }
+    }
+    entries
+}
+
+fn append_lines(path: &Path, lines: &[String]) -> IoResult<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    let mut buf = lines.join("\n");
+    buf.push('\n');
+    file.write_all(buf.as_bytes())?;
+    file.sync_data()
+}
+
+impl qiniu_sdk::http_client::Resolver for CachedResolverImpl {
+    fn resolve(
+        &self,
+        domain: &str,
+        opts: qiniu_sdk::http_client::ResolveOptions<'_>,
+    ) -> qiniu_sdk::http_client::ResolveResult {
+        let answers = self.cached().resolve(domain, opts)?;
+        self.record_resolved(domain, &answers);
+        Ok(answers)
+    }
+
+    fn async_resolve<'a>(
+        &'a self,
+        domain: &'a str,
+        opts: qiniu_sdk::http_client::ResolveOptions<'a>,
+    ) -> futures::future::BoxFuture<'a, qiniu_sdk::http_client::ResolveResult> {
+        Box::pin(async move {
+            let answers = self.cached().async_resolve(domain, opts).await?;
+            self.record_resolved(domain, &answers);
+            Ok(answers)
+        })
     }
 }
 
@@ -575,6 +1080,235 @@ impl TrustDnsResolver {
     }
 }
 
+/// DNS-over-HTTPS 域名解析器
+///
+/// 通过 DoH 服务器的 JSON API 查询域名的 A 记录和 AAAA 记录，适用于普通 DNS 查询被劫持或屏蔽的网络环境。
+/// 如果设置了备选域名解析器，则在 DoH 查询失败时使用备选域名解析器解析域名。
+///
+/// 通过 `DohResolver(endpoint_url, fallback = None, timeout_ms = None, http_caller = None)` 创建 DNS-over-HTTPS 域名解析器
+#[pyclass(extends = Resolver)]
+#[pyo3(
+    text_signature = "(endpoint_url, /, fallback = None, timeout_ms = None, http_caller = None)"
+)]
+#[derive(Clone, Copy)]
+struct DohResolver;
+
+#[pymethods]
+impl DohResolver {
+    #[new]
+    #[args(fallback = "None", timeout_ms = "None", http_caller = "None")]
+    fn new(
+        endpoint_url: String,
+        fallback: Option<Resolver>,
+        timeout_ms: Option<u64>,
+        http_caller: Option<HttpCaller>,
+    ) -> PyResult<(Self, Resolver)> {
+        parse_uri(&endpoint_url)?;
+        let http_caller = if let Some(http_caller) = http_caller {
+            http_caller
+        } else {
+            HttpCaller::new(
+                qiniu_sdk::isahc::Client::default_client().map_err(QiniuIsahcError::from_err)?,
+            )
+        };
+        Ok((
+            Self,
+            Resolver(Box::new(DohResolverImpl {
+                endpoint_url,
+                fallback,
+                timeout: Duration::from_millis(timeout_ms.unwrap_or(5000)),
+                http_caller,
+            })),
+        ))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DohResolverImpl {
+    endpoint_url: String,
+    fallback: Option<Resolver>,
+    timeout: Duration,
+    http_caller: HttpCaller,
+}
+
+const DOH_RECORD_TYPES: [u16; 2] = [1, 28];
+
+impl DohResolverImpl {
+    fn make_request_parts(
+        &self,
+        domain: &str,
+        record_type: u16,
+    ) -> Result<qiniu_sdk::http::RequestParts<'static>, qiniu_sdk::http_client::ResponseError> {
+        let separator = if self.endpoint_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("name", domain)
+            .append_pair("type", &record_type.to_string())
+            .finish();
+        let url = format!("{}{}{}", self.endpoint_url, separator, query)
+            .parse::<qiniu_sdk::http::Uri>()
+            .map_err(|err| {
+                qiniu_sdk::http_client::ResponseError::new(
+                    qiniu_sdk::http::ResponseErrorKind::InvalidUrl.into(),
+                    err,
+                )
+            })?;
+        let mut headers = qiniu_sdk::http::HeaderMap::new();
+        headers.insert(
+            qiniu_sdk::http::header::ACCEPT,
+            qiniu_sdk::http::HeaderValue::from_static("application/dns-json"),
+        );
+        let mut builder = qiniu_sdk::http::RequestParts::builder();
+        builder
+            .url(url)
+            .method(qiniu_sdk::http::Method::GET)
+            .headers(headers)
+            .add_extension(qiniu_sdk::isahc::TimeoutRequestExtension::new(self.timeout));
+        Ok(builder.build())
+    }
+
+    fn query(
+        &self,
+        domain: &str,
+        record_type: u16,
+    ) -> Result<Vec<IpAddr>, qiniu_sdk::http_client::ResponseError> {
+        let mut request = qiniu_sdk::http::Request::from_parts_and_body(
+            self.make_request_parts(domain, record_type)?,
+            qiniu_sdk::http::SyncRequestBody::default(),
+        );
+        let response = qiniu_sdk::http::HttpCaller::call(&self.http_caller, &mut request)?;
+        let status_code = response.status_code();
+        let mut body = Vec::new();
+        std::io::Read::read_to_end(&mut response.into_body(), &mut body).map_err(|err| {
+            qiniu_sdk::http_client::ResponseError::new(
+                qiniu_sdk::http::ResponseErrorKind::ReceiveError.into(),
+                err,
+            )
+        })?;
+        parse_doh_answers(status_code, &body, record_type)
+    }
+
+    async fn async_query(
+        &self,
+        domain: &str,
+        record_type: u16,
+    ) -> Result<Vec<IpAddr>, qiniu_sdk::http_client::ResponseError> {
+        let mut request = qiniu_sdk::http::Request::from_parts_and_body(
+            self.make_request_parts(domain, record_type)?,
+            qiniu_sdk::http::AsyncRequestBody::default(),
+        );
+        let response =
+            qiniu_sdk::http::HttpCaller::async_call(&self.http_caller, &mut request).await?;
+        let status_code = response.status_code();
+        let mut body = Vec::new();
+        futures::AsyncReadExt::read_to_end(&mut response.into_body(), &mut body)
+            .await
+            .map_err(|err| {
+                qiniu_sdk::http_client::ResponseError::new(
+                    qiniu_sdk::http::ResponseErrorKind::ReceiveError.into(),
+                    err,
+                )
+            })?;
+        parse_doh_answers(status_code, &body, record_type)
+    }
+
+    fn collect_answers(
+        results: Vec<Result<Vec<IpAddr>, qiniu_sdk::http_client::ResponseError>>,
+    ) -> qiniu_sdk::http_client::ResolveResult {
+        let mut ips = Vec::new();
+        let mut last_error = None;
+        for result in results {
+            match result {
+                Ok(answers) => ips.extend(answers),
+                Err(err) => last_error = Some(err),
+            }
+        }
+        match last_error {
+            Some(err) if ips.is_empty() => Err(err),
+            _ => Ok(ips.into_boxed_slice().into()),
+        }
+    }
+}
+
+impl qiniu_sdk::http_client::Resolver for DohResolverImpl {
+    fn resolve(
+        &self,
+        domain: &str,
+        opts: qiniu_sdk::http_client::ResolveOptions<'_>,
+    ) -> qiniu_sdk::http_client::ResolveResult {
+        let result = Self::collect_answers(
+            DOH_RECORD_TYPES
+                .iter()
+                .map(|&record_type| self.query(domain, record_type))
+                .collect(),
+        );
+        match (result, &self.fallback) {
+            (Err(_), Some(fallback)) => fallback.0.resolve(domain, opts),
+            (result, _) => result,
+        }
+    }
+
+    fn async_resolve<'a>(
+        &'a self,
+        domain: &'a str,
+        opts: qiniu_sdk::http_client::ResolveOptions<'a>,
+    ) -> futures::future::BoxFuture<'a, qiniu_sdk::http_client::ResolveResult> {
+        Box::pin(async move {
+            let mut results = Vec::with_capacity(DOH_RECORD_TYPES.len());
+            for record_type in DOH_RECORD_TYPES {
+                results.push(self.async_query(domain, record_type).await);
+            }
+            match (Self::collect_answers(results), &self.fallback) {
+                (Err(_), Some(fallback)) => fallback.0.async_resolve(domain, opts).await,
+                (result, _) => result,
+            }
+        })
+    }
+}
+
+fn parse_doh_answers(
+    status_code: qiniu_sdk::http::StatusCode,
+    body: &[u8],
+    record_type: u16,
+) -> Result<Vec<IpAddr>, qiniu_sdk::http_client::ResponseError> {
+    use qiniu_sdk::http_client::{ResponseError, ResponseErrorKind};
+
+    if !status_code.is_success() {
+        return Err(ResponseError::new_with_msg(
+            ResponseErrorKind::StatusCodeError(status_code),
+            String::from_utf8_lossy(body).into_owned(),
+        ));
+    }
+    let value: serde_json::Value = serde_json::from_slice(body)
+        .map_err(|err| ResponseError::new(ResponseErrorKind::ParseResponseError, err))?;
+    match value.get("Status").and_then(|status| status.as_u64()) {
+        Some(0) => {}
+        status => {
+            return Err(ResponseError::new_with_msg(
+                ResponseErrorKind::ParseResponseError,
+                format!("Unexpected DoH response status: {:?}", status),
+            ))
+        }
+    }
+    Ok(value
+        .get("Answer")
+        .and_then(|answers| answers.as_array())
+        .map(|answers| {
+            answers
+                .iter()
+                .filter(|answer| {
+                    answer.get("type").and_then(|t| t.as_u64()) == Some(record_type.into())
+                })
+                .filter_map(|answer| answer.get("data").and_then(|data| data.as_str()))
+                .filter_map(|data| data.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
 /// 选择 IP 地址接口
 ///
 /// 抽象类
@@ -587,54 +1321,55 @@ pub(crate) struct Chooser(Box<dyn qiniu_sdk::http_client::Chooser>);
 #[pymethods]
 impl Chooser {
     /// 选择 IP 地址列表
+    ///
+    /// `ips` 中的每一项既可以是字符串，也可以是 `IpAddrWithPort`
     #[pyo3(text_signature = "(ips, /, domain_with_port = None)")]
     #[args(domain_with_port = "None")]
     fn choose(
         &self,
-        ips: Vec<&str>,
+        ips: Vec<&PyAny>,
         domain_with_port: Option<&str>,
         py: Python<'_>,
     ) -> PyResult<Vec<String>> {
-        let ips = ips
+        Ok(self
+            .choose_ips(&ips, domain_with_port, py)?
             .into_iter()
-            .map(parse_ip_addr_with_port)
-            .collect::<PyResult<Vec<_>>>()?;
-        let domain_with_port = domain_with_port.map(parse_domain_with_port).transpose()?;
-        let mut builder = qiniu_sdk::http_client::ChooseOptions::builder();
-        if let Some(domain_with_port) = &domain_with_port {
-            builder.domain(domain_with_port);
-        }
-        Ok(py.allow_threads(|| {
-            self.0
-                .choose(&ips, builder.build())
-                .into_iter()
-                .map(|ip| ip.to_string())
-                .collect()
-        }))
+            .map(|ip| ip.to_string())
+            .collect())
+    }
+
+    /// 选择 IP 地址列表，返回 `IpAddrWithPort` 列表
+    ///
+    /// `ips` 中的每一项既可以是字符串，也可以是 `IpAddrWithPort`
+    #[pyo3(text_signature = "(ips, /, domain_with_port = None)")]
+    #[args(domain_with_port = "None")]
+    fn choose_detailed(
+        &self,
+        ips: Vec<&PyAny>,
+        domain_with_port: Option<&str>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<IpAddrWithPort>> {
+        Ok(self
+            .choose_ips(&ips, domain_with_port, py)?
+            .into_iter()
+            .map(IpAddrWithPort::from)
+            .collect())
     }
 
     /// 异步选择 IP 地址列表
+    ///
+    /// `ips` 中的每一项既可以是字符串，也可以是 `IpAddrWithPort`
     #[pyo3(text_signature = "(ips, /, domain_with_port = None)")]
     #[args(domain_with_port = "None")]
     fn async_choose<'p>(
         &self,
-        ips: Vec<String>,
+        ips: Vec<&PyAny>,
         domain_with_port: Option<&str>,
         py: Python<'p>,
     ) -> PyResult<&'p PyAny> {
-        let chooser = self.0.to_owned();
-        let ips = ips
-            .iter()
-            .map(|s| parse_ip_addr_with_port(s.as_str()))
-            .collect::<PyResult<Vec<_>>>()?;
-        let domain_with_port = domain_with_port.map(parse_domain_with_port).transpose()?;
+        let chosen = self.async_choose_ips(&ips, domain_with_port)?;
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            let mut builder = qiniu_sdk::http_client::ChooseOptions::builder();
-            if let Some(domain_with_port) = &domain_with_port {
-                builder.domain(domain_with_port);
-            }
-            Ok(chooser
-                .async_choose(&ips, builder.build())
+            Ok(chosen
                 .await
                 .into_iter()
                 .map(|ip| ip.to_string())
@@ -642,21 +1377,44 @@ impl Chooser {
         })
     }
 
+    /// 异步选择 IP 地址列表，返回 `IpAddrWithPort` 列表
+    ///
+    /// `ips` 中的每一项既可以是字符串，也可以是 `IpAddrWithPort`
+    #[pyo3(text_signature = "(ips, /, domain_with_port = None)")]
+    #[args(domain_with_port = "None")]
+    fn async_choose_detailed<'p>(
+        &self,
+        ips: Vec<&PyAny>,
+        domain_with_port: Option<&str>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let chosen = self.async_choose_ips(&ips, domain_with_port)?;
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            Ok(chosen
+                .await
+                .into_iter()
+                .map(IpAddrWithPort::from)
+                .collect::<Vec<_>>())
+        })
+    }
+
     /// 反馈选择的 IP 地址列表的结果
+    ///
+    /// `ips` 中的每一项既可以是字符串，也可以是 `IpAddrWithPort`
     #[pyo3(
         text_signature = "(ips, /, domain = None, retried = None, metrics = None, error = None)"
     )]
     #[args(domain = "None", retried = "None", metrics = "None", error = "None")]
     fn feedback(
         &self,
-        ips: Vec<&str>,
+        ips: Vec<&PyAny>,
         domain: Option<&str>,
         retried: Option<RetriedStatsInfo>,
         metrics: Option<Metrics>,
         error: Option<&QiniuApiCallError>,
         py: Python<'_>,
     ) -> PyResult<()> {
-        let ips = extract_ip_addrs_with_port(&ips)?;
+        let ips = extract_chooser_ips(&ips)?;
         let domain = domain.map(parse_domain_with_port).transpose()?;
         let error = error.map(PyErr::from);
         let error = error.as_ref().map(convert_api_call_error).transpose()?;
@@ -672,13 +1430,15 @@ impl Chooser {
     }
 
     /// 异步反馈选择的 IP 地址列表的结果
+    ///
+    /// `ips` 中的每一项既可以是字符串，也可以是 `IpAddrWithPort`
     #[pyo3(
         text_signature = "(ips, /, domain = None, retried = None, metrics = None, error = None)"
     )]
     #[args(domain = "None", retried = "None", metrics = "None", error = "None")]
     fn async_feedback<'p>(
         &self,
-        ips: Vec<&str>,
+        ips: Vec<&PyAny>,
         domain: Option<&str>,
         retried: Option<RetriedStatsInfo>,
         metrics: Option<Metrics>,
@@ -686,7 +1446,7 @@ impl Chooser {
         py: Python<'p>,
     ) -> PyResult<&'p PyAny> {
         let chooser = self.0.to_owned();
-        let ips = extract_ip_addrs_with_port(&ips)?;
+        let ips = extract_chooser_ips(&ips)?;
         let domain = domain.map(parse_domain_with_port).transpose()?;
         let error = error.map(PyErr::from);
         pyo3_asyncio::async_std::future_into_py(py, async move {
@@ -743,6 +1503,43 @@ impl qiniu_sdk::http_client::Chooser for Chooser {
 }
 
 impl Chooser {
+    fn choose_ips(
+        &self,
+        ips: &[&PyAny],
+        domain_with_port: Option<&str>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<qiniu_sdk::http_client::IpAddrWithPort>> {
+        let ips = extract_chooser_ips(ips)?;
+        let domain_with_port = domain_with_port.map(parse_domain_with_port).transpose()?;
+        let mut builder = qiniu_sdk::http_client::ChooseOptions::builder();
+        if let Some(domain_with_port) = &domain_with_port {
+            builder.domain(domain_with_port);
+        }
+        Ok(py.allow_threads(|| self.0.choose(&ips, builder.build()).into_iter().collect()))
+    }
+
+    fn async_choose_ips(
+        &self,
+        ips: &[&PyAny],
+        domain_with_port: Option<&str>,
+    ) -> PyResult<impl Future<Output = Vec<qiniu_sdk::http_client::IpAddrWithPort>> + Send + 'static>
+    {
+        let chooser = self.0.to_owned();
+        let ips = extract_chooser_ips(ips)?;
+        let domain_with_port = domain_with_port.map(parse_domain_with_port).transpose()?;
+        Ok(async move {
+            let mut builder = qiniu_sdk::http_client::ChooseOptions::builder();
+            if let Some(domain_with_port) = &domain_with_port {
+                builder.domain(domain_with_port);
+            }
+            chooser
+                .async_choose(&ips, builder.build())
+                .await
+                .into_iter()
+                .collect()
+        })
+    }
+
     fn make_feedback<'a>(
         ips: &'a [qiniu_sdk::http_client::IpAddrWithPort],
         domain: Option<&'a qiniu_sdk::http_client::DomainWithPort>,
@@ -767,6 +1564,18 @@ impl Chooser {
     }
 }
 
+fn extract_chooser_ips(ips: &[&PyAny]) -> PyResult<Vec<qiniu_sdk::http_client::IpAddrWithPort>> {
+    ips.iter()
+        .map(|ip| {
+            if let Ok(ip) = ip.extract::<IpAddrWithPort>() {
+                Ok(ip.into())
+            } else {
+                parse_ip_addr_with_port(ip.extract::<&str>()?)
+            }
+        })
+        .collect()
+}
+
 /// 直接选择器
 ///
 /// 不做任何筛选，也不接受任何反馈，直接将给出的 IP 地址列表返回
@@ -796,7 +1605,7 @@ impl DirectChooser {
 #[pyclass(extends = Chooser)]
 #[pyo3(text_signature = "(/, block_duration_secs = None, shrink_interval_secs = None)")]
 #[derive(Clone)]
-struct IpChooser;
+struct IpChooser(ManuallyBlockableChooser<qiniu_sdk::http_client::IpChooser>);
 
 #[pymethods]
 impl IpChooser {
@@ -810,7 +1619,32 @@ impl IpChooser {
         if let Some(shrink_interval_secs) = shrink_interval_secs {
             builder.shrink_interval(Duration::from_secs(shrink_interval_secs));
         }
-        (Self, Chooser(Box::new(builder.build())))
+        let chooser = ManuallyBlockableChooser::new(builder.build());
+        (Self(chooser.to_owned()), Chooser(Box::new(chooser)))
+    }
+
+    /// 手动将 IP 地址标记为不可用，在指定时长内不会被选择
+    #[pyo3(text_signature = "($self, ip, duration_secs)")]
+    fn mark_unavailable(&self, ip: &str, duration_secs: u64) -> PyResult<()> {
+        self.0
+            .blocklist
+            .block(parse_ip_addr(ip)?, Duration::from_secs(duration_secs));
+        Ok(())
+    }
+
+    /// 将手动标记为不可用的 IP 地址恢复为可用
+    #[pyo3(text_signature = "($self, ip)")]
+    fn mark_available(&self, ip: &str) -> PyResult<()> {
+        self.0.blocklist.unblock(parse_ip_addr(ip)?);
+        Ok(())
+    }
+
+    /// 获取当前不可用的 IP 地址列表
+    ///
+    /// 包括手动标记为不可用的 IP 地址，以及因 API 调用失败而被自动屏蔽的 IP 地址
+    #[pyo3(text_signature = "($self)")]
+    fn blocked_ips(&self) -> Vec<String> {
+        self.0.blocked_ips()
     }
 }
 
@@ -824,7 +1658,7 @@ impl IpChooser {
     text_signature = "(/, block_duration_secs = None, shrink_interval_secs = None, ipv4_netmask_prefix_length = None, ipv6_netmask_prefix_length = None)"
 )]
 #[derive(Clone)]
-struct SubnetChooser;
+struct SubnetChooser(ManuallyBlockableChooser<qiniu_sdk::http_client::SubnetChooser>);
 
 #[pymethods]
 impl SubnetChooser {
@@ -858,7 +1692,168 @@ impl SubnetChooser {
                 .ipv6_netmask_prefix_length(ipv6_netmask_prefix_length)
                 .map_err(QiniuInvalidPrefixLengthError::from_err)?;
         }
-        Ok((Self, Chooser(Box::new(builder.build()))))
+        let chooser = ManuallyBlockableChooser::new(builder.build());
+        Ok((Self(chooser.to_owned()), Chooser(Box::new(chooser))))
+    }
+
+    /// 手动将 IP 地址标记为不可用，在指定时长内不会被选择
+    ///
+    /// 仅屏蔽该 IP 地址本身，不会屏蔽其所在子网
+    #[pyo3(text_signature = "($self, ip, duration_secs)")]
+    fn mark_unavailable(&self, ip: &str, duration_secs: u64) -> PyResult<()> {
+        self.0
+            .blocklist
+            .block(parse_ip_addr(ip)?, Duration::from_secs(duration_secs));
+        Ok(())
+    }
+
+    /// 将手动标记为不可用的 IP 地址恢复为可用
+    #[pyo3(text_signature = "($self, ip)")]
+    fn mark_available(&self, ip: &str) -> PyResult<()> {
+        self.0.blocklist.unblock(parse_ip_addr(ip)?);
+        Ok(())
+    }
+
+    /// 获取当前不可用的 IP 地址列表
+    ///
+    /// 包括手动标记为不可用的 IP 地址，以及因 API 调用失败而被自动屏蔽的 IP 地址
+    #[pyo3(text_signature = "($self)")]
+    fn blocked_ips(&self) -> Vec<String> {
+        self.0.blocked_ips()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct ManualBlocklist(Arc<Mutex<HashMap<IpAddr, Instant>>>);
+
+impl ManualBlocklist {
+    fn lock(&self) -> MutexGuard<'_, HashMap<IpAddr, Instant>> {
+        let mut blocklist = self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let now = Instant::now();
+        blocklist.retain(|_, blocked_until| *blocked_until > now);
+        blocklist
+    }
+
+    fn block(&self, ip: IpAddr, duration: Duration) {
+        self.lock().insert(ip, instant_after(duration));
+    }
+
+    fn unblock(&self, ip: IpAddr) {
+        self.lock().remove(&ip);
+    }
+
+    fn filter(
+        &self,
+        ips: &[qiniu_sdk::http_client::IpAddrWithPort],
+    ) -> Vec<qiniu_sdk::http_client::IpAddrWithPort> {
+        let blocklist = self.lock();
+        ips.iter()
+            .filter(|ip| !blocklist.contains_key(&ip.ip_addr()))
+            .cloned()
+            .collect()
+    }
+}
+
+type FailedIp = (
+    qiniu_sdk::http_client::IpAddrWithPort,
+    Option<qiniu_sdk::http_client::DomainWithPort>,
+);
+
+#[derive(Debug, Clone)]
+struct ManuallyBlockableChooser<C> {
+    chooser: C,
+    blocklist: ManualBlocklist,
+    failed_ips: Arc<Mutex<HashSet<FailedIp>>>,
+}
+
+impl<C: qiniu_sdk::http_client::Chooser> ManuallyBlockableChooser<C> {
+    fn new(chooser: C) -> Self {
+        Self {
+            chooser,
+            blocklist: Default::default(),
+            failed_ips: Default::default(),
+        }
+    }
+
+    // 内部选择器的黑名单无法直接读取，因此记录所有被反馈失败的 IP 地址，再逐个确认是否依然被屏蔽
+    fn blocked_ips(&self) -> Vec<String> {
+        let mut ips = self
+            .blocklist
+            .lock()
+            .keys()
+            .copied()
+            .collect::<HashSet<_>>();
+        self.lock_failed_ips().retain(|(ip, domain)| {
+            let mut opts = qiniu_sdk::http_client::ChooseOptions::builder();
+            if let Some(domain) = domain {
+                opts.domain(domain);
+            }
+            let blocked = self.chooser.choose(&[*ip], opts.build()).is_empty();
+            if blocked {
+                ips.insert(ip.ip_addr());
+            }
+            blocked
+        });
+        let mut ips = ips.into_iter().collect::<Vec<_>>();
+        ips.sort();
+        ips.into_iter().map(|ip| ip.to_string()).collect()
+    }
+
+    fn record_feedback(&self, feedback: &qiniu_sdk::http_client::ChooserFeedback) {
+        let mut failed_ips = self.lock_failed_ips();
+        for &ip in feedback.ips() {
+            let failed_ip = (ip, feedback.domain().cloned());
+            if feedback.error().is_some() {
+                failed_ips.insert(failed_ip);
+            } else {
+                failed_ips.remove(&failed_ip);
+            }
+        }
+    }
+
+    fn lock_failed_ips(&self) -> MutexGuard<'_, HashSet<FailedIp>> {
+        self.failed_ips
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<C: qiniu_sdk::http_client::Chooser + Clone> qiniu_sdk::http_client::Chooser
+    for ManuallyBlockableChooser<C>
+{
+    fn choose(
+        &self,
+        ips: &[qiniu_sdk::http_client::IpAddrWithPort],
+        opts: qiniu_sdk::http_client::ChooseOptions,
+    ) -> qiniu_sdk::http_client::ChosenResults {
+        self.chooser.choose(&self.blocklist.filter(ips), opts)
+    }
+
+    fn feedback(&self, feedback: qiniu_sdk::http_client::ChooserFeedback) {
+        self.record_feedback(&feedback);
+        self.chooser.feedback(feedback)
+    }
+
+    fn async_choose<'a>(
+        &'a self,
+        ips: &'a [qiniu_sdk::http_client::IpAddrWithPort],
+        opts: qiniu_sdk::http_client::ChooseOptions<'a>,
+    ) -> futures::future::BoxFuture<'a, qiniu_sdk::http_client::ChosenResults> {
+        Box::pin(async move {
+            let ips = self.blocklist.filter(ips);
+            self.chooser.async_choose(&ips, opts).await
+        })
+    }
+
+    fn async_feedback<'a>(
+        &'a self,
+        feedback: qiniu_sdk::http_client::ChooserFeedback<'a>,
+    ) -> futures::future::BoxFuture<'a, ()> {
+        self.record_feedback(&feedback);
+        self.chooser.async_feedback(feedback)
     }
 }
 
@@ -890,6 +1885,8 @@ impl ShuffledChooser {
 /// 确保 [`Chooser`] 实例不会因为所有可选择的 IP 地址都被屏蔽而导致 HTTP 客户端直接返回错误，
 /// 在内置的 [`Chooser`] 没有返回结果时，将会随机返回一定比例的 IP 地址供 HTTP 客户端做一轮尝试。
 ///
+/// `random_choose_fraction` 可以是 `fractions.Fraction`，也可以是 `(0, 1]` 范围内的浮点数
+///
 /// 通过 `NeverEmptyHandedChooser(chooser, random_choose_fraction)` 创建永不空手的选择器
 #[pyclass(extends = Chooser)]
 #[pyo3(text_signature = "(chooser, random_choose_fraction)")]
@@ -900,7 +1897,7 @@ struct NeverEmptyHandedChooser;
 impl NeverEmptyHandedChooser {
     #[new]
     fn new(chooser: Chooser, random_choose_fraction: &PyAny) -> PyResult<(Self, Chooser)> {
-        let random_choose_ratio = convert_fraction(random_choose_fraction)?;
+        let random_choose_ratio = convert_fraction(random_choose_fraction, 1.0)?;
         Ok((
             Self,
             Chooser(Box::new(
@@ -1169,6 +2166,57 @@ impl LimitedRetrier {
     }
 }
 
+/// 单次请求的重试次数上限
+#[derive(Debug, Clone, Copy)]
+struct MaxRetriesExtension(usize);
+
+/// 单次请求的截止时间，超过截止时间后不再重试
+#[derive(Debug, Clone, Copy)]
+struct DeadlineExtension(Instant);
+
+/// 允许单次请求覆盖重试次数上限的重试器
+///
+/// 如果请求中带有 [`MaxRetriesExtension`]，则使用七牛状态码重试器并限制总重试次数，否则使用原重试器。
+/// 如果请求中带有 [`DeadlineExtension`] 且已经超过截止时间，则不再重试
+#[derive(Debug, Clone)]
+struct OverridableRetrier(Box<dyn qiniu_sdk::http_client::RequestRetrier>);
+
+impl OverridableRetrier {
+    fn new(retrier: Option<RequestRetrier>) -> Self {
+        Self(
+            retrier
+                .map(|retrier| retrier.0)
+                .unwrap_or_else(qiniu_sdk::http_client::HttpClient::default_retrier),
+        )
+    }
+}
+
+impl qiniu_sdk::http_client::RequestRetrier for OverridableRetrier {
+    fn retry(
+        &self,
+        request: &mut qiniu_sdk::http::RequestParts,
+        opts: qiniu_sdk::http_client::RequestRetrierOptions<'_>,
+    ) -> qiniu_sdk::http_client::RetryResult {
+        if let Some(&DeadlineExtension(deadline)) = request.extensions().get::<DeadlineExtension>()
+        {
+            if Instant::now() >= deadline {
+                return qiniu_sdk::http_client::NeverRetrier.retry(request, opts);
+            }
+        }
+        if let Some(&MaxRetriesExtension(max_retries)) =
+            request.extensions().get::<MaxRetriesExtension>()
+        {
+            qiniu_sdk::http_client::LimitedRetrier::limit_total(
+                qiniu_sdk::http_client::ErrorRetrier,
+                max_retries,
+            )
+            .retry(request, opts)
+        } else {
+            self.0.retry(request, opts)
+        }
+    }
+}
+
 /// 退避时长获取接口
 ///
 /// 抽象类
@@ -1239,6 +2287,20 @@ impl FixedBackoff {
         )
     }
 
+    /// 根据毫秒数创建固定时长的退避时长提供者
+    #[staticmethod]
+    #[pyo3(text_signature = "(delay_ms)")]
+    fn from_millis(delay_ms: u64, py: Python<'_>) -> PyResult<Py<Self>> {
+        Py::new(py, Self::new(millis_to_nanos(delay_ms)))
+    }
+
+    /// 根据秒数创建固定时长的退避时长提供者
+    #[staticmethod]
+    #[pyo3(text_signature = "(delay_secs)")]
+    fn from_secs(delay_secs: u64, py: Python<'_>) -> PyResult<Py<Self>> {
+        Py::new(py, Self::new(secs_to_nanos(delay_secs)))
+    }
+
     /// 获取固定时长
     #[getter]
     fn get_delay(&self) -> u64 {
@@ -1273,6 +2335,20 @@ impl ExponentialBackoff {
         )
     }
 
+    /// 根据毫秒数创建指数级增长的退避时长提供者
+    #[staticmethod]
+    #[pyo3(text_signature = "(base_number, base_delay_ms)")]
+    fn from_millis(base_number: u32, base_delay_ms: u64, py: Python<'_>) -> PyResult<Py<Self>> {
+        Py::new(py, Self::new(base_number, millis_to_nanos(base_delay_ms)))
+    }
+
+    /// 根据秒数创建指数级增长的退避时长提供者
+    #[staticmethod]
+    #[pyo3(text_signature = "(base_number, base_delay_secs)")]
+    fn from_secs(base_number: u32, base_delay_secs: u64, py: Python<'_>) -> PyResult<Py<Self>> {
+        Py::new(py, Self::new(base_number, secs_to_nanos(base_delay_secs)))
+    }
+
     /// 获取底数
     #[getter]
     fn get_base_number(&self) -> u32 {
@@ -1290,6 +2366,8 @@ impl ExponentialBackoff {
 ///
 /// 基于一个退避时长提供者并为其增加随机化范围
 ///
+/// `minification` 与 `magnification` 可以是 `fractions.Fraction`，也可以是浮点数，其中 `minification` 必须在 `(0, 1]` 范围内，`magnification` 必须大于 0
+///
 /// 通过 `RandomizedBackoff(base_backoff, minification, magnification)` 创建均匀分布随机化退避时长提供者
 #[pyclass(extends = Backoff)]
 #[pyo3(text_signature = "(base_backoff, minification, magnification)")]
@@ -1308,8 +2386,8 @@ impl RandomizedBackoff {
         magnification: PyObject,
         py: Python<'_>,
     ) -> PyResult<(Self, Backoff)> {
-        let minification_ratio = convert_fraction(minification.as_ref(py))?;
-        let magnification_ratio = convert_fraction(magnification.as_ref(py))?;
+        let minification_ratio = convert_fraction(minification.as_ref(py), 1.0)?;
+        let magnification_ratio = convert_fraction(magnification.as_ref(py), f64::INFINITY)?;
         Ok((
             Self {
                 minification,
@@ -1364,6 +2442,44 @@ impl LimitedBackoff {
         )
     }
 
+    /// 根据毫秒数创建限制范围的退避时长提供者
+    #[staticmethod]
+    #[pyo3(text_signature = "(base_backoff, min_backoff_ms, max_backoff_ms)")]
+    fn from_millis(
+        base_backoff: Backoff,
+        min_backoff_ms: u64,
+        max_backoff_ms: u64,
+        py: Python<'_>,
+    ) -> PyResult<Py<Self>> {
+        Py::new(
+            py,
+            Self::new(
+                base_backoff,
+                millis_to_nanos(min_backoff_ms),
+                millis_to_nanos(max_backoff_ms),
+            ),
+        )
+    }
+
+    /// 根据秒数创建限制范围的退避时长提供者
+    #[staticmethod]
+    #[pyo3(text_signature = "(base_backoff, min_backoff_secs, max_backoff_secs)")]
+    fn from_secs(
+        base_backoff: Backoff,
+        min_backoff_secs: u64,
+        max_backoff_secs: u64,
+        py: Python<'_>,
+    ) -> PyResult<Py<Self>> {
+        Py::new(
+            py,
+            Self::new(
+                base_backoff,
+                secs_to_nanos(min_backoff_secs),
+                secs_to_nanos(max_backoff_secs),
+            ),
+        )
+    }
+
     /// 获取最短的退避时长
     #[getter]
     fn get_min_backoff(&self) -> u64 {
@@ -1377,58 +2493,702 @@ impl LimitedBackoff {
     }
 }
 
-fn convert_fraction<'a, U: FromPyObject<'a> + Clone + Integer>(
-    fraction: &'a PyAny,
-) -> PyResult<qiniu_sdk::http_client::Ratio<U>> {
-    let numerator = fraction.getattr("numerator")?.extract::<'a, U>()?;
-    let denominator = fraction.getattr("denominator")?.extract::<'a, U>()?;
-    let ratio = qiniu_sdk::http_client::Ratio::new(numerator, denominator);
-    Ok(ratio)
-}
-
-/// HTTP 客户端
+/// 带随机抖动的指数级增长的退避时长提供者
 ///
-/// 用于发送 HTTP 请求的入口。
+/// 相当于使用 `RandomizedBackoff` 包装 `ExponentialBackoff`，退避时长将在 `[1 - jitter, 1 + jitter]` 倍之间随机取值，`jitter` 必须在 0 到 1 之间，精确到百分之一
 ///
-/// 创建 `HttpClient(http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None)` 创建 HTTP 客户端
-#[pyclass(subclass)]
-#[pyo3(
-    text_signature = "(/, http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None)"
-)]
-#[derive(Clone)]
-pub(crate) struct HttpClient(qiniu_sdk::http_client::HttpClient);
+/// 通过 `JitteredExponentialBackoff(base_number, base_delay_ns, jitter = 0.3)` 创建带随机抖动的指数级增长的退避时长提供者
+#[pyclass(extends = Backoff)]
+#[pyo3(text_signature = "(base_number, base_delay_ns, /, jitter = 0.3)")]
+#[derive(Copy, Clone)]
+struct JitteredExponentialBackoff {
+    base_number: u32,
+    base_delay_ns: u64,
+    jitter: f64,
+}
 
 #[pymethods]
-impl HttpClient {
+impl JitteredExponentialBackoff {
     #[new]
-    #[args(
-        http_caller = "None",
-        use_https = "None",
-        appended_user_agent = "None",
-        request_retrier = "None",
-        backoff = "None",
-        chooser = "None",
-        resolver = "None",
-        uploading_progress = "None",
-        receive_response_status = "None",
-        receive_response_header = "None",
-        to_resolve_domain = "None",
-        domain_resolved = "None",
-        to_choose_ips = "None",
-        ips_chosen = "None",
-        before_request_signed = "None",
-        after_request_signed = "None",
-        response_ok = "None",
-        response_error = "None",
-        before_backoff = "None",
-        after_backoff = "None"
-    )]
-    #[allow(clippy::too_many_arguments)]
-    pub(crate) fn new(
-        http_caller: Option<HttpCaller>,
-        use_https: Option<bool>,
-        appended_user_agent: Option<&str>,
-        request_retrier: Option<RequestRetrier>,
+    #[args(jitter = "0.3")]
+    fn new(base_number: u32, base_delay_ns: u64, jitter: f64) -> PyResult<(Self, Backoff)> {
+        if !(0.0..=1.0).contains(&jitter) {
+            return Err(QiniuInvalidBackoffJitter::new_err(format!(
+                "jitter must be between 0 and 1, but got {}",
+                jitter
+            )));
+        }
+        let percent = (jitter * 100.0).round() as u8;
+        Ok((
+            Self {
+                base_number,
+                base_delay_ns,
+                jitter,
+            },
+            Backoff(Box::new(qiniu_sdk::http_client::RandomizedBackoff::new(
+                qiniu_sdk::http_client::ExponentialBackoff::new(
+                    base_number,
+                    Duration::from_nanos(base_delay_ns),
+                ),
+                qiniu_sdk::http_client::Ratio::new(100 - percent, 100),
+                qiniu_sdk::http_client::Ratio::new(100 + percent, 100),
+            ))),
+        ))
+    }
+
+    /// 根据毫秒数创建带随机抖动的指数级增长的退避时长提供者
+    #[staticmethod]
+    #[pyo3(text_signature = "(base_number, base_delay_ms, /, jitter = 0.3)")]
+    #[args(jitter = "0.3")]
+    fn from_millis(
+        base_number: u32,
+        base_delay_ms: u64,
+        jitter: f64,
+        py: Python<'_>,
+    ) -> PyResult<Py<Self>> {
+        Py::new(
+            py,
+            Self::new(base_number, millis_to_nanos(base_delay_ms), jitter)?,
+        )
+    }
+
+    /// 根据秒数创建带随机抖动的指数级增长的退避时长提供者
+    #[staticmethod]
+    #[pyo3(text_signature = "(base_number, base_delay_secs, /, jitter = 0.3)")]
+    #[args(jitter = "0.3")]
+    fn from_secs(
+        base_number: u32,
+        base_delay_secs: u64,
+        jitter: f64,
+        py: Python<'_>,
+    ) -> PyResult<Py<Self>> {
+        Py::new(
+            py,
+            Self::new(base_number, secs_to_nanos(base_delay_secs), jitter)?,
+        )
+    }
+
+    /// 获取底数
+    #[getter]
+    fn get_base_number(&self) -> u32 {
+        self.base_number
+    }
+
+    /// 获取基础退避时长
+    #[getter]
+    fn get_base_delay(&self) -> u64 {
+        self.base_delay_ns
+    }
+
+    /// 获取随机抖动比率
+    #[getter]
+    fn get_jitter(&self) -> f64 {
+        self.jitter
+    }
+}
+
+fn millis_to_nanos(millis: u64) -> u64 {
+    millis.saturating_mul(1_000_000)
+}
+
+fn secs_to_nanos(secs: u64) -> u64 {
+    secs.saturating_mul(1_000_000_000)
+}
+
+/// 将 `fractions.Fraction` 或浮点数转换为比率
+///
+/// 浮点数必须在 `(0, max]` 范围内，并且精确到百分之一
+fn convert_fraction<'a, U: FromPyObject<'a> + Clone + Integer>(
+    fraction: &'a PyAny,
+    max: f64,
+) -> PyResult<qiniu_sdk::http_client::Ratio<U>> {
+    let fraction = if let Ok(float) = fraction.downcast::<PyFloat>() {
+        let value = float.value();
+        if !(value > 0.0 && value <= max) {
+            return Err(QiniuInvalidRatio::new_err(format!(
+                "ratio must be in (0, {}], but got {}",
+                max, value
+            )));
+        }
+        fraction
+            .py()
+            .import("fractions")?
+            .getattr("Fraction")?
+            .call1((value,))?
+            .call_method1("limit_denominator", (100,))?
+    } else {
+        fraction
+    };
+    let numerator = fraction.getattr("numerator")?.extract::<'a, U>()?;
+    let denominator = fraction.getattr("denominator")?.extract::<'a, U>()?;
+    let ratio = qiniu_sdk::http_client::Ratio::new(numerator, denominator);
+    Ok(ratio)
+}
+
+/// 令牌桶限速器
+///
+/// 令牌按照设置的速率匀速补充，桶容量为一秒内允许的请求数。
+/// 令牌不足时预支令牌，并返回需要等待的时长
+#[derive(Debug)]
+struct RateLimiter {
+    requests_per_sec: f64,
+    state: Mutex<RateLimiterState>,
+}
+
+#[derive(Debug)]
+struct RateLimiterState {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    fn new(requests_per_sec: f64) -> PyResult<Self> {
+        if !requests_per_sec.is_finite() || requests_per_sec <= 0.0 {
+            return Err(QiniuInvalidRateLimit::new_err(format!(
+                "max_requests_per_sec must be positive, but got {}",
+                requests_per_sec
+            )));
+        }
+        Ok(Self {
+            requests_per_sec,
+            state: Mutex::new(RateLimiterState {
+                tokens: requests_per_sec.max(1.0),
+                refilled_at: Instant::now(),
+            }),
+        })
+    }
+
+    fn acquire(&self) -> Duration {
+        let capacity = self.requests_per_sec.max(1.0);
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        state.tokens = (state.tokens
+            + now.duration_since(state.refilled_at).as_secs_f64() * self.requests_per_sec)
+            .min(capacity);
+        state.refilled_at = now;
+        state.tokens -= 1.0;
+        if state.tokens >= 0.0 {
+            Duration::default()
+        } else {
+            Duration::from_secs_f64(-state.tokens / self.requests_per_sec)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RateLimitedHttpCaller {
+    http_caller: HttpCaller,
+    limiter: Arc<RateLimiter>,
+}
+
+impl qiniu_sdk::http::HttpCaller for RateLimitedHttpCaller {
+    fn call(
+        &self,
+        request: &mut qiniu_sdk::http::SyncRequest<'_>,
+    ) -> qiniu_sdk::http::SyncResponseResult {
+        let wait = self.limiter.acquire();
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+        qiniu_sdk::http::HttpCaller::call(&self.http_caller, request)
+    }
+
+    fn async_call<'a>(
+        &'a self,
+        request: &'a mut qiniu_sdk::http::AsyncRequest<'_>,
+    ) -> futures::future::BoxFuture<'a, qiniu_sdk::http::AsyncResponseResult> {
+        Box::pin(async move {
+            let wait = self.limiter.acquire();
+            if !wait.is_zero() {
+                async_std::task::sleep(wait).await;
+            }
+            qiniu_sdk::http::HttpCaller::async_call(&self.http_caller, request).await
+        })
+    }
+}
+
+/// 跟随重定向的配置
+///
+/// 由请求扩展携带，由 [`ExtendedHttpCaller`] 处理，跟随同源重定向后如果有 `authorization` 将对新的请求重新签名，
+/// 跟随跨域重定向时将移除 `Authorization` 和 `Cookie` 头且不再签名
+#[derive(Clone)]
+pub(crate) struct FollowRedirectsExtension {
+    max_redirects: usize,
+    authorization: Option<qiniu_sdk::http_client::Authorization<'static>>,
+}
+
+impl FollowRedirectsExtension {
+    pub(crate) fn new(
+        max_redirects: usize,
+        authorization: Option<qiniu_sdk::http_client::Authorization<'static>>,
+    ) -> Self {
+        Self {
+            max_redirects,
+            authorization,
+        }
+    }
+}
+
+/// 默认最多跟随的重定向次数
+pub(crate) const DEFAULT_MAX_REDIRECTS: usize = 10;
+
+/// 扩展的 HTTP 客户端实现
+///
+/// 仅当请求中带有 [`FollowRedirectsExtension`] 时才会跟随 `Location` 头，否则原样返回 3xx 响应。
+/// 此外还会将最终请求解析得到的 IP 地址列表写入响应扩展
+#[derive(Debug)]
+struct ExtendedHttpCaller(HttpCaller);
+
+impl qiniu_sdk::http::HttpCaller for ExtendedHttpCaller {
+    fn call(
+        &self,
+        request: &mut qiniu_sdk::http::SyncRequest<'_>,
+    ) -> qiniu_sdk::http::SyncResponseResult {
+        let follow_redirects = request
+            .extensions()
+            .get::<FollowRedirectsExtension>()
+            .cloned();
+        let mut response = qiniu_sdk::http::HttpCaller::call(&self.0, request)?;
+        if let Some(follow_redirects) = follow_redirects {
+            let origin = request.url().to_owned();
+            let mut history = Vec::new();
+            while history.len() < follow_redirects.max_redirects {
+                let switch_to_get = match prepare_redirect(
+                    request.url(),
+                    request.method(),
+                    response.status_code(),
+                    response.headers(),
+                ) {
+                    Some((url, switch_to_get)) => {
+                        history.push(url.to_string());
+                        *request.url_mut() = url;
+                        switch_to_get
+                    }
+                    None => break,
+                };
+                *request.resolved_ip_addrs_mut() = None;
+                if switch_to_get {
+                    *request.method_mut() = qiniu_sdk::http::Method::GET;
+                    remove_body_headers(request.headers_mut());
+                    *request.body_mut() = Default::default();
+                }
+                if !is_same_origin(&origin, request.url()) {
+                    remove_credential_headers(request.headers_mut());
+                } else if let Some(authorization) = &follow_redirects.authorization {
+                    authorization.sign(request).map_err(|err| {
+                        qiniu_sdk::http::ResponseError::builder(
+                            qiniu_sdk::http::ResponseErrorKind::UnknownError,
+                            err,
+                        )
+                        .build()
+                    })?;
+                }
+                response = qiniu_sdk::http::HttpCaller::call(&self.0, request)?;
+            }
+            response.extensions_mut().insert(RedirectHistory(history));
+        }
+        record_resolved_ip_addrs(request.resolved_ip_addrs(), response.extensions_mut());
+        Ok(response)
+    }
+
+    fn async_call<'a>(
+        &'a self,
+        request: &'a mut qiniu_sdk::http::AsyncRequest<'_>,
+    ) -> futures::future::BoxFuture<'a, qiniu_sdk::http::AsyncResponseResult> {
+        Box::pin(async move {
+            let follow_redirects = request
+                .extensions()
+                .get::<FollowRedirectsExtension>()
+                .cloned();
+            let mut response = qiniu_sdk::http::HttpCaller::async_call(&self.0, request).await?;
+            if let Some(follow_redirects) = follow_redirects {
+                let origin = request.url().to_owned();
+                let mut history = Vec::new();
+                while history.len() < follow_redirects.max_redirects {
+                    let switch_to_get = match prepare_redirect(
+                        request.url(),
+                        request.method(),
+                        response.status_code(),
+                        response.headers(),
+                    ) {
+                        Some((url, switch_to_get)) => {
+                            history.push(url.to_string());
+                            *request.url_mut() = url;
+                            switch_to_get
+                        }
+                        None => break,
+                    };
+                    *request.resolved_ip_addrs_mut() = None;
+                    if switch_to_get {
+                        *request.method_mut() = qiniu_sdk::http::Method::GET;
+                        remove_body_headers(request.headers_mut());
+                        *request.body_mut() = Default::default();
+                    }
+                    if !is_same_origin(&origin, request.url()) {
+                        remove_credential_headers(request.headers_mut());
+                    } else if let Some(authorization) = &follow_redirects.authorization {
+                        authorization.async_sign(request).await.map_err(|err| {
+                            qiniu_sdk::http::ResponseError::builder(
+                                qiniu_sdk::http::ResponseErrorKind::UnknownError,
+                                err,
+                            )
+                            .build()
+                        })?;
+                    }
+                    response = qiniu_sdk::http::HttpCaller::async_call(&self.0, request).await?;
+                }
+                response.extensions_mut().insert(RedirectHistory(history));
+            }
+            record_resolved_ip_addrs(request.resolved_ip_addrs(), response.extensions_mut());
+            Ok(response)
+        })
+    }
+}
+
+fn record_resolved_ip_addrs(
+    resolved_ip_addrs: Option<&[IpAddr]>,
+    extensions: &mut qiniu_sdk::http::Extensions,
+) {
+    extensions.insert(ResolvedIpAddrs(
+        resolved_ip_addrs
+            .map(|ips| ips.to_vec())
+            .unwrap_or_default(),
+    ));
+}
+
+/// 根据 3xx 响应计算重定向的目标地址，并返回是否需要改为不带请求体的 GET 请求
+///
+/// 307 和 308 要求使用原方法和原请求体重新发送，由于请求体无法重放，仅对 GET 和 HEAD 请求跟随这两种重定向
+fn prepare_redirect(
+    url: &qiniu_sdk::http::Uri,
+    method: &qiniu_sdk::http::Method,
+    status_code: qiniu_sdk::http::StatusCode,
+    headers: &qiniu_sdk::http::HeaderMap,
+) -> Option<(qiniu_sdk::http::Uri, bool)> {
+    let idempotent =
+        method == qiniu_sdk::http::Method::GET || method == qiniu_sdk::http::Method::HEAD;
+    let switch_to_get = match status_code.as_u16() {
+        301..=303 => !idempotent,
+        307 | 308 if idempotent => false,
+        _ => return None,
+    };
+    let location = headers
+        .get(qiniu_sdk::http::header::LOCATION)?
+        .to_str()
+        .ok()?;
+    let location = if location.starts_with('/') {
+        qiniu_sdk::http::Uri::builder()
+            .scheme(url.scheme()?.to_owned())
+            .authority(url.authority()?.to_owned())
+            .path_and_query(location)
+            .build()
+            .ok()?
+    } else {
+        let location = location.parse::<qiniu_sdk::http::Uri>().ok()?;
+        location.scheme()?;
+        location.authority()?;
+        location
+    };
+    Some((location, switch_to_get))
+}
+
+fn remove_body_headers(headers: &mut qiniu_sdk::http::HeaderMap) {
+    headers.remove(qiniu_sdk::http::header::CONTENT_TYPE);
+    headers.remove(qiniu_sdk::http::header::CONTENT_LENGTH);
+}
+
+fn is_same_origin(url: &qiniu_sdk::http::Uri, other: &qiniu_sdk::http::Uri) -> bool {
+    url.scheme() == other.scheme() && url.authority() == other.authority()
+}
+
+/// 跨域重定向时移除凭证相关的请求头，避免将其泄露给其他服务器
+fn remove_credential_headers(headers: &mut qiniu_sdk::http::HeaderMap) {
+    headers.remove(qiniu_sdk::http::header::AUTHORIZATION);
+    headers.remove(qiniu_sdk::http::header::COOKIE);
+}
+
+/// Multipart 表单
+///
+/// 通过 `MultipartForm()` 创建 Multipart 表单，可以作为 `HttpClient.call()` 的 `multipart` 参数，各个部分将按照添加的顺序发送
+#[pyclass]
+#[derive(Debug, Clone, Default)]
+#[pyo3(text_signature = "()")]
+pub(crate) struct MultipartForm(Vec<(String, MultipartFormPart)>);
+
+#[derive(Debug, Clone)]
+enum MultipartFormPart {
+    Text(String),
+    File {
+        path: PathBuf,
+        file_name: Option<String>,
+        mime: Option<Mime>,
+    },
+    Stream {
+        reader: PyObject,
+        file_name: Option<String>,
+        mime: Option<Mime>,
+        length: Option<u64>,
+    },
+}
+
+#[pymethods]
+impl MultipartForm {
+    #[new]
+    fn new() -> Self {
+        Default::default()
+    }
+
+    /// 添加文本字段
+    #[pyo3(text_signature = "($self, name, value)")]
+    fn add_text(mut self_: PyRefMut<'_, Self>, name: String, value: String) -> PyRefMut<'_, Self> {
+        self_.0.push((name, MultipartFormPart::Text(value)));
+        self_
+    }
+
+    /// 添加文件字段
+    ///
+    /// 默认使用文件名作为 `filename`，并根据文件扩展名猜测 `content_type`
+    #[pyo3(text_signature = "($self, name, path, /, filename = None, content_type = None)")]
+    #[args(filename = "None", content_type = "None")]
+    fn add_file<'p>(
+        mut self_: PyRefMut<'p, Self>,
+        name: String,
+        path: PathBuf,
+        filename: Option<String>,
+        content_type: Option<&str>,
+    ) -> PyResult<PyRefMut<'p, Self>> {
+        let mime = content_type.map(parse_mime).transpose()?;
+        self_.0.push((
+            name,
+            MultipartFormPart::File {
+                path,
+                file_name: filename,
+                mime,
+            },
+        ));
+        Ok(self_)
+    }
+
+    /// 添加数据流字段
+    ///
+    /// 如果传入 `length`，则最多只从数据流中读取 `length` 个字节
+    #[pyo3(
+        text_signature = "($self, name, reader, /, filename = None, content_type = None, length = None)"
+    )]
+    #[args(filename = "None", content_type = "None", length = "None")]
+    fn add_stream<'p>(
+        mut self_: PyRefMut<'p, Self>,
+        name: String,
+        reader: PyObject,
+        filename: Option<String>,
+        content_type: Option<&str>,
+        length: Option<u64>,
+    ) -> PyResult<PyRefMut<'p, Self>> {
+        let mime = content_type.map(parse_mime).transpose()?;
+        self_.0.push((
+            name,
+            MultipartFormPart::Stream {
+                reader,
+                file_name: filename,
+                mime,
+                length,
+            },
+        ));
+        Ok(self_)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl MultipartForm {
+    pub(crate) fn to_sync_multipart(
+        &self,
+        py: Python<'_>,
+    ) -> PyResult<qiniu_sdk::http_client::SyncMultipart<'static>> {
+        let mut multipart = qiniu_sdk::http_client::SyncMultipart::new();
+        for (name, part) in self.0.iter() {
+            let part = match part {
+                MultipartFormPart::Text(text) => {
+                    qiniu_sdk::http_client::SyncPart::text(text.to_owned())
+                }
+                MultipartFormPart::File {
+                    path,
+                    file_name,
+                    mime,
+                } => qiniu_sdk::http_client::SyncPart::file_path(path)
+                    .map_err(QiniuIoError::from_err)?
+                    .metadata(make_part_metadata(file_name, mime)),
+                MultipartFormPart::Stream {
+                    reader,
+                    file_name,
+                    mime,
+                    length,
+                } => {
+                    let reader = PythonIoBase::new(reader.clone_ref(py));
+                    let part = if let Some(length) = length {
+                        qiniu_sdk::http_client::SyncPart::stream(Read::take(reader, *length))
+                    } else {
+                        qiniu_sdk::http_client::SyncPart::stream(reader)
+                    };
+                    part.metadata(make_part_metadata(file_name, mime))
+                }
+            };
+            multipart = multipart.add_part(name.to_owned(), part);
+        }
+        Ok(multipart)
+    }
+
+    pub(crate) async fn to_async_multipart(
+        &self,
+    ) -> PyResult<qiniu_sdk::http_client::AsyncMultipart<'static>> {
+        let mut multipart = qiniu_sdk::http_client::AsyncMultipart::new();
+        for (name, part) in self.0.iter() {
+            let part = match part {
+                MultipartFormPart::Text(text) => {
+                    qiniu_sdk::http_client::AsyncPart::text(text.to_owned())
+                }
+                MultipartFormPart::File {
+                    path,
+                    file_name,
+                    mime,
+                } => qiniu_sdk::http_client::AsyncPart::file_path(path)
+                    .await
+                    .map_err(QiniuIoError::from_err)?
+                    .metadata(make_part_metadata(file_name, mime)),
+                MultipartFormPart::Stream {
+                    reader,
+                    file_name,
+                    mime,
+                    length,
+                } => {
+                    let reader = PythonIoBase::new(Python::with_gil(|py| reader.clone_ref(py)))
+                        .into_async_read();
+                    let part = if let Some(length) = length {
+                        qiniu_sdk::http_client::AsyncPart::stream(AsyncReadExt::take(
+                            reader, *length,
+                        ))
+                    } else {
+                        qiniu_sdk::http_client::AsyncPart::stream(reader)
+                    };
+                    part.metadata(make_part_metadata(file_name, mime))
+                }
+            };
+            multipart = multipart.add_part(name.to_owned(), part);
+        }
+        Ok(multipart)
+    }
+}
+
+fn make_part_metadata(
+    file_name: &Option<String>,
+    mime: &Option<Mime>,
+) -> qiniu_sdk::http_client::PartMetadata {
+    let mut metadata = qiniu_sdk::http_client::PartMetadata::default();
+    if let Some(mime) = mime {
+        metadata = metadata.mime(mime.to_owned());
+    }
+    if let Some(file_name) = file_name {
+        metadata = metadata.file_name(file_name);
+    }
+    metadata
+}
+
+/// 启用 `accept_encoding` 时，如果请求头中没有 `Accept-Encoding`，则添加 `Accept-Encoding: gzip, deflate`
+fn with_accept_encoding(
+    headers: Option<HashMap<String, String>>,
+    accept_encoding: bool,
+) -> Option<HashMap<String, String>> {
+    if !accept_encoding {
+        return headers;
+    }
+    let mut headers = headers.unwrap_or_default();
+    if !headers
+        .keys()
+        .any(|name| name.eq_ignore_ascii_case("accept-encoding"))
+    {
+        headers.insert("Accept-Encoding".to_owned(), "gzip, deflate".to_owned());
+    }
+    Some(headers)
+}
+
+/// HTTP 客户端
+///
+/// 用于发送 HTTP 请求的入口。
+///
+/// 创建 `HttpClient(http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None, max_requests_per_sec = None, progress_interval_ms = None, on_request = None, on_response_log = None, on_retry = None, accept_encoding = None, extra_headers = None)` 创建 HTTP 客户端
+///
+/// 设置 `max_requests_per_sec` 后，该客户端发出的所有 HTTP 请求（包括重试）都将经过令牌桶限速，超出速率时阻塞或异步等待。
+/// 限速器由该客户端及其所有副本共享，因此使用该客户端的上传器即使并行上传多个分片，总体请求速率仍不会超过限制，此时提高并行数并不能加快上传速度。
+///
+/// 设置 `progress_interval_ms` 后，通过 `uploading_progress` 设置的上传进度回调在该间隔内最多只会被调用一次，但上传完成时的进度总是会被回调。
+///
+/// 设置 `on_request` 或 `on_response_log` 后，每次发送请求（包括重试）前都会以 `{"method", "url", "attempt"}` 字典调用 `on_request`，
+/// 每次收到响应或请求失败后都会以 `{"method", "url", "attempt", "status", "duration_ms"}` 字典调用 `on_response_log`，请求失败且没有状态码时 `status` 为 `None`，适用于每个请求输出一行结构化日志。
+///
+/// 设置 `on_retry` 后，每次重试前退避之前都会以 `(context, retried, backoff_ns)` 调用该函数，其中 `retried` 为当时重试统计信息的副本，可用于统计每个地址的重试次数。
+/// 上传管理器和下载管理器发出的请求同样会调用通过 `http_client` 参数传入的客户端上设置的 `on_retry`。
+///
+/// `accept_encoding` 默认为 `True`，此时 `call()` 和 `async_call()` 发出的请求将携带 `Accept-Encoding: gzip, deflate` 头，并自动解压压缩过的响应体。
+/// `call()` 和 `async_call()` 总是关闭 isahc 自带的自动解压，响应体只会由本库解压一次。
+/// 可以通过传入 `accept_encoding = False` 关闭，也可以在每次调用时单独覆盖。
+///
+/// 设置 `extra_headers` 后，该客户端发出的所有 HTTP 请求（包括使用该客户端的上传器和下载器发出的请求）都将在签名前添加这些 HTTP 头，
+/// 如果请求中已经存在同名的 HTTP 头（例如通过 `call()` 的 `headers` 参数传入），则以请求中的为准。
+#[pyclass(subclass)]
+#[pyo3(
+    text_signature = "(/, http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None, max_requests_per_sec = None, progress_interval_ms = None, on_request = None, on_response_log = None, on_retry = None, accept_encoding = None, extra_headers = None)"
+)]
+#[derive(Clone)]
+pub(crate) struct HttpClient(qiniu_sdk::http_client::HttpClient, bool);
+
+#[pymethods]
+impl HttpClient {
+    #[new]
+    #[args(
+        http_caller = "None",
+        use_https = "None",
+        appended_user_agent = "None",
+        request_retrier = "None",
+        backoff = "None",
+        chooser = "None",
+        resolver = "None",
+        uploading_progress = "None",
+        receive_response_status = "None",
+        receive_response_header = "None",
+        to_resolve_domain = "None",
+        domain_resolved = "None",
+        to_choose_ips = "None",
+        ips_chosen = "None",
+        before_request_signed = "None",
+        after_request_signed = "None",
+        response_ok = "None",
+        response_error = "None",
+        before_backoff = "None",
+        after_backoff = "None",
+        max_requests_per_sec = "None",
+        progress_interval_ms = "None",
+        on_request = "None",
+        on_response_log = "None",
+        on_retry = "None",
+        accept_encoding = "None",
+        extra_headers = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        http_caller: Option<HttpCaller>,
+        use_https: Option<bool>,
+        appended_user_agent: Option<&str>,
+        request_retrier: Option<RequestRetrier>,
         backoff: Option<Backoff>,
         chooser: Option<Chooser>,
         resolver: Option<Resolver>,
@@ -1445,12 +3205,32 @@ impl HttpClient {
         response_error: Option<PyObject>,
         before_backoff: Option<PyObject>,
         after_backoff: Option<PyObject>,
+        max_requests_per_sec: Option<f64>,
+        progress_interval_ms: Option<u64>,
+        on_request: Option<PyObject>,
+        on_response_log: Option<PyObject>,
+        on_retry: Option<PyObject>,
+        accept_encoding: Option<bool>,
+        extra_headers: Option<HashMap<String, String>>,
     ) -> PyResult<Self> {
-        let mut builder = if let Some(http_caller) = http_caller {
-            qiniu_sdk::http_client::HttpClient::builder(http_caller)
+        let http_caller = if let Some(http_caller) = http_caller {
+            http_caller
+        } else {
+            HttpCaller::new(
+                qiniu_sdk::isahc::Client::default_client().map_err(QiniuIsahcError::from_err)?,
+            )
+        };
+        let http_caller = if let Some(max_requests_per_sec) = max_requests_per_sec {
+            HttpCaller::new(RateLimitedHttpCaller {
+                http_caller,
+                limiter: Arc::new(RateLimiter::new(max_requests_per_sec)?),
+            })
         } else {
-            qiniu_sdk::http_client::HttpClient::build_isahc().map_err(QiniuIsahcError::from_err)?
+            http_caller
         };
+        let mut builder = qiniu_sdk::http_client::HttpClient::builder(HttpCaller::new(
+            ExtendedHttpCaller(http_caller),
+        ));
 
         if let Some(use_https) = use_https {
             builder.use_https(use_https);
@@ -1458,9 +3238,7 @@ impl HttpClient {
         if let Some(appended_user_agent) = appended_user_agent {
             builder.appended_user_agent(appended_user_agent);
         }
-        if let Some(request_retrier) = request_retrier {
-            builder.request_retrier(request_retrier);
-        }
+        builder.request_retrier(OverridableRetrier::new(request_retrier));
         if let Some(backoff) = backoff {
             builder.backoff(backoff);
         }
@@ -1471,7 +3249,10 @@ impl HttpClient {
             builder.resolver(resolver);
         }
         if let Some(uploading_progress) = uploading_progress {
-            builder.on_uploading_progress(on_uploading_progress(uploading_progress));
+            builder.on_uploading_progress(on_uploading_progress(
+                uploading_progress,
+                progress_interval_ms,
+            ));
         }
         if let Some(receive_response_status) = receive_response_status {
             builder.on_receive_response_status(on_receive_response_status(receive_response_status));
@@ -1500,6 +3281,8 @@ impl HttpClient {
         if let Some(response_ok) = response_ok {
             builder.on_response(on_response(response_ok));
         }
+        builder.on_before_request_signed(on_attempt_record);
+        builder.on_error(on_error_record_retried);
         if let Some(response_error) = response_error {
             builder.on_error(on_error(response_error));
         }
@@ -1509,8 +3292,21 @@ impl HttpClient {
         if let Some(after_backoff) = after_backoff {
             builder.on_after_backoff(on_backoff(after_backoff));
         }
+        if on_request.is_some() || on_response_log.is_some() {
+            builder.on_before_request_signed(on_request_log(on_request));
+        }
+        if let Some(on_response_log) = on_response_log {
+            builder.on_response(on_response_ok_log(on_response_log.to_owned()));
+            builder.on_error(on_response_error_log(on_response_log));
+        }
+        if let Some(on_retry) = on_retry {
+            builder.on_before_backoff(on_retry_backoff(on_retry));
+        }
+        if let Some(extra_headers) = extra_headers {
+            builder.on_before_request_signed(on_extra_headers(parse_headers(extra_headers)?));
+        }
 
-        Ok(Self(builder.build()))
+        Ok(Self(builder.build(), accept_encoding.unwrap_or(true)))
     }
 
     /// 获得默认的 [`HttpCaller`] 实例
@@ -1549,8 +3345,24 @@ impl HttpClient {
     }
 
     /// 发出阻塞请求
+    ///
+    /// 如果传入 `on_body_complete` 回调函数，将在响应体被完全读取后以最终的 `HttpResponseParts` 调用该函数
+    ///
+    /// 如果传入 `max_body_size`，当 `Content-Length` 或实际读取的响应体字节数超过该值时将抛出 `QiniuResponseTooLargeError`
+    ///
+    /// 如果传入 `max_retries`，本次请求将不再使用客户端的重试器，而是使用七牛状态码重试器，并且总重试次数不超过 `max_retries`
+    ///
+    /// `accept_encoding` 默认使用客户端的设置，启用时如果 `headers` 中没有 `Accept-Encoding` 头，将设置为 `gzip, deflate`，并根据 `Content-Encoding` 头自动解压响应体
+    ///
+    /// 如果传入 `follow_redirects = True`，将自动跟随 3xx 响应的 `Location` 头，最多跟随 `max_redirects` 次（默认为 10 次），对于同源的新请求将使用 `authorization` 重新签名，
+    /// 跨域（协议、域名或端口不同）的新请求将移除 `Authorization` 和 `Cookie` 头且不再签名，
+    /// 返回最终的响应，重定向经过的地址可以通过 `HttpResponseParts.redirect_history` 获取。超过次数上限时将返回最后一个 3xx 响应。
+    /// 由于请求体无法重放，307 和 308 重定向仅对 GET 和 HEAD 请求生效，其他方法遇到 301、302 和 303 重定向时将改为不带请求体的 GET 请求
+    ///
+    /// 如果传入 `deadline_ms`，本次请求从调用开始计算，包括所有重试和退避在内的总耗时不超过 `deadline_ms` 毫秒，超过后将抛出 `QiniuTimeoutError`。
+    /// 每次尝试的超时时长都不超过距离截止时间的剩余时长，因此缓慢的单次请求也会在截止时间被中断
     #[pyo3(
-        text_signature = "(method, endpoints, /, service_names = None, use_https = None, version = None, path = None, headers = None, accept_json = None, accept_application_octet_stream = None, query = None, query_pairs = None, appended_user_agent = None, authorization = None, idempotent = None, bytes = None, body = None, body_len = None, content_type = None, json = None, form = None, multipart = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None)"
+        text_signature = "(method, endpoints, /, service_names = None, use_https = None, version = None, path = None, headers = None, accept_json = None, accept_application_octet_stream = None, query = None, query_pairs = None, appended_user_agent = None, authorization = None, idempotent = None, bytes = None, body = None, body_len = None, content_type = None, json = None, form = None, multipart = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None, body_path = None, on_body_complete = None, max_body_size = None, max_retries = None, accept_encoding = None, follow_redirects = None, max_redirects = None, deadline_ms = None)"
     )]
     #[args(
         service_names = "None",
@@ -1584,7 +3396,15 @@ impl HttpClient {
         response_ok = "None",
         response_error = "None",
         before_backoff = "None",
-        after_backoff = "None"
+        after_backoff = "None",
+        body_path = "None",
+        on_body_complete = "None",
+        max_body_size = "None",
+        max_retries = "None",
+        accept_encoding = "None",
+        follow_redirects = "None",
+        max_redirects = "None",
+        deadline_ms = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn call(
@@ -1609,7 +3429,7 @@ impl HttpClient {
         content_type: Option<String>,
         json: Option<PyObject>,
         form: Option<Vec<(String, Option<String>)>>,
-        multipart: Option<HashMap<String, PyObject>>,
+        multipart: Option<PyObject>,
         uploading_progress: Option<PyObject>,
         receive_response_status: Option<PyObject>,
         receive_response_header: Option<PyObject>,
@@ -1623,51 +3443,96 @@ impl HttpClient {
         response_error: Option<PyObject>,
         before_backoff: Option<PyObject>,
         after_backoff: Option<PyObject>,
+        body_path: Option<PathBuf>,
+        on_body_complete: Option<PyObject>,
+        max_body_size: Option<u64>,
+        max_retries: Option<usize>,
+        accept_encoding: Option<bool>,
+        follow_redirects: Option<bool>,
+        max_redirects: Option<usize>,
+        deadline_ms: Option<u64>,
         py: Python<'_>,
     ) -> PyResult<Py<SyncHttpResponse>> {
-        let (resp, parts) = self._call(
+        let accept_encoding = accept_encoding.unwrap_or(self.1);
+        let headers = with_accept_encoding(headers, accept_encoding);
+        let max_redirects = follow_redirects
+            .unwrap_or_default()
+            .then(|| max_redirects.unwrap_or(DEFAULT_MAX_REDIRECTS));
+        let deadline = deadline_ms.map(|ms| instant_after(Duration::from_millis(ms)));
+        let (mut resp, mut parts) = self._call(
             method,
             endpoints,
-            service_names,
-            use_https,
-            version,
-            path,
-            headers,
-            accept_json,
-            accept_application_octet_stream,
-            query,
-            query_pairs,
-            appended_user_agent,
-            authorization,
-            idempotent,
-            bytes,
-            body,
-            body_len,
-            content_type,
-            json,
-            form,
-            multipart,
-            uploading_progress,
-            receive_response_status,
-            receive_response_header,
-            to_resolve_domain,
-            domain_resolved,
-            to_choose_ips,
-            ips_chosen,
-            before_request_signed,
-            after_request_signed,
-            response_ok,
-            response_error,
-            before_backoff,
-            after_backoff,
+            HttpCallOptions {
+                service_names,
+                use_https,
+                version,
+                path,
+                headers,
+                accept_json,
+                accept_application_octet_stream,
+                query,
+                query_pairs,
+                appended_user_agent,
+                authorization,
+                idempotent,
+                bytes,
+                body,
+                body_len,
+                content_type,
+                json,
+                form,
+                multipart,
+                uploading_progress,
+                receive_response_status,
+                receive_response_header,
+                to_resolve_domain,
+                domain_resolved,
+                to_choose_ips,
+                ips_chosen,
+                before_request_signed,
+                after_request_signed,
+                response_ok,
+                response_error,
+                before_backoff,
+                after_backoff,
+                body_path,
+                max_retries,
+                max_redirects,
+                deadline,
+            },
             py,
         )?;
+        if accept_encoding {
+            resp.decode_content_encoding(&mut parts);
+        }
+        if let Some(max_body_size) = max_body_size {
+            resp.limit_body_size(&parts, max_body_size)?;
+        }
+        if let Some(on_body_complete) = on_body_complete {
+            resp.notify_body_complete(&parts, on_body_complete);
+        }
         Py::new(py, (resp, parts))
     }
 
     /// 发出异步请求
+    ///
+    /// 如果传入 `on_body_complete` 回调函数，将在响应体被完全读取后以最终的 `HttpResponseParts` 调用该函数
+    ///
+    /// 如果传入 `max_body_size`，当 `Content-Length` 或实际读取的响应体字节数超过该值时将抛出 `QiniuResponseTooLargeError`
+    ///
+    /// 如果传入 `max_retries`，本次请求将不再使用客户端的重试器，而是使用七牛状态码重试器，并且总重试次数不超过 `max_retries`
+    ///
+    /// `accept_encoding` 默认使用客户端的设置，启用时如果 `headers` 中没有 `Accept-Encoding` 头，将设置为 `gzip, deflate`，并根据 `Content-Encoding` 头自动解压响应体
+    ///
+    /// 如果传入 `follow_redirects = True`，将自动跟随 3xx 响应的 `Location` 头，最多跟随 `max_redirects` 次（默认为 10 次），对于同源的新请求将使用 `authorization` 重新签名，
+    /// 跨域（协议、域名或端口不同）的新请求将移除 `Authorization` 和 `Cookie` 头且不再签名，
+    /// 返回最终的响应，重定向经过的地址可以通过 `HttpResponseParts.redirect_history` 获取。超过次数上限时将返回最后一个 3xx 响应。
+    /// 由于请求体无法重放，307 和 308 重定向仅对 GET 和 HEAD 请求生效，其他方法遇到 301、302 和 303 重定向时将改为不带请求体的 GET 请求
+    ///
+    /// 如果传入 `deadline_ms`，本次请求从调用开始计算，包括所有重试和退避在内的总耗时不超过 `deadline_ms` 毫秒，超过后将抛出 `QiniuTimeoutError`。
+    /// 异步请求超过截止时间后将立即被取消
     #[pyo3(
-        text_signature = "(method, endpoints, /, service_names = None, use_https = None, version = None, path = None, headers = None, accept_json = None, accept_application_octet_stream = None, query = None, query_pairs = None, appended_user_agent = None, authorization = None, idempotent = None, bytes = None, body = None, body_len = None, content_type = None, json = None, form = None, multipart = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None)"
+        text_signature = "(method, endpoints, /, service_names = None, use_https = None, version = None, path = None, headers = None, accept_json = None, accept_application_octet_stream = None, query = None, query_pairs = None, appended_user_agent = None, authorization = None, idempotent = None, bytes = None, body = None, body_len = None, content_type = None, json = None, form = None, multipart = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None, body_path = None, on_body_complete = None, max_body_size = None, max_retries = None, accept_encoding = None, follow_redirects = None, max_redirects = None, deadline_ms = None)"
     )]
     #[args(
         service_names = "None",
@@ -1701,7 +3566,15 @@ impl HttpClient {
         response_ok = "None",
         response_error = "None",
         before_backoff = "None",
-        after_backoff = "None"
+        after_backoff = "None",
+        body_path = "None",
+        on_body_complete = "None",
+        max_body_size = "None",
+        max_retries = "None",
+        accept_encoding = "None",
+        follow_redirects = "None",
+        max_redirects = "None",
+        deadline_ms = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn async_call<'p>(
@@ -1726,7 +3599,7 @@ impl HttpClient {
         content_type: Option<String>,
         json: Option<PyObject>,
         form: Option<Vec<(String, Option<String>)>>,
-        multipart: Option<HashMap<String, PyObject>>,
+        multipart: Option<PyObject>,
         uploading_progress: Option<PyObject>,
         receive_response_status: Option<PyObject>,
         receive_response_header: Option<PyObject>,
@@ -1740,49 +3613,194 @@ impl HttpClient {
         response_error: Option<PyObject>,
         before_backoff: Option<PyObject>,
         after_backoff: Option<PyObject>,
+        body_path: Option<PathBuf>,
+        on_body_complete: Option<PyObject>,
+        max_body_size: Option<u64>,
+        max_retries: Option<usize>,
+        accept_encoding: Option<bool>,
+        follow_redirects: Option<bool>,
+        max_redirects: Option<usize>,
+        deadline_ms: Option<u64>,
         py: Python<'p>,
     ) -> PyResult<&'p PyAny> {
         let http_client = self.to_owned();
+        let accept_encoding = accept_encoding.unwrap_or(self.1);
+        let headers = with_accept_encoding(headers, accept_encoding);
+        let max_redirects = follow_redirects
+            .unwrap_or_default()
+            .then(|| max_redirects.unwrap_or(DEFAULT_MAX_REDIRECTS));
+        let deadline = deadline_ms.map(|ms| instant_after(Duration::from_millis(ms)));
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            let (resp, parts) = http_client
+            let (resp, mut parts) = http_client
                 ._async_call(
                     method,
                     endpoints,
-                    service_names,
-                    use_https,
-                    version,
+                    HttpCallOptions {
+                        service_names,
+                        use_https,
+                        version,
+                        path,
+                        headers,
+                        accept_json,
+                        accept_application_octet_stream,
+                        query,
+                        query_pairs,
+                        appended_user_agent,
+                        authorization,
+                        idempotent,
+                        bytes,
+                        body,
+                        body_len,
+                        content_type,
+                        json,
+                        form,
+                        multipart,
+                        uploading_progress,
+                        receive_response_status,
+                        receive_response_header,
+                        to_resolve_domain,
+                        domain_resolved,
+                        to_choose_ips,
+                        ips_chosen,
+                        before_request_signed,
+                        after_request_signed,
+                        response_ok,
+                        response_error,
+                        before_backoff,
+                        after_backoff,
+                        body_path,
+                        max_retries,
+                        max_redirects,
+                        deadline,
+                    },
+                )
+                .await?;
+            if accept_encoding {
+                resp.decode_content_encoding(&mut parts).await;
+            }
+            if let Some(max_body_size) = max_body_size {
+                resp.limit_body_size(&parts, max_body_size).await?;
+            }
+            if let Some(on_body_complete) = on_body_complete {
+                resp.notify_body_complete(&parts, on_body_complete).await;
+            }
+            Python::with_gil(|py| Py::new(py, (resp, parts)))
+        })
+    }
+
+    /// 发出阻塞 GET 请求，并解析 JSON 响应体
+    ///
+    /// `query` 可以是查询字符串，也可以是查询参数字典
+    #[pyo3(
+        text_signature = "(endpoints, path, /, query = None, headers = None, authorization = None)"
+    )]
+    #[args(query = "None", headers = "None", authorization = "None")]
+    fn get(
+        &self,
+        endpoints: PyObject,
+        path: String,
+        query: Option<PyObject>,
+        headers: Option<HashMap<String, String>>,
+        authorization: Option<Authorization>,
+        py: Python<'_>,
+    ) -> PyResult<Py<JsonResponse>> {
+        self._call_json(
+            "GET",
+            endpoints,
+            path,
+            query,
+            headers,
+            authorization,
+            None,
+            py,
+        )
+    }
+
+    /// 发出异步 GET 请求，并解析 JSON 响应体
+    ///
+    /// `query` 可以是查询字符串，也可以是查询参数字典
+    #[pyo3(
+        text_signature = "(endpoints, path, /, query = None, headers = None, authorization = None)"
+    )]
+    #[args(query = "None", headers = "None", authorization = "None")]
+    fn async_get<'p>(
+        &self,
+        endpoints: PyObject,
+        path: String,
+        query: Option<PyObject>,
+        headers: Option<HashMap<String, String>>,
+        authorization: Option<Authorization>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let http_client = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            http_client
+                ._async_call_json("GET", endpoints, path, query, headers, authorization, None)
+                .await
+        })
+    }
+
+    /// 发出阻塞 POST 请求，请求体为 JSON，并解析 JSON 响应体
+    ///
+    /// `query` 可以是查询字符串，也可以是查询参数字典
+    #[pyo3(
+        text_signature = "(endpoints, path, json, /, query = None, headers = None, authorization = None)"
+    )]
+    #[args(query = "None", headers = "None", authorization = "None")]
+    #[allow(clippy::too_many_arguments)]
+    fn post_json(
+        &self,
+        endpoints: PyObject,
+        path: String,
+        json: PyObject,
+        query: Option<PyObject>,
+        headers: Option<HashMap<String, String>>,
+        authorization: Option<Authorization>,
+        py: Python<'_>,
+    ) -> PyResult<Py<JsonResponse>> {
+        self._call_json(
+            "POST",
+            endpoints,
+            path,
+            query,
+            headers,
+            authorization,
+            Some(json),
+            py,
+        )
+    }
+
+    /// 发出异步 POST 请求，请求体为 JSON，并解析 JSON 响应体
+    ///
+    /// `query` 可以是查询字符串，也可以是查询参数字典
+    #[pyo3(
+        text_signature = "(endpoints, path, json, /, query = None, headers = None, authorization = None)"
+    )]
+    #[args(query = "None", headers = "None", authorization = "None")]
+    #[allow(clippy::too_many_arguments)]
+    fn async_post_json<'p>(
+        &self,
+        endpoints: PyObject,
+        path: String,
+        json: PyObject,
+        query: Option<PyObject>,
+        headers: Option<HashMap<String, String>>,
+        authorization: Option<Authorization>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let http_client = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            http_client
+                ._async_call_json(
+                    "POST",
+                    endpoints,
                     path,
-                    headers,
-                    accept_json,
-                    accept_application_octet_stream,
                     query,
-                    query_pairs,
-                    appended_user_agent,
+                    headers,
                     authorization,
-                    idempotent,
-                    bytes,
-                    body,
-                    body_len,
-                    content_type,
-                    json,
-                    form,
-                    multipart,
-                    uploading_progress,
-                    receive_response_status,
-                    receive_response_header,
-                    to_resolve_domain,
-                    domain_resolved,
-                    to_choose_ips,
-                    ips_chosen,
-                    before_request_signed,
-                    after_request_signed,
-                    response_ok,
-                    response_error,
-                    before_backoff,
-                    after_backoff,
+                    Some(json),
                 )
-                .await?;
-            Python::with_gil(|py| Py::new(py, (resp, parts)))
+                .await
         })
     }
 
@@ -1793,48 +3811,210 @@ impl HttpClient {
     fn __str__(&self) -> String {
         self.__repr__()
     }
+
+    fn __reduce__(slf: &PyCell<Self>) -> PyResult<()> {
+        Err(unpicklable_error(slf))
+    }
+}
+
+fn split_query(
+    query: Option<PyObject>,
+    py: Python<'_>,
+) -> PyResult<(Option<String>, Option<PyObject>)> {
+    match query {
+        Some(query) => {
+            if let Ok(query) = query.extract::<String>(py) {
+                Ok((Some(query), None))
+            } else {
+                Ok((None, Some(query)))
+            }
+        }
+        None => Ok((None, None)),
+    }
+}
+
+/// 将单次尝试的超时时长限制为距离截止时间的剩余时长，使得阻塞请求中单次缓慢的尝试也会在截止时间被中止
+fn limit_attempt_timeout(
+    extensions: &mut qiniu_sdk::http::Extensions,
+    deadline: Instant,
+) -> AnyResult<()> {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining.is_zero() {
+        return Err(IoError::new(IoErrorKind::TimedOut, "http call deadline exceeded").into());
+    }
+    extensions.insert(qiniu_sdk::isahc::TimeoutRequestExtension::new(remaining));
+    Ok(())
+}
+
+fn convert_deadline_error(
+    err: qiniu_sdk::http_client::ResponseError,
+    deadline: Option<Instant>,
+) -> PyErr {
+    match deadline {
+        Some(deadline) if Instant::now() >= deadline => {
+            QiniuTimeoutError::new_err(format!("http call deadline exceeded: {}", err))
+        }
+        _ => QiniuApiCallError::from_err(MaybeOwned::Owned(err)),
+    }
+}
+
+/// `HttpClient._call()` 和 `HttpClient._async_call()` 的请求参数，按字段名称设置，未设置的字段保持默认值
+#[derive(Default)]
+pub(crate) struct HttpCallOptions {
+    pub(crate) service_names: Option<Vec<ServiceName>>,
+    pub(crate) use_https: Option<bool>,
+    pub(crate) version: Option<Version>,
+    pub(crate) path: Option<String>,
+    pub(crate) headers: Option<HashMap<String, String>>,
+    pub(crate) accept_json: Option<bool>,
+    pub(crate) accept_application_octet_stream: Option<bool>,
+    pub(crate) query: Option<String>,
+    pub(crate) query_pairs: Option<PyObject>,
+    pub(crate) appended_user_agent: Option<String>,
+    pub(crate) authorization: Option<Authorization>,
+    pub(crate) idempotent: Option<Idempotent>,
+    pub(crate) bytes: Option<Vec<u8>>,
+    pub(crate) body: Option<PyObject>,
+    pub(crate) body_len: Option<u64>,
+    pub(crate) content_type: Option<String>,
+    pub(crate) json: Option<PyObject>,
+    pub(crate) form: Option<Vec<(String, Option<String>)>>,
+    pub(crate) multipart: Option<PyObject>,
+    pub(crate) uploading_progress: Option<PyObject>,
+    pub(crate) receive_response_status: Option<PyObject>,
+    pub(crate) receive_response_header: Option<PyObject>,
+    pub(crate) to_resolve_domain: Option<PyObject>,
+    pub(crate) domain_resolved: Option<PyObject>,
+    pub(crate) to_choose_ips: Option<PyObject>,
+    pub(crate) ips_chosen: Option<PyObject>,
+    pub(crate) before_request_signed: Option<PyObject>,
+    pub(crate) after_request_signed: Option<PyObject>,
+    pub(crate) response_ok: Option<PyObject>,
+    pub(crate) response_error: Option<PyObject>,
+    pub(crate) before_backoff: Option<PyObject>,
+    pub(crate) after_backoff: Option<PyObject>,
+    pub(crate) body_path: Option<PathBuf>,
+    pub(crate) max_retries: Option<usize>,
+    pub(crate) max_redirects: Option<usize>,
+    pub(crate) deadline: Option<Instant>,
 }
 
 impl HttpClient {
     #[allow(clippy::too_many_arguments)]
-    pub(crate) fn _call(
+    fn _call_json(
         &self,
-        method: String,
+        method: &str,
         endpoints: PyObject,
-        service_names: Option<Vec<ServiceName>>,
-        use_https: Option<bool>,
-        version: Option<Version>,
-        path: Option<String>,
+        path: String,
+        query: Option<PyObject>,
+        headers: Option<HashMap<String, String>>,
+        authorization: Option<Authorization>,
+        json: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<Py<JsonResponse>> {
+        let (query, query_pairs) = split_query(query, py)?;
+        let (mut resp, parts) = self._call(
+            method.to_owned(),
+            endpoints,
+            HttpCallOptions {
+                path: Some(path),
+                headers,
+                accept_json: Some(true),
+                query,
+                query_pairs,
+                authorization,
+                json,
+                ..Default::default()
+            },
+            py,
+        )?;
+        let json = JsonResponse::from(resp.parse_json()?);
+        Py::new(py, (json, parts))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn _async_call_json(
+        &self,
+        method: &str,
+        endpoints: PyObject,
+        path: String,
+        query: Option<PyObject>,
         headers: Option<HashMap<String, String>>,
-        accept_json: Option<bool>,
-        accept_application_octet_stream: Option<bool>,
-        query: Option<String>,
-        query_pairs: Option<PyObject>,
-        appended_user_agent: Option<String>,
         authorization: Option<Authorization>,
-        idempotent: Option<Idempotent>,
-        bytes: Option<Vec<u8>>,
-        body: Option<PyObject>,
-        body_len: Option<u64>,
-        content_type: Option<String>,
         json: Option<PyObject>,
-        form: Option<Vec<(String, Option<String>)>>,
-        multipart: Option<HashMap<String, PyObject>>,
-        uploading_progress: Option<PyObject>,
-        receive_response_status: Option<PyObject>,
-        receive_response_header: Option<PyObject>,
-        to_resolve_domain: Option<PyObject>,
-        domain_resolved: Option<PyObject>,
-        to_choose_ips: Option<PyObject>,
-        ips_chosen: Option<PyObject>,
-        before_request_signed: Option<PyObject>,
-        after_request_signed: Option<PyObject>,
-        response_ok: Option<PyObject>,
-        response_error: Option<PyObject>,
-        before_backoff: Option<PyObject>,
-        after_backoff: Option<PyObject>,
+    ) -> PyResult<Py<JsonResponse>> {
+        let (query, query_pairs) = Python::with_gil(|py| split_query(query, py))?;
+        let (mut resp, parts) = self
+            ._async_call(
+                method.to_owned(),
+                endpoints,
+                HttpCallOptions {
+                    path: Some(path),
+                    headers,
+                    accept_json: Some(true),
+                    query,
+                    query_pairs,
+                    authorization,
+                    json,
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let json = JsonResponse::from(resp._parse_json().await?);
+        Python::with_gil(|py| Py::new(py, (json, parts)))
+    }
+
+    pub(crate) fn _call(
+        &self,
+        method: String,
+        endpoints: PyObject,
+        options: HttpCallOptions,
         py: Python<'_>,
     ) -> PyResult<(SyncHttpResponse, HttpResponseParts)> {
+        let HttpCallOptions {
+            service_names,
+            use_https,
+            version,
+            path,
+            headers,
+            accept_json,
+            accept_application_octet_stream,
+            query,
+            query_pairs,
+            appended_user_agent,
+            authorization,
+            idempotent,
+            bytes,
+            body,
+            body_len,
+            content_type,
+            json,
+            form,
+            multipart,
+            uploading_progress,
+            receive_response_status,
+            receive_response_header,
+            to_resolve_domain,
+            domain_resolved,
+            to_choose_ips,
+            ips_chosen,
+            before_request_signed,
+            after_request_signed,
+            response_ok,
+            response_error,
+            before_backoff,
+            after_backoff,
+            body_path,
+            max_retries,
+            max_redirects,
+            deadline,
+        } = options;
+        let follow_redirects = max_redirects.map(|max_redirects| {
+            FollowRedirectsExtension::new(
+                max_redirects,
+                authorization.as_ref().map(|auth| auth.0.to_owned()),
+            )
+        });
         let service_names = service_names
             .unwrap_or_default()
             .into_iter()
@@ -1878,7 +4058,7 @@ impl HttpClient {
                 content_type.as_ref().map(|s| parse_mime(s)).transpose()?,
             );
         } else if let Some(body) = body {
-            if let Some(body_len) = body_len {
+            if let Some(body_len) = get_body_len(&body, body_len, py)? {
                 builder.stream_as_body(
                     PythonIoBase::new(body),
                     body_len,
@@ -1889,6 +4069,14 @@ impl HttpClient {
                     "`body_len` must be passed",
                 ));
             }
+        } else if let Some(body_path) = body_path {
+            let file = fs::File::open(body_path).map_err(QiniuIoError::from_err)?;
+            let body_len = file.metadata().map_err(QiniuIoError::from_err)?.len();
+            builder.stream_as_body(
+                file,
+                body_len,
+                content_type.as_ref().map(|s| parse_mime(s)).transpose()?,
+            );
         } else if let Some(json) = json {
             builder
                 .json(convert_py_any_to_json_value(json)?)
@@ -1900,55 +4088,80 @@ impl HttpClient {
                 .multipart(extract_sync_multipart(multipart)?)
                 .map_err(QiniuIoError::from_err)?;
         }
+        if let Some(max_retries) = max_retries {
+            builder.add_extension(MaxRetriesExtension(max_retries));
+        }
+        if let Some(follow_redirects) = follow_redirects {
+            builder.add_extension(follow_redirects);
+        }
+        if let Some(deadline) = deadline {
+            builder.add_extension(DeadlineExtension(deadline));
+            builder.on_before_request_signed(move |context| {
+                limit_attempt_timeout(context.extensions_mut(), deadline)
+            });
+        }
+        builder.add_extension(qiniu_sdk::isahc::AutomaticDecompressionRequestExtension::new(false));
 
         let response = py.allow_threads(|| {
             builder
                 .call()
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                .map_err(|err| convert_deadline_error(err, deadline))
         })?;
         let (parts, body) = response.into_parts_and_body();
         Ok((SyncHttpResponse::from(body), HttpResponseParts::from(parts)))
     }
 
-    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn _async_call(
         &self,
         method: String,
         endpoints: PyObject,
-        service_names: Option<Vec<ServiceName>>,
-        use_https: Option<bool>,
-        version: Option<Version>,
-        path: Option<String>,
-        headers: Option<HashMap<String, String>>,
-        accept_json: Option<bool>,
-        accept_application_octet_stream: Option<bool>,
-        query: Option<String>,
-        query_pairs: Option<PyObject>,
-        appended_user_agent: Option<String>,
-        authorization: Option<Authorization>,
-        idempotent: Option<Idempotent>,
-        bytes: Option<Vec<u8>>,
-        body: Option<PyObject>,
-        body_len: Option<u64>,
-        content_type: Option<String>,
-        json: Option<PyObject>,
-        form: Option<Vec<(String, Option<String>)>>,
-        multipart: Option<HashMap<String, PyObject>>,
-        uploading_progress: Option<PyObject>,
-        receive_response_status: Option<PyObject>,
-        receive_response_header: Option<PyObject>,
-        to_resolve_domain: Option<PyObject>,
-        domain_resolved: Option<PyObject>,
-        to_choose_ips: Option<PyObject>,
-        ips_chosen: Option<PyObject>,
-        before_request_signed: Option<PyObject>,
-        after_request_signed: Option<PyObject>,
-        response_ok: Option<PyObject>,
-        response_error: Option<PyObject>,
-        before_backoff: Option<PyObject>,
-        after_backoff: Option<PyObject>,
+        options: HttpCallOptions,
     ) -> PyResult<(AsyncHttpResponse, HttpResponseParts)> {
+        let HttpCallOptions {
+            service_names,
+            use_https,
+            version,
+            path,
+            headers,
+            accept_json,
+            accept_application_octet_stream,
+            query,
+            query_pairs,
+            appended_user_agent,
+            authorization,
+            idempotent,
+            bytes,
+            body,
+            body_len,
+            content_type,
+            json,
+            form,
+            multipart,
+            uploading_progress,
+            receive_response_status,
+            receive_response_header,
+            to_resolve_domain,
+            domain_resolved,
+            to_choose_ips,
+            ips_chosen,
+            before_request_signed,
+            after_request_signed,
+            response_ok,
+            response_error,
+            before_backoff,
+            after_backoff,
+            body_path,
+            max_retries,
+            max_redirects,
+            deadline,
+        } = options;
         let mut local_agent = None;
+        let follow_redirects = max_redirects.map(|max_redirects| {
+            FollowRedirectsExtension::new(
+                max_redirects,
+                authorization.as_ref().map(|auth| auth.0.to_owned()),
+            )
+        });
         let service_names = service_names
             .unwrap_or_default()
             .into_iter()
@@ -1995,7 +4208,7 @@ impl HttpClient {
                     .transpose()?,
             );
         } else if let Some(body) = body {
-            if let Some(body_len) = body_len {
+            if let Some(body_len) = Python::with_gil(|py| get_body_len(&body, body_len, py))? {
                 let (stream, agent) = PythonIoBase::new(body).into_async_read_with_local_agent();
                 local_agent = Some(agent);
                 builder.stream_as_body(
@@ -2011,6 +4224,19 @@ impl HttpClient {
                     "`body_len` must be passed",
                 ));
             }
+        } else if let Some(body_path) = body_path {
+            let file = async_std::fs::File::open(body_path)
+                .await
+                .map_err(QiniuIoError::from_err)?;
+            let body_len = file.metadata().await.map_err(QiniuIoError::from_err)?.len();
+            builder.stream_as_body(
+                file,
+                body_len,
+                content_type
+                    .as_ref()
+                    .map(|s| parse_mime(s.as_str()))
+                    .transpose()?,
+            );
         } else if let Some(json) = json {
             builder
                 .json(convert_py_any_to_json_value(json)?)
@@ -2019,17 +4245,37 @@ impl HttpClient {
             builder.post_form(form);
         } else if let Some(multipart) = multipart {
             builder
-                .multipart(extract_async_multipart(multipart)?)
+                .multipart(extract_async_multipart(multipart).await?)
                 .await
                 .map_err(QiniuIoError::from_err)?;
         }
+        if let Some(max_retries) = max_retries {
+            builder.add_extension(MaxRetriesExtension(max_retries));
+        }
+        if let Some(follow_redirects) = follow_redirects {
+            builder.add_extension(follow_redirects);
+        }
+        if let Some(deadline) = deadline {
+            builder.add_extension(DeadlineExtension(deadline));
+        }
+        builder.add_extension(qiniu_sdk::isahc::AutomaticDecompressionRequestExtension::new(false));
 
-        let response = if let Some(mut local_agent) = local_agent {
-            local_agent.run(builder.call()).await?
+        let call = async move {
+            if let Some(mut local_agent) = local_agent {
+                local_agent.run(builder.call()).await
+            } else {
+                Ok(builder.call().await)
+            }
+        };
+        let response = if let Some(deadline) = deadline {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            async_std::future::timeout(remaining, call)
+                .await
+                .map_err(|_| QiniuTimeoutError::new_err("http call deadline exceeded"))??
         } else {
-            builder.call().await
+            call.await?
         }
-        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+        .map_err(|err| convert_deadline_error(err, deadline))?;
         let (parts, body) = response.into_parts_and_body();
         Ok((
             AsyncHttpResponse::from(body),
@@ -2098,7 +4344,7 @@ impl HttpClient {
             builder.idempotent(idempotent.into());
         }
         if let Some(uploading_progress) = uploading_progress {
-            builder.on_uploading_progress(on_uploading_progress(uploading_progress));
+            builder.on_uploading_progress(on_uploading_progress(uploading_progress, None));
         }
         if let Some(receive_response_status) = receive_response_status {
             builder.on_receive_response_status(on_receive_response_status(receive_response_status));
@@ -2148,10 +4394,72 @@ impl From<HttpClient> for qiniu_sdk::http_client::HttpClient {
 
 impl From<qiniu_sdk::http_client::HttpClient> for HttpClient {
     fn from(client: qiniu_sdk::http_client::HttpClient) -> Self {
-        Self(client)
+        Self(client, true)
     }
 }
 
+static SHARED_HTTP_CLIENT: GILOnceCell<Mutex<SharedHttpClient>> = GILOnceCell::new();
+
+#[derive(Default)]
+struct SharedHttpClient {
+    http_client: Option<HttpClient>,
+    enabled: bool,
+}
+
+/// 获取默认共享的 HTTP 客户端
+///
+/// 首次调用时创建，之后总是返回同一个 HTTP 客户端，以复用其连接池。
+///
+/// 仅在调用 `set_default_shared_http_client` 设置过之后，上传管理器和下载管理器在没有传入 `http_client` 和 `use_https` 时才会默认使用该 HTTP 客户端
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+fn default_shared_http_client(py: Python<'_>) -> PyResult<HttpClient> {
+    let mut shared = shared_http_client_slot(py).lock().unwrap();
+    if let Some(http_client) = shared.http_client.as_ref() {
+        return Ok(http_client.to_owned());
+    }
+    let http_client = new_extended_http_client()?;
+    shared.http_client = Some(http_client.to_owned());
+    Ok(http_client)
+}
+
+/// 设置默认共享的 HTTP 客户端，设置后上传管理器和下载管理器将默认使用该 HTTP 客户端
+///
+/// 如果传入 `None`，则重置默认共享的 HTTP 客户端，上传管理器和下载管理器将不再默认使用共享的 HTTP 客户端
+#[pyfunction(http_client = "None")]
+#[pyo3(text_signature = "(http_client = None)")]
+fn set_default_shared_http_client(http_client: Option<HttpClient>, py: Python<'_>) {
+    let mut shared = shared_http_client_slot(py).lock().unwrap();
+    shared.enabled = http_client.is_some();
+    shared.http_client = http_client;
+}
+
+/// 获取通过 `set_default_shared_http_client` 设置的默认共享 HTTP 客户端，如果没有设置过则返回 `None`
+pub(crate) fn enabled_shared_http_client(py: Python<'_>) -> Option<HttpClient> {
+    let shared = shared_http_client_slot(py).lock().unwrap();
+    shared
+        .enabled
+        .then(|| shared.http_client.to_owned())
+        .flatten()
+}
+
+/// 创建支持扩展功能（如跟随重定向）的 HTTP 客户端
+pub(crate) fn new_extended_http_client() -> PyResult<HttpClient> {
+    let mut builder = qiniu_sdk::http_client::HttpClient::builder(HttpCaller::new(
+        ExtendedHttpCaller(HttpCaller::new(
+            qiniu_sdk::isahc::Client::default_client().map_err(QiniuIsahcError::from_err)?,
+        )),
+    ));
+    builder.request_retrier(OverridableRetrier::new(None));
+    builder.on_before_request_signed(on_attempt_record);
+    builder.on_error(on_error_record_retried);
+    Ok(HttpClient(builder.build(), true))
+}
+
+fn shared_http_client_slot(py: Python<'_>) -> &Mutex<SharedHttpClient> {
+    SHARED_HTTP_CLIENT.get_or_init(py, Default::default)
+}
+
 macro_rules! impl_callback_context {
     ($name:ident) => {
         #[pymethods]
@@ -2290,6 +4598,7 @@ impl_callback_context!(SimplifiedCallbackContext);
 
 fn on_uploading_progress(
     callback: PyObject,
+    progress_interval_ms: Option<u64>,
 ) -> impl Fn(
     &dyn qiniu_sdk::http_client::SimplifiedCallbackContext,
     qiniu_sdk::http::TransferProgressInfo<'_>,
@@ -2297,7 +4606,13 @@ fn on_uploading_progress(
        + Send
        + Sync
        + 'static {
+    let throttle = progress_interval_ms.map(ProgressThrottle::new);
     move |context, progress| {
+        if let Some(throttle) = &throttle {
+            if !throttle.should_emit(progress.transferred_bytes(), Some(progress.total_bytes())) {
+                return Ok(());
+            }
+        }
         Python::with_gil(|py| {
             callback.call1(
                 py,
@@ -2526,7 +4841,7 @@ impl ExtendedCallbackContextRef {
     /// 获取重试统计信息
     #[getter]
     fn get_retried(&self) -> RetriedStatsInfo {
-        RetriedStatsInfo(self.0.retried().to_owned())
+        retried_stats_info_of(&*self.0)
     }
 }
 
@@ -2581,6 +4896,177 @@ fn on_error(
     }
 }
 
+/// 将出错时的重试统计信息记录在错误的扩展中，供 `QiniuApiCallErrorInfo.to_dict()` 读取
+fn on_error_record_retried(
+    context: &mut dyn qiniu_sdk::http_client::ExtendedCallbackContext,
+    error: &mut qiniu_sdk::http_client::ResponseError,
+) -> AnyResult<()> {
+    let retried = context.retried().to_owned();
+    error.extensions_mut().insert(retried);
+    Ok(())
+}
+
+/// 在请求扩展中记录每次尝试发出请求的时间，由于请求扩展在多次尝试之间保留，因此可以得到首次和最近一次尝试的时间
+fn on_attempt_record(
+    context: &mut dyn qiniu_sdk::http_client::ExtendedCallbackContext,
+) -> AnyResult<()> {
+    let extensions = context.extensions_mut();
+    if let Some(timestamps) = extensions.get_mut::<AttemptTimestamps>() {
+        timestamps.record();
+    } else {
+        extensions.insert(AttemptTimestamps::now());
+    }
+    Ok(())
+}
+
+/// 从回调函数上下文中获取重试统计信息，附带请求扩展中记录的尝试时间
+fn retried_stats_info_of(
+    context: &dyn qiniu_sdk::http_client::ExtendedCallbackContext,
+) -> RetriedStatsInfo {
+    RetriedStatsInfo(
+        context.retried().to_owned(),
+        context
+            .extensions()
+            .get::<AttemptTimestamps>()
+            .copied()
+            .unwrap_or_default(),
+    )
+}
+
+/// 记录本次尝试发出请求的时刻，用于计算请求耗时
+#[derive(Debug, Clone, Copy)]
+struct RequestStartedAt(Instant);
+
+fn on_extra_headers(
+    extra_headers: qiniu_sdk::http::HeaderMap,
+) -> impl Fn(&mut dyn qiniu_sdk::http_client::ExtendedCallbackContext) -> AnyResult<()>
+       + Send
+       + Sync
+       + 'static {
+    move |context| {
+        let headers = context.headers_mut();
+        for (name, value) in extra_headers.iter() {
+            if !headers.contains_key(name) {
+                headers.insert(name.to_owned(), value.to_owned());
+            }
+        }
+        Ok(())
+    }
+}
+
+fn on_request_log(
+    callback: Option<PyObject>,
+) -> impl Fn(&mut dyn qiniu_sdk::http_client::ExtendedCallbackContext) -> AnyResult<()>
+       + Send
+       + Sync
+       + 'static {
+    move |context| {
+        context
+            .extensions_mut()
+            .insert(RequestStartedAt(Instant::now()));
+        if let Some(callback) = &callback {
+            Python::with_gil(|py| {
+                let log = make_request_log(context, py)?;
+                callback.call1(py, (log,))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+fn on_response_ok_log(
+    callback: PyObject,
+) -> impl Fn(
+    &mut dyn qiniu_sdk::http_client::ExtendedCallbackContext,
+    &qiniu_sdk::http::ResponseParts,
+) -> AnyResult<()>
+       + Send
+       + Sync
+       + 'static {
+    move |context, parts| {
+        let status = parts.status_code().as_u16();
+        Python::with_gil(|py| {
+            let log = make_response_log(context, Some(status), py)?;
+            callback.call1(py, (log,))
+        })?;
+        Ok(())
+    }
+}
+
+fn on_response_error_log(
+    callback: PyObject,
+) -> impl Fn(
+    &mut dyn qiniu_sdk::http_client::ExtendedCallbackContext,
+    &mut qiniu_sdk::http_client::ResponseError,
+) -> AnyResult<()>
+       + Send
+       + Sync
+       + 'static {
+    move |context, error| {
+        use qiniu_sdk::http_client::ResponseErrorKind;
+        let status = match error.kind() {
+            ResponseErrorKind::StatusCodeError(status_code) => Some(status_code.as_u16()),
+            ResponseErrorKind::UnexpectedStatusCode(status_code) => Some(status_code.as_u16()),
+            _ => None,
+        };
+        Python::with_gil(|py| {
+            let log = make_response_log(context, status, py)?;
+            callback.call1(py, (log,))
+        })?;
+        Ok(())
+    }
+}
+
+fn make_request_log<'p>(
+    context: &dyn qiniu_sdk::http_client::ExtendedCallbackContext,
+    py: Python<'p>,
+) -> PyResult<&'p PyDict> {
+    let log = PyDict::new(py);
+    log.set_item("method", context.method().to_string())?;
+    log.set_item("url", context.url().to_string())?;
+    log.set_item("attempt", context.retried().retried_total() + 1)?;
+    Ok(log)
+}
+
+fn make_response_log<'p>(
+    context: &dyn qiniu_sdk::http_client::ExtendedCallbackContext,
+    status: Option<u16>,
+    py: Python<'p>,
+) -> PyResult<&'p PyDict> {
+    let log = make_request_log(context, py)?;
+    log.set_item("status", status)?;
+    log.set_item(
+        "duration_ms",
+        context
+            .extensions()
+            .get::<RequestStartedAt>()
+            .map(|started_at| started_at.0.elapsed().as_millis()),
+    )?;
+    Ok(log)
+}
+
+fn on_retry_backoff(
+    callback: PyObject,
+) -> impl Fn(&mut dyn qiniu_sdk::http_client::ExtendedCallbackContext, Duration) -> AnyResult<()>
+       + Send
+       + Sync
+       + 'static {
+    move |context, duration| {
+        let retried = retried_stats_info_of(context);
+        Python::with_gil(|py| {
+            callback.call1(
+                py,
+                (
+                    ExtendedCallbackContextRef::new(context),
+                    retried,
+                    duration.as_nanos(),
+                ),
+            )
+        })?;
+        Ok(())
+    }
+}
+
 fn on_backoff(
     callback: PyObject,
 ) -> impl Fn(&mut dyn qiniu_sdk::http_client::ExtendedCallbackContext, Duration) -> AnyResult<()>
@@ -2726,7 +5212,7 @@ impl RequestBuilderPartsRef {
     #[pyo3(text_signature = "($self, callback)")]
     fn on_uploading_progress(&mut self, callback: PyObject) {
         self.0
-            .on_uploading_progress(on_uploading_progress(callback));
+            .on_uploading_progress(on_uploading_progress(callback, None));
     }
 
     /// 设置响应状态码回调函数
@@ -2812,7 +5298,7 @@ impl RequestBuilderPartsRef {
 ///
 /// 该类型没有构造函数，由七牛 API 客户端的 `call()` 方法返回
 #[pyclass(extends = HttpResponseParts)]
-pub(crate) struct JsonResponse(PyObject);
+pub(crate) struct JsonResponse(PyObject, Option<Vec<u8>>);
 
 #[pymethods]
 impl JsonResponse {
@@ -2822,6 +5308,14 @@ impl JsonResponse {
         self.0.as_ref(py)
     }
 
+    /// 获得原始响应体
+    ///
+    /// 仅当调用时传入 `keep_raw=True` 才会保留，否则返回 `None`
+    #[getter]
+    fn get_raw_bytes<'p>(&self, py: Python<'p>) -> Option<&'p PyBytes> {
+        self.1.as_ref().map(|raw| PyBytes::new(py, raw))
+    }
+
     fn __len__(&self, py: Python<'_>) -> PyResult<usize> {
         self.0.as_ref(py).len()
     }
@@ -2847,8 +5341,14 @@ impl JsonResponse {
     }
 }
 
+impl JsonResponse {
+    pub(crate) fn with_raw(obj: PyObject, raw: Vec<u8>) -> Self {
+        Self(obj, Some(raw))
+    }
+}
+
 impl From<PyObject> for JsonResponse {
     fn from(obj: PyObject) -> Self {
-        Self(obj)
+        Self(obj, None)
     }
 }
@@ -4,8 +4,10 @@ mod client;
 mod region;
 
 pub(super) use client::{
-    Authorization, Backoff, CallbackContextMut, Chooser, HttpClient, Idempotent, JsonResponse,
-    RequestBuilderPartsRef, RequestRetrier, Resolver,
+    enabled_shared_http_client, new_extended_http_client, Authorization, Backoff,
+    CallbackContextMut, Chooser, FollowRedirectsExtension, HttpCallOptions, HttpClient, Idempotent,
+    JsonResponse, MultipartForm, RequestBuilderPartsRef, RequestRetrier, Resolver,
+    DEFAULT_MAX_REDIRECTS,
 };
 pub(super) use region::{
     BucketRegionsQueryer, Endpoint, Endpoints, EndpointsProvider, RegionsProvider, ServiceName,
@@ -1,16 +1,33 @@
 use crate::{
     credential::CredentialProvider,
     exceptions::{
-        QiniuApiCallError, QiniuEmptyRegionsProvider, QiniuInvalidEndpointError,
-        QiniuInvalidIpAddrWithPortError,
+        QiniuApiCallError, QiniuEmptyRegionsProvider, QiniuInvalidConcurrency,
+        QiniuInvalidEndpointError, QiniuInvalidIpAddrWithPortError, QiniuJsonError,
+    },
+    utils::{
+        extract_endpoints, instant_after, join_thread, parse_domain_with_port, unpicklable_error,
     },
-    utils::{extract_endpoints, parse_domain_with_port},
 };
-use futures::future::BoxFuture;
+use futures::{future::BoxFuture, StreamExt};
 use maybe_owned::MaybeOwned;
-use pyo3::{prelude::*, pyclass::CompareOp};
-use qiniu_sdk::http_client::EndpointsGetOptions;
-use std::{borrow::Cow, path::PathBuf, time::Duration};
+use pyo3::{
+    prelude::*,
+    pyclass::CompareOp,
+    types::{PyTuple, PyType},
+};
+use qiniu_sdk::http_client::{EndpointsGetOptions, RegionsProvider as _};
+use std::{
+    borrow::Cow,
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
 pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<DomainWithPort>()?;
@@ -22,6 +39,7 @@ pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<Region>()?;
     m.add_class::<RegionsProvider>()?;
     m.add_class::<AllRegionsProvider>()?;
+    m.add_class::<AcceleratedRegionsProvider>()?;
     m.add_class::<BucketRegionsQueryer>()?;
     m.add_class::<BucketDomainsQueryer>()?;
 
@@ -87,7 +105,7 @@ impl DomainWithPort {
 #[pyclass]
 #[pyo3(text_signature = "(ip, port = None)")]
 #[derive(Clone)]
-struct IpAddrWithPort(qiniu_sdk::http_client::IpAddrWithPort);
+pub(super) struct IpAddrWithPort(qiniu_sdk::http_client::IpAddrWithPort);
 
 #[pymethods]
 impl IpAddrWithPort {
@@ -131,28 +149,73 @@ impl IpAddrWithPort {
     }
 }
 
+impl From<IpAddrWithPort> for qiniu_sdk::http_client::IpAddrWithPort {
+    fn from(ip_addr_with_port: IpAddrWithPort) -> Self {
+        ip_addr_with_port.0
+    }
+}
+
+impl From<qiniu_sdk::http_client::IpAddrWithPort> for IpAddrWithPort {
+    fn from(ip_addr_with_port: qiniu_sdk::http_client::IpAddrWithPort) -> Self {
+        Self(ip_addr_with_port)
+    }
+}
+
 /// 终端地址
 ///
 /// 用来表示一个域名和端口号，或 IP 地址和端口号。
 ///
 /// 通过 `Endpoint(domain_or_ip_addr, port = None)` 创建域名和端口号
+///
+/// `domain_or_ip_addr` 也可以是带有 `http://` 或 `https://` 前缀的 URL，
+/// 此时将移除前缀和路径，没有指定端口时使用该协议的默认端口，并通过 `use_https` 记录该协议是否为 HTTPS
 #[pyclass]
 #[pyo3(text_signature = "(domain_or_ip_addr, port = None)")]
 #[derive(Clone)]
-pub(crate) struct Endpoint(qiniu_sdk::http_client::Endpoint);
+pub(crate) struct Endpoint(qiniu_sdk::http_client::Endpoint, Option<bool>);
 
 #[pymethods]
 impl Endpoint {
     #[new]
     #[args(port = "None")]
-    fn new(domain_or_ip_addr: String, port: Option<u16>) -> PyResult<Self> {
-        let host = if let Some(port) = port {
-            format!("{}:{}", domain_or_ip_addr, port).parse()
-        } else {
-            domain_or_ip_addr.parse()
+    fn new(domain_or_ip_addr: &str, port: Option<u16>) -> PyResult<Self> {
+        let (use_https, host) = split_url_scheme(domain_or_ip_addr)?;
+        let parse = |port: Option<u16>| {
+            if let Some(port) = port {
+                format!("{}:{}", host, port).parse()
+            } else {
+                host.parse()
+            }
+            .map_err(QiniuInvalidEndpointError::from_err)
+        };
+        let mut endpoint: qiniu_sdk::http_client::Endpoint = parse(port)?;
+        if let Some(use_https) = use_https {
+            if endpoint.port().is_none() {
+                endpoint = parse(Some(if use_https { 443 } else { 80 }))?;
+            }
         }
-        .map_err(QiniuInvalidEndpointError::from_err)?;
-        Ok(Self(host))
+        Ok(Self(endpoint, use_https))
+    }
+
+    /// 从 URL 创建终端地址
+    ///
+    /// URL 必须带有 `http://` 或 `https://` 前缀
+    #[staticmethod]
+    #[pyo3(text_signature = "(url)")]
+    fn from_url(url: &str) -> PyResult<Self> {
+        if !url.contains("://") {
+            return Err(QiniuInvalidEndpointError::new_err(format!(
+                "URL must start with http:// or https://: {}",
+                url
+            )));
+        }
+        Self::new(url, None)
+    }
+
+    /// 获取创建时的 URL 前缀是否为 HTTPS，没有前缀时返回 `None`
+    #[getter]
+    fn get_use_https(&self) -> Option<bool> {
+        self.1
     }
 
     /// 获取域名
@@ -195,6 +258,33 @@ impl From<Endpoint> for qiniu_sdk::http_client::Endpoint {
     }
 }
 
+impl From<qiniu_sdk::http_client::Endpoint> for Endpoint {
+    fn from(e: qiniu_sdk::http_client::Endpoint) -> Self {
+        Self(e, None)
+    }
+}
+
+/// 拆分 URL 前缀和主机部分，返回前缀是否为 HTTPS，没有前缀时返回 `None`
+fn split_url_scheme(url: &str) -> PyResult<(Option<bool>, &str)> {
+    let (scheme, rest) = if let Some((scheme, rest)) = url.split_once("://") {
+        (scheme, rest)
+    } else {
+        return Ok((None, url));
+    };
+    let use_https = if scheme.eq_ignore_ascii_case("https") {
+        true
+    } else if scheme.eq_ignore_ascii_case("http") {
+        false
+    } else {
+        return Err(QiniuInvalidEndpointError::new_err(format!(
+            "Unsupported URL scheme: {}",
+            scheme
+        )));
+    };
+    let host = rest.split(&['/', '?', '#'][..]).next().unwrap_or_default();
+    Ok((Some(use_https), host))
+}
+
 /// 七牛服务名称
 #[pyclass]
 #[derive(Clone, Copy, Debug)]
@@ -335,6 +425,10 @@ impl EndpointsProvider {
     fn __str__(&self) -> String {
         self.__repr__()
     }
+
+    fn __reduce__(slf: &PyCell<Self>) -> PyResult<()> {
+        Err(unpicklable_error(slf))
+    }
 }
 
 impl qiniu_sdk::http_client::EndpointsProvider for EndpointsProvider {
@@ -387,12 +481,7 @@ impl Endpoints {
         preferred_endpoints: Vec<&PyAny>,
         alternative_endpoints: Option<Vec<&PyAny>>,
     ) -> PyResult<(Self, EndpointsProvider)> {
-        let mut builder = qiniu_sdk::http_client::EndpointsBuilder::default();
-        builder.add_preferred_endpoints(extract_endpoints(preferred_endpoints)?);
-        if let Some(alternative_endpoints) = alternative_endpoints {
-            builder.add_alternative_endpoints(extract_endpoints(alternative_endpoints)?);
-        }
-        let endpoints = builder.build();
+        let endpoints = make_endpoints(preferred_endpoints, alternative_endpoints)?;
         Ok((
             Self(endpoints.to_owned()),
             EndpointsProvider(Box::new(endpoints)),
@@ -402,13 +491,122 @@ impl Endpoints {
     /// 返回主要终端地址列表
     #[getter]
     fn get_preferred(&self) -> Vec<Endpoint> {
-        self.0.preferred().iter().cloned().map(Endpoint).collect()
+        self.0
+            .preferred()
+            .iter()
+            .cloned()
+            .map(Endpoint::from)
+            .collect()
     }
 
     /// 返回备选终端地址列表
     #[getter]
     fn get_alternative(&self) -> Vec<Endpoint> {
-        self.0.alternative().iter().cloned().map(Endpoint).collect()
+        self.0
+            .alternative()
+            .iter()
+            .cloned()
+            .map(Endpoint::from)
+            .collect()
+    }
+
+    /// 从静态的终端地址列表创建终端地址列表
+    ///
+    /// 适用于私有云或终端地址固定的场景，可以直接用于上传、对象管理和 HTTP 客户端调用，无需查询区域信息
+    #[staticmethod]
+    #[pyo3(text_signature = "(preferred, /, alternative = None)")]
+    #[args(alternative = "None")]
+    fn from_list(
+        preferred: Vec<&PyAny>,
+        alternative: Option<Vec<&PyAny>>,
+        py: Python<'_>,
+    ) -> PyResult<Py<Self>> {
+        let endpoints = make_endpoints(preferred, alternative)?;
+        Py::new(
+            py,
+            (
+                Self(endpoints.to_owned()),
+                EndpointsProvider(Box::new(endpoints)),
+            ),
+        )
+    }
+
+    /// 基于当前终端地址列表创建优先使用加速终端地址的终端地址列表
+    ///
+    /// 加速主要终端地址将作为新的主要终端地址，加速备选终端地址和当前的所有终端地址将依次作为新的备选终端地址，
+    /// 因此当加速终端地址不可用时，将通过重试机制回退到当前的终端地址。
+    /// 对于私有云，可以在这里传入自定义的加速域名。
+    #[pyo3(
+        text_signature = "($self, accelerated_preferred_endpoints, /, accelerated_alternative_endpoints = None)"
+    )]
+    #[args(accelerated_alternative_endpoints = "None")]
+    fn with_accelerated_endpoints(
+        &self,
+        accelerated_preferred_endpoints: Vec<&PyAny>,
+        accelerated_alternative_endpoints: Option<Vec<&PyAny>>,
+        py: Python<'_>,
+    ) -> PyResult<Py<Self>> {
+        let accelerated = make_endpoints(
+            accelerated_preferred_endpoints,
+            accelerated_alternative_endpoints,
+        )?;
+        let endpoints = accelerate_endpoints(
+            &accelerated,
+            self.0.preferred().iter().chain(self.0.alternative()),
+        );
+        Py::new(
+            py,
+            (
+                Self(endpoints.to_owned()),
+                EndpointsProvider(Box::new(endpoints)),
+            ),
+        )
+    }
+
+    /// 将终端地址列表序列化为 JSON 字符串
+    ///
+    /// 序列化结果保留主要终端地址和备选终端地址各自的顺序，可以通过 `Endpoints.from_json()` 还原
+    #[pyo3(text_signature = "($self)")]
+    fn to_json(&self) -> String {
+        serde_json::json!({
+            "preferred": self.0.preferred().iter().map(|endpoint| endpoint.to_string()).collect::<Vec<_>>(),
+            "alternative": self.0.alternative().iter().map(|endpoint| endpoint.to_string()).collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+
+    /// 从 JSON 字符串反序列化终端地址列表
+    #[staticmethod]
+    #[pyo3(text_signature = "(json)")]
+    fn from_json(json: &str, py: Python<'_>) -> PyResult<Py<Self>> {
+        let mut lists: HashMap<String, Vec<String>> =
+            serde_json::from_str(json).map_err(QiniuJsonError::from_err)?;
+        let mut builder = qiniu_sdk::http_client::EndpointsBuilder::default();
+        builder.add_preferred_endpoints(parse_endpoint_strs(
+            lists.remove("preferred").unwrap_or_default(),
+        )?);
+        builder.add_alternative_endpoints(parse_endpoint_strs(
+            lists.remove("alternative").unwrap_or_default(),
+        )?);
+        let endpoints = builder.build();
+        Py::new(
+            py,
+            (
+                Self(endpoints.to_owned()),
+                EndpointsProvider(Box::new(endpoints)),
+            ),
+        )
+    }
+
+    /// 支持通过 `pickle` 序列化终端地址列表，以便在多进程之间传递
+    fn __reduce__<'p>(&self, py: Python<'p>) -> (&'p PyType, (Vec<String>, Vec<String>)) {
+        (
+            py.get_type::<Self>(),
+            (
+                endpoint_strs(self.0.preferred()),
+                endpoint_strs(self.0.alternative()),
+            ),
+        )
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
@@ -417,6 +615,64 @@ impl Endpoints {
             _ => py.NotImplemented(),
         }
     }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for endpoints in [self.0.preferred(), self.0.alternative()] {
+            endpoints.len().hash(&mut hasher);
+            for endpoint in endpoints {
+                endpoint.to_string().hash(&mut hasher);
+            }
+        }
+        hasher.finish()
+    }
+}
+
+fn endpoint_strs(endpoints: &[qiniu_sdk::http_client::Endpoint]) -> Vec<String> {
+    endpoints
+        .iter()
+        .map(|endpoint| endpoint.to_string())
+        .collect()
+}
+
+fn parse_endpoint_strs(endpoints: Vec<String>) -> PyResult<Vec<qiniu_sdk::http_client::Endpoint>> {
+    endpoints
+        .into_iter()
+        .map(|endpoint| {
+            endpoint
+                .parse()
+                .map_err(QiniuInvalidEndpointError::from_err)
+        })
+        .collect()
+}
+
+fn make_endpoints(
+    preferred_endpoints: Vec<&PyAny>,
+    alternative_endpoints: Option<Vec<&PyAny>>,
+) -> PyResult<qiniu_sdk::http_client::Endpoints> {
+    let mut builder = qiniu_sdk::http_client::EndpointsBuilder::default();
+    builder.add_preferred_endpoints(extract_endpoints(preferred_endpoints)?);
+    if let Some(alternative_endpoints) = alternative_endpoints {
+        builder.add_alternative_endpoints(extract_endpoints(alternative_endpoints)?);
+    }
+    Ok(builder.build())
+}
+
+fn accelerate_endpoints<'a>(
+    accelerated: &'a qiniu_sdk::http_client::Endpoints,
+    fallback: impl Iterator<Item = &'a qiniu_sdk::http_client::Endpoint>,
+) -> qiniu_sdk::http_client::Endpoints {
+    let mut builder = qiniu_sdk::http_client::EndpointsBuilder::default();
+    builder.add_preferred_endpoints(accelerated.preferred().to_vec());
+    builder.add_alternative_endpoints(
+        accelerated
+            .alternative()
+            .iter()
+            .chain(fallback)
+            .cloned()
+            .collect::<Vec<_>>(),
+    );
+    builder.build()
 }
 
 impl From<Endpoints> for qiniu_sdk::http_client::Endpoints {
@@ -518,6 +774,10 @@ impl RegionsProvider {
     fn __str__(&self) -> String {
         self.__repr__()
     }
+
+    fn __reduce__(slf: &PyCell<Self>) -> PyResult<()> {
+        Err(unpicklable_error(slf))
+    }
 }
 
 impl qiniu_sdk::http_client::RegionsProvider for RegionsProvider {
@@ -562,6 +822,136 @@ impl RegionsProvider {
     }
 }
 
+impl RegionsProvider {
+    /// 将终端地址列表获取接口转换为区域信息获取接口
+    ///
+    /// 转换得到的区域的所有服务都使用终端地址列表获取接口针对该服务返回的终端地址列表
+    pub(crate) fn from_endpoints_provider(endpoints_provider: EndpointsProvider) -> Self {
+        Self(Box::new(EndpointsRegionsProvider(endpoints_provider)))
+    }
+
+    /// 包装为上传加速区域信息获取接口，与 `AcceleratedRegionsProvider` 相同
+    pub(crate) fn accelerated(self, accelerated: Endpoints) -> Self {
+        Self(Box::new(AcceleratedRegionsProviderImpl {
+            regions_provider: self,
+            accelerated: accelerated.into(),
+        }))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct EndpointsRegionsProvider(EndpointsProvider);
+
+impl EndpointsRegionsProvider {
+    fn get_endpoints(
+        &self,
+        service_name: qiniu_sdk::http_client::ServiceName,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::Endpoints> {
+        let service_names = [service_name];
+        let opts = EndpointsGetOptions::builder()
+            .service_names(&service_names)
+            .build();
+        Ok(qiniu_sdk::http_client::EndpointsProvider::get_endpoints(&self.0, opts)?.into_owned())
+    }
+
+    async fn async_get_endpoints(
+        &self,
+        service_name: qiniu_sdk::http_client::ServiceName,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::Endpoints> {
+        let service_names = [service_name];
+        let opts = EndpointsGetOptions::builder()
+            .service_names(&service_names)
+            .build();
+        Ok(
+            qiniu_sdk::http_client::EndpointsProvider::async_get_endpoints(&self.0, opts)
+                .await?
+                .into_owned(),
+        )
+    }
+
+    fn make_region(&self) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::Region> {
+        let endpoints = SERVICE_NAMES
+            .iter()
+            .map(|&service_name| self.get_endpoints(service_name))
+            .collect::<qiniu_sdk::http_client::ApiResult<Vec<_>>>()?;
+        Ok(build_region_from_endpoints(endpoints))
+    }
+
+    async fn async_make_region(
+        &self,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::Region> {
+        let mut endpoints = Vec::with_capacity(SERVICE_NAMES.len());
+        for &service_name in SERVICE_NAMES.iter() {
+            endpoints.push(self.async_get_endpoints(service_name).await?);
+        }
+        Ok(build_region_from_endpoints(endpoints))
+    }
+}
+
+const SERVICE_NAMES: [qiniu_sdk::http_client::ServiceName; 7] = [
+    qiniu_sdk::http_client::ServiceName::Up,
+    qiniu_sdk::http_client::ServiceName::Io,
+    qiniu_sdk::http_client::ServiceName::Uc,
+    qiniu_sdk::http_client::ServiceName::Rs,
+    qiniu_sdk::http_client::ServiceName::Rsf,
+    qiniu_sdk::http_client::ServiceName::Api,
+    qiniu_sdk::http_client::ServiceName::S3,
+];
+
+/// 按照 `SERVICE_NAMES` 的顺序传入各个服务的终端地址列表
+fn build_region_from_endpoints(
+    endpoints: Vec<qiniu_sdk::http_client::Endpoints>,
+) -> qiniu_sdk::http_client::Region {
+    let mut builder = qiniu_sdk::http_client::Region::builder(String::new());
+    let [up, io, uc, rs, rsf, api, s3]: [qiniu_sdk::http_client::Endpoints; 7] =
+        endpoints.try_into().expect("unexpected endpoints count");
+    builder.add_up_preferred_endpoints(up.preferred().to_vec());
+    builder.add_up_alternative_endpoints(up.alternative().to_vec());
+    builder.add_io_preferred_endpoints(io.preferred().to_vec());
+    builder.add_io_alternative_endpoints(io.alternative().to_vec());
+    builder.add_uc_preferred_endpoints(uc.preferred().to_vec());
+    builder.add_uc_alternative_endpoints(uc.alternative().to_vec());
+    builder.add_rs_preferred_endpoints(rs.preferred().to_vec());
+    builder.add_rs_alternative_endpoints(rs.alternative().to_vec());
+    builder.add_rsf_preferred_endpoints(rsf.preferred().to_vec());
+    builder.add_rsf_alternative_endpoints(rsf.alternative().to_vec());
+    builder.add_api_preferred_endpoints(api.preferred().to_vec());
+    builder.add_api_alternative_endpoints(api.alternative().to_vec());
+    builder.add_s3_preferred_endpoints(s3.preferred().to_vec());
+    builder.add_s3_alternative_endpoints(s3.alternative().to_vec());
+    builder.build()
+}
+
+impl qiniu_sdk::http_client::RegionsProvider for EndpointsRegionsProvider {
+    fn get(
+        &self,
+        _opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion> {
+        Ok(self.make_region()?.into())
+    }
+
+    fn get_all(
+        &self,
+        _opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions> {
+        Ok(vec![self.make_region()?].into())
+    }
+
+    fn async_get(
+        &self,
+        _opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion>> {
+        Box::pin(async move { Ok(self.async_make_region().await?.into()) })
+    }
+
+    fn async_get_all(
+        &self,
+        _opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions>> {
+        Box::pin(async move { Ok(vec![self.async_make_region().await?].into()) })
+    }
+}
+
 impl From<Box<dyn qiniu_sdk::http_client::RegionsProvider>> for RegionsProvider {
     fn from(provider: Box<dyn qiniu_sdk::http_client::RegionsProvider>) -> Self {
         RegionsProvider(provider)
@@ -578,10 +968,14 @@ impl From<RegionsProvider> for Box<dyn qiniu_sdk::http_client::RegionsProvider>
 ///
 /// 提供七牛不同服务的终端地址列表
 ///
-/// 通过 `Region(region_id, s3_region_id = None, up_preferred_endpoints = None, up_alternative_endpoints = None, io_preferred_endpoints = None, io_alternative_endpoints = None, uc_preferred_endpoints = None, uc_preferred_endpoints = None, rs_preferred_endpoints = None, rs_alternative_endpoints = None, rsf_preferred_endpoints = None, rsf_alternative_endpoints = None, s3_preferred_endpoints = None, s3_alternative_endpoints = None, api_preferred_endpoints = None, api_alternative_endpoints = None)` 创建七牛存储区域
+/// 通过 `Region(region_id, s3_region_id = None, up_preferred_endpoints = None, up_alternative_endpoints = None, io_preferred_endpoints = None, io_alternative_endpoints = None, uc_preferred_endpoints = None, uc_alternative_endpoints = None, rs_preferred_endpoints = None, rs_alternative_endpoints = None, rsf_preferred_endpoints = None, rsf_alternative_endpoints = None, s3_preferred_endpoints = None, s3_alternative_endpoints = None, api_preferred_endpoints = None, api_alternative_endpoints = None, up = None, io = None, uc = None, rs = None, rsf = None, api = None, s3 = None)` 创建七牛存储区域
+///
+/// `up`、`io`、`uc`、`rs`、`rsf`、`api`、`s3` 可以直接传入 `Endpoints` 或是终端地址列表（作为主要终端地址），便于以代码定义私有云区域，并直接作为区域信息提供者使用，无需查询区域信息
+///
+/// 两个区域的区域 ID 和各个服务的终端地址列表都相同时，两者相等
 #[pyclass(extends = RegionsProvider)]
 #[pyo3(
-    text_signature = "(region_id, /, s3_region_id = None, up_preferred_endpoints = None, up_alternative_endpoints = None, io_preferred_endpoints = None, io_alternative_endpoints = None, uc_preferred_endpoints = None, uc_preferred_endpoints = None, rs_preferred_endpoints = None, rs_alternative_endpoints = None, rsf_preferred_endpoints = None, rsf_alternative_endpoints = None, s3_preferred_endpoints = None, s3_alternative_endpoints = None, api_preferred_endpoints = None, api_alternative_endpoints = None)"
+    text_signature = "(region_id, /, s3_region_id = None, up_preferred_endpoints = None, up_alternative_endpoints = None, io_preferred_endpoints = None, io_alternative_endpoints = None, uc_preferred_endpoints = None, uc_alternative_endpoints = None, rs_preferred_endpoints = None, rs_alternative_endpoints = None, rsf_preferred_endpoints = None, rsf_alternative_endpoints = None, s3_preferred_endpoints = None, s3_alternative_endpoints = None, api_preferred_endpoints = None, api_alternative_endpoints = None, up = None, io = None, uc = None, rs = None, rsf = None, api = None, s3 = None)"
 )]
 #[derive(Clone)]
 struct Region(qiniu_sdk::http_client::Region);
@@ -596,7 +990,7 @@ impl Region {
         io_preferred_endpoints = "None",
         io_alternative_endpoints = "None",
         uc_preferred_endpoints = "None",
-        uc_preferred_endpoints = "None",
+        uc_alternative_endpoints = "None",
         rs_preferred_endpoints = "None",
         rs_alternative_endpoints = "None",
         rsf_preferred_endpoints = "None",
@@ -604,7 +998,14 @@ impl Region {
         s3_preferred_endpoints = "None",
         s3_alternative_endpoints = "None",
         api_preferred_endpoints = "None",
-        api_alternative_endpoints = "None"
+        api_alternative_endpoints = "None",
+        up = "None",
+        io = "None",
+        uc = "None",
+        rs = "None",
+        rsf = "None",
+        api = "None",
+        s3 = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -624,6 +1025,13 @@ impl Region {
         s3_alternative_endpoints: Option<Vec<&PyAny>>,
         api_preferred_endpoints: Option<Vec<&PyAny>>,
         api_alternative_endpoints: Option<Vec<&PyAny>>,
+        up: Option<&PyAny>,
+        io: Option<&PyAny>,
+        uc: Option<&PyAny>,
+        rs: Option<&PyAny>,
+        rsf: Option<&PyAny>,
+        api: Option<&PyAny>,
+        s3: Option<&PyAny>,
     ) -> PyResult<(Self, RegionsProvider)> {
         let mut builder = qiniu_sdk::http_client::Region::builder(region_id);
         if let Some(s3_region_id) = s3_region_id {
@@ -671,6 +1079,34 @@ impl Region {
         if let Some(endpoints) = api_alternative_endpoints {
             builder.add_api_alternative_endpoints(extract_endpoints(endpoints)?);
         }
+        if let Some(endpoints) = up.map(extract_service_endpoints).transpose()? {
+            builder.add_up_preferred_endpoints(endpoints.preferred().to_vec());
+            builder.add_up_alternative_endpoints(endpoints.alternative().to_vec());
+        }
+        if let Some(endpoints) = io.map(extract_service_endpoints).transpose()? {
+            builder.add_io_preferred_endpoints(endpoints.preferred().to_vec());
+            builder.add_io_alternative_endpoints(endpoints.alternative().to_vec());
+        }
+        if let Some(endpoints) = uc.map(extract_service_endpoints).transpose()? {
+            builder.add_uc_preferred_endpoints(endpoints.preferred().to_vec());
+            builder.add_uc_alternative_endpoints(endpoints.alternative().to_vec());
+        }
+        if let Some(endpoints) = rs.map(extract_service_endpoints).transpose()? {
+            builder.add_rs_preferred_endpoints(endpoints.preferred().to_vec());
+            builder.add_rs_alternative_endpoints(endpoints.alternative().to_vec());
+        }
+        if let Some(endpoints) = rsf.map(extract_service_endpoints).transpose()? {
+            builder.add_rsf_preferred_endpoints(endpoints.preferred().to_vec());
+            builder.add_rsf_alternative_endpoints(endpoints.alternative().to_vec());
+        }
+        if let Some(endpoints) = api.map(extract_service_endpoints).transpose()? {
+            builder.add_api_preferred_endpoints(endpoints.preferred().to_vec());
+            builder.add_api_alternative_endpoints(endpoints.alternative().to_vec());
+        }
+        if let Some(endpoints) = s3.map(extract_service_endpoints).transpose()? {
+            builder.add_s3_preferred_endpoints(endpoints.preferred().to_vec());
+            builder.add_s3_alternative_endpoints(endpoints.alternative().to_vec());
+        }
         let region = builder.build();
         Ok((Self(region.to_owned()), RegionsProvider(Box::new(region))))
     }
@@ -813,6 +1249,33 @@ impl Region {
         encapsulate_endpoint_vec(self.0.s3_alternative_endpoints())
     }
 
+    /// 支持通过 `pickle` 序列化区域信息，以便在多进程之间传递
+    fn __reduce__<'p>(&self, py: Python<'p>) -> (&'p PyType, &'p PyTuple) {
+        let mut args = vec![
+            self.0.region_id().into_py(py),
+            self.0.s3_region_id().into_py(py),
+        ];
+        for endpoints in [
+            self.0.up_preferred_endpoints(),
+            self.0.up_alternative_endpoints(),
+            self.0.io_preferred_endpoints(),
+            self.0.io_alternative_endpoints(),
+            self.0.uc_preferred_endpoints(),
+            self.0.uc_alternative_endpoints(),
+            self.0.rs_preferred_endpoints(),
+            self.0.rs_alternative_endpoints(),
+            self.0.rsf_preferred_endpoints(),
+            self.0.rsf_alternative_endpoints(),
+            self.0.s3_preferred_endpoints(),
+            self.0.s3_alternative_endpoints(),
+            self.0.api_preferred_endpoints(),
+            self.0.api_alternative_endpoints(),
+        ] {
+            args.push(endpoint_strs(endpoints).into_py(py));
+        }
+        (py.get_type::<Self>(), PyTuple::new(py, args))
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -831,13 +1294,20 @@ impl Region {
 
 /// 七牛所有区域信息查询器
 ///
-/// 通过 `AllRegionsProvider(credential_provider, auto_persistent = True, use_https = True, uc_endpoints = None, cache_lifetime_secs = None, shrink_interval_secs = None)` 创建七牛所有区域信息查询器
+/// 通过 `AllRegionsProvider(credential_provider, auto_persistent = True, use_https = True, uc_endpoints = None, cache_lifetime_secs = None, shrink_interval_secs = None, cache_path = None)` 创建七牛所有区域信息查询器
+///
+/// 指定 `cache_path` 时将使用该路径作为持久化缓存文件，等同于 `AllRegionsProvider.load_or_create_from()`
+///
+/// 可以通过 `get_all()` 或 `async_get_all()` 获取所有区域信息，通过 `refresh()` 或 `async_refresh()` 跳过缓存强制重新获取。
 #[pyclass(extends = RegionsProvider)]
 #[pyo3(
-    text_signature = "(credential_provider, /, auto_persistent = True, use_https = True, uc_endpoints = None, cache_lifetime_secs = None, shrink_interval_secs = None)"
+    text_signature = "(credential_provider, /, auto_persistent = True, use_https = True, uc_endpoints = None, cache_lifetime_secs = None, shrink_interval_secs = None, cache_path = None)"
 )]
 #[derive(Clone)]
-struct AllRegionsProvider;
+struct AllRegionsProvider {
+    config: AllRegionsProviderConfig,
+    persistence: AllRegionsProviderPersistence,
+}
 
 #[pymethods]
 impl AllRegionsProvider {
@@ -847,7 +1317,8 @@ impl AllRegionsProvider {
         use_https = "true",
         uc_endpoints = "None",
         cache_lifetime_secs = "None",
-        shrink_interval_secs = "None"
+        shrink_interval_secs = "None",
+        cache_path = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -857,19 +1328,24 @@ impl AllRegionsProvider {
         uc_endpoints: Option<Endpoints>,
         cache_lifetime_secs: Option<u64>,
         shrink_interval_secs: Option<u64>,
+        cache_path: Option<PathBuf>,
     ) -> (Self, RegionsProvider) {
-        let builder = Self::new_builder(
-            credential_provider,
-            use_https,
-            uc_endpoints,
-            cache_lifetime_secs,
-            shrink_interval_secs,
-        );
-        (
-            Self,
-            RegionsProvider(Box::new(
-                builder.default_load_or_create_from(auto_persistent),
-            )),
+        let persistence = match cache_path {
+            Some(path) => AllRegionsProviderPersistence::Path {
+                path,
+                auto_persistent,
+            },
+            None => AllRegionsProviderPersistence::Default { auto_persistent },
+        };
+        Self::new_with(
+            AllRegionsProviderConfig {
+                credential_provider,
+                use_https,
+                uc_endpoints,
+                cache_lifetime_secs,
+                shrink_interval_secs,
+            },
+            persistence,
         )
     }
 
@@ -898,18 +1374,20 @@ impl AllRegionsProvider {
         shrink_interval_secs: Option<u64>,
         py: Python<'_>,
     ) -> PyResult<Py<Self>> {
-        let builder = Self::new_builder(
-            credential_provider,
-            use_https,
-            uc_endpoints,
-            cache_lifetime_secs,
-            shrink_interval_secs,
-        );
         Py::new(
             py,
-            (
-                Self,
-                RegionsProvider(Box::new(builder.load_or_create_from(path, auto_persistent))),
+            Self::new_with(
+                AllRegionsProviderConfig {
+                    credential_provider,
+                    use_https,
+                    uc_endpoints,
+                    cache_lifetime_secs,
+                    shrink_interval_secs,
+                },
+                AllRegionsProviderPersistence::Path {
+                    path,
+                    auto_persistent,
+                },
             ),
         )
     }
@@ -936,40 +1414,359 @@ impl AllRegionsProvider {
         shrink_interval_secs: Option<u64>,
         py: Python<'_>,
     ) -> PyResult<Py<Self>> {
-        let builder = Self::new_builder(
-            credential_provider,
-            use_https,
-            uc_endpoints,
-            cache_lifetime_secs,
-            shrink_interval_secs,
-        );
-        Py::new(py, (Self, RegionsProvider(Box::new(builder.in_memory()))))
+        Py::new(
+            py,
+            Self::new_with(
+                AllRegionsProviderConfig {
+                    credential_provider,
+                    use_https,
+                    uc_endpoints,
+                    cache_lifetime_secs,
+                    shrink_interval_secs,
+                },
+                AllRegionsProviderPersistence::InMemory,
+            ),
+        )
+    }
+
+    /// 跳过缓存，强制重新获取所有区域信息
+    ///
+    /// 获取成功后，该查询器在缓存时长内将使用新获取到的区域信息，并返回获取到的所有区域信息；获取失败时原有的缓存保持不变。
+    /// 持久化缓存的设置保持不变，但持久化缓存文件中的记录仍将在其自身过期后才会被更新。
+    /// 已经使用该查询器创建的上传器或对象管理器不受影响
+    #[pyo3(text_signature = "($self)")]
+    fn refresh(mut self_: PyRefMut<'_, Self>, py: Python<'_>) -> PyResult<Vec<Py<Region>>> {
+        let fetcher = Self::new_builder(&self_.config).in_memory();
+        let regions = py
+            .allow_threads(|| {
+                qiniu_sdk::http_client::RegionsProvider::get_all(&fetcher, Default::default())
+            })
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+        self_.as_mut().0 = Box::new(self_.make_refreshed_provider(regions.to_owned()));
+        regions
+            .into_regions()
+            .into_iter()
+            .map(|region| RegionsProvider::make_initializer(region, py))
+            .collect()
+    }
+
+    /// 异步跳过缓存，强制重新获取所有区域信息
+    ///
+    /// 获取成功后，该查询器在缓存时长内将使用新获取到的区域信息，并返回获取到的所有区域信息；获取失败时原有的缓存保持不变
+    #[pyo3(text_signature = "($self)")]
+    fn async_refresh<'p>(self_: PyRef<'p, Self>, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let fetcher = Self::new_builder(&self_.config).in_memory();
+        let self_: Py<Self> = self_.into();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let regions = qiniu_sdk::http_client::RegionsProvider::async_get_all(
+                &fetcher,
+                Default::default(),
+            )
+            .await
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Python::with_gil(|py| {
+                let mut self_ = self_.borrow_mut(py);
+                let provider = self_.make_refreshed_provider(regions.to_owned());
+                self_.as_mut().0 = Box::new(provider);
+                regions
+                    .into_regions()
+                    .into_iter()
+                    .map(|region| RegionsProvider::make_initializer(region, py))
+                    .collect::<PyResult<Vec<Py<Region>>>>()
+            })
+        })
+    }
+}
+
+/// 刷新后的七牛所有区域信息查询器
+///
+/// 在刷新得到的区域信息过期前直接返回它们，过期后再交给按原有持久化设置构建的查询器
+#[derive(Clone, Debug)]
+struct RefreshedAllRegionsProvider {
+    regions: qiniu_sdk::http_client::GotRegions,
+    expired_at: Instant,
+    provider: qiniu_sdk::http_client::AllRegionsProvider,
+}
+
+impl RefreshedAllRegionsProvider {
+    fn refreshed(&self) -> Option<qiniu_sdk::http_client::GotRegions> {
+        (Instant::now() < self.expired_at).then(|| self.regions.to_owned())
     }
 }
 
+impl qiniu_sdk::http_client::RegionsProvider for RefreshedAllRegionsProvider {
+    fn get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion> {
+        match self.refreshed() {
+            Some(regions) => first_refreshed_region(regions),
+            None => self.provider.get(opts),
+        }
+    }
+
+    fn get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions> {
+        match self.refreshed() {
+            Some(regions) => Ok(regions),
+            None => self.provider.get_all(opts),
+        }
+    }
+
+    fn async_get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion>> {
+        match self.refreshed() {
+            Some(regions) => Box::pin(async move { first_refreshed_region(regions) }),
+            None => self.provider.async_get(opts),
+        }
+    }
+
+    fn async_get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions>> {
+        match self.refreshed() {
+            Some(regions) => Box::pin(async move { Ok(regions) }),
+            None => self.provider.async_get_all(opts),
+        }
+    }
+}
+
+/// 区域信息 API 返回空列表时返回错误，而不是 panic
+fn first_refreshed_region(
+    regions: qiniu_sdk::http_client::GotRegions,
+) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion> {
+    regions.try_into().map_err(|err| {
+        qiniu_sdk::http_client::ResponseError::new(
+            qiniu_sdk::http_client::ResponseErrorKind::ParseResponseError,
+            err,
+        )
+    })
+}
+
+/// 与 SDK 中七牛所有区域信息查询器默认的缓存时长一致
+const DEFAULT_ALL_REGIONS_CACHE_LIFETIME_SECS: u64 = 86400;
+
+#[derive(Clone)]
+struct AllRegionsProviderConfig {
+    credential_provider: CredentialProvider,
+    use_https: bool,
+    uc_endpoints: Option<Endpoints>,
+    cache_lifetime_secs: Option<u64>,
+    shrink_interval_secs: Option<u64>,
+}
+
+#[derive(Clone)]
+enum AllRegionsProviderPersistence {
+    Default {
+        auto_persistent: bool,
+    },
+    Path {
+        path: PathBuf,
+        auto_persistent: bool,
+    },
+    InMemory,
+}
+
 impl AllRegionsProvider {
+    fn new_with(
+        config: AllRegionsProviderConfig,
+        persistence: AllRegionsProviderPersistence,
+    ) -> (Self, RegionsProvider) {
+        let provider = Self::make_provider(&config, &persistence);
+        (
+            Self {
+                config,
+                persistence,
+            },
+            RegionsProvider(Box::new(provider)),
+        )
+    }
+
+    fn make_provider(
+        config: &AllRegionsProviderConfig,
+        persistence: &AllRegionsProviderPersistence,
+    ) -> qiniu_sdk::http_client::AllRegionsProvider {
+        let builder = Self::new_builder(config);
+        match persistence {
+            AllRegionsProviderPersistence::Default { auto_persistent } => {
+                builder.default_load_or_create_from(*auto_persistent)
+            }
+            AllRegionsProviderPersistence::Path {
+                path,
+                auto_persistent,
+            } => builder.load_or_create_from(path, *auto_persistent),
+            AllRegionsProviderPersistence::InMemory => builder.in_memory(),
+        }
+    }
+
+    fn make_refreshed_provider(
+        &self,
+        regions: qiniu_sdk::http_client::GotRegions,
+    ) -> RefreshedAllRegionsProvider {
+        let cache_lifetime = Duration::from_secs(
+            self.config
+                .cache_lifetime_secs
+                .unwrap_or(DEFAULT_ALL_REGIONS_CACHE_LIFETIME_SECS),
+        );
+        RefreshedAllRegionsProvider {
+            regions,
+            expired_at: instant_after(cache_lifetime),
+            provider: Self::make_provider(&self.config, &self.persistence),
+        }
+    }
+
     fn new_builder(
-        credential_provider: CredentialProvider,
-        use_https: bool,
-        uc_endpoints: Option<Endpoints>,
-        cache_lifetime_secs: Option<u64>,
-        shrink_interval_secs: Option<u64>,
+        config: &AllRegionsProviderConfig,
     ) -> qiniu_sdk::http_client::AllRegionsProviderBuilder {
-        let mut builder = qiniu_sdk::http_client::AllRegionsProvider::builder(credential_provider);
-        builder = builder.use_https(use_https);
-        if let Some(uc_endpoints) = uc_endpoints {
-            builder = builder.uc_endpoints(uc_endpoints.0);
+        let mut builder = qiniu_sdk::http_client::AllRegionsProvider::builder(
+            config.credential_provider.to_owned(),
+        );
+        builder = builder.use_https(config.use_https);
+        if let Some(uc_endpoints) = &config.uc_endpoints {
+            builder = builder.uc_endpoints(uc_endpoints.0.to_owned());
         }
-        if let Some(cache_lifetime_secs) = cache_lifetime_secs {
+        if let Some(cache_lifetime_secs) = config.cache_lifetime_secs {
             builder = builder.cache_lifetime(Duration::from_secs(cache_lifetime_secs));
         }
-        if let Some(shrink_interval_secs) = shrink_interval_secs {
+        if let Some(shrink_interval_secs) = config.shrink_interval_secs {
             builder = builder.shrink_interval(Duration::from_secs(shrink_interval_secs));
         }
         builder
     }
 }
 
+/// 上传加速区域信息获取接口
+///
+/// 基于一个区域信息获取接口，将其返回的所有区域的上传服务终端地址替换为优先使用加速终端地址，
+/// 当加速终端地址不可用时，将通过重试机制回退到区域原本的上传服务终端地址。
+/// 对于私有云，可以在这里传入自定义的加速域名。
+///
+/// 通过 `AcceleratedRegionsProvider(regions_provider, accelerated_preferred_endpoints, accelerated_alternative_endpoints = None)` 创建上传加速区域信息获取接口
+#[pyclass(extends = RegionsProvider)]
+#[pyo3(
+    text_signature = "(regions_provider, accelerated_preferred_endpoints, /, accelerated_alternative_endpoints = None)"
+)]
+#[derive(Clone, Copy)]
+struct AcceleratedRegionsProvider;
+
+#[pymethods]
+impl AcceleratedRegionsProvider {
+    #[new]
+    #[args(accelerated_alternative_endpoints = "None")]
+    fn new(
+        regions_provider: RegionsProvider,
+        accelerated_preferred_endpoints: Vec<&PyAny>,
+        accelerated_alternative_endpoints: Option<Vec<&PyAny>>,
+    ) -> PyResult<(Self, RegionsProvider)> {
+        let accelerated = make_endpoints(
+            accelerated_preferred_endpoints,
+            accelerated_alternative_endpoints,
+        )?;
+        Ok((
+            Self,
+            RegionsProvider(Box::new(AcceleratedRegionsProviderImpl {
+                regions_provider,
+                accelerated,
+            })),
+        ))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct AcceleratedRegionsProviderImpl {
+    regions_provider: RegionsProvider,
+    accelerated: qiniu_sdk::http_client::Endpoints,
+}
+
+impl AcceleratedRegionsProviderImpl {
+    fn accelerate(
+        &self,
+        region: &qiniu_sdk::http_client::Region,
+    ) -> qiniu_sdk::http_client::Region {
+        let up = accelerate_endpoints(
+            &self.accelerated,
+            region
+                .up_preferred_endpoints()
+                .iter()
+                .chain(region.up_alternative_endpoints()),
+        );
+        let mut builder = qiniu_sdk::http_client::Region::builder(region.region_id().to_owned());
+        builder.s3_region_id(region.s3_region_id().to_owned());
+        builder.add_up_preferred_endpoints(up.preferred().to_vec());
+        builder.add_up_alternative_endpoints(up.alternative().to_vec());
+        builder.add_io_preferred_endpoints(region.io_preferred_endpoints().to_vec());
+        builder.add_io_alternative_endpoints(region.io_alternative_endpoints().to_vec());
+        builder.add_uc_preferred_endpoints(region.uc_preferred_endpoints().to_vec());
+        builder.add_uc_alternative_endpoints(region.uc_alternative_endpoints().to_vec());
+        builder.add_rs_preferred_endpoints(region.rs_preferred_endpoints().to_vec());
+        builder.add_rs_alternative_endpoints(region.rs_alternative_endpoints().to_vec());
+        builder.add_rsf_preferred_endpoints(region.rsf_preferred_endpoints().to_vec());
+        builder.add_rsf_alternative_endpoints(region.rsf_alternative_endpoints().to_vec());
+        builder.add_s3_preferred_endpoints(region.s3_preferred_endpoints().to_vec());
+        builder.add_s3_alternative_endpoints(region.s3_alternative_endpoints().to_vec());
+        builder.add_api_preferred_endpoints(region.api_preferred_endpoints().to_vec());
+        builder.add_api_alternative_endpoints(region.api_alternative_endpoints().to_vec());
+        builder.build()
+    }
+
+    fn accelerate_one(
+        &self,
+        mut region: qiniu_sdk::http_client::GotRegion,
+    ) -> qiniu_sdk::http_client::GotRegion {
+        *region = self.accelerate(&region);
+        region
+    }
+
+    fn accelerate_all(
+        &self,
+        mut regions: qiniu_sdk::http_client::GotRegions,
+    ) -> qiniu_sdk::http_client::GotRegions {
+        for region in regions.regions_mut() {
+            *region = self.accelerate(region);
+        }
+        regions
+    }
+}
+
+impl qiniu_sdk::http_client::RegionsProvider for AcceleratedRegionsProviderImpl {
+    fn get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion> {
+        Ok(self.accelerate_one(self.regions_provider.0.get(opts)?))
+    }
+
+    fn get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions> {
+        Ok(self.accelerate_all(self.regions_provider.0.get_all(opts)?))
+    }
+
+    fn async_get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion>> {
+        Box::pin(
+            async move { Ok(self.accelerate_one(self.regions_provider.0.async_get(opts).await?)) },
+        )
+    }
+
+    fn async_get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions>> {
+        Box::pin(async move {
+            Ok(self.accelerate_all(self.regions_provider.0.async_get_all(opts).await?))
+        })
+    }
+}
+
 /// 存储空间相关区域查询构建器
 ///
 /// 通过 `BucketRegionsQueryer(auto_persistent = True, use_https = True, uc_endpoints = None, cache_lifetime_secs = None, shrink_interval_secs = None)` 创建存储空间相关区域查询构建器
@@ -1079,6 +1876,107 @@ impl BucketRegionsQueryer {
     fn query(&self, access_key: &str, bucket_name: &str) -> RegionsProvider {
         RegionsProvider(Box::new(self.0.query(access_key, bucket_name)))
     }
+
+    /// 并发预查询多个存储空间的区域并写入缓存
+    ///
+    /// 之后使用该查询器（或其副本）查询这些存储空间时将直接命中缓存，无需再次请求 UC 服务器。
+    /// 缓存的区域信息在 `cache_lifetime_secs` 后过期，过期后的首次查询仍会重新请求，因此长时间运行的程序可能需要定期调用该方法。
+    /// 启用自动持久化时，缓存同样会被写入文件，从同一路径加载的其他查询器也可以使用。
+    ///
+    /// 任意一个存储空间查询失败时抛出第一个错误，其他存储空间的查询结果仍会被缓存
+    ///
+    /// 最多同时查询 `concurrency` 个存储空间，默认为 8
+    #[pyo3(text_signature = "($self, access_key, bucket_names, /, concurrency = None)")]
+    #[args(concurrency = "None")]
+    fn warm(
+        &self,
+        access_key: &str,
+        bucket_names: Vec<String>,
+        concurrency: Option<usize>,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        let concurrency = get_warm_concurrency(concurrency, bucket_names.len())?;
+        let next = Arc::new(AtomicUsize::new(0));
+        let access_key: Arc<str> = access_key.into();
+        let bucket_names = Arc::new(bucket_names);
+        let mut results = py
+            .allow_threads(|| {
+                (0..concurrency)
+                    .map(|_| {
+                        let queryer = self.0.to_owned();
+                        let access_key = access_key.to_owned();
+                        let bucket_names = bucket_names.to_owned();
+                        let next = next.to_owned();
+                        thread::spawn(move || {
+                            let mut results = Vec::new();
+                            loop {
+                                let index = next.fetch_add(1, Ordering::Relaxed);
+                                let bucket_name = match bucket_names.get(index) {
+                                    Some(bucket_name) => bucket_name,
+                                    None => break,
+                                };
+                                let provider = queryer.query(&*access_key, bucket_name.as_str());
+                                results.push((index, provider.get_all(Default::default())));
+                            }
+                            results
+                        })
+                    })
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .map(join_thread)
+                    .collect::<PyResult<Vec<_>>>()
+            })?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        results.sort_by_key(|(index, _)| *index);
+        results
+            .into_iter()
+            .try_for_each(|(_, result)| result.map(drop))
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+    }
+
+    /// 异步并发预查询多个存储空间的区域并写入缓存
+    ///
+    /// 缓存行为与并发限制与 `warm()` 相同
+    #[pyo3(text_signature = "($self, access_key, bucket_names, /, concurrency = None)")]
+    #[args(concurrency = "None")]
+    fn async_warm<'p>(
+        &self,
+        access_key: String,
+        bucket_names: Vec<String>,
+        concurrency: Option<usize>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let concurrency = get_warm_concurrency(concurrency, bucket_names.len())?;
+        let queryer = self.0.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let providers = bucket_names
+                .iter()
+                .map(|bucket_name| queryer.query(access_key.as_str(), bucket_name.as_str()))
+                .collect::<Vec<_>>();
+            futures::stream::iter(providers.into_iter().map(|provider| async move {
+                provider.async_get_all(Default::default()).await.map(drop)
+            }))
+            .buffered(concurrency)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<qiniu_sdk::http_client::ApiResult<()>>()
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+}
+
+const DEFAULT_WARM_CONCURRENCY: usize = 8;
+
+fn get_warm_concurrency(concurrency: Option<usize>, buckets: usize) -> PyResult<usize> {
+    match concurrency {
+        Some(0) => Err(QiniuInvalidConcurrency::new_err("Invalid concurrency")),
+        Some(concurrency) => Ok(concurrency.min(buckets.max(1))),
+        None => Ok(DEFAULT_WARM_CONCURRENCY.min(buckets.max(1))),
+    }
 }
 
 impl BucketRegionsQueryer {
@@ -1251,7 +2149,16 @@ impl BucketDomainsQueryer {
 }
 
 fn encapsulate_endpoint_vec(endpoints: &[qiniu_sdk::http_client::Endpoint]) -> Vec<Endpoint> {
-    endpoints.iter().cloned().map(Endpoint).collect()
+    endpoints.iter().cloned().map(Endpoint::from).collect()
+}
+
+/// 服务终端地址既可以是 `Endpoints`，也可以是作为主要终端地址的列表
+fn extract_service_endpoints(endpoints: &PyAny) -> PyResult<qiniu_sdk::http_client::Endpoints> {
+    if let Ok(endpoints) = endpoints.extract::<Endpoints>() {
+        Ok(endpoints.0)
+    } else {
+        make_endpoints(endpoints.extract()?, None)
+    }
 }
 
 fn encapsulate_endpoints(endpoints: &qiniu_sdk::http_client::Endpoints) -> PyResult<Py<Endpoints>> {
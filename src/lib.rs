@@ -1,3 +1,6 @@
+// 大多数返回值的错误类型为七牛 SDK 的 `ResponseError`，且不少闭包的签名由 SDK 决定，无法通过装箱缩小错误类型
+#![allow(clippy::result_large_err)]
+
 mod apis;
 mod credential;
 mod download;
@@ -10,7 +13,7 @@ mod upload;
 mod upload_token;
 mod utils;
 
-use exceptions::QiniuUserAgentInitializeError;
+use exceptions::{QiniuRunningEventLoopError, QiniuUserAgentInitializeError};
 use pyo3::prelude::*;
 
 #[pymodule]
@@ -28,6 +31,8 @@ fn qiniu_bindings(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_submodule(objects::create_module(py)?)?;
     m.add_submodule(upload::create_module(py)?)?;
     m.add_submodule(download::create_module(py)?)?;
+    m.add_submodule(utils::create_module(py)?)?;
+    m.add_function(wrap_pyfunction!(run_async, m)?)?;
 
     return Ok(());
 
@@ -48,3 +53,35 @@ fn qiniu_bindings(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         })
     }
 }
+
+/// 阻塞当前线程，直到异步调用完成，并返回其结果
+///
+/// `awaitable` 可以是协程，也可以是无参数且返回可等待对象的函数，例如 `lambda: uploader.async_upload_path(path)`。
+/// 由于 `async_*` 方法必须在事件循环中调用，直接调用它们的方式应当放在函数或协程中传入。
+///
+/// 该函数将创建新的事件循环来驱动异步调用，不能在正在运行的事件循环中调用，否则将抛出 `QiniuRunningEventLoopError`。
+#[pyfunction]
+#[pyo3(text_signature = "(awaitable)")]
+fn run_async(awaitable: PyObject, py: Python<'_>) -> PyResult<PyObject> {
+    let asyncio = py.import("asyncio")?;
+    if asyncio.call_method0("get_running_loop").is_ok() {
+        return Err(QiniuRunningEventLoopError::new_err(
+            "run_async() cannot be called from a running event loop",
+        ));
+    }
+    let event_loop = asyncio.call_method0("new_event_loop")?;
+    let result = pyo3_asyncio::async_std::run_until_complete(event_loop, async move {
+        let future = Python::with_gil(|py| {
+            let awaitable = awaitable.as_ref(py);
+            let awaitable = if awaitable.is_callable() {
+                awaitable.call0()?
+            } else {
+                awaitable
+            };
+            pyo3_asyncio::async_std::into_future(awaitable)
+        })?;
+        future.await
+    });
+    event_loop.call_method0("close")?;
+    result
+}
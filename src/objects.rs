@@ -1,12 +1,15 @@
 use super::{
     credential::CredentialProvider,
-    exceptions::QiniuApiCallError,
+    exceptions::{QiniuApiCallError, QiniuInvalidStorageType},
     http::{HttpResponseParts, HttpResponsePartsMut},
     http_client::{
         BucketRegionsQueryer, Endpoints, HttpClient, JsonResponse, RegionsProvider,
         RequestBuilderPartsRef,
     },
-    utils::{convert_api_call_error, convert_json_value_to_py_object, parse_mime},
+    utils::{
+        convert_api_call_error, convert_json_value_to_py_object, extract_regions_provider,
+        parse_mime,
+    },
 };
 use anyhow::Result as AnyResult;
 use futures::{
@@ -15,10 +18,11 @@ use futures::{
 use indexmap::IndexMap;
 use maybe_owned::MaybeOwned;
 use mime::Mime;
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyStopAsyncIteration, prelude::*};
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
+    io::Result as IOResult,
     mem::transmute,
     pin::Pin,
     sync::{
@@ -31,6 +35,8 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "objects")?;
     m.add_class::<ObjectsManager>()?;
     m.add_class::<Bucket>()?;
+    m.add_class::<ObjectStat>()?;
+    m.add_class::<FetchedObject>()?;
     m.add_class::<OperationProvider>()?;
     m.add_class::<StatObject>()?;
     m.add_class::<CopyObject>()?;
@@ -48,6 +54,7 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<BatchSizeProvider>()?;
     m.add_class::<FixedBatchSizeProvider>()?;
     m.add_class::<BatchOperations>()?;
+    m.add_class::<BatchOperationResult>()?;
     m.add_class::<BatchOperationsIterator>()?;
     m.add_class::<AsyncBatchOperationsIterator>()?;
     Ok(m)
@@ -96,15 +103,20 @@ impl ObjectsManager {
     }
 
     /// 获取七牛存储空间管理器
+    ///
+    /// `regions` 可以是区域信息获取接口，也可以是终端地址列表获取接口
     #[pyo3(text_signature = "($self, name, /, regions = None)")]
     #[args(regions = "None")]
-    fn bucket(&self, name: &str, regions: Option<RegionsProvider>) -> Bucket {
-        let bucket = if let Some(regions) = regions {
-            self.0.bucket_with_region(name, regions)
+    fn bucket(&self, name: &str, regions: Option<&PyAny>) -> PyResult<Bucket> {
+        if let Some(regions) = regions {
+            let regions = extract_regions_provider(regions)?;
+            Ok(Bucket(
+                self.0.bucket_with_region(name, regions.to_owned()),
+                Some(regions),
+            ))
         } else {
-            self.0.bucket(name)
-        };
-        Bucket(bucket)
+            Ok(Bucket(self.0.bucket(name), None))
+        }
     }
 
     fn __str__(&self) -> String {
@@ -121,7 +133,7 @@ impl ObjectsManager {
 /// 由 `objects_manager.bucket()` 方法创建
 #[pyclass]
 #[derive(Clone, Debug)]
-struct Bucket(qiniu_sdk::objects::Bucket);
+struct Bucket(qiniu_sdk::objects::Bucket, Option<RegionsProvider>);
 
 #[pymethods]
 impl Bucket {
@@ -132,8 +144,10 @@ impl Bucket {
     }
 
     /// 列举对象
+    ///
+    /// 指定 `delimiter` 时总是使用列举 API V1，返回的公共前缀可以通过迭代器的 `common_prefixes` 获取
     #[pyo3(
-        text_signature = "($self, /, limit = None, prefix = None, marker = None, version = None, need_parts = None, before_request_callback = None, after_response_ok_callback = None, after_response_error_callback = None)"
+        text_signature = "($self, /, limit = None, prefix = None, marker = None, version = None, need_parts = None, before_request_callback = None, after_response_ok_callback = None, after_response_error_callback = None, delimiter = None)"
     )]
     #[args(
         limit = "None",
@@ -143,7 +157,8 @@ impl Bucket {
         need_parts = "None",
         before_request_callback = "None",
         after_response_ok_callback = "None",
-        after_response_error_callback = "None"
+        after_response_error_callback = "None",
+        delimiter = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     fn list(
@@ -156,12 +171,14 @@ impl Bucket {
         before_request_callback: Option<PyObject>,
         after_response_ok_callback: Option<PyObject>,
         after_response_error_callback: Option<PyObject>,
+        delimiter: Option<String>,
     ) -> ObjectsLister {
         let params = Arc::pin(ObjectsIteratorParams {
             bucket: self.to_owned(),
             limit,
             prefix,
             marker,
+            delimiter,
             version,
             need_parts,
             before_request_callback,
@@ -172,12 +189,12 @@ impl Bucket {
     }
 
     fn __iter__(&self, py: Python<'_>) -> PyResult<ObjectsIterator> {
-        self.list(None, None, None, None, None, None, None, None)
+        self.list(None, None, None, None, None, None, None, None, None)
             .__iter__(py)
     }
 
     fn __aiter__(&self, py: Python<'_>) -> PyResult<AsyncObjectsIterator> {
-        self.list(None, None, None, None, None, None, None, None)
+        self.list(None, None, None, None, None, None, None, None, None)
             .__aiter__(py)
     }
 
@@ -202,6 +219,33 @@ impl Bucket {
         Py::new(py, (stat_object, operation_provider))
     }
 
+    /// 阻塞获取对象元信息
+    #[pyo3(text_signature = "($self, object)")]
+    fn stat(&self, object: &str, py: Python<'_>) -> PyResult<ObjectStat> {
+        let resp = py.allow_threads(|| {
+            self.0
+                .stat_object(object)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        Ok(ObjectStat(resp.into_body().as_ref().to_owned()))
+    }
+
+    /// 异步获取对象元信息
+    #[pyo3(text_signature = "($self, object)")]
+    fn async_stat<'p>(&self, object: String, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = bucket
+                .0
+                .stat_object(&object)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(ObjectStat(resp.into_body().as_ref().to_owned()))
+        })
+    }
+
     /// 复制对象
     #[pyo3(
         text_signature = "($self, from_object, to_bucket, to_object, /, force = None, before_request_callback = None)"
@@ -316,7 +360,7 @@ impl Bucket {
     ) -> PyResult<Py<SetObjectType>> {
         let set_object_type = SetObjectType {
             entry: Entry::new(self.to_owned(), object),
-            file_type: qiniu_sdk::upload_token::FileType::from(object_type),
+            file_type: parse_storage_type(object_type)?,
             before_request_callback,
         };
         let operation_provider = OperationProvider {
@@ -423,9 +467,10 @@ impl Bucket {
 
     /// 对空间内多个对象发起批量操作
     #[pyo3(
-        text_signature = "($self, operations, batch_size = None, before_request_callback = None, after_response_ok_callback = None, after_response_error_callback = None)"
+        text_signature = "($self, /, operations = None, batch_size = None, before_request_callback = None, after_response_ok_callback = None, after_response_error_callback = None)"
     )]
     #[args(
+        operations = "None",
         batch_size = "None",
         before_request_callback = "None",
         after_response_ok_callback = "None",
@@ -433,7 +478,7 @@ impl Bucket {
     )]
     fn batch_ops(
         &self,
-        operations: Vec<OperationProvider>,
+        operations: Option<Vec<OperationProvider>>,
         batch_size: Option<BatchSizeProvider>,
         before_request_callback: Option<PyObject>,
         after_response_ok_callback: Option<PyObject>,
@@ -444,7 +489,7 @@ impl Bucket {
         let mut batch_ops = unsafe {
             transmute::<_, qiniu_sdk::objects::BatchOperations<'static>>(bucket.0.batch_ops())
         };
-        for operation in operations {
+        for operation in operations.unwrap_or_default() {
             batch_ops.add_operation(operation);
         }
         if let Some(batch_size) = batch_size {
@@ -462,6 +507,237 @@ impl Bucket {
         BatchOperations { bucket, batch_ops }
     }
 
+    /// 阻塞修改对象存储类型
+    ///
+    /// `storage_type` 取值为 0（标准存储）、1（低频存储）、2（归档存储）、3（深度归档存储）或 4（归档直读存储）
+    #[pyo3(text_signature = "($self, object, storage_type)")]
+    fn set_storage_type(&self, object: &str, storage_type: u8, py: Python<'_>) -> PyResult<()> {
+        let file_type = parse_storage_type(storage_type)?;
+        py.allow_threads(|| {
+            self.0
+                .set_object_type(object, file_type)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        Ok(())
+    }
+
+    /// 异步修改对象存储类型
+    ///
+    /// `storage_type` 取值为 0（标准存储）、1（低频存储）、2（归档存储）、3（深度归档存储）或 4（归档直读存储）
+    #[pyo3(text_signature = "($self, object, storage_type)")]
+    fn async_set_storage_type<'p>(
+        &self,
+        object: String,
+        storage_type: u8,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let file_type = parse_storage_type(storage_type)?;
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            bucket
+                .0
+                .set_object_type(&object, file_type)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 阻塞修改对象生命周期
+    ///
+    /// 各参数单位均为天，未传入的参数保持不变
+    #[pyo3(
+        text_signature = "($self, object, /, delete_after_days = None, to_ia_after_days = None, to_archive_after_days = None, to_deep_archive_after_days = None)"
+    )]
+    #[args(
+        delete_after_days = "None",
+        to_ia_after_days = "None",
+        to_archive_after_days = "None",
+        to_deep_archive_after_days = "None"
+    )]
+    fn set_lifecycle(
+        &self,
+        object: String,
+        delete_after_days: Option<isize>,
+        to_ia_after_days: Option<isize>,
+        to_archive_after_days: Option<isize>,
+        to_deep_archive_after_days: Option<isize>,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        let modify_object_life_cycle = ModifyObjectLifeCycle {
+            entry: Entry::new(self.to_owned(), object),
+            ia_after_days: to_ia_after_days,
+            archive_after_days: to_archive_after_days,
+            deep_archive_after_days: to_deep_archive_after_days,
+            delete_after_days,
+            before_request_callback: None,
+        };
+        py.allow_threads(|| {
+            modify_object_life_cycle
+                .make_operation()
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        Ok(())
+    }
+
+    /// 异步修改对象生命周期
+    ///
+    /// 各参数单位均为天，未传入的参数保持不变
+    #[pyo3(
+        text_signature = "($self, object, /, delete_after_days = None, to_ia_after_days = None, to_archive_after_days = None, to_deep_archive_after_days = None)"
+    )]
+    #[args(
+        delete_after_days = "None",
+        to_ia_after_days = "None",
+        to_archive_after_days = "None",
+        to_deep_archive_after_days = "None"
+    )]
+    fn async_set_lifecycle<'p>(
+        &self,
+        object: String,
+        delete_after_days: Option<isize>,
+        to_ia_after_days: Option<isize>,
+        to_archive_after_days: Option<isize>,
+        to_deep_archive_after_days: Option<isize>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let modify_object_life_cycle = ModifyObjectLifeCycle {
+            entry: Entry::new(self.to_owned(), object),
+            ia_after_days: to_ia_after_days,
+            archive_after_days: to_archive_after_days,
+            deep_archive_after_days: to_deep_archive_after_days,
+            delete_after_days,
+            before_request_callback: None,
+        };
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            modify_object_life_cycle
+                .make_operation()
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 阻塞抓取指定 URL 的资源并存储为空间内的对象
+    ///
+    /// 由七牛服务器直接抓取资源，无需在本地下载后再上传。`host` 用于指定抓取资源时使用的 `Host` 头
+    #[pyo3(text_signature = "($self, from_url, to_object, /, host = None)")]
+    #[args(host = "None")]
+    fn fetch(
+        &self,
+        from_url: &str,
+        to_object: &str,
+        host: Option<&str>,
+        py: Python<'_>,
+    ) -> PyResult<FetchedObject> {
+        let resp = py.allow_threads(|| {
+            let objects_manager = self.0.objects_manager();
+            objects_manager
+                .client()
+                .storage()
+                .fetch_object()
+                .new_request(
+                    qiniu_sdk::http_client::RegionsProviderEndpoints::new(self.region_provider()?),
+                    self.make_fetch_path_params(from_url, to_object, host),
+                    objects_manager.credential(),
+                )
+                .call()
+        });
+        let resp = resp.map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+        Ok(FetchedObject(resp.into_body().as_ref().to_owned()))
+    }
+
+    /// 异步抓取指定 URL 的资源并存储为空间内的对象
+    ///
+    /// 由七牛服务器直接抓取资源，无需在本地下载后再上传。`host` 用于指定抓取资源时使用的 `Host` 头
+    #[pyo3(text_signature = "($self, from_url, to_object, /, host = None)")]
+    #[args(host = "None")]
+    fn async_fetch<'p>(
+        &self,
+        from_url: String,
+        to_object: String,
+        host: Option<String>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let objects_manager = bucket.0.objects_manager();
+            let resp = async {
+                objects_manager
+                    .client()
+                    .storage()
+                    .fetch_object()
+                    .new_async_request(
+                        qiniu_sdk::http_client::RegionsProviderEndpoints::new(
+                            bucket.async_region_provider().await?,
+                        ),
+                        bucket.make_fetch_path_params(&from_url, &to_object, host.as_deref()),
+                        objects_manager.credential(),
+                    )
+                    .call()
+                    .await
+            }
+            .await
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(FetchedObject(resp.into_body().as_ref().to_owned()))
+        })
+    }
+
+    /// 阻塞从镜像源站预取对象
+    ///
+    /// 对于设置了镜像回源的空间，从镜像源站拉取对象并更新空间内的对象
+    #[pyo3(text_signature = "($self, object)")]
+    fn prefetch(&self, object: &str, py: Python<'_>) -> PyResult<FetchedObject> {
+        let resp = py.allow_threads(|| {
+            let objects_manager = self.0.objects_manager();
+            objects_manager
+                .client()
+                .storage()
+                .prefetch_object()
+                .new_request(
+                    qiniu_sdk::http_client::RegionsProviderEndpoints::new(self.region_provider()?),
+                    self.make_prefetch_path_params(object),
+                    objects_manager.credential(),
+                )
+                .call()
+        });
+        let resp = resp.map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+        Ok(FetchedObject(resp.into_body().as_ref().to_owned()))
+    }
+
+    /// 异步从镜像源站预取对象
+    ///
+    /// 对于设置了镜像回源的空间，从镜像源站拉取对象并更新空间内的对象
+    #[pyo3(text_signature = "($self, object)")]
+    fn async_prefetch<'p>(&self, object: String, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let objects_manager = bucket.0.objects_manager();
+            let resp = async {
+                objects_manager
+                    .client()
+                    .storage()
+                    .prefetch_object()
+                    .new_async_request(
+                        qiniu_sdk::http_client::RegionsProviderEndpoints::new(
+                            bucket.async_region_provider().await?,
+                        ),
+                        bucket.make_prefetch_path_params(&object),
+                        objects_manager.credential(),
+                    )
+                    .call()
+                    .await
+            }
+            .await
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(FetchedObject(resp.into_body().as_ref().to_owned()))
+        })
+    }
+
     fn __str__(&self) -> String {
         self.__repr__()
     }
@@ -471,6 +747,205 @@ impl Bucket {
     }
 }
 
+impl Bucket {
+    /// SDK 的 `Bucket::region_provider()` 并不公开，因此在这里自行获取区域信息
+    fn region_provider(&self) -> IOResult<RegionsProvider> {
+        if let Some(region_provider) = &self.1 {
+            return Ok(region_provider.to_owned());
+        }
+        let objects_manager = self.0.objects_manager();
+        let access_key = objects_manager
+            .credential()
+            .get(Default::default())?
+            .access_key()
+            .to_owned();
+        Ok(Self::query_region_provider(
+            objects_manager,
+            access_key,
+            self.0.name(),
+        ))
+    }
+
+    async fn async_region_provider(&self) -> IOResult<RegionsProvider> {
+        if let Some(region_provider) = &self.1 {
+            return Ok(region_provider.to_owned());
+        }
+        let objects_manager = self.0.objects_manager();
+        let access_key = objects_manager
+            .credential()
+            .async_get(Default::default())
+            .await?
+            .access_key()
+            .to_owned();
+        Ok(Self::query_region_provider(
+            objects_manager,
+            access_key,
+            self.0.name(),
+        ))
+    }
+
+    fn query_region_provider(
+        objects_manager: &qiniu_sdk::objects::ObjectsManager,
+        access_key: qiniu_sdk::credential::AccessKey,
+        bucket_name: &qiniu_sdk::upload_token::BucketName,
+    ) -> RegionsProvider {
+        let provider: Box<dyn qiniu_sdk::http_client::RegionsProvider> = Box::new(
+            objects_manager
+                .queryer()
+                .query(access_key, bucket_name.to_owned()),
+        );
+        provider.into()
+    }
+
+    fn make_fetch_path_params(
+        &self,
+        from_url: &str,
+        to_object: &str,
+        host: Option<&str>,
+    ) -> qiniu_sdk::apis::storage::fetch_object::PathParams {
+        let mut params = qiniu_sdk::apis::storage::fetch_object::PathParams::default()
+            .set_from_url_as_str(from_url.to_owned())
+            .set_to_entry_as_str(self.make_entry(to_object));
+        if let Some(host) = host {
+            params = params.set_host_as_str(host.to_owned());
+        }
+        params
+    }
+
+    fn make_prefetch_path_params(
+        &self,
+        object: &str,
+    ) -> qiniu_sdk::apis::storage::prefetch_object::PathParams {
+        qiniu_sdk::apis::storage::prefetch_object::PathParams::default()
+            .set_entry_as_str(self.make_entry(object))
+    }
+
+    fn make_entry(&self, object: &str) -> String {
+        format!("{}:{}", self.0.name(), object)
+    }
+}
+
+/// 抓取得到的对象信息
+///
+/// 可以通过 `bucket.fetch()` 或 `bucket.prefetch()` 方法获取
+#[pyclass]
+#[derive(Clone, Debug)]
+struct FetchedObject(serde_json::Value);
+
+#[pymethods]
+impl FetchedObject {
+    /// 获取对象名称
+    #[getter]
+    fn get_key(&self) -> Option<&str> {
+        self.0.get("key").and_then(|v| v.as_str())
+    }
+
+    /// 获取对象哈希值
+    #[getter]
+    fn get_hash(&self) -> Option<&str> {
+        self.0.get("hash").and_then(|v| v.as_str())
+    }
+
+    /// 获取对象大小
+    #[getter]
+    fn get_size(&self) -> Option<u64> {
+        self.0.get("fsize").and_then(|v| v.as_u64())
+    }
+
+    /// 获取对象 MIME 类型
+    #[getter]
+    fn get_mime_type(&self) -> Option<&str> {
+        self.0.get("mimeType").and_then(|v| v.as_str())
+    }
+
+    /// 获取原始的抓取结果
+    #[getter]
+    fn get_raw(&self) -> PyResult<PyObject> {
+        convert_json_value_to_py_object(&self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// 对象元信息
+///
+/// 可以通过 `bucket.stat()` 方法获取
+#[pyclass]
+#[derive(Clone, Debug)]
+struct ObjectStat(serde_json::Value);
+
+#[pymethods]
+impl ObjectStat {
+    /// 获取对象大小
+    #[getter]
+    fn get_size(&self) -> Option<u64> {
+        self.0.get("fsize").and_then(|v| v.as_u64())
+    }
+
+    /// 获取对象哈希值
+    #[getter]
+    fn get_hash(&self) -> Option<&str> {
+        self.0.get("hash").and_then(|v| v.as_str())
+    }
+
+    /// 获取对象 MIME 类型
+    #[getter]
+    fn get_mime_type(&self) -> Option<&str> {
+        self.0.get("mimeType").and_then(|v| v.as_str())
+    }
+
+    /// 获取对象上传时间，单位为 100 纳秒
+    #[getter]
+    fn get_put_time(&self) -> Option<u64> {
+        self.0.get("putTime").and_then(|v| v.as_u64())
+    }
+
+    /// 获取对象存储类型
+    #[getter]
+    fn get_storage_type(&self) -> u64 {
+        self.0.get("type").and_then(|v| v.as_u64()).unwrap_or(0)
+    }
+
+    /// 获取对象自定义元数据
+    #[getter]
+    fn get_metadata(&self) -> HashMap<String, String> {
+        self.0
+            .get("x-qn-meta")
+            .and_then(|v| v.as_object())
+            .map(|metadata| {
+                metadata
+                    .iter()
+                    .filter_map(|(key, value)| {
+                        value
+                            .as_str()
+                            .map(|value| (key.to_owned(), value.to_owned()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 获取原始的对象元信息
+    #[getter]
+    fn get_raw(&self) -> PyResult<PyObject> {
+        convert_json_value_to_py_object(&self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
 /// 对象操作提供者接口
 ///
 /// 抽象类
@@ -1072,6 +1547,16 @@ impl ModifyObjectLifeCycle {
     }
 }
 
+fn parse_storage_type(storage_type: u8) -> PyResult<qiniu_sdk::upload_token::FileType> {
+    match storage_type {
+        0..=4 => Ok(qiniu_sdk::upload_token::FileType::from(storage_type)),
+        _ => Err(QiniuInvalidStorageType::new_err(format!(
+            "unknown storage type {}, expected 0 (standard), 1 (infrequent access), 2 (archive), 3 (deep archive) or 4 (archive IR)",
+            storage_type
+        ))),
+    }
+}
+
 fn make_json_response(
     parts: qiniu_sdk::http::ResponseParts,
     body: &serde_json::Value,
@@ -1127,7 +1612,11 @@ struct ObjectsLister {
 #[pymethods]
 impl ObjectsLister {
     fn __iter__(&self, py: Python<'_>) -> PyResult<ObjectsIterator> {
-        let iter = self.make_list_builder(py).iter();
+        let iter = if self.params.delimiter.is_some() {
+            ObjectsIter::Delimited(DelimitedLister::new(self.params.to_owned()))
+        } else {
+            ObjectsIter::Sdk(self.make_list_builder(py).iter())
+        };
         Ok(ObjectsIterator {
             iter,
             _params: self.params.to_owned(),
@@ -1135,7 +1624,11 @@ impl ObjectsLister {
     }
 
     fn __aiter__(&self, py: Python<'_>) -> PyResult<AsyncObjectsIterator> {
-        let stream = self.make_list_builder(py).stream().peekable();
+        let stream = if self.params.delimiter.is_some() {
+            AsyncObjectsStream::Delimited(DelimitedLister::new(self.params.to_owned()))
+        } else {
+            AsyncObjectsStream::Sdk(self.make_list_builder(py).stream().peekable())
+        };
         Ok(AsyncObjectsIterator {
             inner: Arc::new(AsyncObjectsIteratorInner {
                 stream: AsyncMutex::new(stream),
@@ -1199,6 +1692,7 @@ struct ObjectsIteratorParams {
     limit: Option<usize>,
     prefix: Option<String>,
     marker: Option<String>,
+    delimiter: Option<String>,
     version: Option<ListVersion>,
     need_parts: Option<bool>,
     before_request_callback: Option<PyObject>,
@@ -1206,31 +1700,250 @@ struct ObjectsIteratorParams {
     after_response_error_callback: Option<PyObject>,
 }
 
+// 不同列举版本返回的字段名称不同，这里统一补齐 `size`，`put_time` 和 `mime_type` 字段，原始字段依然保留
+fn normalize_listed_object(mut value: serde_json::Value) -> serde_json::Value {
+    if let Some(object) = value.as_object_mut() {
+        for (normalized, original) in [
+            ("size", "fsize"),
+            ("put_time", "putTime"),
+            ("mime_type", "mimeType"),
+        ] {
+            if !object.contains_key(normalized) {
+                if let Some(v) = object.get(original).cloned() {
+                    object.insert(normalized.to_owned(), v);
+                }
+            }
+        }
+    }
+    value
+}
+
+/// 指定分隔符时使用的列举器
+///
+/// SDK 的列举迭代器会丢弃响应中的 `commonPrefixes`，因此指定分隔符时直接调用列举 API V1
+#[derive(Debug)]
+struct DelimitedLister {
+    params: Pin<Arc<ObjectsIteratorParams>>,
+    marker: Option<String>,
+    remaining: Option<usize>,
+    buffer: VecDeque<serde_json::Value>,
+    common_prefixes: Vec<String>,
+    done: bool,
+}
+
+impl DelimitedLister {
+    const PAGE_SIZE_MAX: usize = 1000;
+
+    fn new(params: Pin<Arc<ObjectsIteratorParams>>) -> Self {
+        Self {
+            marker: params.marker.to_owned(),
+            remaining: params.limit,
+            params,
+            buffer: Default::default(),
+            common_prefixes: Default::default(),
+            done: false,
+        }
+    }
+
+    fn next(&mut self) -> qiniu_sdk::http_client::ApiResult<Option<serde_json::Value>> {
+        while self.need_next_page() {
+            self.next_page()?;
+        }
+        Ok(self.buffer.pop_front())
+    }
+
+    async fn async_next(&mut self) -> qiniu_sdk::http_client::ApiResult<Option<serde_json::Value>> {
+        while self.need_next_page() {
+            self.async_next_page().await?;
+        }
+        Ok(self.buffer.pop_front())
+    }
+
+    fn need_next_page(&self) -> bool {
+        self.buffer.is_empty() && !self.done && self.remaining != Some(0)
+    }
+
+    fn next_page(&mut self) -> qiniu_sdk::http_client::ApiResult<()> {
+        let params = self.params.to_owned();
+        let objects_manager = params.bucket.0.objects_manager();
+        let mut request = objects_manager
+            .client()
+            .storage()
+            .get_objects()
+            .new_request(
+                qiniu_sdk::http_client::RegionsProviderEndpoints::new(
+                    params.bucket.region_provider()?,
+                ),
+                objects_manager.credential(),
+            );
+        request.query_pairs(self.make_query_pairs());
+        self.before_request(request.parts_mut())
+            .map_err(make_callback_error)?;
+        let mut result = request.call();
+        self.after_response(&mut result)
+            .map_err(make_callback_error)?;
+        self.handle_response(result?.into_body().into());
+        Ok(())
+    }
+
+    async fn async_next_page(&mut self) -> qiniu_sdk::http_client::ApiResult<()> {
+        let params = self.params.to_owned();
+        let objects_manager = params.bucket.0.objects_manager();
+        let mut request = objects_manager
+            .client()
+            .storage()
+            .get_objects()
+            .new_async_request(
+                qiniu_sdk::http_client::RegionsProviderEndpoints::new(
+                    params.bucket.async_region_provider().await?,
+                ),
+                objects_manager.credential(),
+            );
+        request.query_pairs(self.make_query_pairs());
+        self.before_request(request.parts_mut())
+            .map_err(make_callback_error)?;
+        let mut result = request.call().await;
+        self.after_response(&mut result)
+            .map_err(make_callback_error)?;
+        self.handle_response(result?.into_body().into());
+        Ok(())
+    }
+
+    fn make_query_pairs(&self) -> Vec<qiniu_sdk::http_client::QueryPair<'static>> {
+        let page_size = self.remaining.map_or(Self::PAGE_SIZE_MAX, |remaining| {
+            remaining.min(Self::PAGE_SIZE_MAX)
+        });
+        let mut query = qiniu_sdk::apis::storage::get_objects::QueryParams::default()
+            .set_bucket_as_str(self.params.bucket.0.name().to_string())
+            .set_limit_as_usize(page_size);
+        if let Some(delimiter) = &self.params.delimiter {
+            query = query.set_delimiter_as_str(delimiter.to_owned());
+        }
+        if let Some(prefix) = &self.params.prefix {
+            query = query.set_prefix_as_str(prefix.to_owned());
+        }
+        if let Some(marker) = self.marker.as_ref().filter(|marker| !marker.is_empty()) {
+            query = query.set_marker_as_str(marker.to_owned());
+        }
+        if let Some(true) = self.params.need_parts {
+            query = query.set_need_parts_as_bool(true);
+        }
+        query.into()
+    }
+
+    fn before_request(
+        &self,
+        parts: &mut qiniu_sdk::http_client::RequestBuilderParts<'_>,
+    ) -> AnyResult<()> {
+        if let Some(callback) = &self.params.before_request_callback {
+            let mut callback =
+                Python::with_gil(|py| make_before_request_callback(callback.clone_ref(py)));
+            callback(parts)?;
+        }
+        Ok(())
+    }
+
+    fn after_response<B>(
+        &self,
+        result: &mut qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::Response<B>>,
+    ) -> AnyResult<()> {
+        match result {
+            Ok(response) => {
+                if let Some(callback) = &self.params.after_response_ok_callback {
+                    let mut callback = Python::with_gil(|py| {
+                        make_after_response_ok_callback(callback.clone_ref(py))
+                    });
+                    callback(response)?;
+                }
+            }
+            Err(err) => {
+                if let Some(callback) = &self.params.after_response_error_callback {
+                    let mut callback = Python::with_gil(|py| {
+                        make_after_response_error_callback(callback.clone_ref(py))
+                    });
+                    callback(err)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_response(&mut self, body: serde_json::Value) {
+        if let Some(items) = body.get("items").and_then(|items| items.as_array()) {
+            if let Some(remaining) = &mut self.remaining {
+                *remaining = remaining.saturating_sub(items.len());
+            }
+            self.buffer.extend(items.iter().cloned());
+        }
+        if let Some(prefixes) = body
+            .get("commonPrefixes")
+            .and_then(|prefixes| prefixes.as_array())
+        {
+            self.common_prefixes.extend(
+                prefixes
+                    .iter()
+                    .filter_map(|prefix| prefix.as_str())
+                    .map(|prefix| prefix.to_owned()),
+            );
+        }
+        let marker = body.get("marker").and_then(|marker| marker.as_str());
+        self.done = marker.map_or(true, |marker| marker.is_empty());
+        self.marker = marker.map(|marker| marker.to_owned());
+    }
+}
+
+fn make_callback_error(err: anyhow::Error) -> qiniu_sdk::http_client::ResponseError {
+    qiniu_sdk::http_client::ResponseError::new_with_msg(
+        qiniu_sdk::http::ResponseErrorKind::CallbackError.into(),
+        err,
+    )
+}
+
+#[derive(Debug)]
+enum ObjectsIter {
+    Sdk(qiniu_sdk::objects::ListIter<'static>),
+    Delimited(DelimitedLister),
+}
+
 /// 列举操作迭代器
 #[pyclass]
 #[derive(Debug)]
 struct ObjectsIterator {
     _params: Pin<Arc<ObjectsIteratorParams>>,
-    iter: qiniu_sdk::objects::ListIter<'static>,
+    iter: ObjectsIter,
 }
 
 #[pymethods]
 impl ObjectsIterator {
     fn __next__(&mut self) -> PyResult<Option<PyObject>> {
-        self.iter
-            .next()
-            .map(|result| {
-                result.map(|entry| convert_json_value_to_py_object(&serde_json::Value::from(entry)))
-            })
-            .transpose()
+        let entry = match &mut self.iter {
+            ObjectsIter::Sdk(iter) => iter.next().transpose().map(|entry| entry.map(Into::into)),
+            ObjectsIter::Delimited(lister) => lister.next(),
+        };
+        entry
             .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
+            .map(|entry| convert_json_value_to_py_object(&normalize_listed_object(entry)))
             .transpose()
     }
 
     /// 获取上一次列举返回的位置标记
     #[getter]
     fn get_marker(&self) -> Option<&str> {
-        self.iter.marker()
+        match &self.iter {
+            ObjectsIter::Sdk(iter) => iter.marker(),
+            ObjectsIter::Delimited(lister) => lister.marker.as_deref(),
+        }
+    }
+
+    /// 获取目前为止列举返回的所有公共前缀
+    ///
+    /// 仅在指定 `delimiter` 时返回
+    #[getter]
+    fn get_common_prefixes(&self) -> Vec<String> {
+        match &self.iter {
+            ObjectsIter::Sdk(_) => Default::default(),
+            ObjectsIter::Delimited(lister) => lister.common_prefixes.to_owned(),
+        }
     }
 
     fn __str__(&self) -> String {
@@ -1252,10 +1965,16 @@ struct AsyncObjectsIterator {
 
 #[derive(Debug)]
 struct AsyncObjectsIteratorInner {
-    stream: AsyncMutex<AsyncPeekable<qiniu_sdk::objects::ListStream<'static>>>,
+    stream: AsyncMutex<AsyncObjectsStream>,
     ended: AtomicBool,
 }
 
+#[derive(Debug)]
+enum AsyncObjectsStream {
+    Sdk(AsyncPeekable<qiniu_sdk::objects::ListStream<'static>>),
+    Delimited(DelimitedLister),
+}
+
 #[pymethods]
 impl AsyncObjectsIterator {
     fn __anext__(&mut self, py: Python<'_>) -> Option<PyObject> {
@@ -1265,25 +1984,34 @@ impl AsyncObjectsIterator {
         let inner = self.inner.to_owned();
         pyo3_asyncio::async_std::future_into_py(py, async move {
             let mut stream = inner.stream.lock().await;
-            let entry = stream
-                .try_next()
-                .await
-                .map(|entry| {
-                    entry.map(|entry| {
-                        convert_json_value_to_py_object(&serde_json::Value::from(entry))
-                    })
-                })
+            let entry: Option<serde_json::Value> = match &mut *stream {
+                AsyncObjectsStream::Sdk(stream) => {
+                    let entry = stream
+                        .try_next()
+                        .await
+                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+                    if Pin::new(&mut *stream).peek_mut().await.is_none() {
+                        inner.ended.store(true, Ordering::SeqCst);
+                    }
+                    entry.map(Into::into)
+                }
+                AsyncObjectsStream::Delimited(lister) => {
+                    let entry = lister
+                        .async_next()
+                        .await
+                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+                    if lister.buffer.is_empty() && !lister.need_next_page() {
+                        inner.ended.store(true, Ordering::SeqCst);
+                    }
+                    match entry {
+                        Some(entry) => Some(entry),
+                        None => return Err(PyStopAsyncIteration::new_err(())),
+                    }
+                }
+            };
+            entry
+                .map(|entry| convert_json_value_to_py_object(&normalize_listed_object(entry)))
                 .transpose()
-                .map(|result| {
-                    result
-                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                        .and_then(|res| res)
-                })
-                .transpose()?;
-            if Pin::new(&mut *stream).peek_mut().await.is_none() {
-                inner.ended.store(true, Ordering::SeqCst);
-            }
-            Ok(entry)
         })
         .ok()
         .map(|any| any.into_py(py))
@@ -1295,7 +2023,25 @@ impl AsyncObjectsIterator {
         let inner = self.inner.to_owned();
         pyo3_asyncio::async_std::future_into_py(py, async move {
             let stream = inner.stream.lock().await;
-            Ok(stream.get_ref().marker().map(|s| s.to_owned()))
+            Ok(match &*stream {
+                AsyncObjectsStream::Sdk(stream) => stream.get_ref().marker().map(|s| s.to_owned()),
+                AsyncObjectsStream::Delimited(lister) => lister.marker.to_owned(),
+            })
+        })
+    }
+
+    /// 获取目前为止列举返回的所有公共前缀
+    ///
+    /// 仅在指定 `delimiter` 时返回
+    #[pyo3(text_signature = "($self)")]
+    fn common_prefixes<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let inner = self.inner.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let stream = inner.stream.lock().await;
+            Ok(match &*stream {
+                AsyncObjectsStream::Sdk(_) => Vec::new(),
+                AsyncObjectsStream::Delimited(lister) => lister.common_prefixes.to_owned(),
+            })
         })
     }
 
@@ -1403,6 +2149,113 @@ struct BatchOperations {
 
 #[pymethods]
 impl BatchOperations {
+    /// 添加对象操作
+    #[pyo3(text_signature = "($self, operation)")]
+    fn add_operation(&mut self, operation: OperationProvider) {
+        self.batch_ops.add_operation(operation);
+    }
+
+    /// 添加获取对象元信息操作
+    #[pyo3(text_signature = "($self, object)")]
+    fn stat(&mut self, object: String) {
+        let stat_object = StatObject {
+            entry: Entry::new(Bucket::clone(&self.bucket), object),
+            before_request_callback: None,
+        };
+        self.add_sdk_operation(stat_object.make_operation());
+    }
+
+    /// 添加删除对象操作
+    #[pyo3(text_signature = "($self, object)")]
+    fn delete(&mut self, object: String) {
+        let delete_object = DeleteObject {
+            entry: Entry::new(Bucket::clone(&self.bucket), object),
+            before_request_callback: None,
+        };
+        self.add_sdk_operation(delete_object.make_operation());
+    }
+
+    /// 添加复制对象操作
+    #[pyo3(text_signature = "($self, from_object, to_bucket, to_object, /, force = None)")]
+    #[args(force = "None")]
+    fn copy(
+        &mut self,
+        from_object: String,
+        to_bucket: String,
+        to_object: String,
+        force: Option<bool>,
+    ) {
+        let copy_object = CopyObject {
+            from_entry: Entry::new(Bucket::clone(&self.bucket), from_object),
+            to_entry: SimpleEntry::new(to_bucket, to_object),
+            force,
+            before_request_callback: None,
+        };
+        self.add_sdk_operation(copy_object.make_operation());
+    }
+
+    /// 添加移动对象操作
+    #[pyo3(text_signature = "($self, from_object, to_bucket, to_object, /, force = None)")]
+    #[args(force = "None")]
+    fn r#move(
+        &mut self,
+        from_object: String,
+        to_bucket: String,
+        to_object: String,
+        force: Option<bool>,
+    ) {
+        let move_object = MoveObject {
+            from_entry: Entry::new(Bucket::clone(&self.bucket), from_object),
+            to_entry: SimpleEntry::new(to_bucket, to_object),
+            force,
+            before_request_callback: None,
+        };
+        self.add_sdk_operation(move_object.make_operation());
+    }
+
+    /// 添加修改对象 MIME 类型操作
+    #[pyo3(text_signature = "($self, object, mime_type)")]
+    fn change_mime(&mut self, object: String, mime_type: &str) -> PyResult<()> {
+        let modify_object_metadata = ModifyObjectMetadata {
+            entry: Entry::new(Bucket::clone(&self.bucket), object),
+            mime_type: parse_mime(mime_type)?,
+            metadata: Default::default(),
+            conditions: Default::default(),
+            before_request_callback: None,
+        };
+        self.add_sdk_operation(modify_object_metadata.make_operation());
+        Ok(())
+    }
+
+    /// 阻塞执行批量操作，返回所有操作的结果
+    ///
+    /// 如果操作数超过单次请求的最大批量操作数，将会自动分批发送请求
+    #[pyo3(text_signature = "($self)")]
+    fn execute(&mut self, py: Python<'_>) -> Vec<BatchOperationResult> {
+        let batch_ops = &mut self.batch_ops;
+        py.allow_threads(|| batch_ops.call().collect::<Vec<_>>())
+            .into_iter()
+            .map(BatchOperationResult::new)
+            .collect()
+    }
+
+    /// 异步执行批量操作，返回所有操作的结果
+    ///
+    /// 如果操作数超过单次请求的最大批量操作数，将会自动分批发送请求
+    #[pyo3(text_signature = "($self)")]
+    fn async_execute<'p>(&mut self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let bucket = self.bucket.to_owned();
+        let stream = self.batch_ops.async_call();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let results = stream
+                .map(BatchOperationResult::new)
+                .collect::<Vec<_>>()
+                .await;
+            drop(bucket);
+            Ok(results)
+        })
+    }
+
     fn __iter__(&mut self) -> BatchOperationsIterator {
         BatchOperationsIterator {
             iter: self.batch_ops.call(),
@@ -1429,23 +2282,40 @@ impl BatchOperations {
     }
 }
 
+impl BatchOperations {
+    fn add_sdk_operation(&mut self, mut operation: impl qiniu_sdk::objects::OperationProvider) {
+        self.batch_ops.add_operation(OperationProvider {
+            operation: operation.to_operation(),
+        });
+    }
+}
+
 //// 批量操作结果
 #[pyclass]
 #[derive(Debug)]
-struct BatchOperationResult(PyResult<PyObject>);
+struct BatchOperationResult {
+    result: PyResult<PyObject>,
+    status_code: Option<u16>,
+}
 
 #[pymethods]
 impl BatchOperationResult {
     /// 获取数据
     #[getter]
     fn get_data(&self, py: Python<'_>) -> Option<PyObject> {
-        self.0.as_ref().ok().map(|data| data.clone_ref(py))
+        self.result.as_ref().ok().map(|data| data.clone_ref(py))
     }
 
     /// 获取错误信息
     #[getter]
     fn get_error(&self) -> Option<&PyErr> {
-        self.0.as_ref().err()
+        self.result.as_ref().err()
+    }
+
+    /// 获取 HTTP 状态码
+    #[getter]
+    fn get_status_code(&self) -> Option<u16> {
+        self.status_code
     }
 
     fn __str__(&self) -> String {
@@ -1453,7 +2323,35 @@ impl BatchOperationResult {
     }
 
     fn __repr__(&self) -> String {
-        format!("{:?}", self.0)
+        format!("{:?}", self.result)
+    }
+}
+
+impl BatchOperationResult {
+    fn new<T: Into<serde_json::Value>>(
+        result: Result<T, qiniu_sdk::http_client::ResponseError>,
+    ) -> Self {
+        use qiniu_sdk::http_client::ResponseErrorKind;
+
+        match result {
+            Ok(data) => Self {
+                result: convert_json_value_to_py_object(&data.into()),
+                status_code: Some(200),
+            },
+            Err(err) => {
+                let status_code = match err.kind() {
+                    ResponseErrorKind::StatusCodeError(status_code)
+                    | ResponseErrorKind::UnexpectedStatusCode(status_code) => {
+                        Some(status_code.as_u16())
+                    }
+                    _ => None,
+                };
+                Self {
+                    result: Err(QiniuApiCallError::from_err(MaybeOwned::Owned(err))),
+                    status_code,
+                }
+            }
+        }
     }
 }
 
@@ -1468,15 +2366,7 @@ struct BatchOperationsIterator {
 #[pymethods]
 impl BatchOperationsIterator {
     fn __next__(&mut self) -> PyResult<Option<BatchOperationResult>> {
-        Ok(self.iter.next().map(|result| {
-            BatchOperationResult(
-                result
-                    .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                    .and_then(|entry| {
-                        convert_json_value_to_py_object(&serde_json::Value::from(entry))
-                    }),
-            )
-        }))
+        Ok(self.iter.next().map(BatchOperationResult::new))
     }
 
     fn __str__(&self) -> String {
@@ -1511,22 +2401,7 @@ impl AsyncBatchOperationsIterator {
         let inner = self.inner.to_owned();
         pyo3_asyncio::async_std::future_into_py(py, async move {
             let mut stream = inner.stream.lock().await;
-            let entry = stream
-                .try_next()
-                .await
-                .map(|entry| {
-                    entry.map(|entry| {
-                        convert_json_value_to_py_object(&serde_json::Value::from(entry))
-                    })
-                })
-                .transpose()
-                .map(|result| {
-                    BatchOperationResult(
-                        result
-                            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                            .and_then(|res| res),
-                    )
-                });
+            let entry = stream.next().await.map(BatchOperationResult::new);
             if Pin::new(&mut *stream).peek_mut().await.is_none() {
                 inner.ended.store(true, Ordering::SeqCst);
             }
@@ -1,21 +1,37 @@
 use super::{
     credential::CredentialProvider,
     exceptions::{
-        QiniuApiCallError, QiniuInvalidConcurrency, QiniuInvalidLimitation, QiniuInvalidMultiply,
-        QiniuInvalidObjectSize, QiniuInvalidPartSize, QiniuInvalidSourceKeyLengthError,
-        QiniuIoError,
+        QiniuApiCallError, QiniuEtagMismatchError, QiniuInvalidCallback,
+        QiniuInvalidCompressionLevel, QiniuInvalidConcurrency, QiniuInvalidLimitation,
+        QiniuInvalidMultiply, QiniuInvalidObjectSize, QiniuInvalidPartSize, QiniuInvalidSaveKey,
+        QiniuInvalidSourceKeyLengthError, QiniuIoError, QiniuTimeoutError,
+        QiniuUnsupportedRecorderOperation, QiniuUnsupportedTypeError,
+        QiniuUploadManagerClosedError,
     },
-    http::HttpResponsePartsMut,
+    http::{calculate_percentage, HttpResponsePartsMut},
     http_client::{
-        BucketRegionsQueryer, Endpoints, HttpClient, RegionsProvider, RequestBuilderPartsRef,
+        enabled_shared_http_client, BucketRegionsQueryer, Endpoints, HttpClient, RegionsProvider,
+        RequestBuilderPartsRef,
     },
     upload_token::{on_policy_generated_callback, UploadTokenProvider},
-    utils::{convert_api_call_error, convert_json_value_to_py_object, parse_mime, PythonIoBase},
+    utils::{
+        convert_api_call_error, convert_json_value_to_py_object, extract_regions_provider,
+        get_concurrency, instant_after, join_thread, parse_mime, user_warning, AesCtrCipher,
+        ProgressThrottle, PythonAsyncGeneratorRead, PythonIoBase,
+    },
 };
 use anyhow::Result as AnyResult;
-use futures::{lock::Mutex as AsyncMutex, AsyncRead, AsyncReadExt, AsyncWriteExt};
+use futures::{
+    lock::Mutex as AsyncMutex, AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, AsyncWrite,
+    AsyncWriteExt, FutureExt, StreamExt, TryStreamExt,
+};
 use maybe_owned::MaybeOwned;
-use pyo3::{exceptions::PyIOError, prelude::*, types::PyBytes};
+use pyo3::{
+    exceptions::{PyIOError, PyStopAsyncIteration},
+    prelude::*,
+    pyclass::CompareOp,
+    types::PyBytes,
+};
 use qiniu_sdk::{
     etag::GenericArray,
     prelude::{
@@ -26,8 +42,26 @@ use qiniu_sdk::{
 };
 use sha1::{digest::OutputSizeUser, Sha1};
 use std::{
-    collections::HashMap, fmt::Debug, io::Read, mem::transmute, num::NonZeroU64, sync::Arc,
-    time::Duration,
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap},
+    fmt::Debug,
+    fs::File,
+    future::Future,
+    hash::{Hash, Hasher},
+    io::{
+        Error as IoError, ErrorKind as IoErrorKind, Read, Result as IoResult, Seek, SeekFrom, Write,
+    },
+    mem::{take, transmute},
+    net::IpAddr,
+    num::{NonZeroU16, NonZeroU64, NonZeroUsize},
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc, Condvar, Mutex, MutexGuard,
+    },
+    task::{Context, Poll, Waker},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
@@ -35,10 +69,12 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<UploadTokenSigner>()?;
     m.add_class::<ConcurrencyProvider>()?;
     m.add_class::<FixedConcurrencyProvider>()?;
+    m.add_class::<AdaptiveConcurrencyProvider>()?;
     m.add_class::<DataPartitionProvider>()?;
     m.add_class::<FixedDataPartitionProvider>()?;
     m.add_class::<MultiplyDataPartitionProvider>()?;
     m.add_class::<LimitedDataPartitionProvider>()?;
+    m.add_class::<ThrottledDataPartitionProvider>()?;
     m.add_class::<ResumablePolicy>()?;
     m.add_class::<ResumablePolicyProvider>()?;
     m.add_class::<AlwaysSinglePart>()?;
@@ -47,18 +83,27 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<MultiplePartitionsResumablePolicyProvider>()?;
     m.add_class::<SourceKey>()?;
     m.add_class::<ResumableRecorder>()?;
+    m.add_class::<ResumableRecordInfo>()?;
     m.add_class::<ReadOnlyResumableRecorderMedium>()?;
     m.add_class::<AppendOnlyResumableRecorderMedium>()?;
     m.add_class::<ReadOnlyAsyncResumableRecorderMedium>()?;
     m.add_class::<AppendOnlyAsyncResumableRecorderMedium>()?;
     m.add_class::<DummyResumableRecorder>()?;
+    m.add_class::<WarningResumableRecorder>()?;
     m.add_class::<FileSystemResumableRecorder>()?;
+    m.add_class::<SqliteResumableRecorder>()?;
+    m.add_class::<MemoryResumableRecorder>()?;
     m.add_class::<DataSource>()?;
     m.add_class::<FileDataSource>()?;
     m.add_class::<UnseekableDataSource>()?;
+    m.add_class::<GzipDataSource>()?;
+    m.add_class::<EncryptingDataSource>()?;
     m.add_class::<AsyncDataSource>()?;
     m.add_class::<AsyncFileDataSource>()?;
     m.add_class::<AsyncUnseekableDataSource>()?;
+    m.add_class::<AsyncGeneratorDataSource>()?;
+    m.add_class::<AsyncGzipDataSource>()?;
+    m.add_class::<AsyncEncryptingDataSource>()?;
     m.add_class::<DataSourceReader>()?;
     m.add_class::<AsyncDataSourceReader>()?;
     m.add_class::<UploadManager>()?;
@@ -81,7 +126,11 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<MultiPartsUploaderSchedulerPrefer>()?;
     m.add_class::<SinglePartUploaderPrefer>()?;
     m.add_class::<MultiPartsUploaderPrefer>()?;
+    m.add_class::<ObjectParams>()?;
+    m.add_class::<AutoUploaderObjectParams>()?;
     m.add_class::<AutoUploader>()?;
+    m.add_class::<TryUploadResult>()?;
+    m.add_class::<UploadResult>()?;
     m.add_class::<Reader>()?;
     m.add_class::<AsyncReader>()?;
     Ok(m)
@@ -89,10 +138,10 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
 
 /// 上传凭证签发器
 ///
-/// 通过 `UploadTokenSigner.new_upload_token_provider(upload_token_provider)` 或 `UploadTokenSigner.new_credential_provider(credential, bucket_name, lifetime_secs, on_policy_generated = None)` 创建上传凭证签发器
+/// 通过 `UploadTokenSigner.new_upload_token_provider(upload_token_provider)` 或 `UploadTokenSigner.new_credential_provider(credential, bucket_name, lifetime_secs, on_policy_generated = None, save_key = None, force_save_key = False)` 创建上传凭证签发器
 #[pyclass]
 #[derive(Clone, Debug)]
-struct UploadTokenSigner(qiniu_sdk::upload::UploadTokenSigner);
+struct UploadTokenSigner(qiniu_sdk::upload::UploadTokenSigner, Option<String>);
 
 #[pymethods]
 impl UploadTokenSigner {
@@ -100,30 +149,60 @@ impl UploadTokenSigner {
     #[staticmethod]
     #[pyo3(text_signature = "(upload_token_provider)")]
     fn new_upload_token_provider(upload_token_provider: UploadTokenProvider) -> Self {
-        Self(qiniu_sdk::upload::UploadTokenSigner::new_upload_token_provider(upload_token_provider))
+        Self(
+            qiniu_sdk::upload::UploadTokenSigner::new_upload_token_provider(upload_token_provider),
+            None,
+        )
     }
 
     /// 根据认证信息提供者和存储空间名称创建上传凭证签发器
+    ///
+    /// 如果传入 `save_key`，生成的上传策略将包含该自定义对象名称，它仅当上传时没有指定 `object_name` 时起作用，
+    /// 如果同时传入 `force_save_key=True`，则即使指定了 `object_name` 也将强制使用 `save_key` 作为对象名称。
+    ///
+    /// 上传时对象参数中的上传回调设置也将写入生成的上传策略。
+    ///
+    /// 这些字段在调用 `on_policy_generated` 之前设置，因此仍然可以在回调函数中修改
     #[staticmethod]
     #[pyo3(
-        text_signature = "(credential, bucket_name, lifetime_secs, /, on_policy_generated = None)"
+        text_signature = "(credential, bucket_name, lifetime_secs, /, on_policy_generated = None, save_key = None, force_save_key = False)"
+    )]
+    #[args(
+        on_policy_generated = "None",
+        save_key = "None",
+        force_save_key = "false"
     )]
-    #[args(on_policy_generated = "None")]
     fn new_credential_provider(
         credential: CredentialProvider,
         bucket_name: String,
         lifetime_secs: u64,
         on_policy_generated: Option<PyObject>,
-    ) -> Self {
-        let mut builder = qiniu_sdk::upload::UploadTokenSigner::new_credential_provider_builder(
+        save_key: Option<String>,
+        force_save_key: bool,
+    ) -> PyResult<Self> {
+        if force_save_key && save_key.is_none() {
+            return Err(QiniuInvalidSaveKey::new_err(
+                "force_save_key requires save_key",
+            ));
+        }
+        let on_policy_generated = on_policy_generated.map(on_policy_generated_callback);
+        let signer = qiniu_sdk::upload::UploadTokenSigner::new_credential_provider_builder(
             credential,
-            bucket_name,
+            bucket_name.to_owned(),
             Duration::from_secs(lifetime_secs),
-        );
-        if let Some(callback) = on_policy_generated {
-            builder = builder.on_policy_generated(on_policy_generated_callback(callback));
-        }
-        Self(builder.build())
+        )
+        .on_policy_generated(move |policy_builder| {
+            if let Some(save_key) = &save_key {
+                policy_builder.save_as(save_key, force_save_key);
+            }
+            UploadCallback::apply_uploading(policy_builder);
+            if let Some(on_policy_generated) = &on_policy_generated {
+                on_policy_generated(policy_builder)?;
+            }
+            Ok(())
+        })
+        .build();
+        Ok(Self(signer, Some(bucket_name)))
     }
 
     fn __str__(&self) -> String {
@@ -135,6 +214,139 @@ impl UploadTokenSigner {
     }
 }
 
+/// 根据上传凭证签发器中的 AccessKey 和存储空间名称查询区域信息，与上传管理器默认查询区域的方式相同
+#[derive(Clone, Debug)]
+struct SignerRegionsProvider {
+    queryer: qiniu_sdk::http_client::BucketRegionsQueryer,
+    signer: UploadTokenSigner,
+}
+
+impl SignerRegionsProvider {
+    fn query(
+        &self,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::BucketRegionsProvider> {
+        let (access_key, bucket_name) = match (
+            self.signer.0.upload_token_provider(),
+            self.signer.0.credential_provider(),
+            &self.signer.1,
+        ) {
+            (Some(provider), _, _) => (
+                provider
+                    .access_key(Default::default())
+                    .map_err(make_invalid_request_error)?
+                    .into(),
+                bucket_name_of_policy(provider.policy(Default::default()))?,
+            ),
+            (None, Some(credential), Some(bucket_name)) => (
+                credential.get(Default::default())?.access_key().to_owned(),
+                bucket_name.as_str().into(),
+            ),
+            _ => return Err(signer_without_bucket_error()),
+        };
+        Ok(self.queryer.query(access_key, bucket_name))
+    }
+
+    async fn async_query(
+        &self,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::BucketRegionsProvider> {
+        let (access_key, bucket_name) = match (
+            self.signer.0.upload_token_provider(),
+            self.signer.0.credential_provider(),
+            &self.signer.1,
+        ) {
+            (Some(provider), _, _) => (
+                provider
+                    .async_access_key(Default::default())
+                    .await
+                    .map_err(make_invalid_request_error)?
+                    .into(),
+                bucket_name_of_policy(provider.async_policy(Default::default()).await)?,
+            ),
+            (None, Some(credential), Some(bucket_name)) => (
+                credential
+                    .async_get(Default::default())
+                    .await?
+                    .access_key()
+                    .to_owned(),
+                bucket_name.as_str().into(),
+            ),
+            _ => return Err(signer_without_bucket_error()),
+        };
+        Ok(self.queryer.query(access_key, bucket_name))
+    }
+}
+
+fn signer_without_bucket_error() -> qiniu_sdk::http_client::ResponseError {
+    qiniu_sdk::http_client::ResponseError::new_with_msg(
+        qiniu_sdk::http::ResponseErrorKind::InvalidRequestResponse.into(),
+        "upload token signer has neither upload token provider nor bucket name",
+    )
+}
+
+fn bucket_name_of_policy(
+    policy: qiniu_sdk::upload_token::ParseResult<qiniu_sdk::upload_token::GotUploadPolicy<'_>>,
+) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::upload_token::BucketName> {
+    policy
+        .and_then(|policy| {
+            policy
+                .bucket()
+                .map(qiniu_sdk::upload_token::BucketName::from)
+                .ok_or(qiniu_sdk::upload_token::ParseError::InvalidUploadTokenFormat)
+        })
+        .map_err(make_invalid_request_error)
+}
+
+fn make_invalid_request_error(
+    err: qiniu_sdk::upload_token::ParseError,
+) -> qiniu_sdk::http_client::ResponseError {
+    qiniu_sdk::http_client::ResponseError::new(
+        qiniu_sdk::http::ResponseErrorKind::InvalidRequestResponse.into(),
+        err,
+    )
+}
+
+impl qiniu_sdk::http_client::RegionsProvider for SignerRegionsProvider {
+    fn get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion> {
+        qiniu_sdk::http_client::RegionsProvider::get(&self.query()?, opts)
+    }
+
+    fn get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions> {
+        qiniu_sdk::http_client::RegionsProvider::get_all(&self.query()?, opts)
+    }
+
+    fn async_get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> futures::future::BoxFuture<
+        '_,
+        qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion>,
+    > {
+        Box::pin(async move {
+            qiniu_sdk::http_client::RegionsProvider::async_get(&self.async_query().await?, opts)
+                .await
+        })
+    }
+
+    fn async_get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> futures::future::BoxFuture<
+        '_,
+        qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions>,
+    > {
+        Box::pin(async move {
+            qiniu_sdk::http_client::RegionsProvider::async_get_all(&self.async_query().await?, opts)
+                .await
+        })
+    }
+}
+
 /// 并发数获取接口
 ///
 /// 抽象类
@@ -230,6 +442,78 @@ impl FixedConcurrencyProvider {
     }
 }
 
+/// 自适应并发数提供者
+///
+/// 采用加性增、乘性减的策略调整并发数：上传成功且耗时低于 `slow_threshold_ms` 时，并发数加一；上传失败或耗时过长时，并发数减半。并发数始终保持在 `min` 与 `max` 之间。
+///
+/// 通过 `AdaptiveConcurrencyProvider(initial, min, max, slow_threshold_ms = None)` 创建自适应并发数提供者
+#[pyclass(extends = ConcurrencyProvider)]
+#[derive(Copy, Clone, Debug)]
+#[pyo3(text_signature = "(initial, min, max, /, slow_threshold_ms = None)")]
+struct AdaptiveConcurrencyProvider;
+
+#[pymethods]
+impl AdaptiveConcurrencyProvider {
+    /// 创建自适应并发数提供者
+    ///
+    /// 如果传入 `0` 作为 `min`，或是 `initial` 不在 `min` 与 `max` 之间，将抛出异常。`slow_threshold_ms` 默认为 30 秒
+    #[new]
+    #[args(slow_threshold_ms = "None")]
+    fn new(
+        initial: usize,
+        min: usize,
+        max: usize,
+        slow_threshold_ms: Option<u64>,
+    ) -> PyResult<(Self, ConcurrencyProvider)> {
+        if min == 0 || min > max {
+            return Err(QiniuInvalidLimitation::new_err("Invalid limitation"));
+        }
+        if initial < min || initial > max {
+            return Err(QiniuInvalidConcurrency::new_err("Invalid concurrency"));
+        }
+        Ok((
+            Self,
+            ConcurrencyProvider(Box::new(AdaptiveConcurrency {
+                current: Arc::new(AtomicUsize::new(initial)),
+                min,
+                max,
+                slow_threshold: slow_threshold_ms
+                    .map(Duration::from_millis)
+                    .unwrap_or_else(|| Duration::from_secs(30)),
+            })),
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AdaptiveConcurrency {
+    current: Arc<AtomicUsize>,
+    min: usize,
+    max: usize,
+    slow_threshold: Duration,
+}
+
+impl qiniu_sdk::upload::ConcurrencyProvider for AdaptiveConcurrency {
+    fn concurrency(&self) -> qiniu_sdk::upload::Concurrency {
+        let current = self.current.load(Ordering::Relaxed);
+        qiniu_sdk::upload::Concurrency::new(current)
+            .unwrap_or_else(|| qiniu_sdk::upload::Concurrency::new(self.min).unwrap())
+    }
+
+    fn feedback(&self, feedback: qiniu_sdk::upload::ConcurrencyProviderFeedback<'_>) {
+        let succeeded = feedback.error().is_none() && feedback.elapsed() < self.slow_threshold;
+        self.current
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+                Some(if succeeded {
+                    current.saturating_add(1).min(self.max)
+                } else {
+                    (current / 2).max(self.min)
+                })
+            })
+            .ok();
+    }
+}
+
 /// 分片大小获取接口
 ///
 /// 抽象类
@@ -375,6 +659,84 @@ impl LimitedDataPartitionProvider {
     }
 }
 
+/// 限速的分片大小提供者
+///
+/// 基于一个分片大小提供者实例，根据反馈的分片上传耗时估算上传速度，如果速度超过限制，则下调分片大小，直到速度回到限制范围内。
+///
+/// 需要注意的是，这里仅通过缩小分片大小来近似控制速度，并不是严格的带宽限制，且该限制仅针对单个分片的上传速度，实际的总上传速度约为分片上传速度乘以并发数提供者提供的并发数，因此如果需要限制总上传速度，应该将 `max_bytes_per_sec` 设置为总速度除以并发数。
+///
+/// 通过 `ThrottledDataPartitionProvider(base, max_bytes_per_sec)` 创建限速的分片大小提供者
+#[pyclass(extends = DataPartitionProvider)]
+#[derive(Copy, Clone, Debug)]
+#[pyo3(text_signature = "(base, max_bytes_per_sec)")]
+struct ThrottledDataPartitionProvider;
+
+#[pymethods]
+impl ThrottledDataPartitionProvider {
+    /// 创建限速的分片大小提供者
+    ///
+    /// 如果传入 `0` 作为 `max_bytes_per_sec` 将抛出异常
+    #[new]
+    fn new(
+        base: DataPartitionProvider,
+        max_bytes_per_sec: u64,
+    ) -> PyResult<(Self, DataPartitionProvider)> {
+        let max_bytes_per_sec = NonZeroU64::new(max_bytes_per_sec).map_or_else(
+            || Err(QiniuInvalidLimitation::new_err("Invalid limitation")),
+            Ok,
+        )?;
+        Ok((
+            Self,
+            DataPartitionProvider(Box::new(ThrottledPartitionProvider {
+                base,
+                max_bytes_per_sec,
+                limit: Arc::new(AtomicU64::new(u64::MAX)),
+            })),
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ThrottledPartitionProvider {
+    base: DataPartitionProvider,
+    max_bytes_per_sec: NonZeroU64,
+    limit: Arc<AtomicU64>,
+}
+
+impl qiniu_sdk::upload::DataPartitionProvider for ThrottledPartitionProvider {
+    fn part_size(&self) -> qiniu_sdk::upload::PartSize {
+        let part_size = self.base.0.part_size();
+        let limit = self.limit.load(Ordering::Relaxed);
+        if part_size.as_u64() > limit {
+            qiniu_sdk::upload::PartSize::new(limit).unwrap_or(part_size)
+        } else {
+            part_size
+        }
+    }
+
+    fn feedback(&self, feedback: qiniu_sdk::upload::DataPartitionProviderFeedback<'_>) {
+        if feedback.error().is_none() {
+            let part_size = feedback.part_size().as_u64();
+            let elapsed = feedback.elapsed().as_secs_f64();
+            let max_bytes_per_sec = self.max_bytes_per_sec.get();
+            if elapsed > 0.0 && part_size as f64 / elapsed > max_bytes_per_sec as f64 {
+                // 按照限速计算出在本次耗时内允许上传的数据量，作为新的分片大小上限
+                let limit = ((max_bytes_per_sec as f64 * elapsed) as u64).max(1);
+                self.limit.store(limit, Ordering::Relaxed);
+            } else {
+                // 放宽上限时不超过基础分片大小，避免上限无限增长后再次限速时需要很多次反馈才能生效
+                let max_part_size = self.base.0.part_size().as_u64();
+                let limit = self.limit.load(Ordering::Relaxed);
+                self.limit.store(
+                    limit.saturating_mul(2).min(max_part_size),
+                    Ordering::Relaxed,
+                );
+            }
+        }
+        self.base.0.feedback(feedback)
+    }
+}
+
 /// 可恢复策略
 ///
 /// 选择使用单请求上传或分片上传
@@ -633,13 +995,40 @@ impl SourceKey {
     /// 创建数据源 KEY，只能接受 20 个字节的二进制数据
     #[new]
     fn new(key: &[u8]) -> PyResult<Self> {
-        if key.len() != Sha1::output_size() {
-            return Err(QiniuInvalidSourceKeyLengthError::new_err(
-                "Invalid source key length, expected 20",
-            ));
+        source_key_from_bytes(key).map(Self).ok_or_else(|| {
+            QiniuInvalidSourceKeyLengthError::new_err("Invalid source key length, expected 20")
+        })
+    }
+
+    /// 从十六进制字符串创建数据源 KEY，只能接受 40 个字符的十六进制字符串
+    #[staticmethod]
+    #[pyo3(text_signature = "(s)")]
+    fn from_hex(s: &str) -> PyResult<Self> {
+        let key = hex::decode(s).map_err(|_| {
+            QiniuInvalidSourceKeyLengthError::new_err(
+                "Invalid source key hex string, expected 40 hex characters",
+            )
+        })?;
+        Self::new(&key)
+    }
+
+    /// 获取数据源 KEY 的二进制数据
+    #[pyo3(text_signature = "($self)")]
+    fn to_bytes<'p>(&self, py: Python<'p>) -> &'p PyBytes {
+        PyBytes::new(py, &self.0)
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
+        match op {
+            CompareOp::Eq => (*self.0 == *other.0).to_object(py),
+            _ => py.NotImplemented(),
         }
-        let arr = GenericArray::<u8, <Sha1 as OutputSizeUser>::OutputSize>::clone_from_slice(key);
-        Ok(Self(qiniu_sdk::upload::SourceKey::from(arr)))
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.as_slice().hash(&mut hasher);
+        hasher.finish()
     }
 
     fn __str__(&self) -> String {
@@ -656,7 +1045,10 @@ impl SourceKey {
 /// 抽象类
 #[pyclass(subclass)]
 #[derive(Clone, Debug)]
-struct ResumableRecorder(Box<dyn qiniu_sdk::upload::ResumableRecorder<HashAlgorithm = Sha1>>);
+struct ResumableRecorder(
+    Box<dyn qiniu_sdk::upload::ResumableRecorder<HashAlgorithm = Sha1>>,
+    Option<Arc<dyn ResumableRecordsManager>>,
+);
 
 #[pymethods]
 impl ResumableRecorder {
@@ -774,6 +1166,55 @@ impl ResumableRecorder {
         })
     }
 
+    /// 列出所有断点记录
+    ///
+    /// 仅文件系统断点恢复记录器和 SQLite 断点恢复记录器支持该方法
+    #[pyo3(text_signature = "($self)")]
+    fn list_records(&self, py: Python<'_>) -> PyResult<Vec<ResumableRecordInfo>> {
+        let manager = self.records_manager()?;
+        py.allow_threads(|| manager.list_records().map_err(QiniuIoError::from_err))
+    }
+
+    /// 删除最后修改时间早于 `older_than_secs` 秒之前的断点记录，返回删除的记录数量
+    ///
+    /// 仅文件系统断点恢复记录器和 SQLite 断点恢复记录器支持该方法
+    #[pyo3(text_signature = "($self, older_than_secs)")]
+    fn prune(&self, older_than_secs: u64, py: Python<'_>) -> PyResult<usize> {
+        let manager = self.records_manager()?;
+        py.allow_threads(|| {
+            prune_records(&*manager, Duration::from_secs(older_than_secs))
+                .map_err(QiniuIoError::from_err)
+        })
+    }
+
+    /// 异步列出所有断点记录
+    ///
+    /// 仅文件系统断点恢复记录器和 SQLite 断点恢复记录器支持该方法
+    #[pyo3(text_signature = "($self)")]
+    fn async_list_records<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let manager = self.records_manager()?;
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            async_std::task::spawn_blocking(move || manager.list_records())
+                .await
+                .map_err(QiniuIoError::from_err)
+        })
+    }
+
+    /// 异步删除最后修改时间早于 `older_than_secs` 秒之前的断点记录，返回删除的记录数量
+    ///
+    /// 仅文件系统断点恢复记录器和 SQLite 断点恢复记录器支持该方法
+    #[pyo3(text_signature = "($self, older_than_secs)")]
+    fn async_prune<'p>(&self, older_than_secs: u64, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let manager = self.records_manager()?;
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            async_std::task::spawn_blocking(move || {
+                prune_records(&*manager, Duration::from_secs(older_than_secs))
+            })
+            .await
+            .map_err(QiniuIoError::from_err)
+        })
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -783,6 +1224,17 @@ impl ResumableRecorder {
     }
 }
 
+impl ResumableRecorder {
+    fn records_manager(&self) -> PyResult<Arc<dyn ResumableRecordsManager>> {
+        self.1.to_owned().ok_or_else(|| {
+            QiniuUnsupportedRecorderOperation::new_err(format!(
+                "{:?} does not support listing records",
+                self.0
+            ))
+        })
+    }
+}
+
 impl qiniu_sdk::upload::ResumableRecorder for ResumableRecorder {
     type HashAlgorithm = Sha1;
 
@@ -852,6 +1304,82 @@ impl qiniu_sdk::upload::ResumableRecorder for ResumableRecorder {
     }
 }
 
+/// 断点记录信息
+///
+/// 该类型没有构造函数，仅限于通过 `resumable_recorder.list_records()` 获取
+#[pyclass]
+#[derive(Clone, Debug)]
+struct ResumableRecordInfo {
+    source_key: qiniu_sdk::upload::SourceKey<Sha1>,
+    modified_at: u64,
+    size: u64,
+}
+
+#[pymethods]
+impl ResumableRecordInfo {
+    /// 获取数据源 KEY
+    #[getter]
+    fn get_source_key(&self) -> SourceKey {
+        SourceKey(self.source_key.to_owned())
+    }
+
+    /// 获取最后修改时间，单位为秒的 UNIX 时间戳
+    #[getter]
+    fn get_modified_at(&self) -> u64 {
+        self.modified_at
+    }
+
+    /// 获取记录的数据大小，单位为字节
+    #[getter]
+    fn get_size(&self) -> u64 {
+        self.size
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// 可以列出和删除断点记录的记录器
+trait ResumableRecordsManager: Debug + Send + Sync {
+    fn list_records(&self) -> IoResult<Vec<ResumableRecordInfo>>;
+
+    fn delete_record(&self, source_key: &[u8]) -> IoResult<()>;
+}
+
+fn prune_records(manager: &dyn ResumableRecordsManager, older_than: Duration) -> IoResult<usize> {
+    let deadline = unix_timestamp(SystemTime::now()).saturating_sub(older_than.as_secs());
+    let mut pruned = 0;
+    for record in manager.list_records()? {
+        if record.modified_at < deadline {
+            match manager.delete_record(&record.source_key) {
+                Ok(()) => pruned += 1,
+                Err(err) if err.kind() == IoErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+    Ok(pruned)
+}
+
+fn unix_timestamp(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+fn source_key_from_bytes(key: &[u8]) -> Option<qiniu_sdk::upload::SourceKey<Sha1>> {
+    if key.len() == Sha1::output_size() {
+        let arr = GenericArray::<u8, <Sha1 as OutputSizeUser>::OutputSize>::clone_from_slice(key);
+        Some(qiniu_sdk::upload::SourceKey::from(arr))
+    } else {
+        None
+    }
+}
+
 /// 只读介质接口
 ///
 /// 抽象类
@@ -1063,467 +1591,2729 @@ impl DummyResumableRecorder {
     fn new() -> (Self, ResumableRecorder) {
         (
             Self,
-            ResumableRecorder(Box::new(qiniu_sdk::upload::DummyResumableRecorder::new())),
+            ResumableRecorder(
+                Box::new(qiniu_sdk::upload::DummyResumableRecorder::new()),
+                None,
+            ),
         )
     }
 }
 
-/// 文件系统断点恢复记录器
+/// 警告断点恢复记录器
 ///
-/// 基于文件系统提供断点恢复记录功能
+/// 与无断点恢复记录器一样总是返回找不到记录，但在第一次尝试读取断点记录时会发出 Python 警告，提示配置一个真正的断点恢复记录器
 ///
-/// 通过 `FileSystemResumableRecorder(path = None)` 创建文件系统断点恢复记录器
+/// 通过 `WarningResumableRecorder()` 创建警告断点恢复记录器
 #[pyclass(extends = ResumableRecorder)]
-#[derive(Debug, Clone)]
-#[pyo3(text_signature = "(/, path = None)")]
-struct FileSystemResumableRecorder;
+#[derive(Clone, Debug)]
+#[pyo3(text_signature = "()")]
+struct WarningResumableRecorder;
 
 #[pymethods]
-impl FileSystemResumableRecorder {
-    /// 创建文件系统断点恢复记录器，传入一个目录路径用于储存断点记录
+impl WarningResumableRecorder {
+    /// 创建警告断点恢复记录器
     #[new]
-    #[args(path = "None")]
-    fn new(path: Option<String>) -> (Self, ResumableRecorder) {
-        let recorder = if let Some(path) = path {
-            qiniu_sdk::upload::FileSystemResumableRecorder::new(path)
-        } else {
-            qiniu_sdk::upload::FileSystemResumableRecorder::default()
-        };
-        (Self, ResumableRecorder(Box::new(recorder)))
+    fn new() -> (Self, ResumableRecorder) {
+        (
+            Self,
+            ResumableRecorder(Box::new(WarningRecorder::new()), None),
+        )
     }
 }
 
-macro_rules! impl_uploader {
-    ($name:ident) => {
-        #[pymethods]
-        impl $name {
-            #[pyo3(
-                text_signature = "($self, path, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None)"
-            )]
-            #[args(
-                region_provider = "None",
-                object_name = "None",
-                file_name = "None",
-                content_type = "None",
-                metadata = "None",
-                custom_vars = "None",
-            )]
-            #[allow(clippy::too_many_arguments)]
-            fn upload_path(
-                &self,
-                path: &str,
-                region_provider: Option<RegionsProvider>,
-                object_name: Option<&str>,
-                file_name: Option<&str>,
-                content_type: Option<&str>,
-                metadata: Option<HashMap<String, String>>,
-                custom_vars: Option<HashMap<String, String>>,
-                py: Python<'_>,
-            ) -> PyResult<PyObject> {
-                let object_params = make_object_params(
-                    region_provider,
-                    object_name,
-                    file_name,
-                    content_type,
-                    metadata,
-                    custom_vars,
-                )?;
-                py.allow_threads(|| {
-                    self.0
-                        .upload_path(path, object_params)
-                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                        .and_then(|v| convert_json_value_to_py_object(&v))
-                })
-            }
+#[derive(Debug, Clone)]
+struct WarningRecorder {
+    dummy: qiniu_sdk::upload::DummyResumableRecorder<Sha1>,
+    warned: Arc<AtomicBool>,
+}
 
-            #[pyo3(
-                text_signature = "($self, reader, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None)"
-            )]
-            #[args(
-                region_provider = "None",
-                object_name = "None",
-                file_name = "None",
-                content_type = "None",
-                metadata = "None",
-                custom_vars = "None",
-            )]
-            #[allow(clippy::too_many_arguments)]
-            fn upload_reader(
-                &self,
-                reader: PyObject,
-                region_provider: Option<RegionsProvider>,
-                object_name: Option<&str>,
-                file_name: Option<&str>,
-                content_type: Option<&str>,
-                metadata: Option<HashMap<String, String>>,
-                custom_vars: Option<HashMap<String, String>>,
-                py: Python<'_>,
-            ) -> PyResult<PyObject> {
-                let object_params = make_object_params(
-                    region_provider,
-                    object_name,
-                    file_name,
-                    content_type,
-                    metadata,
-                    custom_vars,
-                )?;
-                py.allow_threads(|| {
-                    self.0
-                        .upload_reader(PythonIoBase::new(reader), object_params)
-                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                        .and_then(|v| convert_json_value_to_py_object(&v))
-                })
-            }
+impl WarningRecorder {
+    fn new() -> Self {
+        Self {
+            dummy: qiniu_sdk::upload::DummyResumableRecorder::new(),
+            warned: Default::default(),
+        }
+    }
 
-            #[pyo3(
-                text_signature = "($self, path, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None)"
-            )]
-            #[args(
-                region_provider = "None",
-                object_name = "None",
-                file_name = "None",
-                content_type = "None",
-                metadata = "None",
-                custom_vars = "None",
-            )]
-            #[allow(clippy::too_many_arguments)]
-            fn async_upload_path<'p>(
-                &self,
-                path: String,
-                region_provider: Option<RegionsProvider>,
-                object_name: Option<&str>,
-                file_name: Option<&str>,
-                content_type: Option<&str>,
-                metadata: Option<HashMap<String, String>>,
-                custom_vars: Option<HashMap<String, String>>,
-                py: Python<'p>,
-            ) -> PyResult<&'p PyAny> {
-                let object_params = make_object_params(
-                    region_provider,
-                    object_name,
-                    file_name,
-                    content_type,
-                    metadata,
-                    custom_vars,
-                )?;
-                let uploader = self.0.to_owned();
-                pyo3_asyncio::async_std::future_into_py(py, async move {
-                    uploader
-                        .async_upload_path(&path, object_params)
-                        .await
-                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                        .and_then(|v| convert_json_value_to_py_object(&v))
-                })
-            }
+    fn warn_once(&self) -> IoResult<()> {
+        if self.warned.swap(true, Ordering::Relaxed) {
+            return Ok(());
+        }
+        Python::with_gil(|py| {
+            PyErr::warn(
+                py,
+                user_warning(py)?,
+                "Resumable upload is not available because no resumable recorder is configured, use FileSystemResumableRecorder or SqliteResumableRecorder instead",
+                1,
+            )
+        })
+        .map_err(|err| IoError::new(IoErrorKind::Other, err))
+    }
+}
 
-            #[pyo3(
-                text_signature = "($self, reader, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None)"
-            )]
-            #[args(
-                region_provider = "None",
-                object_name = "None",
-                file_name = "None",
-                content_type = "None",
-                metadata = "None",
-                custom_vars = "None",
-            )]
-            #[allow(clippy::too_many_arguments)]
-            fn async_upload_reader<'p>(
-                &self,
-                reader: PyObject,
-                region_provider: Option<RegionsProvider>,
-                object_name: Option<&str>,
-                file_name: Option<&str>,
-                content_type: Option<&str>,
-                metadata: Option<HashMap<String, String>>,
-                custom_vars: Option<HashMap<String, String>>,
-                py: Python<'p>,
-            ) -> PyResult<&'p PyAny> {
-                let object_params = make_object_params(
-                    region_provider,
-                    object_name,
-                    file_name,
-                    content_type,
-                    metadata,
-                    custom_vars,
-                )?;
-                let uploader = self.0.to_owned();
-                pyo3_asyncio::async_std::future_into_py(py, async move {
-                    uploader
-                        .async_upload_reader(PythonIoBase::new(reader).into_async_read(), object_params)
-                        .await
-                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                        .and_then(|v| convert_json_value_to_py_object(&v))
-                })
-            }
+impl qiniu_sdk::upload::ResumableRecorder for WarningRecorder {
+    type HashAlgorithm = Sha1;
 
-            fn __repr__(&self) -> String {
-                format!("{:?}", self.0)
-            }
+    fn open_for_read(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::ReadOnlyResumableRecorderMedium>> {
+        self.warn_once()?;
+        qiniu_sdk::upload::ResumableRecorder::open_for_read(&self.dummy, source_key)
+    }
 
-            fn __str__(&self) -> String {
-                self.__repr__()
-            }
+    fn open_for_append(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyResumableRecorderMedium>> {
+        qiniu_sdk::upload::ResumableRecorder::open_for_append(&self.dummy, source_key)
+    }
+
+    fn open_for_create_new(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyResumableRecorderMedium>> {
+        qiniu_sdk::upload::ResumableRecorder::open_for_create_new(&self.dummy, source_key)
+    }
+
+    fn delete(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<()> {
+        qiniu_sdk::upload::ResumableRecorder::delete(&self.dummy, source_key)
+    }
+
+    fn open_for_async_read<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::ReadOnlyAsyncResumableRecorderMedium>>,
+    > {
+        if let Err(err) = self.warn_once() {
+            return Box::pin(async move { Err(err) });
         }
+        qiniu_sdk::upload::ResumableRecorder::open_for_async_read(&self.dummy, source_key)
+    }
+
+    fn open_for_async_append<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium>>,
+    > {
+        qiniu_sdk::upload::ResumableRecorder::open_for_async_append(&self.dummy, source_key)
+    }
+
+    fn open_for_async_create_new<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium>>,
+    > {
+        qiniu_sdk::upload::ResumableRecorder::open_for_async_create_new(&self.dummy, source_key)
+    }
+
+    fn async_delete<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<'a, IoResult<()>> {
+        qiniu_sdk::upload::ResumableRecorder::async_delete(&self.dummy, source_key)
     }
 }
 
-/// 数据源接口
+/// 与 SDK 的文件系统断点恢复记录器一致的默认记录目录名称，位于系统临时目录下
+const DEFAULT_FILE_SYSTEM_RECORDER_DIRECTORY_NAME: &str = ".qiniu-rust-sdk";
+
+/// 文件系统断点恢复记录器
 ///
-/// 抽象类
+/// 基于文件系统提供断点恢复记录功能
 ///
-/// 提供上传所用的数据源
-#[pyclass(subclass)]
+/// 如果 `fsync` 为 `True`，每次刷新追加介质时都会将断点记录同步到磁盘，进程或系统崩溃时不会丢失已经刷新的记录，但会明显降低记录的写入吞吐量，默认为 `False`
+///
+/// 通过 `FileSystemResumableRecorder(path = None, fsync = False)` 创建文件系统断点恢复记录器
+#[pyclass(extends = ResumableRecorder)]
 #[derive(Debug, Clone)]
-struct DataSource(Box<dyn qiniu_sdk::upload::DataSource<Sha1>>);
+#[pyo3(text_signature = "(/, path = None, fsync = False)")]
+struct FileSystemResumableRecorder;
 
 #[pymethods]
-impl DataSource {
-    /// 数据源切片
-    #[pyo3(text_signature = "($self, size)")]
-    fn slice(&self, size: u64, py: Python<'_>) -> PyResult<Option<DataSourceReader>> {
-        let part_size = qiniu_sdk::upload::PartSize::new(size).map_or_else(
-            || Err(QiniuInvalidPartSize::new_err("part_size must not be zero")),
-            Ok,
-        )?;
-        let reader = py
-            .allow_threads(|| self.0.slice(part_size))
-            .map_err(PyIOError::new_err)?
-            .map(DataSourceReader);
-        Ok(reader)
+impl FileSystemResumableRecorder {
+    /// 创建文件系统断点恢复记录器，传入一个目录路径用于储存断点记录
+    #[new]
+    #[args(path = "None", fsync = "false")]
+    fn new(path: Option<String>, fsync: bool) -> (Self, ResumableRecorder) {
+        let path = path.map_or_else(
+            || std::env::temp_dir().join(DEFAULT_FILE_SYSTEM_RECORDER_DIRECTORY_NAME),
+            PathBuf::from,
+        );
+        let manager = Arc::new(FileSystemRecordsManager {
+            path: path.to_owned(),
+        });
+        if fsync {
+            let recorder = FsyncFileSystemRecorder::new(path);
+            return (Self, ResumableRecorder(Box::new(recorder), Some(manager)));
+        }
+        let recorder = qiniu_sdk::upload::FileSystemResumableRecorder::<Sha1>::new(path);
+        (Self, ResumableRecorder(Box::new(recorder), Some(manager)))
     }
+}
 
-    /// 获取数据源 KEY
-    ///
-    /// 用于区分不同的数据源
-    #[pyo3(text_signature = "($self)")]
-    fn source_key(&self, py: Python<'_>) -> PyResult<Option<SourceKey>> {
-        py.allow_threads(|| self.0.source_key())
-            .map(|s| s.map(SourceKey))
-            .map_err(PyIOError::new_err)
+/// 列出文件系统断点恢复记录器目录中的断点记录
+///
+/// 只有以数据源 KEY 的十六进制字符串命名的文件才会被视为断点记录
+#[derive(Debug, Clone)]
+struct FileSystemRecordsManager {
+    path: PathBuf,
+}
+
+impl ResumableRecordsManager for FileSystemRecordsManager {
+    fn list_records(&self) -> IoResult<Vec<ResumableRecordInfo>> {
+        let entries = match std::fs::read_dir(&self.path) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == IoErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut records = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            let source_key = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| hex::decode(name).ok())
+                .and_then(|key| source_key_from_bytes(&key));
+            if let Some(source_key) = source_key {
+                let metadata = entry.metadata()?;
+                if metadata.is_file() {
+                    records.push(ResumableRecordInfo {
+                        source_key,
+                        modified_at: unix_timestamp(metadata.modified()?),
+                        size: metadata.len(),
+                    });
+                }
+            }
+        }
+        Ok(records)
     }
 
-    /// 获取数据源大小
-    #[pyo3(text_signature = "($self)")]
-    fn total_size(&self, py: Python<'_>) -> PyResult<Option<u64>> {
-        py.allow_threads(|| self.0.total_size())
-            .map_err(PyIOError::new_err)
+    fn delete_record(&self, source_key: &[u8]) -> IoResult<()> {
+        std::fs::remove_file(self.path.join(hex::encode(source_key)))
+    }
+}
+
+/// 刷新时同步到磁盘的文件系统断点恢复记录器
+///
+/// 读取和删除断点记录都直接交给 SDK 的文件系统断点恢复记录器，只替换追加介质，断点记录文件的路径和格式与其完全一致
+#[derive(Debug, Clone)]
+struct FsyncFileSystemRecorder {
+    recorder: qiniu_sdk::upload::FileSystemResumableRecorder<Sha1>,
+    path: PathBuf,
+}
+
+impl FsyncFileSystemRecorder {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            recorder: qiniu_sdk::upload::FileSystemResumableRecorder::new(path.to_owned()),
+            path,
+        }
+    }
+
+    fn open_record_file(path: &Path, source_key: &[u8], create_new: bool) -> IoResult<File> {
+        std::fs::DirBuilder::new().recursive(true).create(path)?;
+        let mut options = std::fs::OpenOptions::new();
+        if create_new {
+            options.create(true).truncate(true).write(true);
+        } else {
+            options.append(true);
+        }
+        options.open(path.join(hex::encode(source_key)))
+    }
+
+    fn open_medium(&self, source_key: &[u8], create_new: bool) -> IoResult<FsyncFileMedium> {
+        let file = Self::open_record_file(&self.path, source_key, create_new)?;
+        Ok(FsyncFileMedium(std::io::BufWriter::new(file)))
+    }
+
+    async fn async_open_medium(
+        &self,
+        source_key: &[u8],
+        create_new: bool,
+    ) -> IoResult<AsyncFsyncFileMedium> {
+        let path = self.path.to_owned();
+        let source_key = source_key.to_owned();
+        let file = async_std::task::spawn_blocking(move || {
+            Self::open_record_file(&path, &source_key, create_new)
+        })
+        .await?;
+        AsyncFsyncFileMedium::new(file)
+    }
+}
+
+impl qiniu_sdk::upload::ResumableRecorder for FsyncFileSystemRecorder {
+    type HashAlgorithm = Sha1;
+
+    fn open_for_read(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::ReadOnlyResumableRecorderMedium>> {
+        self.recorder.open_for_read(source_key)
+    }
+
+    fn open_for_append(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyResumableRecorderMedium>> {
+        Ok(Box::new(self.open_medium(source_key, false)?))
+    }
+
+    fn open_for_create_new(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyResumableRecorderMedium>> {
+        Ok(Box::new(self.open_medium(source_key, true)?))
+    }
+
+    fn delete(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<()> {
+        self.recorder.delete(source_key)
+    }
+
+    fn open_for_async_read<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::ReadOnlyAsyncResumableRecorderMedium>>,
+    > {
+        self.recorder.open_for_async_read(source_key)
+    }
+
+    fn open_for_async_append<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium>>,
+    > {
+        Box::pin(async move {
+            Ok(Box::new(self.async_open_medium(source_key, false).await?)
+                as Box<
+                    dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium,
+                >)
+        })
+    }
+
+    fn open_for_async_create_new<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium>>,
+    > {
+        Box::pin(async move {
+            Ok(Box::new(self.async_open_medium(source_key, true).await?)
+                as Box<
+                    dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium,
+                >)
+        })
+    }
+
+    fn async_delete<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<'a, IoResult<()>> {
+        self.recorder.async_delete(source_key)
+    }
+}
+
+/// 刷新时同步到磁盘的断点记录追加介质
+#[derive(Debug)]
+struct FsyncFileMedium(std::io::BufWriter<File>);
+
+impl Write for FsyncFileMedium {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        self.0.flush()?;
+        self.0.get_ref().sync_data()
+    }
+}
+
+/// 刷新时同步到磁盘的异步断点记录追加介质
+///
+/// 写入的数据先缓存在内存中，刷新时写入文件后在阻塞线程池中同步到磁盘
+#[derive(Debug)]
+struct AsyncFsyncFileMedium {
+    writer: futures::io::BufWriter<async_std::fs::File>,
+    file: Arc<File>,
+    syncing: Option<async_std::task::JoinHandle<IoResult<()>>>,
+}
+
+impl AsyncFsyncFileMedium {
+    fn new(file: File) -> IoResult<Self> {
+        Ok(Self {
+            writer: futures::io::BufWriter::new(file.try_clone()?.into()),
+            file: Arc::new(file),
+            syncing: None,
+        })
+    }
+}
+
+impl AsyncWrite for AsyncFsyncFileMedium {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<IoResult<usize>> {
+        Pin::new(&mut self.get_mut().writer).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<IoResult<()>> {
+        let this = self.get_mut();
+        if this.syncing.is_none() {
+            futures::ready!(Pin::new(&mut this.writer).poll_flush(cx))?;
+            let file = this.file.to_owned();
+            this.syncing = Some(async_std::task::spawn_blocking(move || file.sync_data()));
+        }
+        let result = futures::ready!(this.syncing.as_mut().unwrap().poll_unpin(cx));
+        this.syncing = None;
+        Poll::Ready(result)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<IoResult<()>> {
+        futures::ready!(self.as_mut().poll_flush(cx))?;
+        Pin::new(&mut self.get_mut().writer).poll_close(cx)
+    }
+}
+
+/// SQLite 断点恢复记录器
+///
+/// 基于 SQLite 数据库提供断点恢复记录功能，所有断点记录都储存在同一个数据库文件中
+///
+/// 通过 `SqliteResumableRecorder(path)` 创建 SQLite 断点恢复记录器
+#[pyclass(extends = ResumableRecorder)]
+#[derive(Debug, Clone)]
+#[pyo3(text_signature = "(path)")]
+struct SqliteResumableRecorder;
+
+#[pymethods]
+impl SqliteResumableRecorder {
+    /// 创建 SQLite 断点恢复记录器，传入一个数据库文件路径用于储存断点记录
+    ///
+    /// 如果数据库文件不存在，将会自动创建
+    #[new]
+    fn new(path: &str, py: Python<'_>) -> PyResult<(Self, ResumableRecorder)> {
+        let recorder = py
+            .allow_threads(|| SqliteRecorder::open(path))
+            .map_err(QiniuIoError::from_err)?;
+        Ok((
+            Self,
+            ResumableRecorder(Box::new(recorder.to_owned()), Some(Arc::new(recorder))),
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SqliteRecorder(Arc<Mutex<rusqlite::Connection>>);
+
+impl SqliteRecorder {
+    fn open(path: &str) -> IoResult<Self> {
+        let conn = rusqlite::Connection::open(path).map_err(convert_sqlite_error)?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS resumable_records (source_key BLOB PRIMARY KEY NOT NULL, data BLOB NOT NULL, modified_at INTEGER NOT NULL DEFAULT 0)",
+            [],
+        )
+        .map_err(convert_sqlite_error)?;
+        let has_modified_at: bool = conn
+            .query_row(
+                "SELECT COUNT(*) > 0 FROM pragma_table_info('resumable_records') WHERE name = 'modified_at'",
+                [],
+                |row| row.get(0),
+            )
+            .map_err(convert_sqlite_error)?;
+        if !has_modified_at {
+            conn.execute(
+                "ALTER TABLE resumable_records ADD COLUMN modified_at INTEGER NOT NULL DEFAULT 0",
+                [],
+            )
+            .map_err(convert_sqlite_error)?;
+        }
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS resumable_record_chunks (id INTEGER PRIMARY KEY AUTOINCREMENT, source_key BLOB NOT NULL, data BLOB NOT NULL);
+             CREATE INDEX IF NOT EXISTS resumable_record_chunks_source_key ON resumable_record_chunks (source_key, id);
+             BEGIN;
+             INSERT INTO resumable_record_chunks (source_key, data) SELECT source_key, data FROM resumable_records WHERE length(CAST(data AS BLOB)) > 0;
+             UPDATE resumable_records SET data = x'' WHERE length(CAST(data AS BLOB)) > 0;
+             COMMIT;",
+        )
+        .map_err(convert_sqlite_error)?;
+        Ok(Self(Arc::new(Mutex::new(conn))))
+    }
+
+    fn lock(&self) -> IoResult<MutexGuard<'_, rusqlite::Connection>> {
+        self.0
+            .lock()
+            .map_err(|err| IoError::new(IoErrorKind::Other, err.to_string()))
+    }
+
+    fn record_exists(&self, source_key: &[u8]) -> IoResult<()> {
+        use rusqlite::OptionalExtension;
+
+        self.lock()?
+            .query_row(
+                "SELECT 1 FROM resumable_records WHERE source_key = ?1",
+                rusqlite::params![source_key],
+                |_| Ok(()),
+            )
+            .optional()
+            .map_err(convert_sqlite_error)?
+            .ok_or_else(|| IoError::new(IoErrorKind::NotFound, "resumable record not found"))
+    }
+
+    fn read_chunk_after(&self, source_key: &[u8], after: i64) -> IoResult<Option<(i64, Vec<u8>)>> {
+        use rusqlite::OptionalExtension;
+
+        self.lock()?
+            .query_row(
+                "SELECT id, CAST(data AS BLOB) FROM resumable_record_chunks WHERE source_key = ?1 AND id > ?2 ORDER BY id LIMIT 1",
+                rusqlite::params![source_key, after],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(convert_sqlite_error)
+    }
+
+    fn append_chunk(&self, source_key: &[u8], data: &[u8]) -> IoResult<()> {
+        let mut conn = self.lock()?;
+        let tx = conn.transaction().map_err(convert_sqlite_error)?;
+        let updated = tx
+            .execute(
+                "UPDATE resumable_records SET modified_at = ?1 WHERE source_key = ?2",
+                rusqlite::params![unix_timestamp(SystemTime::now()), source_key],
+            )
+            .map_err(convert_sqlite_error)?;
+        if updated == 0 {
+            return Err(IoError::new(
+                IoErrorKind::NotFound,
+                "resumable record not found",
+            ));
+        }
+        tx.execute(
+            "INSERT INTO resumable_record_chunks (source_key, data) VALUES (?1, ?2)",
+            rusqlite::params![source_key, data],
+        )
+        .map_err(convert_sqlite_error)?;
+        tx.commit().map_err(convert_sqlite_error)
+    }
+
+    fn create_record(&self, source_key: &[u8]) -> IoResult<()> {
+        let mut conn = self.lock()?;
+        let tx = conn.transaction().map_err(convert_sqlite_error)?;
+        tx.execute(
+            "DELETE FROM resumable_record_chunks WHERE source_key = ?1",
+            rusqlite::params![source_key],
+        )
+        .map_err(convert_sqlite_error)?;
+        tx.execute(
+            "INSERT OR REPLACE INTO resumable_records (source_key, data, modified_at) VALUES (?1, x'', ?2)",
+            rusqlite::params![source_key, unix_timestamp(SystemTime::now())],
+        )
+        .map_err(convert_sqlite_error)?;
+        tx.commit().map_err(convert_sqlite_error)
+    }
+
+    fn delete_record(&self, source_key: &[u8]) -> IoResult<()> {
+        let mut conn = self.lock()?;
+        let tx = conn.transaction().map_err(convert_sqlite_error)?;
+        tx.execute(
+            "DELETE FROM resumable_record_chunks WHERE source_key = ?1",
+            rusqlite::params![source_key],
+        )
+        .map_err(convert_sqlite_error)?;
+        tx.execute(
+            "DELETE FROM resumable_records WHERE source_key = ?1",
+            rusqlite::params![source_key],
+        )
+        .map_err(convert_sqlite_error)?;
+        tx.commit().map_err(convert_sqlite_error)
+    }
+
+    fn reader(&self, source_key: &[u8]) -> SqliteRecordReader {
+        SqliteRecordReader {
+            recorder: self.to_owned(),
+            source_key: source_key.to_vec(),
+            last_chunk_id: 0,
+            chunk: Default::default(),
+            finished: false,
+            pending: None,
+        }
+    }
+
+    fn writer(&self, source_key: &[u8]) -> SqliteAppendOnlyMedium {
+        SqliteAppendOnlyMedium {
+            recorder: self.to_owned(),
+            source_key: source_key.to_vec(),
+            pending: None,
+        }
+    }
+
+    async fn spawn_blocking<T: Send + 'static>(
+        &self,
+        source_key: &[u8],
+        f: impl FnOnce(&Self, &[u8]) -> IoResult<T> + Send + 'static,
+    ) -> IoResult<T> {
+        let recorder = self.to_owned();
+        let source_key = source_key.to_vec();
+        async_std::task::spawn_blocking(move || f(&recorder, &source_key)).await
+    }
+}
+
+impl ResumableRecordsManager for SqliteRecorder {
+    fn list_records(&self) -> IoResult<Vec<ResumableRecordInfo>> {
+        let conn = self.lock()?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT r.source_key, r.modified_at, COALESCE(SUM(length(CAST(c.data AS BLOB))), 0) FROM resumable_records r LEFT JOIN resumable_record_chunks c ON c.source_key = r.source_key GROUP BY r.source_key",
+            )
+            .map_err(convert_sqlite_error)?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, Vec<u8>>(0)?,
+                    row.get::<_, u64>(1)?,
+                    row.get::<_, u64>(2)?,
+                ))
+            })
+            .map_err(convert_sqlite_error)?;
+        let mut records = Vec::new();
+        for row in rows {
+            let (source_key, modified_at, size) = row.map_err(convert_sqlite_error)?;
+            if let Some(source_key) = source_key_from_bytes(&source_key) {
+                records.push(ResumableRecordInfo {
+                    source_key,
+                    modified_at,
+                    size,
+                });
+            }
+        }
+        Ok(records)
+    }
+
+    fn delete_record(&self, source_key: &[u8]) -> IoResult<()> {
+        SqliteRecorder::delete_record(self, source_key)
+    }
+}
+
+impl qiniu_sdk::upload::ResumableRecorder for SqliteRecorder {
+    type HashAlgorithm = Sha1;
+
+    fn open_for_read(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::ReadOnlyResumableRecorderMedium>> {
+        self.record_exists(source_key)?;
+        Ok(Box::new(self.reader(source_key)))
+    }
+
+    fn open_for_append(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyResumableRecorderMedium>> {
+        self.record_exists(source_key)?;
+        Ok(Box::new(self.writer(source_key)))
+    }
+
+    fn open_for_create_new(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyResumableRecorderMedium>> {
+        self.create_record(source_key)?;
+        Ok(Box::new(self.writer(source_key)))
+    }
+
+    fn delete(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<()> {
+        self.delete_record(source_key)
+    }
+
+    fn open_for_async_read<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::ReadOnlyAsyncResumableRecorderMedium>>,
+    > {
+        Box::pin(async move {
+            self.spawn_blocking(source_key, |recorder, source_key| {
+                recorder.record_exists(source_key)
+            })
+            .await?;
+            Ok(Box::new(self.reader(source_key))
+                as Box<
+                    dyn qiniu_sdk::prelude::ReadOnlyAsyncResumableRecorderMedium,
+                >)
+        })
+    }
+
+    fn open_for_async_append<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium>>,
+    > {
+        Box::pin(async move {
+            self.spawn_blocking(source_key, |recorder, source_key| {
+                recorder.record_exists(source_key)
+            })
+            .await?;
+            Ok(Box::new(self.writer(source_key))
+                as Box<
+                    dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium,
+                >)
+        })
+    }
+
+    fn open_for_async_create_new<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium>>,
+    > {
+        Box::pin(async move {
+            self.spawn_blocking(source_key, |recorder, source_key| {
+                recorder.create_record(source_key)
+            })
+            .await?;
+            Ok(Box::new(self.writer(source_key))
+                as Box<
+                    dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium,
+                >)
+        })
+    }
+
+    fn async_delete<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<'a, IoResult<()>> {
+        Box::pin(self.spawn_blocking(source_key, |recorder, source_key| {
+            recorder.delete_record(source_key)
+        }))
+    }
+}
+
+/// 后台读取下一个记录块的任务，结果为块 ID 与块数据
+type PendingChunk = async_std::task::JoinHandle<IoResult<Option<(i64, Vec<u8>)>>>;
+
+/// SQLite 断点记录只读介质
+///
+/// 按写入顺序逐块读取断点记录，不会一次性将整个记录载入内存
+#[derive(Debug)]
+struct SqliteRecordReader {
+    recorder: SqliteRecorder,
+    source_key: Vec<u8>,
+    last_chunk_id: i64,
+    chunk: std::io::Cursor<Vec<u8>>,
+    finished: bool,
+    pending: Option<PendingChunk>,
+}
+
+impl SqliteRecordReader {
+    fn chunk_is_consumed(&self) -> bool {
+        self.chunk.position() >= self.chunk.get_ref().len() as u64
+    }
+
+    fn set_next_chunk(&mut self, next: Option<(i64, Vec<u8>)>) {
+        if let Some((id, data)) = next {
+            self.last_chunk_id = id;
+            self.chunk = std::io::Cursor::new(data);
+        } else {
+            self.finished = true;
+        }
+    }
+}
+
+impl Read for SqliteRecordReader {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        while !self.finished && self.chunk_is_consumed() {
+            let next = self
+                .recorder
+                .read_chunk_after(&self.source_key, self.last_chunk_id)?;
+            self.set_next_chunk(next);
+        }
+        self.chunk.read(buf)
+    }
+}
+
+impl AsyncRead for SqliteRecordReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<IoResult<usize>> {
+        while !self.finished && self.chunk_is_consumed() {
+            let recorder = self.recorder.to_owned();
+            let source_key = self.source_key.to_owned();
+            let after = self.last_chunk_id;
+            let pending = self.pending.get_or_insert_with(|| {
+                async_std::task::spawn_blocking(move || {
+                    recorder.read_chunk_after(&source_key, after)
+                })
+            });
+            let next = futures::ready!(Pin::new(pending).poll(cx));
+            self.pending = None;
+            self.set_next_chunk(next?);
+        }
+        Poll::Ready(self.chunk.read(buf))
+    }
+}
+
+/// SQLite 断点记录追加介质
+///
+/// 每次写入的数据都作为一个独立的数据块追加到数据库记录中
+#[derive(Debug)]
+struct SqliteAppendOnlyMedium {
+    recorder: SqliteRecorder,
+    source_key: Vec<u8>,
+    pending: Option<(async_std::task::JoinHandle<IoResult<()>>, usize)>,
+}
+
+impl Write for SqliteAppendOnlyMedium {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        if !buf.is_empty() {
+            self.recorder.append_chunk(&self.source_key, buf)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+impl AsyncWrite for SqliteAppendOnlyMedium {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<IoResult<usize>> {
+        if buf.is_empty() && self.pending.is_none() {
+            return Poll::Ready(Ok(0));
+        }
+        let recorder = self.recorder.to_owned();
+        let source_key = self.source_key.to_owned();
+        let (pending, written) = self.pending.get_or_insert_with(|| {
+            let data = buf.to_vec();
+            (
+                async_std::task::spawn_blocking(move || recorder.append_chunk(&source_key, &data)),
+                buf.len(),
+            )
+        });
+        let written = *written;
+        let result = futures::ready!(Pin::new(pending).poll(cx));
+        self.pending = None;
+        Poll::Ready(result.map(|_| written))
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<IoResult<()>> {
+        if let Some((pending, _)) = self.pending.as_mut() {
+            let result = futures::ready!(Pin::new(pending).poll(cx));
+            self.pending = None;
+            result?;
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<IoResult<()>> {
+        self.poll_flush(cx)
+    }
+}
+
+fn convert_sqlite_error(err: rusqlite::Error) -> IoError {
+    IoError::new(IoErrorKind::Other, err)
+}
+
+/// 内存断点恢复记录器
+///
+/// 基于内存提供断点恢复记录功能，断点记录仅在当前进程内有效，可以在多个线程和上传器之间共享
+///
+/// 通过 `MemoryResumableRecorder()` 创建内存断点恢复记录器
+#[pyclass(extends = ResumableRecorder)]
+#[derive(Debug, Clone)]
+#[pyo3(text_signature = "()")]
+struct MemoryResumableRecorder;
+
+#[pymethods]
+impl MemoryResumableRecorder {
+    /// 创建内存断点恢复记录器
+    #[new]
+    fn new() -> (Self, ResumableRecorder) {
+        (
+            Self,
+            ResumableRecorder(Box::new(MemoryRecorder::default()), None),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct MemoryRecorder(Arc<Mutex<HashMap<Vec<u8>, Vec<u8>>>>);
+
+impl MemoryRecorder {
+    fn lock(&self) -> IoResult<MutexGuard<'_, HashMap<Vec<u8>, Vec<u8>>>> {
+        self.0
+            .lock()
+            .map_err(|err| IoError::new(IoErrorKind::Other, err.to_string()))
+    }
+
+    fn read_record(&self, source_key: &[u8]) -> IoResult<Vec<u8>> {
+        self.lock()?
+            .get(source_key)
+            .cloned()
+            .ok_or_else(|| IoError::new(IoErrorKind::NotFound, "resumable record not found"))
+    }
+
+    fn open_append_medium(&self, source_key: &[u8]) -> IoResult<MemoryAppendOnlyMedium> {
+        if !self.lock()?.contains_key(source_key) {
+            return Err(IoError::new(
+                IoErrorKind::NotFound,
+                "resumable record not found",
+            ));
+        }
+        Ok(MemoryAppendOnlyMedium {
+            recorder: self.to_owned(),
+            source_key: source_key.to_vec(),
+        })
+    }
+
+    fn create_append_medium(&self, source_key: &[u8]) -> IoResult<MemoryAppendOnlyMedium> {
+        self.lock()?.insert(source_key.to_vec(), Vec::new());
+        Ok(MemoryAppendOnlyMedium {
+            recorder: self.to_owned(),
+            source_key: source_key.to_vec(),
+        })
+    }
+
+    fn delete_record(&self, source_key: &[u8]) -> IoResult<()> {
+        self.lock()?.remove(source_key);
+        Ok(())
+    }
+}
+
+impl qiniu_sdk::upload::ResumableRecorder for MemoryRecorder {
+    type HashAlgorithm = Sha1;
+
+    fn open_for_read(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::ReadOnlyResumableRecorderMedium>> {
+        let data = self.read_record(source_key)?;
+        Ok(Box::new(std::io::Cursor::new(data)))
+    }
+
+    fn open_for_append(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyResumableRecorderMedium>> {
+        Ok(Box::new(self.open_append_medium(source_key)?))
+    }
+
+    fn open_for_create_new(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyResumableRecorderMedium>> {
+        Ok(Box::new(self.create_append_medium(source_key)?))
+    }
+
+    fn delete(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> IoResult<()> {
+        self.delete_record(source_key)
+    }
+
+    fn open_for_async_read<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::ReadOnlyAsyncResumableRecorderMedium>>,
+    > {
+        Box::pin(async move {
+            let data = self.read_record(source_key)?;
+            Ok(Box::new(futures::io::Cursor::new(data))
+                as Box<
+                    dyn qiniu_sdk::prelude::ReadOnlyAsyncResumableRecorderMedium,
+                >)
+        })
+    }
+
+    fn open_for_async_append<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium>>,
+    > {
+        Box::pin(async move {
+            Ok(Box::new(self.open_append_medium(source_key)?)
+                as Box<
+                    dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium,
+                >)
+        })
+    }
+
+    fn open_for_async_create_new<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<
+        'a,
+        IoResult<Box<dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium>>,
+    > {
+        Box::pin(async move {
+            Ok(Box::new(self.create_append_medium(source_key)?)
+                as Box<
+                    dyn qiniu_sdk::prelude::AppendOnlyAsyncResumableRecorderMedium,
+                >)
+        })
+    }
+
+    fn async_delete<'a>(
+        &'a self,
+        source_key: &'a qiniu_sdk::upload::SourceKey<Self::HashAlgorithm>,
+    ) -> futures::future::BoxFuture<'a, IoResult<()>> {
+        Box::pin(async move { self.delete_record(source_key) })
+    }
+}
+
+/// 内存断点记录追加介质
+///
+/// 写入的数据直接追加到共享的内存记录中
+#[derive(Debug)]
+struct MemoryAppendOnlyMedium {
+    recorder: MemoryRecorder,
+    source_key: Vec<u8>,
+}
+
+impl Write for MemoryAppendOnlyMedium {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.recorder
+            .lock()?
+            .entry(self.source_key.to_owned())
+            .or_default()
+            .extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+impl AsyncWrite for MemoryAppendOnlyMedium {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<IoResult<usize>> {
+        Poll::Ready(Write::write(self.get_mut(), buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<IoResult<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<IoResult<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+macro_rules! impl_last_server {
+    ($name:ident) => {
+        #[pymethods]
+        impl $name {
+            /// 获取最近一次成功响应的服务器 IP 地址
+            #[getter]
+            fn get_last_server_ip(&self) -> Option<String> {
+                self.1.server_ip()
+            }
+
+            /// 获取最近一次成功响应的服务器端口
+            #[getter]
+            fn get_last_server_port(&self) -> Option<u16> {
+                self.1.server_port()
+            }
+
+            /// 获取最近一次成功响应的服务器终端地址
+            #[getter]
+            fn get_last_endpoint(&self) -> Option<String> {
+                self.1.endpoint()
+            }
+        }
+    };
+}
+
+macro_rules! impl_uploader {
+    ($name:ident) => {
+        impl_uploader!(
+            $name,
+            |_reader, _py| Ok::<_, PyErr>(()),
+            |_reader| async { Ok::<_, PyErr>(()) }
+        );
+    };
+    ($name:ident, $check_reader:expr, $async_check_reader:expr) => {
+        #[pymethods]
+        impl $name {
+            #[pyo3(
+                text_signature = "($self, path, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, guess_content_type=False, typed=False, params=None)"
+            )]
+            #[args(
+                region_provider = "None",
+                object_name = "None",
+                file_name = "None",
+                content_type = "None",
+                metadata = "None",
+                custom_vars = "None",
+                guess_content_type = "false",
+                typed = "false",
+                params = "None",
+            )]
+            #[allow(clippy::too_many_arguments)]
+            fn upload_path(
+                &self,
+                path: &str,
+                region_provider: Option<&PyAny>,
+                object_name: Option<&str>,
+                file_name: Option<&str>,
+                content_type: Option<&str>,
+                metadata: Option<HashMap<String, String>>,
+                custom_vars: Option<HashMap<String, String>>,
+                guess_content_type: bool,
+                typed: bool,
+                params: Option<ObjectParams>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let object_params = match params {
+                    Some(params) => params.0,
+                    None => make_object_params(
+                        region_provider,
+                        object_name,
+                        file_name,
+                        content_type,
+                        metadata,
+                        custom_vars,
+                        guess_content_type,
+                        None,
+                    )?,
+                };
+                let _registration = UploadCallbackRegistration::new(&object_params)?;
+                py.allow_threads(|| {
+                    self.0
+                        .upload_path(path, object_params)
+                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                        .and_then(|v| convert_upload_response(v, typed))
+                })
+            }
+
+            #[pyo3(
+                text_signature = "($self, reader, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, guess_content_type=False, typed=False, params=None)"
+            )]
+            #[args(
+                region_provider = "None",
+                object_name = "None",
+                file_name = "None",
+                content_type = "None",
+                metadata = "None",
+                custom_vars = "None",
+                guess_content_type = "false",
+                typed = "false",
+                params = "None",
+            )]
+            #[allow(clippy::too_many_arguments)]
+            fn upload_reader(
+                &self,
+                reader: PyObject,
+                region_provider: Option<&PyAny>,
+                object_name: Option<&str>,
+                file_name: Option<&str>,
+                content_type: Option<&str>,
+                metadata: Option<HashMap<String, String>>,
+                custom_vars: Option<HashMap<String, String>>,
+                guess_content_type: bool,
+                typed: bool,
+                params: Option<ObjectParams>,
+                py: Python<'_>,
+            ) -> PyResult<PyObject> {
+                let object_params = match params {
+                    Some(params) => params.0,
+                    None => make_object_params(
+                        region_provider,
+                        object_name,
+                        file_name,
+                        content_type,
+                        metadata,
+                        custom_vars,
+                        guess_content_type,
+                        None,
+                    )?,
+                };
+                ($check_reader)(&reader, py)?;
+                let _registration = UploadCallbackRegistration::new(&object_params)?;
+                py.allow_threads(|| {
+                    self.0
+                        .upload_reader(PythonIoBase::new(reader), object_params)
+                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                        .and_then(|v| convert_upload_response(v, typed))
+                })
+            }
+
+            #[pyo3(
+                text_signature = "($self, path, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, guess_content_type=False, typed=False, params=None)"
+            )]
+            #[args(
+                region_provider = "None",
+                object_name = "None",
+                file_name = "None",
+                content_type = "None",
+                metadata = "None",
+                custom_vars = "None",
+                guess_content_type = "false",
+                typed = "false",
+                params = "None",
+            )]
+            #[allow(clippy::too_many_arguments)]
+            fn async_upload_path<'p>(
+                &self,
+                path: String,
+                region_provider: Option<&PyAny>,
+                object_name: Option<&str>,
+                file_name: Option<&str>,
+                content_type: Option<&str>,
+                metadata: Option<HashMap<String, String>>,
+                custom_vars: Option<HashMap<String, String>>,
+                guess_content_type: bool,
+                typed: bool,
+                params: Option<ObjectParams>,
+                py: Python<'p>,
+            ) -> PyResult<&'p PyAny> {
+                let object_params = match params {
+                    Some(params) => params.0,
+                    None => make_object_params(
+                        region_provider,
+                        object_name,
+                        file_name,
+                        content_type,
+                        metadata,
+                        custom_vars,
+                        guess_content_type,
+                        None,
+                    )?,
+                };
+                let registration = UploadCallbackRegistration::new(&object_params)?;
+                let uploader = self.0.to_owned();
+                pyo3_asyncio::async_std::future_into_py(py, async move {
+                    let _registration = registration;
+                    uploader
+                        .async_upload_path(&path, object_params)
+                        .await
+                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                        .and_then(|v| convert_upload_response(v, typed))
+                })
+            }
+
+            #[pyo3(
+                text_signature = "($self, reader, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, guess_content_type=False, typed=False, params=None)"
+            )]
+            #[args(
+                region_provider = "None",
+                object_name = "None",
+                file_name = "None",
+                content_type = "None",
+                metadata = "None",
+                custom_vars = "None",
+                guess_content_type = "false",
+                typed = "false",
+                params = "None",
+            )]
+            #[allow(clippy::too_many_arguments)]
+            fn async_upload_reader<'p>(
+                &self,
+                reader: PyObject,
+                region_provider: Option<&PyAny>,
+                object_name: Option<&str>,
+                file_name: Option<&str>,
+                content_type: Option<&str>,
+                metadata: Option<HashMap<String, String>>,
+                custom_vars: Option<HashMap<String, String>>,
+                guess_content_type: bool,
+                typed: bool,
+                params: Option<ObjectParams>,
+                py: Python<'p>,
+            ) -> PyResult<&'p PyAny> {
+                let object_params = match params {
+                    Some(params) => params.0,
+                    None => make_object_params(
+                        region_provider,
+                        object_name,
+                        file_name,
+                        content_type,
+                        metadata,
+                        custom_vars,
+                        guess_content_type,
+                        None,
+                    )?,
+                };
+                let registration = UploadCallbackRegistration::new(&object_params)?;
+                let checked_reader = reader.clone_ref(py);
+                let uploader = self.0.to_owned();
+                pyo3_asyncio::async_std::future_into_py(py, async move {
+                    let _registration = registration;
+                    ($async_check_reader)(checked_reader).await?;
+                    uploader
+                        .async_upload_reader(PythonIoBase::new(reader).into_async_read(), object_params)
+                        .await
+                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                        .and_then(|v| convert_upload_response(v, typed))
+                })
+            }
+
+            fn __repr__(&self) -> String {
+                format!("{:?}", self.0)
+            }
+
+            fn __str__(&self) -> String {
+                self.__repr__()
+            }
+        }
+    }
+}
+
+/// 数据源接口
+///
+/// 抽象类
+///
+/// 提供上传所用的数据源
+///
+/// 数据源 KEY 计算完成后会被缓存，可以调用 `precompute_source_key()` 提前计算，这仅仅是一项优化，不调用也不影响上传结果
+#[pyclass(subclass)]
+#[derive(Debug, Clone)]
+struct DataSource(
+    Box<dyn qiniu_sdk::upload::DataSource<Sha1>>,
+    SourceKeyCache,
+    Arc<Mutex<u64>>,
+);
+
+#[pymethods]
+impl DataSource {
+    /// 数据源切片
+    #[pyo3(text_signature = "($self, size)")]
+    fn slice(&self, size: u64, py: Python<'_>) -> PyResult<Option<DataSourceReader>> {
+        let part_size = qiniu_sdk::upload::PartSize::new(size).map_or_else(
+            || Err(QiniuInvalidPartSize::new_err("part_size must not be zero")),
+            Ok,
+        )?;
+        let reader = py
+            .allow_threads(|| self.tracked_slice(part_size))
+            .map_err(PyIOError::new_err)?
+            .map(|(reader, offset, size)| {
+                DataSourceReader(reader, DEFAULT_READER_CHUNK_SIZE, offset, size)
+            });
+        Ok(reader)
+    }
+
+    /// 获取数据源 KEY
+    ///
+    /// 用于区分不同的数据源
+    #[pyo3(text_signature = "($self)")]
+    fn source_key(&self, py: Python<'_>) -> PyResult<Option<SourceKey>> {
+        py.allow_threads(|| qiniu_sdk::upload::DataSource::source_key(self))
+            .map(|s| s.map(SourceKey))
+            .map_err(PyIOError::new_err)
+    }
+
+    /// 预先计算数据源 KEY
+    ///
+    /// 计算结果将被缓存，之后调用 `source_key()` 或是上传时将直接使用缓存的结果。
+    /// 对于大文件，计算数据源 KEY 需要读取整个文件，可以在上传前（例如在后台线程中）调用该方法，避免阻塞第一个分片的上传
+    #[pyo3(text_signature = "($self)")]
+    fn precompute_source_key(&self, py: Python<'_>) -> PyResult<()> {
+        py.allow_threads(|| qiniu_sdk::upload::DataSource::source_key(self))
+            .map(|_| ())
+            .map_err(PyIOError::new_err)
+    }
+
+    /// 获取数据源大小
+    #[pyo3(text_signature = "($self)")]
+    fn total_size(&self, py: Python<'_>) -> PyResult<Option<u64>> {
+        py.allow_threads(|| self.0.total_size())
+            .map_err(PyIOError::new_err)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl DataSource {
+    /// 数据源切片，同时返回切片的偏移量和大小
+    fn tracked_slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> IoResult<Option<(qiniu_sdk::upload::DataSourceReader, u64, u64)>> {
+        let mut offset = self.2.lock().unwrap();
+        if let Some(reader) = self.0.slice(size)? {
+            let (part_offset, part_size) = advance_slice(&mut offset, size, self.0.total_size()?);
+            Ok(Some((reader, part_offset, part_size)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl qiniu_sdk::upload::DataSource<Sha1> for DataSource {
+    fn slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> std::io::Result<Option<qiniu_sdk::upload::DataSourceReader>> {
+        self.tracked_slice(size)
+            .map(|reader| reader.map(|(reader, _, _)| reader))
+    }
+
+    fn reset(&self) -> std::io::Result<()> {
+        let mut offset = self.2.lock().unwrap();
+        self.0.reset()?;
+        *offset = 0;
+        Ok(())
+    }
+
+    fn source_key(&self) -> std::io::Result<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
+        self.1.get_or_try_init(|| self.0.source_key())
+    }
+
+    fn total_size(&self) -> std::io::Result<Option<u64>> {
+        self.0.total_size()
+    }
+}
+
+/// 文件数据源
+///
+/// 基于一个文件实现了数据源接口
+///
+/// 如果传入 `offset` 或 `length`，数据源将只包含文件中 `[offset, offset + length)` 范围内的数据，`total_size()` 返回该范围的大小，
+/// 并且数据源 KEY 也会根据该范围计算，同一个文件的不同范围将使用不同的断点续传记录
+///
+/// 通过 `FileDataSource(path, offset = 0, length = None)` 创建文件数据源
+#[pyclass(extends = DataSource)]
+#[derive(Debug, Clone, Copy)]
+#[pyo3(text_signature = "(path, /, offset = 0, length = None)")]
+struct FileDataSource;
+
+#[pymethods]
+impl FileDataSource {
+    /// 创建文件数据源
+    #[new]
+    #[args(offset = "0", length = "None")]
+    fn new(path: &str, offset: u64, length: Option<u64>) -> (Self, DataSource) {
+        let source = qiniu_sdk::upload::FileDataSource::new(path);
+        let source: Box<dyn qiniu_sdk::upload::DataSource<Sha1>> =
+            if offset == 0 && length.is_none() {
+                Box::new(source)
+            } else {
+                Box::new(FileWindowDataSource {
+                    path: path.into(),
+                    source,
+                    window: FileWindow { offset, length },
+                    opened: Default::default(),
+                    cursor: Default::default(),
+                })
+            };
+        (
+            Self,
+            DataSource(source, Default::default(), Default::default()),
+        )
+    }
+}
+
+/// 不可寻址的数据源
+///
+/// 基于一个不可寻址的阅读器实现了数据源接口
+///
+/// 如果已知数据的总大小，可以通过 `total_size` 传入，该值仅用于 `total_size()` 的返回值和上传进度的展示，数据仍然以流的方式读取。
+/// 如果传入的值与实际大小不符，上传进度将不准确
+///
+/// 通过 `UnseekableDataSource(source, total_size = None)` 创建不可寻址的数据源
+#[pyclass(extends = DataSource)]
+#[derive(Debug, Clone, Copy)]
+#[pyo3(text_signature = "(source, /, total_size = None)")]
+struct UnseekableDataSource;
+
+#[pymethods]
+impl UnseekableDataSource {
+    /// 创建不可寻址的数据源
+    #[new]
+    #[args(total_size = "None")]
+    fn new(source: PyObject, total_size: Option<u64>) -> (Self, DataSource) {
+        let source = qiniu_sdk::upload::UnseekableDataSource::new(PythonIoBase::new(source));
+        let source: Box<dyn qiniu_sdk::upload::DataSource<Sha1>> =
+            if let Some(total_size) = total_size {
+                Box::new(KnownSizeDataSource { source, total_size })
+            } else {
+                Box::new(source)
+            };
+        (
+            Self,
+            DataSource(source, Default::default(), Default::default()),
+        )
+    }
+}
+
+/// 异步数据源接口
+///
+/// 抽象类
+///
+/// 提供上传所用的数据源
+///
+/// 数据源 KEY 计算完成后会被缓存，可以调用 `precompute_source_key()` 提前计算，这仅仅是一项优化，不调用也不影响上传结果
+#[pyclass(subclass)]
+#[derive(Debug, Clone)]
+struct AsyncDataSource(
+    Box<dyn qiniu_sdk::upload::AsyncDataSource<Sha1>>,
+    SourceKeyCache,
+    Arc<AsyncMutex<u64>>,
+);
+
+#[pymethods]
+impl AsyncDataSource {
+    /// 异步数据源切片
+    #[pyo3(text_signature = "($self, size)")]
+    fn slice<'p>(&self, size: u64, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let part_size = qiniu_sdk::upload::PartSize::new(size).map_or_else(
+            || Err(QiniuInvalidPartSize::new_err("part_size must not be zero")),
+            Ok,
+        )?;
+        let source = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            source
+                .tracked_slice(part_size)
+                .await
+                .map(|r| {
+                    r.map(|(r, offset, size)| {
+                        AsyncDataSourceReader(
+                            Arc::new(AsyncMutex::new(r)),
+                            DEFAULT_READER_CHUNK_SIZE,
+                            offset,
+                            size,
+                        )
+                    })
+                })
+                .map_err(PyIOError::new_err)
+        })
+    }
+
+    /// 异步获取数据源 KEY
+    ///
+    /// 用于区分不同的数据源
+    #[pyo3(text_signature = "($self)")]
+    fn source_key<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let source = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            qiniu_sdk::upload::AsyncDataSource::source_key(&source)
+                .await
+                .map_err(PyIOError::new_err)
+                .map(|k| k.map(SourceKey))
+        })
+    }
+
+    /// 异步预先计算数据源 KEY
+    ///
+    /// 计算结果将被缓存，之后调用 `source_key()` 或是上传时将直接使用缓存的结果。
+    /// 对于大文件，计算数据源 KEY 需要读取整个文件，可以在上传前调用该方法，避免阻塞第一个分片的上传
+    #[pyo3(text_signature = "($self)")]
+    fn precompute_source_key<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let source = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            qiniu_sdk::upload::AsyncDataSource::source_key(&source)
+                .await
+                .map(|_| ())
+                .map_err(PyIOError::new_err)
+        })
+    }
+
+    /// 异步获取数据源大小
+    #[pyo3(text_signature = "($self)")]
+    fn total_size<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let source = self.0.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            source.total_size().await.map_err(PyIOError::new_err)
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl AsyncDataSource {
+    /// 异步数据源切片，同时返回切片的偏移量和大小
+    async fn tracked_slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> IoResult<Option<(qiniu_sdk::upload::AsyncDataSourceReader, u64, u64)>> {
+        let mut offset = self.2.lock().await;
+        if let Some(reader) = self.0.slice(size).await? {
+            let (part_offset, part_size) =
+                advance_slice(&mut offset, size, self.0.total_size().await?);
+            Ok(Some((reader, part_offset, part_size)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl qiniu_sdk::upload::AsyncDataSource<Sha1> for AsyncDataSource {
+    fn slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> futures::future::BoxFuture<std::io::Result<Option<qiniu_sdk::upload::AsyncDataSourceReader>>>
+    {
+        Box::pin(async move {
+            self.tracked_slice(size)
+                .await
+                .map(|reader| reader.map(|(reader, _, _)| reader))
+        })
+    }
+
+    fn reset(&self) -> futures::future::BoxFuture<std::io::Result<()>> {
+        Box::pin(async move {
+            let mut offset = self.2.lock().await;
+            self.0.reset().await?;
+            *offset = 0;
+            Ok(())
+        })
+    }
+
+    fn source_key(
+        &self,
+    ) -> futures::future::BoxFuture<std::io::Result<Option<qiniu_sdk::upload::SourceKey<Sha1>>>>
+    {
+        Box::pin(self.1.async_get_or_try_init(self.0.source_key()))
+    }
+
+    fn total_size(&self) -> futures::future::BoxFuture<std::io::Result<Option<u64>>> {
+        self.0.total_size()
+    }
+}
+
+/// 记录一个切片，返回其偏移量和大小
+///
+/// 数据源大小未知时，切片大小即为请求的大小，最后一个切片的实际大小可能更小
+fn advance_slice(
+    offset: &mut u64,
+    size: qiniu_sdk::upload::PartSize,
+    total_size: Option<u64>,
+) -> (u64, u64) {
+    let part_offset = *offset;
+    let part_size = total_size.map_or(size.as_u64(), |total_size| {
+        size.as_u64().min(total_size.saturating_sub(part_offset))
+    });
+    *offset += part_size;
+    (part_offset, part_size)
+}
+
+#[derive(Debug, Clone, Default)]
+struct SourceKeyCache(Arc<Mutex<Option<Option<qiniu_sdk::upload::SourceKey<Sha1>>>>>);
+
+impl SourceKeyCache {
+    fn get(&self) -> Option<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
+        self.0.lock().unwrap().to_owned()
+    }
+
+    fn set(
+        &self,
+        source_key: Option<qiniu_sdk::upload::SourceKey<Sha1>>,
+    ) -> Option<qiniu_sdk::upload::SourceKey<Sha1>> {
+        *self.0.lock().unwrap() = Some(source_key.to_owned());
+        source_key
+    }
+
+    fn get_or_try_init(
+        &self,
+        f: impl FnOnce() -> IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>>,
+    ) -> IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
+        if let Some(source_key) = self.get() {
+            return Ok(source_key);
+        }
+        f().map(|source_key| self.set(source_key))
+    }
+
+    async fn async_get_or_try_init(
+        &self,
+        f: impl Future<Output = IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>>>,
+    ) -> IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
+        if let Some(source_key) = self.get() {
+            return Ok(source_key);
+        }
+        f.await.map(|source_key| self.set(source_key))
+    }
+}
+
+/// 异步文件数据源
+///
+/// 基于一个文件实现了数据源接口
+///
+/// `offset` 与 `length` 的含义与 `FileDataSource` 一致
+///
+/// 通过 `AsyncFileDataSource(path, offset = 0, length = None)` 创建异步文件数据源
+#[pyclass(extends = AsyncDataSource)]
+#[derive(Debug, Clone, Copy)]
+#[pyo3(text_signature = "(path, /, offset = 0, length = None)")]
+struct AsyncFileDataSource;
+
+#[pymethods]
+impl AsyncFileDataSource {
+    /// 创建异步文件数据源
+    #[new]
+    #[args(offset = "0", length = "None")]
+    fn new(path: &str, offset: u64, length: Option<u64>) -> (Self, AsyncDataSource) {
+        let source = qiniu_sdk::upload::AsyncFileDataSource::new(path);
+        let source: Box<dyn qiniu_sdk::upload::AsyncDataSource<Sha1>> =
+            if offset == 0 && length.is_none() {
+                Box::new(source)
+            } else {
+                Box::new(AsyncFileWindowDataSource {
+                    path: path.into(),
+                    source,
+                    window: FileWindow { offset, length },
+                    opened: Default::default(),
+                    cursor: Default::default(),
+                })
+            };
+        (
+            Self,
+            AsyncDataSource(source, Default::default(), Default::default()),
+        )
+    }
+}
+
+/// 不可寻址的异步数据源
+///
+/// 基于一个不可寻址的异步阅读器实现了异步数据源接口
+///
+/// 如果已知数据的总大小，可以通过 `total_size` 传入，该值仅用于 `total_size()` 的返回值和上传进度的展示，数据仍然以流的方式读取。
+/// 如果传入的值与实际大小不符，上传进度将不准确
+///
+/// 通过 `AsyncUnseekableDataSource(source, total_size = None)` 创建不可寻址的异步数据源
+#[pyclass(extends = AsyncDataSource)]
+#[derive(Debug, Clone, Copy)]
+#[pyo3(text_signature = "(source, /, total_size = None)")]
+struct AsyncUnseekableDataSource;
+
+#[pymethods]
+impl AsyncUnseekableDataSource {
+    /// 创建不可寻址的异步数据源
+    #[new]
+    #[args(total_size = "None")]
+    fn new(source: PyObject, total_size: Option<u64>) -> (Self, AsyncDataSource) {
+        let source = qiniu_sdk::upload::AsyncUnseekableDataSource::new(
+            PythonIoBase::new(source).into_async_read(),
+        );
+        let source: Box<dyn qiniu_sdk::upload::AsyncDataSource<Sha1>> =
+            if let Some(total_size) = total_size {
+                Box::new(KnownSizeDataSource { source, total_size })
+            } else {
+                Box::new(source)
+            };
+        (
+            Self,
+            AsyncDataSource(source, Default::default(), Default::default()),
+        )
+    }
+}
+
+/// 异步生成器数据源
+///
+/// 依次读取 Python 异步生成器产生的 `bytes`，作为不可寻址的异步数据源，适合上传在异步流水线中计算得到的数据流。
+/// 数据源大小和数据源 KEY 均无法获知，`total_size()` 和 `source_key()` 总是返回 `None`
+///
+/// 通过 `AsyncGeneratorDataSource(agen)` 创建异步生成器数据源
+#[pyclass(extends = AsyncDataSource)]
+#[derive(Debug, Clone, Copy)]
+#[pyo3(text_signature = "(agen)")]
+struct AsyncGeneratorDataSource;
+
+#[pymethods]
+impl AsyncGeneratorDataSource {
+    /// 创建异步生成器数据源
+    #[new]
+    fn new(agen: PyObject, py: Python<'_>) -> PyResult<(Self, AsyncDataSource)> {
+        if !agen.as_ref(py).hasattr("__anext__")? {
+            return Err(QiniuUnsupportedTypeError::new_err(
+                "agen must be an async generator",
+            ));
+        }
+        let source =
+            qiniu_sdk::upload::AsyncUnseekableDataSource::new(PythonAsyncGeneratorRead::new(agen));
+        Ok((
+            Self,
+            AsyncDataSource(Box::new(source), Default::default(), Default::default()),
+        ))
+    }
+}
+
+/// 已知大小的数据源
+///
+/// 除了 `total_size()` 返回指定的大小以外，其他方法都委托给内部数据源
+#[derive(Debug, Clone)]
+struct KnownSizeDataSource<S> {
+    source: S,
+    total_size: u64,
+}
+
+impl<S: qiniu_sdk::upload::DataSource<Sha1> + Clone> qiniu_sdk::upload::DataSource<Sha1>
+    for KnownSizeDataSource<S>
+{
+    fn slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> IoResult<Option<qiniu_sdk::upload::DataSourceReader>> {
+        qiniu_sdk::upload::DataSource::slice(&self.source, size)
+    }
+
+    fn reset(&self) -> IoResult<()> {
+        qiniu_sdk::upload::DataSource::reset(&self.source)
+    }
+
+    fn source_key(&self) -> IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
+        qiniu_sdk::upload::DataSource::source_key(&self.source)
+    }
+
+    fn total_size(&self) -> IoResult<Option<u64>> {
+        Ok(Some(self.total_size))
+    }
+}
+
+impl<S: qiniu_sdk::upload::AsyncDataSource<Sha1> + Clone> qiniu_sdk::upload::AsyncDataSource<Sha1>
+    for KnownSizeDataSource<S>
+{
+    fn slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::AsyncDataSourceReader>>>
+    {
+        qiniu_sdk::upload::AsyncDataSource::slice(&self.source, size)
+    }
+
+    fn reset(&self) -> futures::future::BoxFuture<IoResult<()>> {
+        qiniu_sdk::upload::AsyncDataSource::reset(&self.source)
+    }
+
+    fn source_key(
+        &self,
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>>> {
+        qiniu_sdk::upload::AsyncDataSource::source_key(&self.source)
+    }
+
+    fn total_size(&self) -> futures::future::BoxFuture<IoResult<Option<u64>>> {
+        let total_size = self.total_size;
+        Box::pin(async move { Ok(Some(total_size)) })
+    }
+}
+
+/// Gzip 压缩数据源
+///
+/// 包装另一个数据源，将其数据经过 Gzip 压缩后作为上传数据。
+///
+/// 由于无法预知压缩后的数据大小，`total_size()` 总是返回 `None`，并且不支持断点续传。
+/// 压缩后的对象在下载时不会被自动解压，建议通过 `metadata` 或 `custom_vars` 记录压缩方式，以便下载后自行解压。
+///
+/// 通过 `GzipDataSource(inner, level = 6)` 创建 Gzip 压缩数据源
+#[pyclass(extends = DataSource)]
+#[derive(Debug, Clone, Copy)]
+#[pyo3(text_signature = "(inner, /, level = 6)")]
+struct GzipDataSource;
+
+#[pymethods]
+impl GzipDataSource {
+    /// 创建 Gzip 压缩数据源
+    #[new]
+    #[args(level = "6")]
+    fn new(inner: DataSource, level: u32) -> PyResult<(Self, DataSource)> {
+        let level = check_compression_level(level)?;
+        let encoder = flate2::read::GzEncoder::new(
+            DataSourceStream {
+                source: inner,
+                reader: None,
+            },
+            flate2::Compression::new(level),
+        );
+        Ok((
+            Self,
+            DataSource(
+                Box::new(qiniu_sdk::upload::UnseekableDataSource::new(SyncReader(
+                    Mutex::new(encoder),
+                ))),
+                Default::default(),
+                Default::default(),
+            ),
+        ))
+    }
+}
+
+/// Gzip 压缩异步数据源
+///
+/// 包装另一个异步数据源，将其数据经过 Gzip 压缩后作为上传数据，使用限制与 `GzipDataSource` 相同。
+///
+/// 通过 `AsyncGzipDataSource(inner, level = 6)` 创建 Gzip 压缩异步数据源
+#[pyclass(extends = AsyncDataSource)]
+#[derive(Debug, Clone, Copy)]
+#[pyo3(text_signature = "(inner, /, level = 6)")]
+struct AsyncGzipDataSource;
+
+#[pymethods]
+impl AsyncGzipDataSource {
+    /// 创建 Gzip 压缩异步数据源
+    #[new]
+    #[args(level = "6")]
+    fn new(inner: AsyncDataSource, level: u32) -> PyResult<(Self, AsyncDataSource)> {
+        let level = check_compression_level(level)?;
+        let encoder = async_compression::futures::bufread::GzipEncoder::with_quality(
+            async_data_source_stream(inner).into_async_read(),
+            async_compression::Level::Precise(level),
+        );
+        Ok((
+            Self,
+            AsyncDataSource(
+                Box::new(qiniu_sdk::upload::AsyncUnseekableDataSource::new(
+                    SyncReader(Mutex::new(encoder)),
+                )),
+                Default::default(),
+                Default::default(),
+            ),
+        ))
+    }
+}
+
+fn check_compression_level(level: u32) -> PyResult<u32> {
+    if level > 9 {
+        return Err(QiniuInvalidCompressionLevel::new_err(
+            "compression level must be between 0 and 9",
+        ));
+    }
+    Ok(level)
+}
+
+/// AES-CTR 加密数据源
+///
+/// 包装另一个数据源，将其数据使用 AES-CTR 加密后作为上传数据，加密后的数据大小与原数据一致。
+///
+/// `key` 必须为 16、24 或 32 个字节，分别对应 AES-128、AES-192 和 AES-256，`nonce` 必须为 16 个字节。
+/// 由于 CTR 模式下相同的 `key` 和 `nonce` 总是产生相同的密文，断点续传可以正常工作，
+/// 但同一个 `key` 不能与同一个 `nonce` 重复用于加密不同的对象，建议为每个对象生成随机的 `nonce` 并妥善保存，下载时通过 `download.DecryptingReader` 解密。
+///
+/// 通过 `EncryptingDataSource(inner, key, nonce)` 创建 AES-CTR 加密数据源
+#[pyclass(extends = DataSource)]
+#[derive(Debug, Clone, Copy)]
+#[pyo3(text_signature = "(inner, key, nonce)")]
+struct EncryptingDataSource;
+
+#[pymethods]
+impl EncryptingDataSource {
+    /// 创建 AES-CTR 加密数据源
+    #[new]
+    fn new(inner: DataSource, key: &[u8], nonce: &[u8]) -> PyResult<(Self, DataSource)> {
+        let cipher = AesCtrCipher::new(key, nonce)?;
+        Ok((
+            Self,
+            DataSource(
+                Box::new(EncryptingDataSourceImpl {
+                    source: inner,
+                    cipher,
+                    cursor: Default::default(),
+                }),
+                Default::default(),
+                Default::default(),
+            ),
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EncryptingDataSourceImpl {
+    source: DataSource,
+    cipher: AesCtrCipher,
+    cursor: Arc<Mutex<SliceCursor>>,
+}
+
+/// 记录下一个切片的分片号和偏移量
+#[derive(Debug, Default)]
+struct SliceCursor {
+    part_number: usize,
+    offset: u64,
+}
+
+impl SliceCursor {
+    /// 加密切片数据，返回其分片号和偏移量
+    fn encrypt(&mut self, cipher: &AesCtrCipher, data: &mut [u8]) -> (NonZeroUsize, u64) {
+        cipher.apply_keystream(self.offset, data);
+        self.advance(data.len() as u64)
+    }
+
+    /// 前进一个切片，返回其分片号和偏移量
+    fn advance(&mut self, size: u64) -> (NonZeroUsize, u64) {
+        let offset = self.offset;
+        self.part_number += 1;
+        self.offset += size;
+        (
+            NonZeroUsize::new(self.part_number).expect("part number must not be zero"),
+            offset,
+        )
+    }
+}
+
+impl qiniu_sdk::upload::DataSource<Sha1> for EncryptingDataSourceImpl {
+    fn slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> IoResult<Option<qiniu_sdk::upload::DataSourceReader>> {
+        let mut cursor = self.cursor.lock().unwrap();
+        if let Some(mut reader) = qiniu_sdk::upload::DataSource::slice(&self.source, size)? {
+            let mut data = Vec::new();
+            reader.read_to_end(&mut data)?;
+            let (part_number, offset) = cursor.encrypt(&self.cipher, &mut data);
+            Ok(Some(qiniu_sdk::upload::DataSourceReader::unseekable(
+                part_number,
+                data,
+                offset,
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn reset(&self) -> IoResult<()> {
+        let mut cursor = self.cursor.lock().unwrap();
+        qiniu_sdk::upload::DataSource::reset(&self.source)?;
+        *cursor = Default::default();
+        Ok(())
+    }
+
+    fn source_key(&self) -> IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
+        Ok(qiniu_sdk::upload::DataSource::source_key(&self.source)?
+            .map(|source_key| make_encrypted_source_key(&source_key, &self.cipher)))
+    }
+
+    fn total_size(&self) -> IoResult<Option<u64>> {
+        qiniu_sdk::upload::DataSource::total_size(&self.source)
+    }
+}
+
+/// AES-CTR 加密异步数据源
+///
+/// 包装另一个异步数据源，将其数据使用 AES-CTR 加密后作为上传数据，加密后的数据大小与原数据一致。
+///
+/// `key` 与 `nonce` 的要求与 `EncryptingDataSource` 一致。
+///
+/// 通过 `AsyncEncryptingDataSource(inner, key, nonce)` 创建 AES-CTR 加密异步数据源
+#[pyclass(extends = AsyncDataSource)]
+#[derive(Debug, Clone, Copy)]
+#[pyo3(text_signature = "(inner, key, nonce)")]
+struct AsyncEncryptingDataSource;
+
+#[pymethods]
+impl AsyncEncryptingDataSource {
+    /// 创建 AES-CTR 加密异步数据源
+    #[new]
+    fn new(inner: AsyncDataSource, key: &[u8], nonce: &[u8]) -> PyResult<(Self, AsyncDataSource)> {
+        let cipher = AesCtrCipher::new(key, nonce)?;
+        Ok((
+            Self,
+            AsyncDataSource(
+                Box::new(AsyncEncryptingDataSourceImpl {
+                    source: inner,
+                    cipher,
+                    cursor: Default::default(),
+                }),
+                Default::default(),
+                Default::default(),
+            ),
+        ))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AsyncEncryptingDataSourceImpl {
+    source: AsyncDataSource,
+    cipher: AesCtrCipher,
+    cursor: Arc<AsyncMutex<SliceCursor>>,
+}
+
+impl qiniu_sdk::upload::AsyncDataSource<Sha1> for AsyncEncryptingDataSourceImpl {
+    fn slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::AsyncDataSourceReader>>>
+    {
+        Box::pin(async move {
+            let mut cursor = self.cursor.lock().await;
+            if let Some(mut reader) =
+                qiniu_sdk::upload::AsyncDataSource::slice(&self.source, size).await?
+            {
+                let mut data = Vec::new();
+                reader.read_to_end(&mut data).await?;
+                let (part_number, offset) = cursor.encrypt(&self.cipher, &mut data);
+                Ok(Some(qiniu_sdk::upload::AsyncDataSourceReader::unseekable(
+                    part_number,
+                    data,
+                    offset,
+                )))
+            } else {
+                Ok(None)
+            }
+        })
+    }
+
+    fn reset(&self) -> futures::future::BoxFuture<IoResult<()>> {
+        Box::pin(async move {
+            let mut cursor = self.cursor.lock().await;
+            qiniu_sdk::upload::AsyncDataSource::reset(&self.source).await?;
+            *cursor = Default::default();
+            Ok(())
+        })
+    }
+
+    fn source_key(
+        &self,
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>>> {
+        Box::pin(async move {
+            Ok(qiniu_sdk::upload::AsyncDataSource::source_key(&self.source)
+                .await?
+                .map(|source_key| make_encrypted_source_key(&source_key, &self.cipher)))
+        })
+    }
+
+    fn total_size(&self) -> futures::future::BoxFuture<IoResult<Option<u64>>> {
+        qiniu_sdk::upload::AsyncDataSource::total_size(&self.source)
+    }
+}
+
+/// 加密数据源的数据源 KEY 由被包装的数据源 KEY 与 `nonce` 共同计算得到，避免与未加密的数据源混淆
+fn make_encrypted_source_key(
+    source_key: &qiniu_sdk::upload::SourceKey<Sha1>,
+    cipher: &AesCtrCipher,
+) -> qiniu_sdk::upload::SourceKey<Sha1> {
+    use sha1::Digest;
+
+    let digest = Sha1::new()
+        .chain_update(**source_key)
+        .chain_update(cipher.nonce())
+        .finalize();
+    qiniu_sdk::upload::SourceKey::from(digest)
+}
+
+/// 文件中的数据范围
+#[derive(Debug, Clone, Copy)]
+struct FileWindow {
+    offset: u64,
+    length: Option<u64>,
+}
+
+impl FileWindow {
+    /// 根据文件大小计算范围的实际大小
+    fn size(&self, file_size: u64) -> u64 {
+        let size = file_size.saturating_sub(self.offset);
+        self.length.map_or(size, |length| length.min(size))
+    }
+
+    /// 范围数据源的数据源 KEY 由文件的数据源 KEY 与范围共同计算得到
+    fn make_source_key(
+        &self,
+        source_key: &qiniu_sdk::upload::SourceKey<Sha1>,
+    ) -> qiniu_sdk::upload::SourceKey<Sha1> {
+        use sha1::Digest;
+
+        let digest = Sha1::new()
+            .chain_update(**source_key)
+            .chain_update(self.offset.to_be_bytes())
+            .chain_update([self.length.is_some() as u8])
+            .chain_update(self.length.unwrap_or_default().to_be_bytes())
+            .finalize();
+        qiniu_sdk::upload::SourceKey::from(digest)
+    }
+}
+
+/// 已打开的文件范围
+///
+/// 同一个数据源的所有切片共享同一个文件句柄，每次读取前各自定位，因此切片之间互不影响
+#[derive(Debug, Clone)]
+struct OpenedFileWindow {
+    file: Arc<Mutex<File>>,
+    offset: u64,
+    size: u64,
+}
+
+impl OpenedFileWindow {
+    fn open(path: &Path, window: FileWindow) -> IoResult<Self> {
+        let file = File::open(path)?;
+        let size = window.size(file.metadata()?.len());
+        Ok(Self {
+            file: Arc::new(Mutex::new(file)),
+            offset: window.offset,
+            size,
+        })
+    }
+
+    /// 从范围内的 `pos` 处读取数据
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> IoResult<usize> {
+        let max_read = self.size.saturating_sub(pos).min(buf.len() as u64) as usize;
+        if max_read == 0 {
+            return Ok(0);
+        }
+        let mut file = self.file.lock().unwrap();
+        file.seek(SeekFrom::Start(self.offset + pos))?;
+        file.read(&mut buf[..max_read])
+    }
+
+    /// 计算在范围内定位后的位置
+    fn seek_from(&self, pos: u64, seek: SeekFrom) -> IoResult<u64> {
+        let (base, delta) = match seek {
+            SeekFrom::Start(pos) => return Ok(pos),
+            SeekFrom::Current(delta) => (pos, delta),
+            SeekFrom::End(delta) => (self.size, delta),
+        };
+        u64::try_from(i128::from(base) + i128::from(delta)).map_err(|_| {
+            IoError::new(
+                IoErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })
+    }
+}
+
+#[derive(Debug)]
+struct FileWindowReader {
+    window: OpenedFileWindow,
+    pos: u64,
+}
+
+impl Read for FileWindowReader {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        let have_read = self.window.read_at(self.pos, buf)?;
+        self.pos += have_read as u64;
+        Ok(have_read)
+    }
+}
+
+impl Seek for FileWindowReader {
+    fn seek(&mut self, pos: SeekFrom) -> IoResult<u64> {
+        self.pos = self.window.seek_from(self.pos, pos)?;
+        Ok(self.pos)
+    }
+}
+
+/// 异步读取文件范围，实际的读取在阻塞线程池中完成
+#[derive(Debug)]
+struct AsyncFileWindowReader {
+    window: OpenedFileWindow,
+    pos: u64,
+    reading: Mutex<Option<async_std::task::JoinHandle<IoResult<Vec<u8>>>>>,
+}
+
+impl AsyncRead for AsyncFileWindowReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<IoResult<usize>> {
+        let this = self.get_mut();
+        let reading = this.reading.get_mut().unwrap();
+        let handle = reading.get_or_insert_with(|| {
+            let window = this.window.to_owned();
+            let pos = this.pos;
+            let len = buf.len();
+            async_std::task::spawn_blocking(move || {
+                let mut data = vec![0u8; len];
+                let have_read = window.read_at(pos, &mut data)?;
+                data.truncate(have_read);
+                Ok(data)
+            })
+        });
+        let data = futures::ready!(handle.poll_unpin(cx));
+        *reading = None;
+        let data = data?;
+        let have_read = data.len().min(buf.len());
+        buf[..have_read].copy_from_slice(&data[..have_read]);
+        this.pos += have_read as u64;
+        Poll::Ready(Ok(have_read))
+    }
+}
+
+impl AsyncSeek for AsyncFileWindowReader {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<IoResult<u64>> {
+        let this = self.get_mut();
+        Poll::Ready(this.window.seek_from(this.pos, pos).map(|pos| {
+            this.pos = pos;
+            pos
+        }))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FileWindowDataSource {
+    path: PathBuf,
+    source: qiniu_sdk::upload::FileDataSource,
+    window: FileWindow,
+    opened: Arc<Mutex<Option<OpenedFileWindow>>>,
+    cursor: Arc<Mutex<SliceCursor>>,
+}
+
+impl FileWindowDataSource {
+    /// 首次使用时打开文件，之后的切片复用同一个文件句柄
+    fn opened(&self) -> IoResult<OpenedFileWindow> {
+        let mut opened = self.opened.lock().unwrap();
+        if let Some(opened) = &*opened {
+            return Ok(opened.to_owned());
+        }
+        let window = OpenedFileWindow::open(&self.path, self.window)?;
+        *opened = Some(window.to_owned());
+        Ok(window)
+    }
+}
+
+impl qiniu_sdk::upload::DataSource<Sha1> for FileWindowDataSource {
+    fn slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> IoResult<Option<qiniu_sdk::upload::DataSourceReader>> {
+        let mut cursor = self.cursor.lock().unwrap();
+        let window = self.opened()?;
+        let remaining = window.size.saturating_sub(cursor.offset);
+        if remaining == 0 {
+            return Ok(None);
+        }
+        let size = remaining.min(size.as_u64());
+        let (part_number, offset) = cursor.advance(size);
+        Ok(Some(qiniu_sdk::upload::DataSourceReader::seekable(
+            part_number,
+            qiniu_sdk::upload::SeekableSource::new(
+                FileWindowReader { window, pos: 0 },
+                offset,
+                size,
+            ),
+        )))
+    }
+
+    fn reset(&self) -> IoResult<()> {
+        *self.cursor.lock().unwrap() = Default::default();
+        Ok(())
     }
 
-    fn __repr__(&self) -> String {
-        format!("{:?}", self.0)
+    fn source_key(&self) -> IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
+        Ok(qiniu_sdk::upload::DataSource::source_key(&self.source)?
+            .map(|source_key| self.window.make_source_key(&source_key)))
     }
 
-    fn __str__(&self) -> String {
-        self.__repr__()
+    fn total_size(&self) -> IoResult<Option<u64>> {
+        Ok(Some(self.opened()?.size))
     }
 }
 
-impl qiniu_sdk::upload::DataSource<Sha1> for DataSource {
+#[derive(Debug, Clone)]
+struct AsyncFileWindowDataSource {
+    path: PathBuf,
+    source: qiniu_sdk::upload::AsyncFileDataSource<Sha1>,
+    window: FileWindow,
+    opened: Arc<AsyncMutex<Option<OpenedFileWindow>>>,
+    cursor: Arc<AsyncMutex<SliceCursor>>,
+}
+
+impl AsyncFileWindowDataSource {
+    /// 首次使用时打开文件，之后的切片复用同一个文件句柄
+    async fn opened(&self) -> IoResult<OpenedFileWindow> {
+        let mut opened = self.opened.lock().await;
+        if let Some(opened) = &*opened {
+            return Ok(opened.to_owned());
+        }
+        let path = self.path.to_owned();
+        let window = self.window;
+        let window =
+            async_std::task::spawn_blocking(move || OpenedFileWindow::open(&path, window)).await?;
+        *opened = Some(window.to_owned());
+        Ok(window)
+    }
+}
+
+impl qiniu_sdk::upload::AsyncDataSource<Sha1> for AsyncFileWindowDataSource {
     fn slice(
         &self,
         size: qiniu_sdk::upload::PartSize,
-    ) -> std::io::Result<Option<qiniu_sdk::upload::DataSourceReader>> {
-        self.0.slice(size)
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::AsyncDataSourceReader>>>
+    {
+        Box::pin(async move {
+            let mut cursor = self.cursor.lock().await;
+            let window = self.opened().await?;
+            let remaining = window.size.saturating_sub(cursor.offset);
+            if remaining == 0 {
+                return Ok(None);
+            }
+            let size = remaining.min(size.as_u64());
+            let (part_number, offset) = cursor.advance(size);
+            Ok(Some(qiniu_sdk::upload::AsyncDataSourceReader::seekable(
+                part_number,
+                qiniu_sdk::upload::AsyncSeekableSource::new(
+                    AsyncFileWindowReader {
+                        window,
+                        pos: 0,
+                        reading: Default::default(),
+                    },
+                    offset,
+                    size,
+                ),
+            )))
+        })
     }
 
-    fn reset(&self) -> std::io::Result<()> {
-        self.0.reset()
+    fn reset(&self) -> futures::future::BoxFuture<IoResult<()>> {
+        Box::pin(async move {
+            *self.cursor.lock().await = Default::default();
+            Ok(())
+        })
     }
 
-    fn source_key(&self) -> std::io::Result<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
-        self.0.source_key()
+    fn source_key(
+        &self,
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>>> {
+        Box::pin(async move {
+            Ok(qiniu_sdk::upload::AsyncDataSource::source_key(&self.source)
+                .await?
+                .map(|source_key| self.window.make_source_key(&source_key)))
+        })
     }
 
-    fn total_size(&self) -> std::io::Result<Option<u64>> {
-        self.0.total_size()
+    fn total_size(&self) -> futures::future::BoxFuture<IoResult<Option<u64>>> {
+        Box::pin(async move { Ok(Some(self.opened().await?.size)) })
     }
 }
 
-/// 文件数据源
-///
-/// 基于一个文件实现了数据源接口
+/// 同时打开的数据源数量限制
 ///
-/// 通过 `FileDataSource(path)` 创建文件数据源
-#[pyclass(extends = DataSource)]
-#[derive(Debug, Clone, Copy)]
-#[pyo3(text_signature = "(path)")]
-struct FileDataSource;
+/// 由同一个上传管理器创建的所有上传器共享，每个数据源在第一次读取前获取许可，并一直持有到数据源被释放为止，
+/// 因为文件数据源在整个生命周期内都会保持文件打开，所以这样才能保证同时打开的数据源数量不会超过许可数。不限制时为 `None`
+#[derive(Debug, Clone, Default)]
+struct SourcePermits(Option<Arc<SourcePermitsState>>);
 
-#[pymethods]
-impl FileDataSource {
-    /// 创建文件数据源
-    #[new]
-    fn new(path: &str) -> (Self, DataSource) {
-        (
-            Self,
-            DataSource(Box::new(qiniu_sdk::upload::FileDataSource::new(path))),
-        )
-    }
+#[derive(Debug)]
+struct SourcePermitsState {
+    available: Mutex<(usize, Vec<Waker>)>,
+    released: Condvar,
 }
 
-/// 不可寻址的数据源
-///
-/// 基于一个不可寻址的阅读器实现了数据源接口
-///
-/// 通过 `UnseekableDataSource(source)` 创建不可寻址的数据源
-#[pyclass(extends = DataSource)]
-#[derive(Debug, Clone, Copy)]
-#[pyo3(text_signature = "(source)")]
-struct UnseekableDataSource;
+impl SourcePermits {
+    fn new(max_open_sources: Option<usize>) -> PyResult<Self> {
+        match max_open_sources {
+            Some(0) => Err(QiniuInvalidConcurrency::new_err(
+                "max_open_sources must not be zero",
+            )),
+            Some(max_open_sources) => Ok(Self(Some(Arc::new(SourcePermitsState {
+                available: Mutex::new((max_open_sources, Vec::new())),
+                released: Condvar::new(),
+            })))),
+            None => Ok(Self(None)),
+        }
+    }
 
-#[pymethods]
-impl UnseekableDataSource {
-    /// 创建不可寻址的数据源
-    #[new]
-    fn new(source: PyObject) -> (Self, DataSource) {
-        (
-            Self,
-            DataSource(Box::new(qiniu_sdk::upload::UnseekableDataSource::new(
-                PythonIoBase::new(source),
-            ))),
+    /// 阻塞获取许可，不限制时直接返回 `None`
+    fn acquire(&self) -> Option<SourcePermit> {
+        let state = self.0.as_ref()?;
+        let mut available = state.available.lock().unwrap();
+        while available.0 == 0 {
+            available = state.released.wait(available).unwrap();
+        }
+        available.0 -= 1;
+        Some(SourcePermit(state.to_owned()))
+    }
+
+    /// 异步获取许可，不限制时直接返回 `None`
+    async fn async_acquire(&self) -> Option<SourcePermit> {
+        let state = self.0.as_ref()?;
+        futures::future::poll_fn(|cx| {
+            let mut available = state.available.lock().unwrap();
+            if available.0 > 0 {
+                available.0 -= 1;
+                Poll::Ready(())
+            } else {
+                available.1.push(cx.waker().to_owned());
+                Poll::Pending
+            }
+        })
+        .await;
+        Some(SourcePermit(state.to_owned()))
+    }
+
+    /// 为数据源增加许可限制，不限制时原样返回
+    fn limit(&self, source: DataSource) -> DataSource {
+        if self.0.is_none() {
+            return source;
+        }
+        DataSource(
+            Box::new(PermittedDataSource {
+                source,
+                permits: self.to_owned(),
+                permit: Default::default(),
+            }),
+            Default::default(),
+            Default::default(),
+        )
+    }
+
+    /// 为异步数据源增加许可限制，不限制时原样返回
+    fn async_limit(&self, source: AsyncDataSource) -> AsyncDataSource {
+        if self.0.is_none() {
+            return source;
+        }
+        AsyncDataSource(
+            Box::new(AsyncPermittedDataSource {
+                source,
+                permits: self.to_owned(),
+                permit: Default::default(),
+            }),
+            Default::default(),
+            Default::default(),
         )
     }
 }
 
-/// 异步数据源接口
-///
-/// 抽象类
-///
-/// 提供上传所用的数据源
-#[pyclass(subclass)]
+/// 数据源许可，被释放时唤醒等待中的切片
+#[derive(Debug)]
+struct SourcePermit(Arc<SourcePermitsState>);
+
+impl Drop for SourcePermit {
+    fn drop(&mut self) {
+        let wakers = {
+            let mut available = self.0.available.lock().unwrap();
+            available.0 += 1;
+            take(&mut available.1)
+        };
+        self.0.released.notify_one();
+        wakers.into_iter().for_each(Waker::wake);
+    }
+}
+
 #[derive(Debug, Clone)]
-struct AsyncDataSource(Box<dyn qiniu_sdk::upload::AsyncDataSource<Sha1>>);
+struct PermittedDataSource {
+    source: DataSource,
+    permits: SourcePermits,
+    permit: Arc<Mutex<Option<SourcePermit>>>,
+}
 
-#[pymethods]
-impl AsyncDataSource {
-    /// 异步数据源切片
-    #[pyo3(text_signature = "($self, size)")]
-    fn slice<'p>(&self, size: u64, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let part_size = qiniu_sdk::upload::PartSize::new(size).map_or_else(
-            || Err(QiniuInvalidPartSize::new_err("part_size must not be zero")),
-            Ok,
-        )?;
-        let source = self.0.to_owned();
-        pyo3_asyncio::async_std::future_into_py(py, async move {
-            source
-                .slice(part_size)
-                .await
-                .map(|r| r.map(|r| AsyncDataSourceReader(Arc::new(AsyncMutex::new(r)))))
-                .map_err(PyIOError::new_err)
-        })
+impl PermittedDataSource {
+    /// 第一次读取数据源前获取许可，之后一直持有到数据源被释放
+    fn acquire(&self) {
+        let mut permit = self.permit.lock().unwrap();
+        if permit.is_none() {
+            *permit = self.permits.acquire();
+        }
     }
+}
 
-    /// 异步获取数据源 KEY
-    ///
-    /// 用于区分不同的数据源
-    #[pyo3(text_signature = "($self)")]
-    fn source_key<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let source = self.0.to_owned();
-        pyo3_asyncio::async_std::future_into_py(py, async move {
-            source
-                .source_key()
-                .await
-                .map_err(PyIOError::new_err)
-                .map(|k| k.map(SourceKey))
-        })
+impl qiniu_sdk::upload::DataSource<Sha1> for PermittedDataSource {
+    fn slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> IoResult<Option<qiniu_sdk::upload::DataSourceReader>> {
+        self.acquire();
+        qiniu_sdk::upload::DataSource::slice(&self.source, size)
     }
 
-    /// 异步获取数据源大小
-    #[pyo3(text_signature = "($self)")]
-    fn total_size<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let source = self.0.to_owned();
-        pyo3_asyncio::async_std::future_into_py(py, async move {
-            source.total_size().await.map_err(PyIOError::new_err)
-        })
+    fn reset(&self) -> IoResult<()> {
+        qiniu_sdk::upload::DataSource::reset(&self.source)
     }
 
-    fn __repr__(&self) -> String {
-        format!("{:?}", self.0)
+    fn source_key(&self) -> IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
+        self.acquire();
+        qiniu_sdk::upload::DataSource::source_key(&self.source)
     }
 
-    fn __str__(&self) -> String {
-        self.__repr__()
+    fn total_size(&self) -> IoResult<Option<u64>> {
+        qiniu_sdk::upload::DataSource::total_size(&self.source)
     }
 }
 
-impl qiniu_sdk::upload::AsyncDataSource<Sha1> for AsyncDataSource {
+#[derive(Debug, Clone)]
+struct AsyncPermittedDataSource {
+    source: AsyncDataSource,
+    permits: SourcePermits,
+    permit: Arc<AsyncMutex<Option<SourcePermit>>>,
+}
+
+impl AsyncPermittedDataSource {
+    /// 第一次读取数据源前获取许可，之后一直持有到数据源被释放
+    async fn acquire(&self) {
+        let mut permit = self.permit.lock().await;
+        if permit.is_none() {
+            *permit = self.permits.async_acquire().await;
+        }
+    }
+}
+
+impl qiniu_sdk::upload::AsyncDataSource<Sha1> for AsyncPermittedDataSource {
     fn slice(
         &self,
         size: qiniu_sdk::upload::PartSize,
-    ) -> futures::future::BoxFuture<std::io::Result<Option<qiniu_sdk::upload::AsyncDataSourceReader>>>
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::AsyncDataSourceReader>>>
     {
-        self.0.slice(size)
+        Box::pin(async move {
+            self.acquire().await;
+            qiniu_sdk::upload::AsyncDataSource::slice(&self.source, size).await
+        })
     }
 
-    fn reset(&self) -> futures::future::BoxFuture<std::io::Result<()>> {
-        self.0.reset()
+    fn reset(&self) -> futures::future::BoxFuture<IoResult<()>> {
+        qiniu_sdk::upload::AsyncDataSource::reset(&self.source)
     }
 
     fn source_key(
         &self,
-    ) -> futures::future::BoxFuture<std::io::Result<Option<qiniu_sdk::upload::SourceKey<Sha1>>>>
-    {
-        self.0.source_key()
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>>> {
+        Box::pin(async move {
+            self.acquire().await;
+            qiniu_sdk::upload::AsyncDataSource::source_key(&self.source).await
+        })
     }
 
-    fn total_size(&self) -> futures::future::BoxFuture<std::io::Result<Option<u64>>> {
-        self.0.total_size()
+    fn total_size(&self) -> futures::future::BoxFuture<IoResult<Option<u64>>> {
+        qiniu_sdk::upload::AsyncDataSource::total_size(&self.source)
     }
 }
 
-/// 异步文件数据源
-///
-/// 基于一个文件实现了数据源接口
-///
-/// 通过 `AsyncFileDataSource(path)` 创建异步文件数据源
-#[pyclass(extends = AsyncDataSource)]
-#[derive(Debug, Clone, Copy)]
-#[pyo3(text_signature = "(path)")]
-struct AsyncFileDataSource;
+/// 从被包装的数据源中每次切出的数据大小
+const WRAPPED_SOURCE_SLICE_SIZE: u64 = 1 << 22;
 
-#[pymethods]
-impl AsyncFileDataSource {
-    /// 创建异步文件数据源
-    #[new]
-    fn new(path: &str) -> (Self, AsyncDataSource) {
-        (
-            Self,
-            AsyncDataSource(Box::new(qiniu_sdk::upload::AsyncFileDataSource::new(path))),
-        )
+fn wrapped_source_slice_size() -> qiniu_sdk::upload::PartSize {
+    qiniu_sdk::upload::PartSize::new(WRAPPED_SOURCE_SLICE_SIZE).unwrap()
+}
+
+/// 将数据源的所有切片依次串联为一个阅读器
+#[derive(Debug)]
+struct DataSourceStream {
+    source: DataSource,
+    reader: Option<qiniu_sdk::upload::DataSourceReader>,
+}
+
+impl Read for DataSourceStream {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        loop {
+            let reader = if let Some(reader) = self.reader.as_mut() {
+                reader
+            } else if let Some(reader) =
+                qiniu_sdk::upload::DataSource::slice(&self.source, wrapped_source_slice_size())?
+            {
+                self.reader.insert(reader)
+            } else {
+                return Ok(0);
+            };
+            let have_read = reader.read(buf)?;
+            if have_read > 0 || buf.is_empty() {
+                return Ok(have_read);
+            }
+            self.reader = None;
+        }
     }
 }
 
-/// 不可寻址的异步数据源
-///
-/// 基于一个不可寻址的异步阅读器实现了异步数据源接口
-///
-/// 通过 `AsyncUnseekableDataSource(source)` 创建不可寻址的异步数据源
-#[pyclass(extends = AsyncDataSource)]
-#[derive(Debug, Clone, Copy)]
-#[pyo3(text_signature = "(source)")]
-struct AsyncUnseekableDataSource;
+/// 将异步数据源的所有切片依次串联为一个数据流
+fn async_data_source_stream(
+    source: AsyncDataSource,
+) -> Pin<Box<dyn futures::Stream<Item = IoResult<Vec<u8>>> + Send>> {
+    Box::pin(futures::stream::try_unfold(
+        (source, None),
+        |(source, mut reader): (
+            AsyncDataSource,
+            Option<qiniu_sdk::upload::AsyncDataSourceReader>,
+        )| async move {
+            loop {
+                let current = if let Some(current) = reader.as_mut() {
+                    current
+                } else if let Some(current) =
+                    qiniu_sdk::upload::AsyncDataSource::slice(&source, wrapped_source_slice_size())
+                        .await?
+                {
+                    reader.insert(current)
+                } else {
+                    return Ok(None);
+                };
+                let mut buf = Vec::new();
+                current
+                    .take(DEFAULT_READER_CHUNK_SIZE as u64)
+                    .read_to_end(&mut buf)
+                    .await?;
+                if !buf.is_empty() {
+                    return Ok(Some((buf, (source, reader))));
+                }
+                reader = None;
+            }
+        },
+    ))
+}
 
-#[pymethods]
-impl AsyncUnseekableDataSource {
-    /// 创建不可寻址的异步数据源
-    #[new]
-    fn new(source: PyObject) -> (Self, AsyncDataSource) {
-        (
-            Self,
-            AsyncDataSource(Box::new(qiniu_sdk::upload::AsyncUnseekableDataSource::new(
-                PythonIoBase::new(source).into_async_read(),
-            ))),
-        )
+/// 将仅实现了 `Send` 的阅读器包装为同时实现 `Sync` 的阅读器
+struct SyncReader<R>(Mutex<R>);
+
+impl<R: Read> Read for SyncReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        self.0
+            .get_mut()
+            .unwrap_or_else(|err| err.into_inner())
+            .read(buf)
     }
 }
 
-/// 追加介质接口
+impl<R: AsyncRead + Unpin> AsyncRead for SyncReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<IoResult<usize>> {
+        let reader = self
+            .get_mut()
+            .0
+            .get_mut()
+            .unwrap_or_else(|err| err.into_inner());
+        Pin::new(reader).poll_read(cx, buf)
+    }
+}
+
+impl<R> Debug for SyncReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncReader").finish_non_exhaustive()
+    }
+}
+
+const DEFAULT_READER_CHUNK_SIZE: usize = 1 << 16;
+
+/// 数据源阅读器
 ///
-/// 抽象类
+/// 支持迭代，每次迭代返回不超过 `chunk_size` 字节的数据，直到读取完毕
+///
+/// 通过 `data_source.slice()` 创建
 #[pyclass]
 #[derive(Debug)]
-struct DataSourceReader(qiniu_sdk::upload::DataSourceReader);
+struct DataSourceReader(qiniu_sdk::upload::DataSourceReader, usize, u64, u64);
 
 #[pymethods]
 impl DataSourceReader {
@@ -1557,6 +4347,46 @@ impl DataSourceReader {
             .map_err(PyIOError::new_err)
     }
 
+    /// 获取迭代时每次读取的数据块大小
+    #[getter]
+    fn get_chunk_size(&self) -> usize {
+        self.1
+    }
+
+    /// 设置迭代时每次读取的数据块大小
+    #[setter]
+    fn set_chunk_size(&mut self, chunk_size: usize) -> PyResult<()> {
+        self.1 = check_reader_chunk_size(chunk_size)?;
+        Ok(())
+    }
+
+    /// 获取切片在数据源中的偏移量
+    #[getter]
+    fn get_offset(&self) -> u64 {
+        self.2
+    }
+
+    /// 获取切片大小
+    ///
+    /// 如果数据源大小未知，则为切片时请求的大小，最后一个切片的实际大小可能更小
+    #[getter]
+    fn get_size(&self) -> u64 {
+        self.3
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__<'a>(&mut self, py: Python<'a>) -> PyResult<Option<&'a PyBytes>> {
+        let chunk = self.read(self.1 as i64, py)?;
+        Ok(if chunk.as_bytes().is_empty() {
+            None
+        } else {
+            Some(chunk)
+        })
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -1566,12 +4396,19 @@ impl DataSourceReader {
     }
 }
 
-/// 异步只读介质接口
+/// 异步数据源阅读器
 ///
-/// 抽象类
+/// 支持异步迭代，每次迭代返回不超过 `chunk_size` 字节的数据，直到读取完毕
+///
+/// 通过 `async_data_source.slice()` 创建
 #[pyclass]
 #[derive(Debug)]
-struct AsyncDataSourceReader(Arc<AsyncMutex<qiniu_sdk::upload::AsyncDataSourceReader>>);
+struct AsyncDataSourceReader(
+    Arc<AsyncMutex<qiniu_sdk::upload::AsyncDataSourceReader>>,
+    usize,
+    u64,
+    u64,
+);
 
 #[pymethods]
 impl AsyncDataSourceReader {
@@ -1614,6 +4451,56 @@ impl AsyncDataSourceReader {
         })
     }
 
+    /// 获取异步迭代时每次读取的数据块大小
+    #[getter]
+    fn get_chunk_size(&self) -> usize {
+        self.1
+    }
+
+    /// 设置异步迭代时每次读取的数据块大小
+    #[setter]
+    fn set_chunk_size(&mut self, chunk_size: usize) -> PyResult<()> {
+        self.1 = check_reader_chunk_size(chunk_size)?;
+        Ok(())
+    }
+
+    /// 获取切片在数据源中的偏移量
+    #[getter]
+    fn get_offset(&self) -> u64 {
+        self.2
+    }
+
+    /// 获取切片大小
+    ///
+    /// 如果数据源大小未知，则为切片时请求的大小，最后一个切片的实际大小可能更小
+    #[getter]
+    fn get_size(&self) -> u64 {
+        self.3
+    }
+
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let reader = self.0.to_owned();
+        let chunk_size = self.1 as u64;
+        let fut = pyo3_asyncio::async_std::future_into_py(py, async move {
+            let mut reader = reader.lock().await;
+            let mut buf = Vec::new();
+            (&mut *reader)
+                .take(chunk_size)
+                .read_to_end(&mut buf)
+                .await
+                .map_err(PyIOError::new_err)?;
+            if buf.is_empty() {
+                return Err(PyStopAsyncIteration::new_err(()));
+            }
+            Python::with_gil(|py| Ok(PyBytes::new(py, &buf).to_object(py)))
+        })?;
+        Ok(Some(fut.into()))
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -1623,15 +4510,45 @@ impl AsyncDataSourceReader {
     }
 }
 
+fn check_reader_chunk_size(chunk_size: usize) -> PyResult<usize> {
+    if chunk_size == 0 {
+        Err(QiniuInvalidPartSize::new_err("chunk_size must not be zero"))
+    } else {
+        Ok(chunk_size)
+    }
+}
+
 /// 上传管理器
 ///
-/// 通过 `UploadManager(signer, http_client = None, use_https = None, queryer = None, uc_endpoints = None)` 创建上传管理器
+/// 通过 `UploadManager(signer, http_client = None, use_https = None, queryer = None, uc_endpoints = None, max_open_sources = None, accelerated_endpoints = None)` 创建上传管理器
+///
+/// 创建上传器时如果传入 `progress_interval_ms`，`upload_progress` 回调在该间隔内最多只会被调用一次，但上传完成时的进度总是会被回调
+///
+/// 创建分片上传器时如果传入 `part_upload_error`，每次上传分片请求失败时都会调用 `part_upload_error(part_offset, part_size, error, retried)`，
+/// 其中 `retried` 为该分片此前已经失败的次数。分片的偏移量仅在通过分片上传调度器上传时可知，分片大小还要求数据源大小已知，否则为 `None`
+///
+/// 上传管理器可以作为（异步）上下文管理器使用，退出时将关闭上传管理器并释放其持有的 HTTP 客户端
+///
+/// 通过上传管理器创建的上传器在上传完成后，可以通过 `last_server_ip`、`last_server_port` 和 `last_endpoint` 获取最近一次成功响应的服务器地址
+///
+/// `max_open_sources` 用于限制同时被打开读取的数据源数量，由该上传管理器创建的所有上传器共享，默认不限制。
+/// 设置后，分片上传调度器在第一次读取数据源前获取许可，并一直持有到该数据源被释放为止；自动上传器上传文件时则在整个上传期间持有许可。
+/// 大批量并发上传文件时，可以将其设置为明显小于进程文件描述符上限（可以通过 `ulimit -n` 查看）的值，例如 `256`，以免耗尽文件描述符，
+/// 该值越小，同时读取的数据源越少，但并发上传的吞吐量也可能随之降低
+///
+/// 如果传入 `accelerated_endpoints`，由该上传管理器创建的自动上传器在没有指定 `region_provider` 时将优先使用这些上传加速终端地址，
+/// 失败后通过重试机制回退到存储空间所在区域原本的上传服务终端地址。对于私有云，可以在这里传入自定义的加速域名。
+/// 通过 `params` 传入的对象参数不受影响，创建自动上传器时传入 `prefer_accelerated_endpoints = False` 则不使用上传加速终端地址
 #[pyclass]
 #[derive(Debug, Clone)]
 #[pyo3(
-    text_signature = "(signer, /, http_client = None, use_https = None, queryer = None, uc_endpoints = None)"
+    text_signature = "(signer, /, http_client = None, use_https = None, queryer = None, uc_endpoints = None, max_open_sources = None, accelerated_endpoints = None)"
 )]
-struct UploadManager(qiniu_sdk::upload::UploadManager);
+struct UploadManager(
+    Option<qiniu_sdk::upload::UploadManager>,
+    SourcePermits,
+    Option<RegionsProvider>,
+);
 
 #[pymethods]
 impl UploadManager {
@@ -1641,18 +4558,29 @@ impl UploadManager {
         http_client = "None",
         use_https = "None",
         queryer = "None",
-        uc_endpoints = "None"
+        uc_endpoints = "None",
+        max_open_sources = "None",
+        accelerated_endpoints = "None"
     )]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         signer: UploadTokenSigner,
         http_client: Option<HttpClient>,
         use_https: Option<bool>,
         queryer: Option<BucketRegionsQueryer>,
         uc_endpoints: Option<Endpoints>,
-    ) -> Self {
-        let mut builder = qiniu_sdk::upload::UploadManager::builder(signer.0);
+        max_open_sources: Option<usize>,
+        accelerated_endpoints: Option<Endpoints>,
+        py: Python<'_>,
+    ) -> PyResult<Self> {
+        let source_permits = SourcePermits::new(max_open_sources)?;
+        let mut builder = qiniu_sdk::upload::UploadManager::builder(signer.0.to_owned());
         if let Some(http_client) = http_client {
             builder.http_client(http_client.into());
+        } else if let Some(http_client) =
+            enabled_shared_http_client(py).filter(|_| use_https.is_none())
+        {
+            builder.http_client(http_client.into());
         }
         if let Some(use_https) = use_https {
             builder.use_https(use_https);
@@ -1663,18 +4591,70 @@ impl UploadManager {
         if let Some(uc_endpoints) = uc_endpoints {
             builder.uc_endpoints(uc_endpoints);
         }
-        Self(builder.build())
+        let manager = builder.build();
+        let accelerated_region_provider = accelerated_endpoints.map(|accelerated_endpoints| {
+            let provider: Box<dyn qiniu_sdk::http_client::RegionsProvider> =
+                Box::new(SignerRegionsProvider {
+                    queryer: manager.queryer().to_owned(),
+                    signer,
+                });
+            RegionsProvider::from(provider).accelerated(accelerated_endpoints)
+        });
+        Ok(Self(
+            Some(manager),
+            source_permits,
+            accelerated_region_provider,
+        ))
+    }
+
+    /// 关闭上传管理器，释放其持有的 HTTP 客户端
+    ///
+    /// 关闭后再创建上传器将抛出 `QiniuUploadManagerClosedError`，已经创建的上传器不受影响
+    #[pyo3(text_signature = "($self)")]
+    fn close(&mut self) {
+        self.0 = None;
+    }
+
+    fn __enter__(self_: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        self_
+    }
+
+    fn __exit__(
+        &mut self,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+    ) -> bool {
+        self.close();
+        false
+    }
+
+    fn __aenter__<'p>(self_: PyRef<'p, Self>, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let self_: Py<Self> = self_.into();
+        pyo3_asyncio::async_std::future_into_py(py, async move { Ok(self_) })
+    }
+
+    fn __aexit__<'p>(
+        &mut self,
+        _exc_type: PyObject,
+        _exc_value: PyObject,
+        _traceback: PyObject,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        self.close();
+        pyo3_asyncio::async_std::future_into_py(py, async move { Ok(false) })
     }
 
     /// 创建表单上传器
     #[pyo3(
-        text_signature = "($self, /, before_request = None, upload_progress = None, response_ok = None, response_error = None)"
+        text_signature = "($self, /, before_request = None, upload_progress = None, response_ok = None, response_error = None, progress_interval_ms = None)"
     )]
     #[args(
         response_ok = "None",
         response_error = "None",
         before_backoff = "None",
-        after_backoff = "None"
+        after_backoff = "None",
+        progress_interval_ms = "None"
     )]
     fn form_uploader(
         &self,
@@ -1682,13 +4662,14 @@ impl UploadManager {
         upload_progress: Option<PyObject>,
         response_ok: Option<PyObject>,
         response_error: Option<PyObject>,
-    ) -> FormUploader {
-        let mut uploader = self.0.form_uploader();
+        progress_interval_ms: Option<u64>,
+    ) -> PyResult<FormUploader> {
+        let mut uploader = self.manager()?.form_uploader();
         if let Some(before_request) = before_request {
             uploader.on_before_request(on_before_request(before_request));
         }
         if let Some(upload_progress) = upload_progress {
-            uploader.on_upload_progress(on_upload_progress(upload_progress));
+            uploader.on_upload_progress(on_upload_progress(upload_progress, progress_interval_ms));
         }
         if let Some(response_ok) = response_ok {
             uploader.on_response_ok(on_response(response_ok));
@@ -1696,20 +4677,25 @@ impl UploadManager {
         if let Some(response_error) = response_error {
             uploader.on_response_error(on_error(response_error));
         }
-        FormUploader(uploader)
+        let last_server = LastServer::default();
+        uploader.on_response_ok(last_server.recorder());
+        Ok(FormUploader(uploader, last_server))
     }
 
     /// 创建分片上传器 V1
     #[pyo3(
-        text_signature = "($self, resumable_recorder, /, before_request = None, upload_progress = None, response_ok = None, response_error = None, part_uploaded = None)"
+        text_signature = "($self, resumable_recorder, /, before_request = None, upload_progress = None, response_ok = None, response_error = None, part_uploaded = None, progress_interval_ms = None, part_upload_error = None)"
     )]
     #[args(
         response_ok = "None",
         response_error = "None",
         before_backoff = "None",
         after_backoff = "None",
-        part_uploaded = "None"
+        part_uploaded = "None",
+        progress_interval_ms = "None",
+        part_upload_error = "None"
     )]
+    #[allow(clippy::too_many_arguments)]
     fn multi_parts_v1_uploader(
         &self,
         resumable_recorder: ResumableRecorder,
@@ -1718,13 +4704,17 @@ impl UploadManager {
         response_ok: Option<PyObject>,
         response_error: Option<PyObject>,
         part_uploaded: Option<PyObject>,
-    ) -> MultiPartsV1Uploader {
-        let mut uploader = self.0.multi_parts_v1_uploader(resumable_recorder);
+        progress_interval_ms: Option<u64>,
+        part_upload_error: Option<PyObject>,
+    ) -> PyResult<MultiPartsV1Uploader> {
+        let mut uploader = self
+            .manager()?
+            .multi_parts_v1_uploader(resumable_recorder.to_owned());
         if let Some(before_request) = before_request {
             uploader.on_before_request(on_before_request(before_request));
         }
         if let Some(upload_progress) = upload_progress {
-            uploader.on_upload_progress(on_upload_progress(upload_progress));
+            uploader.on_upload_progress(on_upload_progress(upload_progress, progress_interval_ms));
         }
         if let Some(response_ok) = response_ok {
             uploader.on_response_ok(on_response(response_ok));
@@ -1732,24 +4722,45 @@ impl UploadManager {
         if let Some(response_error) = response_error {
             uploader.on_response_error(on_error(response_error));
         }
+        let part_tracker = PartTracker::default();
         if let Some(part_uploaded) = part_uploaded {
-            uploader.on_part_uploaded(on_part_uploaded(part_uploaded));
+            uploader.on_part_uploaded(on_part_uploaded(
+                part_uploaded,
+                Some(part_tracker.to_owned()),
+            ));
+        }
+        if let Some(part_upload_error) = part_upload_error {
+            uploader.on_response_error(on_part_upload_error(
+                part_upload_error,
+                part_tracker.to_owned(),
+            ));
         }
-        MultiPartsV1Uploader(uploader)
+        uploader.on_part_uploaded(on_part_upload_finished(part_tracker.to_owned()));
+        let last_server = LastServer::default();
+        uploader.on_response_ok(last_server.recorder());
+        Ok(MultiPartsV1Uploader(
+            uploader,
+            last_server,
+            self.1.to_owned(),
+            part_tracker,
+            resumable_recorder,
+        ))
     }
 
     /// 创建分片上传器 V2
     #[pyo3(
-        text_signature = "($self, resumable_recorder, /, before_request = None, upload_progress = None, response_ok = None, response_error = None, part_uploaded = None)"
+        text_signature = "($self, resumable_recorder, /, before_request = None, upload_progress = None, response_ok = None, response_error = None, part_uploaded = None, progress_interval_ms = None, part_upload_error = None)"
     )]
     #[args(
         response_ok = "None",
         response_error = "None",
         before_backoff = "None",
         after_backoff = "None",
-        part_uploaded = "None"
+        part_uploaded = "None",
+        progress_interval_ms = "None",
+        part_upload_error = "None"
     )]
-
+    #[allow(clippy::too_many_arguments)]
     fn multi_parts_v2_uploader(
         &self,
         resumable_recorder: ResumableRecorder,
@@ -1758,13 +4769,17 @@ impl UploadManager {
         response_ok: Option<PyObject>,
         response_error: Option<PyObject>,
         part_uploaded: Option<PyObject>,
-    ) -> MultiPartsV2Uploader {
-        let mut uploader = self.0.multi_parts_v2_uploader(resumable_recorder);
+        progress_interval_ms: Option<u64>,
+        part_upload_error: Option<PyObject>,
+    ) -> PyResult<MultiPartsV2Uploader> {
+        let mut uploader = self
+            .manager()?
+            .multi_parts_v2_uploader(resumable_recorder.to_owned());
         if let Some(before_request) = before_request {
             uploader.on_before_request(on_before_request(before_request));
         }
         if let Some(upload_progress) = upload_progress {
-            uploader.on_upload_progress(on_upload_progress(upload_progress));
+            uploader.on_upload_progress(on_upload_progress(upload_progress, progress_interval_ms));
         }
         if let Some(response_ok) = response_ok {
             uploader.on_response_ok(on_response(response_ok));
@@ -1772,15 +4787,34 @@ impl UploadManager {
         if let Some(response_error) = response_error {
             uploader.on_response_error(on_error(response_error));
         }
+        let part_tracker = PartTracker::default();
         if let Some(part_uploaded) = part_uploaded {
-            uploader.on_part_uploaded(on_part_uploaded(part_uploaded));
+            uploader.on_part_uploaded(on_part_uploaded(
+                part_uploaded,
+                Some(part_tracker.to_owned()),
+            ));
+        }
+        if let Some(part_upload_error) = part_upload_error {
+            uploader.on_response_error(on_part_upload_error(
+                part_upload_error,
+                part_tracker.to_owned(),
+            ));
         }
-        MultiPartsV2Uploader(uploader)
+        uploader.on_part_uploaded(on_part_upload_finished(part_tracker.to_owned()));
+        let last_server = LastServer::default();
+        uploader.on_response_ok(last_server.recorder());
+        Ok(MultiPartsV2Uploader(
+            uploader,
+            last_server,
+            self.1.to_owned(),
+            part_tracker,
+            resumable_recorder,
+        ))
     }
 
     /// 创建自动上传器
     #[pyo3(
-        text_signature = "($self, /, concurrency_provider = None, data_partition_provider = None, resumable_recorder = None, resumable_policy_provider = None, before_request = None, upload_progress = None, response_ok = None, response_error = None, part_uploaded = None)"
+        text_signature = "($self, /, concurrency_provider = None, data_partition_provider = None, resumable_recorder = None, resumable_policy_provider = None, before_request = None, upload_progress = None, response_ok = None, response_error = None, part_uploaded = None, progress_interval_ms = None, prefer_accelerated_endpoints = True)"
     )]
     #[args(
         concurrency_provider = "None",
@@ -1791,7 +4825,9 @@ impl UploadManager {
         response_error = "None",
         before_backoff = "None",
         after_backoff = "None",
-        part_uploaded = "None"
+        part_uploaded = "None",
+        progress_interval_ms = "None",
+        prefer_accelerated_endpoints = "true"
     )]
     #[allow(clippy::too_many_arguments)]
     fn auto_uploader(
@@ -1805,8 +4841,10 @@ impl UploadManager {
         response_ok: Option<PyObject>,
         response_error: Option<PyObject>,
         part_uploaded: Option<PyObject>,
-    ) -> AutoUploader {
-        let mut builder = self.0.auto_uploader_builder();
+        progress_interval_ms: Option<u64>,
+        prefer_accelerated_endpoints: bool,
+    ) -> PyResult<AutoUploader> {
+        let mut builder = self.manager()?.auto_uploader_builder();
         if let Some(concurrency_provider) = concurrency_provider {
             builder.concurrency_provider(concurrency_provider);
         }
@@ -1824,7 +4862,7 @@ impl UploadManager {
             uploader.on_before_request(on_before_request(before_request));
         }
         if let Some(upload_progress) = upload_progress {
-            uploader.on_upload_progress(on_upload_progress(upload_progress));
+            uploader.on_upload_progress(on_upload_progress(upload_progress, progress_interval_ms));
         }
         if let Some(response_ok) = response_ok {
             uploader.on_response_ok(on_response(response_ok));
@@ -1833,9 +4871,73 @@ impl UploadManager {
             uploader.on_response_error(on_error(response_error));
         }
         if let Some(part_uploaded) = part_uploaded {
-            uploader.on_part_uploaded(on_part_uploaded(part_uploaded));
+            uploader.on_part_uploaded(on_part_uploaded(part_uploaded, None));
+        }
+        let last_server = LastServer::default();
+        uploader.on_response_ok(last_server.recorder());
+        Ok(AutoUploader(
+            uploader,
+            last_server,
+            self.1.to_owned(),
+            self.2.to_owned().filter(|_| prefer_accelerated_endpoints),
+        ))
+    }
+}
+
+/// 如果数据流不可寻址（即长度未知），则发出警告，表单上传器将把整个数据流读入内存后再上传
+fn warn_unsized_reader(reader: &PyObject, py: Python<'_>) -> PyResult<()> {
+    let seekable = reader.as_ref(py).hasattr("seekable")?
+        && reader.call_method0(py, "seekable")?.is_true(py)?;
+    if !seekable {
+        warn_reader_buffered(py)?;
+    }
+    Ok(())
+}
+
+/// 异步数据流的 `seekable()` 返回的是协程，需要等待其结果后再判断是否发出警告
+async fn async_warn_unsized_reader(reader: PyObject) -> PyResult<()> {
+    let seekable = Python::with_gil(|py| -> PyResult<Result<bool, _>> {
+        if !reader.as_ref(py).hasattr("seekable")? {
+            return Ok(Ok(false));
+        }
+        let retval = reader.call_method0(py, "seekable")?;
+        let is_awaitable = py
+            .import("inspect")?
+            .call_method1("isawaitable", (retval.as_ref(py),))?
+            .is_true()?;
+        if is_awaitable {
+            pyo3_asyncio::async_std::into_future(retval.as_ref(py)).map(Err)
+        } else {
+            retval.is_true(py).map(Ok)
         }
-        AutoUploader(uploader)
+    })?;
+    let seekable = match seekable {
+        Ok(seekable) => seekable,
+        Err(seekable) => {
+            let seekable = seekable.await?;
+            Python::with_gil(|py| seekable.is_true(py))?
+        }
+    };
+    if !seekable {
+        Python::with_gil(warn_reader_buffered)?;
+    }
+    Ok(())
+}
+
+fn warn_reader_buffered(py: Python<'_>) -> PyResult<()> {
+    PyErr::warn(
+        py,
+        user_warning(py)?,
+        "Reader length is unknown and form upload can not use chunked transfer encoding, the whole reader will be buffered in memory before uploading, use multi-parts uploader for large streams instead",
+        1,
+    )
+}
+
+impl UploadManager {
+    fn manager(&self) -> PyResult<&qiniu_sdk::upload::UploadManager> {
+        self.0
+            .as_ref()
+            .ok_or_else(|| QiniuUploadManagerClosedError::new_err("upload manager is closed"))
     }
 }
 
@@ -1843,12 +4945,17 @@ impl UploadManager {
 ///
 /// 通过七牛表单上传 API 一次上传整个数据流
 ///
+/// 为了能在多个上传域名间重试，表单上传器总是在发送前将整个 multipart 请求体构建在内存中，因此无法使用分块传输编码发送，
+/// 内存占用与数据流大小相当。调用 `upload_reader()` 或 `async_upload_reader()` 时如果数据流不可寻址（即长度未知），将会发出警告，
+/// 上传大小未知的大数据流时请改用分片上传
+///
 /// 通过 `upload_manager.form_uploader()` 创建表单上传器
 #[pyclass]
 #[derive(Debug, Clone)]
-struct FormUploader(qiniu_sdk::upload::FormUploader);
+struct FormUploader(qiniu_sdk::upload::FormUploader, LastServer);
 
-impl_uploader!(FormUploader);
+impl_uploader!(FormUploader, warn_unsized_reader, async_warn_unsized_reader);
+impl_last_server!(FormUploader);
 
 macro_rules! impl_multi_parts_uploader {
     ($name:ident, $initialized_parts:ident, $async_initialize_parts:ident, $uploaded_part:ident, $async_uploaded_part:ident) => {
@@ -1858,7 +4965,7 @@ macro_rules! impl_multi_parts_uploader {
             ///
             /// 该步骤只负责初始化分片，但不实际上传数据，如果提供了有效的断点续传记录器，则可以尝试在这一步找到记录。
             #[pyo3(
-                text_signature = "($self, source, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None)"
+                text_signature = "($self, source, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, guess_content_type=False)"
             )]
             #[args(
                 region_provider = "None",
@@ -1867,17 +4974,19 @@ macro_rules! impl_multi_parts_uploader {
                 content_type = "None",
                 metadata = "None",
                 custom_vars = "None",
+                guess_content_type = "false",
             )]
             #[allow(clippy::too_many_arguments)]
             fn initialize_parts(
                 &self,
                 source: DataSource,
-                region_provider: Option<RegionsProvider>,
+                region_provider: Option<&PyAny>,
                 object_name: Option<&str>,
                 file_name: Option<&str>,
                 content_type: Option<&str>,
                 metadata: Option<HashMap<String, String>>,
                 custom_vars: Option<HashMap<String, String>>,
+                guess_content_type: bool,
                 py: Python<'_>,
             ) -> PyResult<$initialized_parts> {
                 let object_params = make_object_params(
@@ -1887,12 +4996,16 @@ macro_rules! impl_multi_parts_uploader {
                     content_type,
                     metadata,
                     custom_vars,
+                    guess_content_type,
+                    None,
                 )?;
                 py.allow_threads(|| {
-                    self.0
+                    let source_key = qiniu_sdk::upload::DataSource::source_key(&source).ok().flatten();
+                    let initialized = self.0
                         .initialize_parts(source, object_params)
-                        .map($initialized_parts)
-                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+                    let resumed_parts = ResumedParts::read(&self.4, source_key.as_ref());
+                    Ok($initialized_parts(initialized, Default::default(), resumed_parts))
                 })
             }
 
@@ -1905,13 +5018,17 @@ macro_rules! impl_multi_parts_uploader {
                 initialized: &mut $initialized_parts,
                 keep_original_region: Option<bool>,
                 refresh_regions:Option<bool>,
-                regions_provider: Option<RegionsProvider>,
+                regions_provider: Option<&PyAny>,
                 py: Python<'_>,
             ) -> PyResult<()> {
-                let options = make_reinitialize_options(keep_original_region, refresh_regions, regions_provider);
+                let options = make_reinitialize_options(keep_original_region, refresh_regions, regions_provider)?;
                 py.allow_threads(|| {
                     self.0
                         .reinitialize_parts(&mut initialized.0, options)
+                        .map(|()| {
+                            initialized.1.reset();
+                            initialized.2.clear();
+                        })
                         .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
                 })
             }
@@ -1931,7 +5048,7 @@ macro_rules! impl_multi_parts_uploader {
                 py.allow_threads(|| {
                     self.0
                         .upload_part(&initialized.0, data_partitioner_provider)
-                        .map(|p| p.map($uploaded_part))
+                        .map(|p| initialized.1.track(p).map(|(p, is_last)| $uploaded_part(p, is_last)))
                         .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
                 })
             }
@@ -1946,6 +5063,7 @@ macro_rules! impl_multi_parts_uploader {
                 parts: Vec<$uploaded_part>,
                 py: Python<'_>,
             ) -> PyResult<PyObject> {
+                let _registration = UploadCallbackRegistration::new(initialized.0.params())?;
                 py.allow_threads(|| {
                     self.0
                         .complete_parts(
@@ -1961,7 +5079,7 @@ macro_rules! impl_multi_parts_uploader {
             ///
             /// 该步骤只负责初始化分片，但不实际上传数据，如果提供了有效的断点续传记录器，则可以尝试在这一步找到记录。
             #[pyo3(
-                text_signature = "($self, source, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None)"
+                text_signature = "($self, source, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, guess_content_type=False)"
             )]
             #[args(
                 region_provider = "None",
@@ -1970,17 +5088,19 @@ macro_rules! impl_multi_parts_uploader {
                 content_type = "None",
                 metadata = "None",
                 custom_vars = "None",
+                guess_content_type = "false",
             )]
             #[allow(clippy::too_many_arguments)]
             fn async_initialize_parts<'p>(
                 &self,
                 source: AsyncDataSource,
-                region_provider: Option<RegionsProvider>,
+                region_provider: Option<&PyAny>,
                 object_name: Option<&str>,
                 file_name: Option<&str>,
                 content_type: Option<&str>,
                 metadata: Option<HashMap<String, String>>,
                 custom_vars: Option<HashMap<String, String>>,
+                guess_content_type: bool,
                 py: Python<'p>,
             ) -> PyResult<&'p PyAny> {
                 let object_params = make_object_params(
@@ -1990,14 +5110,19 @@ macro_rules! impl_multi_parts_uploader {
                     content_type,
                     metadata,
                     custom_vars,
+                    guess_content_type,
+                    None,
                 )?;
                 let uploader = self.0.to_owned();
+                let recorder = self.4.to_owned();
                 pyo3_asyncio::async_std::future_into_py(py, async move {
-                    uploader
+                    let source_key = qiniu_sdk::upload::AsyncDataSource::source_key(&source).await.ok().flatten();
+                    let initialized = uploader
                         .async_initialize_parts(source, object_params)
                         .await
-                        .map($async_initialize_parts)
-                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+                    let resumed_parts = ResumedParts::async_read(&recorder, source_key.as_ref()).await;
+                    Ok($async_initialize_parts(initialized, Default::default(), resumed_parts))
                 })
             }
 
@@ -2010,16 +5135,22 @@ macro_rules! impl_multi_parts_uploader {
                 initialized: $async_initialize_parts,
                 keep_original_region: Option<bool>,
                 refresh_regions:Option<bool>,
-                regions_provider: Option<RegionsProvider>,
+                regions_provider: Option<&PyAny>,
                 py: Python<'p>,
             ) -> PyResult<&'p PyAny> {
-                let options = make_reinitialize_options(keep_original_region, refresh_regions, regions_provider);
+                let options = make_reinitialize_options(keep_original_region, refresh_regions, regions_provider)?;
                 let uploader = self.0.to_owned();
+                let tracker = initialized.1.to_owned();
+                let resumed_parts = initialized.2.to_owned();
                 let mut initialized = initialized.0.to_owned();
                 pyo3_asyncio::async_std::future_into_py(py, async move {
                     uploader
                         .async_reinitialize_parts(&mut initialized, options)
                         .await
+                        .map(|()| {
+                            tracker.reset();
+                            resumed_parts.clear();
+                        })
                         .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
                 })
             }
@@ -2041,7 +5172,7 @@ macro_rules! impl_multi_parts_uploader {
                     uploader
                         .async_upload_part(&initialized.0, &data_partitioner_provider)
                         .await
-                        .map(|p| p.map($async_uploaded_part))
+                        .map(|p| initialized.1.track(p).map(|(p, is_last)| $async_uploaded_part(p, is_last)))
                         .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
                 })
             }
@@ -2056,8 +5187,10 @@ macro_rules! impl_multi_parts_uploader {
                 parts: Vec<$async_uploaded_part>,
                 py: Python<'p>,
             ) -> PyResult<&'p PyAny> {
+                let registration = UploadCallbackRegistration::new(initialized.0.params())?;
                 let uploader = self.0.to_owned();
                 pyo3_asyncio::async_std::future_into_py(py, async move {
+                    let _registration = registration;
                     uploader
                         .async_complete_parts(
                             &initialized.0,
@@ -2087,7 +5220,13 @@ macro_rules! impl_multi_parts_uploader {
 /// 通过 `upload_manager.multi_parts_v1_uploader()` 创建分片上传器 V1
 #[pyclass]
 #[derive(Debug, Clone)]
-struct MultiPartsV1Uploader(qiniu_sdk::upload::MultiPartsV1Uploader);
+struct MultiPartsV1Uploader(
+    qiniu_sdk::upload::MultiPartsV1Uploader,
+    LastServer,
+    SourcePermits,
+    PartTracker,
+    ResumableRecorder,
+);
 
 impl_multi_parts_uploader!(
     MultiPartsV1Uploader,
@@ -2096,6 +5235,7 @@ impl_multi_parts_uploader!(
     MultiPartsV1UploaderUploadedPart,
     AsyncMultiPartsV1UploaderUploadedPart
 );
+impl_last_server!(MultiPartsV1Uploader);
 
 /// 分片上传器 V2
 ///
@@ -2104,7 +5244,13 @@ impl_multi_parts_uploader!(
 /// 通过 `upload_manager.multi_parts_v2_uploader()` 创建分片上传器 V2
 #[pyclass]
 #[derive(Debug, Clone)]
-struct MultiPartsV2Uploader(qiniu_sdk::upload::MultiPartsV2Uploader);
+struct MultiPartsV2Uploader(
+    qiniu_sdk::upload::MultiPartsV2Uploader,
+    LastServer,
+    SourcePermits,
+    PartTracker,
+    ResumableRecorder,
+);
 
 impl_multi_parts_uploader!(
     MultiPartsV2Uploader,
@@ -2113,6 +5259,7 @@ impl_multi_parts_uploader!(
     MultiPartsV2UploaderUploadedPart,
     AsyncMultiPartsV2UploaderUploadedPart
 );
+impl_last_server!(MultiPartsV2Uploader);
 
 macro_rules! impl_initialized_object {
     ($name:ident) => {
@@ -2148,6 +5295,14 @@ macro_rules! impl_initialized_object {
                 self.0.params().custom_vars().to_owned()
             }
 
+            /// 获取初始化分片时从断点续传记录器中恢复的分片信息，按偏移量排序
+            ///
+            /// 这些分片在之后调用 `upload_part()` 时仍可能因为数据已被修改而重新上传，重新初始化分片后将返回空列表
+            #[pyo3(text_signature = "($self)")]
+            fn resumed_parts(&self) -> Vec<UploadedPartInfo> {
+                self.2.get()
+            }
+
             fn __repr__(&self) -> String {
                 format!("{:?}", self.0)
             }
@@ -2165,6 +5320,8 @@ macro_rules! impl_initialized_object {
 #[pyclass]
 struct MultiPartsV1UploaderInitializedObject(
     <qiniu_sdk::upload::MultiPartsV1Uploader as qiniu_sdk::upload::MultiPartsUploader>::InitializedParts,
+    LastPartTracker,
+    ResumedParts,
 );
 impl_initialized_object!(MultiPartsV1UploaderInitializedObject);
 
@@ -2175,6 +5332,8 @@ impl_initialized_object!(MultiPartsV1UploaderInitializedObject);
 #[derive(Clone)]
 struct AsyncMultiPartsV1UploaderInitializedObject(
     <qiniu_sdk::upload::MultiPartsV1Uploader as qiniu_sdk::upload::MultiPartsUploader>::AsyncInitializedParts,
+    LastPartTracker,
+    ResumedParts,
 );
 impl_initialized_object!(AsyncMultiPartsV1UploaderInitializedObject);
 
@@ -2184,6 +5343,8 @@ impl_initialized_object!(AsyncMultiPartsV1UploaderInitializedObject);
 #[pyclass]
 struct MultiPartsV2UploaderInitializedObject(
     <qiniu_sdk::upload::MultiPartsV2Uploader as qiniu_sdk::upload::MultiPartsUploader>::InitializedParts,
+    LastPartTracker,
+    ResumedParts,
 );
 impl_initialized_object!(MultiPartsV2UploaderInitializedObject);
 
@@ -2194,9 +5355,144 @@ impl_initialized_object!(MultiPartsV2UploaderInitializedObject);
 #[derive(Clone)]
 struct AsyncMultiPartsV2UploaderInitializedObject(
     <qiniu_sdk::upload::MultiPartsV2Uploader as qiniu_sdk::upload::MultiPartsUploader>::AsyncInitializedParts,
+    LastPartTracker,
+    ResumedParts,
 );
 impl_initialized_object!(AsyncMultiPartsV2UploaderInitializedObject);
 
+/// 初始化分片时从断点续传记录器中恢复的分片信息
+#[derive(Debug, Clone, Default)]
+struct ResumedParts(Arc<Mutex<Vec<UploadedPartInfo>>>);
+
+impl ResumedParts {
+    /// 读取断点续传记录
+    ///
+    /// 必须在初始化分片之后调用，此时记录器中要么是已经成功恢复的记录，要么是被新建的空记录
+    fn read(
+        recorder: &ResumableRecorder,
+        source_key: Option<&qiniu_sdk::upload::SourceKey<Sha1>>,
+    ) -> Self {
+        let mut records = String::new();
+        if let Some(mut medium) =
+            source_key.and_then(|source_key| recorder.0.open_for_read(source_key).ok())
+        {
+            if medium.read_to_string(&mut records).is_err() {
+                records.clear();
+            }
+        }
+        Self::parse(&records)
+    }
+
+    async fn async_read(
+        recorder: &ResumableRecorder,
+        source_key: Option<&qiniu_sdk::upload::SourceKey<Sha1>>,
+    ) -> Self {
+        let mut records = String::new();
+        if let Some(source_key) = source_key {
+            if let Ok(mut medium) = recorder.0.open_for_async_read(source_key).await {
+                if medium.read_to_string(&mut records).await.is_err() {
+                    records.clear();
+                }
+            }
+        }
+        Self::parse(&records)
+    }
+
+    /// 第一行为记录头，之后每行为一个分片记录
+    ///
+    /// 分片上传 V1 的每个分片记录各自带有过期时间，分片上传 V2 则由记录头带有整个上传的过期时间
+    fn parse(records: &str) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        let mut lines = records.lines();
+        let header = lines
+            .next()
+            .and_then(|line| serde_json::from_str::<serde_json::Value>(line).ok());
+        let field = |name: &str| {
+            header
+                .as_ref()
+                .and_then(|header| header.get(name))
+                .and_then(|value| value.as_u64())
+        };
+        let check_record_expiry = match (field("apiver"), field("fmtver"), field("exat")) {
+            (Some(1), Some(2), _) => true,
+            (Some(2), Some(2), Some(expired_at)) if expired_at > now => false,
+            _ => return Self::default(),
+        };
+        let parts = lines
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .filter_map(|record| {
+                let offset = record.get("off")?.as_u64()?;
+                let size = NonZeroU64::new(record.get("size")?.as_u64()?)?;
+                if check_record_expiry && record.get("exat")?.as_u64()? <= now {
+                    return None;
+                }
+                Some((
+                    offset,
+                    UploadedPartInfo {
+                        size,
+                        offset,
+                        resumed: true,
+                        is_last: false,
+                    },
+                ))
+            })
+            .collect::<BTreeMap<_, _>>()
+            .into_values()
+            .collect();
+        Self(Arc::new(Mutex::new(parts)))
+    }
+
+    fn get(&self) -> Vec<UploadedPartInfo> {
+        self.0
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .to_owned()
+    }
+
+    fn clear(&self) {
+        self.0.lock().unwrap_or_else(|err| err.into_inner()).clear();
+    }
+}
+
+/// 分片是否为最后一个分片的标记
+#[derive(Debug, Clone, Default)]
+struct LastPartFlag(Arc<AtomicBool>);
+
+impl LastPartFlag {
+    fn get(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// 记录已经上传的分片中偏移量最大的分片，在 `upload_part()` 返回 `None` 时将其标记为最后一个分片
+#[derive(Debug, Clone, Default)]
+struct LastPartTracker(Arc<Mutex<Option<(u64, LastPartFlag)>>>);
+
+impl LastPartTracker {
+    fn track<P: UploadedPart>(&self, part: Option<P>) -> Option<(P, LastPartFlag)> {
+        let mut last = self.0.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(part) = part {
+            let flag = LastPartFlag::default();
+            let end = part.offset() + part.size().get();
+            if last.as_ref().map_or(true, |(last_end, _)| *last_end <= end) {
+                *last = Some((end, flag.to_owned()));
+            }
+            Some((part, flag))
+        } else {
+            if let Some((_, flag)) = last.as_ref() {
+                flag.0.store(true, Ordering::SeqCst);
+            }
+            None
+        }
+    }
+
+    fn reset(&self) {
+        *self.0.lock().unwrap_or_else(|err| err.into_inner()) = None;
+    }
+}
+
 macro_rules! impl_uploaded_part {
     ($name:ident) => {
         #[pymethods]
@@ -2219,6 +5515,15 @@ macro_rules! impl_uploaded_part {
                 self.0.resumed()
             }
 
+            /// 是否为最后一个分片
+            ///
+            /// 只有在同一个初始化的分片信息上再次调用 `upload_part()` 并返回 `None` 后，偏移量最大的分片才会被标记为最后一个分片，
+            /// 在此之前总是返回 `False`
+            #[getter]
+            fn get_is_last(&self) -> bool {
+                self.1.get()
+            }
+
             /// 获取响应体
             #[getter]
             fn get_response_body(&self) -> PyResult<PyObject> {
@@ -2241,7 +5546,7 @@ macro_rules! impl_uploaded_part {
 /// 通过 `multi_parts_uploader_v1.upload_part()` 创建
 #[pyclass]
 #[derive(Clone, Debug)]
-struct MultiPartsV1UploaderUploadedPart(<qiniu_sdk::upload::MultiPartsV1Uploader as qiniu_sdk::upload::MultiPartsUploader>::UploadedPart);
+struct MultiPartsV1UploaderUploadedPart(<qiniu_sdk::upload::MultiPartsV1Uploader as qiniu_sdk::upload::MultiPartsUploader>::UploadedPart, LastPartFlag);
 impl_uploaded_part!(MultiPartsV1UploaderUploadedPart);
 
 /// 已经通过 分片上传器 V1 异步上传的分片信息
@@ -2249,7 +5554,7 @@ impl_uploaded_part!(MultiPartsV1UploaderUploadedPart);
 /// 通过 `multi_parts_uploader_v1.async_upload_part()` 创建
 #[pyclass]
 #[derive(Clone, Debug)]
-struct AsyncMultiPartsV1UploaderUploadedPart(<qiniu_sdk::upload::MultiPartsV1Uploader as qiniu_sdk::upload::MultiPartsUploader>::AsyncUploadedPart);
+struct AsyncMultiPartsV1UploaderUploadedPart(<qiniu_sdk::upload::MultiPartsV1Uploader as qiniu_sdk::upload::MultiPartsUploader>::AsyncUploadedPart, LastPartFlag);
 impl_uploaded_part!(AsyncMultiPartsV1UploaderUploadedPart);
 
 /// 已经通过 分片上传器 V2 上传的分片信息
@@ -2257,7 +5562,7 @@ impl_uploaded_part!(AsyncMultiPartsV1UploaderUploadedPart);
 /// 通过 `multi_parts_uploader_v2.upload_part()` 创建
 #[pyclass]
 #[derive(Clone, Debug)]
-struct MultiPartsV2UploaderUploadedPart(<qiniu_sdk::upload::MultiPartsV2Uploader as qiniu_sdk::upload::MultiPartsUploader>::UploadedPart);
+struct MultiPartsV2UploaderUploadedPart(<qiniu_sdk::upload::MultiPartsV2Uploader as qiniu_sdk::upload::MultiPartsUploader>::UploadedPart, LastPartFlag);
 impl_uploaded_part!(MultiPartsV2UploaderUploadedPart);
 
 /// 已经通过 分片上传器 V2 异步上传的分片信息
@@ -2265,7 +5570,7 @@ impl_uploaded_part!(MultiPartsV2UploaderUploadedPart);
 /// 通过 `multi_parts_uploader_v2.async_upload_part()` 创建
 #[pyclass]
 #[derive(Clone, Debug)]
-struct AsyncMultiPartsV2UploaderUploadedPart(<qiniu_sdk::upload::MultiPartsV2Uploader as qiniu_sdk::upload::MultiPartsUploader>::AsyncUploadedPart);
+struct AsyncMultiPartsV2UploaderUploadedPart(<qiniu_sdk::upload::MultiPartsV2Uploader as qiniu_sdk::upload::MultiPartsUploader>::AsyncUploadedPart, LastPartFlag);
 impl_uploaded_part!(AsyncMultiPartsV2UploaderUploadedPart);
 
 /// 分片上传调度器接口
@@ -2275,7 +5580,12 @@ impl_uploaded_part!(AsyncMultiPartsV2UploaderUploadedPart);
 /// 负责分片上传的调度，包括初始化分片信息、上传分片、完成分片上传。
 #[pyclass(subclass)]
 #[derive(Debug, Clone)]
-struct MultiPartsUploaderScheduler(Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>);
+struct MultiPartsUploaderScheduler(
+    Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>,
+    LastServer,
+    SourcePermits,
+    PartTracker,
+);
 
 #[pymethods]
 impl MultiPartsUploaderScheduler {
@@ -2294,7 +5604,7 @@ impl MultiPartsUploaderScheduler {
 
     /// 上传数据源
     #[pyo3(
-        text_signature = "($self, source, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None)"
+        text_signature = "($self, source, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, guess_content_type=False, params=None)"
     )]
     #[args(
         region_provider = "None",
@@ -2302,31 +5612,47 @@ impl MultiPartsUploaderScheduler {
         file_name = "None",
         content_type = "None",
         metadata = "None",
-        custom_vars = "None"
+        custom_vars = "None",
+        guess_content_type = "false",
+        params = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     fn upload(
         &self,
         source: DataSource,
-        region_provider: Option<RegionsProvider>,
+        region_provider: Option<&PyAny>,
         object_name: Option<&str>,
         file_name: Option<&str>,
         content_type: Option<&str>,
         metadata: Option<HashMap<String, String>>,
         custom_vars: Option<HashMap<String, String>>,
+        guess_content_type: bool,
+        params: Option<ObjectParams>,
         py: Python<'_>,
     ) -> PyResult<PyObject> {
-        let object_params = make_object_params(
-            region_provider,
-            object_name,
-            file_name,
-            content_type,
-            metadata,
-            custom_vars,
-        )?;
+        let object_params = match params {
+            Some(params) => params.0,
+            None => make_object_params(
+                region_provider,
+                object_name,
+                file_name,
+                content_type,
+                metadata,
+                custom_vars,
+                guess_content_type,
+                None,
+            )?,
+        };
+        let _registration = UploadCallbackRegistration::new(&object_params)?;
         py.allow_threads(|| {
             self.0
-                .upload(source.0, object_params)
+                .upload(
+                    Box::new(PartTrackingDataSource::new(
+                        self.2.limit(source),
+                        self.3.to_owned(),
+                    )),
+                    object_params,
+                )
                 .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
                 .and_then(|v| convert_json_value_to_py_object(&v))
         })
@@ -2334,7 +5660,7 @@ impl MultiPartsUploaderScheduler {
 
     /// 异步上传数据源
     #[pyo3(
-        text_signature = "($self, source, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None)"
+        text_signature = "($self, source, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, guess_content_type=False, params=None)"
     )]
     #[args(
         region_provider = "None",
@@ -2342,32 +5668,45 @@ impl MultiPartsUploaderScheduler {
         file_name = "None",
         content_type = "None",
         metadata = "None",
-        custom_vars = "None"
+        custom_vars = "None",
+        guess_content_type = "false",
+        params = "None"
     )]
     #[allow(clippy::too_many_arguments)]
     fn async_upload<'p>(
         &'p self,
         source: AsyncDataSource,
-        region_provider: Option<RegionsProvider>,
+        region_provider: Option<&PyAny>,
         object_name: Option<&str>,
         file_name: Option<&str>,
         content_type: Option<&str>,
         metadata: Option<HashMap<String, String>>,
         custom_vars: Option<HashMap<String, String>>,
+        guess_content_type: bool,
+        params: Option<ObjectParams>,
         py: Python<'p>,
     ) -> PyResult<&'p PyAny> {
         let scheduler = self.0.to_owned();
-        let object_params = make_object_params(
-            region_provider,
-            object_name,
-            file_name,
-            content_type,
-            metadata,
-            custom_vars,
-        )?;
+        let source =
+            AsyncPartTrackingDataSource::new(self.2.async_limit(source), self.3.to_owned());
+        let object_params = match params {
+            Some(params) => params.0,
+            None => make_object_params(
+                region_provider,
+                object_name,
+                file_name,
+                content_type,
+                metadata,
+                custom_vars,
+                guess_content_type,
+                None,
+            )?,
+        };
+        let registration = UploadCallbackRegistration::new(&object_params)?;
         pyo3_asyncio::async_std::future_into_py(py, async move {
+            let _registration = registration;
             scheduler
-                .async_upload(source.0, object_params)
+                .async_upload(Box::new(source), object_params)
                 .await
                 .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
                 .and_then(|v| convert_json_value_to_py_object(&v))
@@ -2375,6 +5714,7 @@ impl MultiPartsUploaderScheduler {
     }
 }
 impl_uploader!(MultiPartsUploaderScheduler);
+impl_last_server!(MultiPartsUploaderScheduler);
 
 /// 串行分片上传调度器
 ///
@@ -2391,17 +5731,32 @@ impl SerialMultiPartsUploaderScheduler {
     /// 创建串行分片上传调度器
     #[new]
     fn new(uploader: PyObject, py: Python<'_>) -> PyResult<(Self, MultiPartsUploaderScheduler)> {
-        let scheduler = if let Ok(uploader_v1) = uploader.extract::<MultiPartsV1Uploader>(py) {
-            Box::new(qiniu_sdk::upload::SerialMultiPartsUploaderScheduler::new(
-                uploader_v1.0,
-            )) as Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>
+        let (scheduler, last_server, source_permits, part_tracker) = if let Ok(uploader_v1) =
+            uploader.extract::<MultiPartsV1Uploader>(py)
+        {
+            (
+                Box::new(qiniu_sdk::upload::SerialMultiPartsUploaderScheduler::new(
+                    uploader_v1.0,
+                )) as Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>,
+                uploader_v1.1,
+                uploader_v1.2,
+                uploader_v1.3,
+            )
         } else {
             let uploader_v2 = uploader.extract::<MultiPartsV2Uploader>(py)?;
-            Box::new(qiniu_sdk::upload::SerialMultiPartsUploaderScheduler::new(
-                uploader_v2.0,
-            )) as Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>
+            (
+                Box::new(qiniu_sdk::upload::SerialMultiPartsUploaderScheduler::new(
+                    uploader_v2.0,
+                )) as Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>,
+                uploader_v2.1,
+                uploader_v2.2,
+                uploader_v2.3,
+            )
         };
-        Ok((Self, MultiPartsUploaderScheduler(scheduler)))
+        Ok((
+            Self,
+            MultiPartsUploaderScheduler(scheduler, last_server, source_permits, part_tracker),
+        ))
     }
 }
 
@@ -2420,29 +5775,237 @@ impl ConcurrentMultiPartsUploaderScheduler {
     /// 创建串行分片上传调度器
     #[new]
     fn new(uploader: PyObject, py: Python<'_>) -> PyResult<(Self, MultiPartsUploaderScheduler)> {
-        let scheduler = if let Ok(uploader_v1) = uploader.extract::<MultiPartsV1Uploader>(py) {
-            Box::new(qiniu_sdk::upload::ConcurrentMultiPartsUploaderScheduler::new(uploader_v1.0))
-                as Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>
+        let (scheduler, last_server, source_permits, part_tracker) = if let Ok(uploader_v1) =
+            uploader.extract::<MultiPartsV1Uploader>(py)
+        {
+            (
+                Box::new(
+                    qiniu_sdk::upload::ConcurrentMultiPartsUploaderScheduler::new(uploader_v1.0),
+                ) as Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>,
+                uploader_v1.1,
+                uploader_v1.2,
+                uploader_v1.3,
+            )
         } else {
             let uploader_v2 = uploader.extract::<MultiPartsV2Uploader>(py)?;
-            Box::new(qiniu_sdk::upload::ConcurrentMultiPartsUploaderScheduler::new(uploader_v2.0))
-                as Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>
+            (
+                Box::new(
+                    qiniu_sdk::upload::ConcurrentMultiPartsUploaderScheduler::new(uploader_v2.0),
+                ) as Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>,
+                uploader_v2.1,
+                uploader_v2.2,
+                uploader_v2.3,
+            )
         };
-        Ok((Self, MultiPartsUploaderScheduler(scheduler)))
+        Ok((
+            Self,
+            MultiPartsUploaderScheduler(scheduler, last_server, source_permits, part_tracker),
+        ))
+    }
+}
+
+/// 上传回调设置
+///
+/// 由对象上传参数携带，上传凭证签发器生成上传策略时写入
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct UploadCallback {
+    urls: Vec<String>,
+    host: String,
+    body: String,
+    body_type: String,
+}
+
+/// 正在上传的对象的上传回调设置，按对象名称索引，并记录正在上传该对象的次数
+///
+/// 上传凭证签发器生成上传策略时只能得到对象名称，因此上传期间在这里登记对象参数中的上传回调设置
+static UPLOADING_CALLBACKS: Mutex<BTreeMap<Option<String>, (Option<UploadCallback>, usize)>> =
+    Mutex::new(BTreeMap::new());
+
+impl UploadCallback {
+    fn new(
+        urls: Option<Vec<String>>,
+        host: Option<String>,
+        body: Option<String>,
+        body_type: Option<String>,
+    ) -> PyResult<Option<Self>> {
+        match urls {
+            Some(urls) if urls.is_empty() => Err(QiniuInvalidCallback::new_err(
+                "callback_urls must not be empty",
+            )),
+            Some(urls) => Ok(Some(Self {
+                urls,
+                host: host.unwrap_or_default(),
+                body: body.unwrap_or_default(),
+                body_type: body_type.unwrap_or_default(),
+            })),
+            None if host.is_some() || body.is_some() || body_type.is_some() => {
+                Err(QiniuInvalidCallback::new_err(
+                    "callback_host, callback_body and callback_body_type require callback_urls",
+                ))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn uploading_callbacks(
+    ) -> MutexGuard<'static, BTreeMap<Option<String>, (Option<UploadCallback>, usize)>> {
+        UPLOADING_CALLBACKS
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// 将正在上传的对象的上传回调设置写入上传策略
+    fn apply_uploading(policy_builder: &mut qiniu_sdk::upload_token::UploadPolicyBuilder) {
+        let object_name = policy_builder.build().key().map(ToOwned::to_owned);
+        if let Some((Some(callback), _)) = Self::uploading_callbacks().get(&object_name) {
+            policy_builder.callback(
+                &callback.urls,
+                callback.host.as_str(),
+                callback.body.as_str(),
+                callback.body_type.as_str(),
+            );
+        }
+    }
+}
+
+/// 上传期间登记的上传回调设置，析构时注销
+///
+/// 同名对象并发上传时，它们的上传回调设置必须一致
+#[derive(Debug)]
+struct UploadCallbackRegistration(Option<String>);
+
+impl UploadCallbackRegistration {
+    fn new(params: &qiniu_sdk::upload::ObjectParams) -> PyResult<Self> {
+        let object_name = params.object_name().map(ToOwned::to_owned);
+        let callback = params.extensions().get::<UploadCallback>();
+        let mut callbacks = UploadCallback::uploading_callbacks();
+        match callbacks.get_mut(&object_name) {
+            Some((registered, count)) if registered.as_ref() == callback => *count += 1,
+            Some(_) => {
+                return Err(QiniuInvalidCallback::new_err(format!(
+                    "object {:?} is being uploaded with different callback settings",
+                    object_name
+                )));
+            }
+            None => {
+                callbacks.insert(object_name.to_owned(), (callback.cloned(), 1));
+            }
+        }
+        Ok(Self(object_name))
+    }
+}
+
+impl Drop for UploadCallbackRegistration {
+    fn drop(&mut self) {
+        let mut callbacks = UploadCallback::uploading_callbacks();
+        if let Some((_, count)) = callbacks.get_mut(&self.0) {
+            *count -= 1;
+            if *count == 0 {
+                callbacks.remove(&self.0);
+            }
+        }
+    }
+}
+
+/// 对象上传参数
+///
+/// 通过 `ObjectParams(region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, guess_content_type=False, callback_urls=None, callback_host=None, callback_body=None, callback_body_type=None)` 创建对象上传参数
+///
+/// 创建后可以通过 `params` 参数传给上传器的各个上传方法重复使用，此时这些方法的其他对象参数将被忽略
+///
+/// 如果传入 `callback_urls`，上传时生成的上传策略将包含上传回调设置，`callback_host`、`callback_body` 和 `callback_body_type` 仅在此时可用，
+/// 上传成功后七牛云将回调业务服务器，上传结果即为业务服务器的回调响应体。
+/// 上传回调设置仅对 `UploadTokenSigner.new_credential_provider()` 创建的上传凭证签发器有效，同名对象并发上传时它们的上传回调设置必须一致
+#[pyclass]
+#[derive(Debug, Clone)]
+#[pyo3(
+    text_signature = "(/, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, guess_content_type=False, callback_urls=None, callback_host=None, callback_body=None, callback_body_type=None)"
+)]
+struct ObjectParams(qiniu_sdk::upload::ObjectParams);
+
+#[pymethods]
+impl ObjectParams {
+    #[new]
+    #[args(
+        region_provider = "None",
+        object_name = "None",
+        file_name = "None",
+        content_type = "None",
+        metadata = "None",
+        custom_vars = "None",
+        guess_content_type = "false",
+        callback_urls = "None",
+        callback_host = "None",
+        callback_body = "None",
+        callback_body_type = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        region_provider: Option<&PyAny>,
+        object_name: Option<&str>,
+        file_name: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        custom_vars: Option<HashMap<String, String>>,
+        guess_content_type: bool,
+        callback_urls: Option<Vec<String>>,
+        callback_host: Option<String>,
+        callback_body: Option<String>,
+        callback_body_type: Option<String>,
+    ) -> PyResult<Self> {
+        make_object_params(
+            region_provider,
+            object_name,
+            file_name,
+            content_type,
+            metadata,
+            custom_vars,
+            guess_content_type,
+            UploadCallback::new(
+                callback_urls,
+                callback_host,
+                callback_body,
+                callback_body_type,
+            )?,
+        )
+        .map(Self)
+    }
+
+    /// 对象名称
+    #[getter]
+    fn get_object_name(&self) -> Option<&str> {
+        self.0.object_name()
+    }
+
+    /// 文件名称
+    #[getter]
+    fn get_file_name(&self) -> Option<&str> {
+        self.0.file_name()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn make_object_params(
-    region_provider: Option<RegionsProvider>,
+    region_provider: Option<&PyAny>,
     object_name: Option<&str>,
     file_name: Option<&str>,
     content_type: Option<&str>,
     metadata: Option<HashMap<String, String>>,
     custom_vars: Option<HashMap<String, String>>,
+    guess_content_type: bool,
+    callback: Option<UploadCallback>,
 ) -> PyResult<qiniu_sdk::upload::ObjectParams> {
     let mut builder = qiniu_sdk::upload::ObjectParams::builder();
     if let Some(region_provider) = region_provider {
-        builder.region_provider(region_provider);
+        builder.region_provider(extract_regions_provider(region_provider)?);
     }
     if let Some(object_name) = object_name {
         builder.object_name(object_name);
@@ -2452,24 +6015,165 @@ fn make_object_params(
     }
     if let Some(content_type) = content_type {
         builder.content_type(parse_mime(content_type)?);
+    } else if guess_content_type {
+        if let Some(content_type) = guess_mime(&[file_name, object_name]) {
+            builder.content_type(content_type);
+        }
     }
     if let Some(metadata) = metadata {
         builder.metadata(metadata);
     }
-    if let Some(custom_vars) = custom_vars {
-        builder.custom_vars(custom_vars);
+    if let Some(custom_vars) = custom_vars {
+        builder.custom_vars(custom_vars);
+    }
+    if let Some(callback) = callback {
+        builder.insert_extension(callback);
+    }
+    Ok(builder.build())
+}
+
+/// 上传操作的截止时间
+///
+/// 超过截止时间后，上传器将在下一次发送请求或是汇报上传进度时中止上传，已经上传的分片仍然会被记录，因此可以在之后断点续传
+///
+/// 异步上传时，截止时间从上传任务开始执行时计算
+#[derive(Debug, Clone, Copy)]
+struct OperationDeadline(Option<Instant>);
+
+impl OperationDeadline {
+    fn new(timeout_secs: Option<u64>) -> Self {
+        Self(timeout_secs.map(|secs| instant_after(Duration::from_secs(secs))))
+    }
+
+    fn is_exceeded(&self) -> bool {
+        matches!(self.0, Some(deadline) if Instant::now() >= deadline)
+    }
+
+    fn make_uploader(
+        &self,
+        uploader: &qiniu_sdk::upload::AutoUploader,
+    ) -> qiniu_sdk::upload::AutoUploader {
+        let mut uploader = uploader.to_owned();
+        if self.0.is_some() {
+            let deadline = *self;
+            uploader.on_before_request(move |_| deadline.check());
+            uploader.on_upload_progress(move |_| deadline.check());
+        }
+        uploader
+    }
+
+    fn check(&self) -> AnyResult<()> {
+        if self.is_exceeded() {
+            Err(IoError::new(IoErrorKind::TimedOut, "upload operation timed out").into())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn convert_error(&self, err: qiniu_sdk::http_client::ResponseError) -> PyErr {
+        if self.is_exceeded() {
+            QiniuTimeoutError::new_err(format!("upload operation timed out: {}", err))
+        } else {
+            QiniuApiCallError::from_err(MaybeOwned::Owned(err))
+        }
+    }
+
+    async fn race<T>(
+        &self,
+        fut: impl Future<Output = qiniu_sdk::http_client::ApiResult<T>>,
+    ) -> PyResult<T> {
+        let result = if let Some(deadline) = self.0 {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            async_std::future::timeout(remaining, fut)
+                .await
+                .map_err(|_| QiniuTimeoutError::new_err("upload operation timed out"))?
+        } else {
+            fut.await
+        };
+        result.map_err(|err| self.convert_error(err))
+    }
+}
+
+/// 比对本地计算得到的 Etag 与上传结果中的 `hash` 字段，如果上传结果中没有 `hash` 字段则跳过校验
+fn check_uploaded_etag(local_etag: &str, value: &serde_json::Value) -> PyResult<()> {
+    match value.get("hash").and_then(|hash| hash.as_str()) {
+        Some(hash) if hash != local_etag => Err(QiniuEtagMismatchError::new_err(format!(
+            "Etag mismatch: local etag is {}, but uploaded object hash is {}",
+            local_etag, hash
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// 记录实际上传的各个分片的偏移量和大小
+///
+/// 分片大小由分片大小提供者决定，并经过 SDK 按照分片上传协议的限制调整，因此只能在上传过程中记录
+#[derive(Debug, Clone, Default)]
+struct UploadedPartSizes(Arc<Mutex<BTreeMap<u64, u64>>>);
+
+impl UploadedPartSizes {
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<u64, u64>> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn record(&self, uploader: &mut qiniu_sdk::upload::AutoUploader) {
+        let part_sizes = self.to_owned();
+        uploader.on_part_uploaded(move |part| {
+            part_sizes.lock().insert(part.offset(), part.size().get());
+            Ok(())
+        });
+    }
+
+    /// 如果上传结果中的 `hash` 为 Etag V2，则返回按照偏移量排序的分片大小列表，用于计算本地数据的 Etag V2
+    fn etag_parts(&self, value: &serde_json::Value) -> Option<Vec<usize>> {
+        value
+            .get("hash")
+            .and_then(|hash| hash.as_str())
+            .filter(|hash| is_etag_v2(hash))?;
+        Some(self.lock().values().map(|&size| size as usize).collect())
+    }
+}
+
+fn local_etag_of(reader: impl Read, parts: Option<&[usize]>) -> IoResult<String> {
+    match parts {
+        Some(parts) => qiniu_sdk::etag::etag_with_parts(reader, parts),
+        None => qiniu_sdk::etag::etag_of(reader),
+    }
+}
+
+async fn async_local_etag_of(
+    reader: impl AsyncRead + Unpin,
+    parts: Option<&[usize]>,
+) -> IoResult<String> {
+    match parts {
+        Some(parts) => qiniu_sdk::etag::async_etag_with_parts(reader, parts).await,
+        None => qiniu_sdk::etag::async_etag_of(reader).await,
     }
-    Ok(builder.build())
+}
+
+fn is_etag_v2(hash: &str) -> bool {
+    qiniu_sdk::utils::base64::decode(hash.as_bytes())
+        .map_or(false, |decoded| decoded.first() == Some(&0x9e))
+}
+
+/// 依次根据文件名称和对象名称的扩展名猜测 MIME 类型
+fn guess_mime(names: &[Option<&str>]) -> Option<qiniu_sdk::http_client::mime::Mime> {
+    names
+        .iter()
+        .flatten()
+        .find_map(|name| mime_guess::from_path(name).first())
 }
 
 fn make_reinitialize_options(
     keep_original_region: Option<bool>,
     refresh_regions: Option<bool>,
-    region_provider: Option<RegionsProvider>,
-) -> qiniu_sdk::upload::ReinitializeOptions {
+    region_provider: Option<&PyAny>,
+) -> PyResult<qiniu_sdk::upload::ReinitializeOptions> {
     let mut builder = qiniu_sdk::upload::ReinitializeOptions::builder();
     if let Some(region_provider) = region_provider {
-        builder.regions_provider(region_provider);
+        builder.regions_provider(extract_regions_provider(region_provider)?);
     }
     if let Some(true) = refresh_regions {
         builder.refresh_regions();
@@ -2477,7 +6181,7 @@ fn make_reinitialize_options(
     if let Some(true) = keep_original_region {
         builder.keep_original_region();
     }
-    builder.build()
+    Ok(builder.build())
 }
 
 fn on_before_request(
@@ -2525,6 +6229,16 @@ impl UploadingProgressInfo {
         self.0.total_bytes()
     }
 
+    /// 获取上传进度百分比
+    ///
+    /// 范围为 `0` 到 `100`，如果总共需要传输的数据量未知或为 `0`，则返回 `None`
+    #[getter]
+    fn get_percentage(&self) -> Option<f64> {
+        self.0
+            .total_bytes()
+            .and_then(|total_bytes| calculate_percentage(self.0.transferred_bytes(), total_bytes))
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self)
     }
@@ -2542,13 +6256,14 @@ impl ToPyObject for UploadingProgressInfo {
 
 /// 已经上传的分片信息
 ///
-/// 该类型没有构造函数，仅限于在回调函数中使用
+/// 该类型没有构造函数，仅限于在回调函数中使用，或是通过初始化的分片信息的 `resumed_parts()` 获取
 #[pyclass]
 #[derive(Clone, Copy, Debug)]
 struct UploadedPartInfo {
     size: NonZeroU64,
     offset: u64,
     resumed: bool,
+    is_last: bool,
 }
 
 #[pymethods]
@@ -2568,6 +6283,15 @@ impl UploadedPartInfo {
         self.resumed
     }
 
+    /// 是否为最后一个分片
+    ///
+    /// 由于回调函数在后续的 `upload_part()` 返回 `None` 之前就被调用，
+    /// 因此只有在数据源大小已知，且该分片到达数据源末尾时才返回 `True`，否则总是返回 `False`
+    #[getter]
+    fn get_is_last(&self) -> bool {
+        self.is_last
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self)
     }
@@ -2579,8 +6303,15 @@ impl UploadedPartInfo {
 
 fn on_upload_progress(
     callback: PyObject,
+    progress_interval_ms: Option<u64>,
 ) -> impl Fn(&qiniu_sdk::upload::UploadingProgressInfo) -> AnyResult<()> + Send + Sync + 'static {
+    let throttle = progress_interval_ms.map(ProgressThrottle::new);
     move |progress| {
+        if let Some(throttle) = &throttle {
+            if !throttle.should_emit(progress.transferred_bytes(), progress.total_bytes()) {
+                return Ok(());
+            }
+        }
         Python::with_gil(|py| {
             callback.call1(
                 py,
@@ -2619,18 +6350,285 @@ fn on_error(
 
 fn on_part_uploaded(
     callback: PyObject,
+    tracker: Option<PartTracker>,
 ) -> impl Fn(&dyn UploadedPart) -> AnyResult<()> + Send + Sync + 'static {
     move |part| {
+        let is_last = tracker
+            .as_ref()
+            .and_then(|tracker| tracker.current())
+            .map_or(false, |uploading| {
+                uploading.is_last && uploading.offset == part.offset()
+            });
         let part = UploadedPartInfo {
             size: part.size(),
             offset: part.offset(),
             resumed: part.resumed(),
+            is_last,
         };
         Python::with_gil(|py| callback.call1(py, (part,)))?;
         Ok(())
     }
 }
 
+/// 服务器 IP 地址与端口
+type ServerAddr = (IpAddr, Option<NonZeroU16>);
+
+/// 记录最近一次成功响应的服务器地址
+#[derive(Debug, Clone, Default)]
+struct LastServer(Arc<Mutex<Option<ServerAddr>>>);
+
+impl LastServer {
+    fn recorder(
+        &self,
+    ) -> impl Fn(&mut qiniu_sdk::http::ResponseParts) -> AnyResult<()> + Send + Sync + 'static {
+        let last_server = self.to_owned();
+        move |parts| {
+            if let Some(server_ip) = parts.server_ip() {
+                *last_server.lock() = Some((server_ip, parts.server_port()));
+            }
+            Ok(())
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<(IpAddr, Option<NonZeroU16>)>> {
+        self.0.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn server_ip(&self) -> Option<String> {
+        self.lock().map(|(ip, _)| ip.to_string())
+    }
+
+    fn server_port(&self) -> Option<u16> {
+        self.lock()
+            .and_then(|(_, port)| port)
+            .map(|port| port.get())
+    }
+
+    fn endpoint(&self) -> Option<String> {
+        self.lock().map(|(ip, port)| match (ip, port) {
+            (IpAddr::V4(ip), Some(port)) => format!("{}:{}", ip, port),
+            (IpAddr::V6(ip), Some(port)) => format!("[{}]:{}", ip, port),
+            (ip, None) => ip.to_string(),
+        })
+    }
+}
+
+fn on_part_upload_error(
+    callback: PyObject,
+    tracker: PartTracker,
+) -> impl Fn(&mut qiniu_sdk::http_client::ResponseError) -> AnyResult<()> + Send + Sync + 'static {
+    move |error| {
+        let part = tracker.retry();
+        #[allow(unsafe_code)]
+        let error: &'static qiniu_sdk::http_client::ResponseError = unsafe { transmute(error) };
+        let error = QiniuApiCallError::from_err(MaybeOwned::Borrowed(error));
+        let error = convert_api_call_error(&error)?;
+        Python::with_gil(|py| {
+            callback.call1(
+                py,
+                (
+                    part.map(|part| part.offset),
+                    part.and_then(|part| part.size),
+                    error,
+                    part.map_or(0, |part| part.retried),
+                ),
+            )
+        })?;
+        Ok(())
+    }
+}
+
+fn on_part_upload_finished(
+    tracker: PartTracker,
+) -> impl Fn(&dyn UploadedPart) -> AnyResult<()> + Send + Sync + 'static {
+    move |_| {
+        tracker.finish();
+        Ok(())
+    }
+}
+
+/// 上传分片的线程或异步任务
+///
+/// 分片调度器总是在同一个线程或异步任务中切片并上传该分片，因此可以据此找到出错的分片
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum PartKey {
+    Thread(thread::ThreadId),
+    Task(async_std::task::TaskId),
+}
+
+impl PartKey {
+    fn current() -> Self {
+        match async_std::task::try_current() {
+            Some(task) => Self::Task(task.id()),
+            None => Self::Thread(thread::current().id()),
+        }
+    }
+}
+
+/// 记录分片上传器正在上传的分片，由同一个上传器创建的分片调度器共享
+#[derive(Debug, Clone, Default)]
+struct PartTracker(Arc<Mutex<HashMap<PartKey, UploadingPart>>>);
+
+impl PartTracker {
+    fn lock(&self) -> MutexGuard<'_, HashMap<PartKey, UploadingPart>> {
+        self.0.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// 记录新的切片，数据源大小未知时无法确定分片的实际大小
+    fn track(
+        &self,
+        cursor: &mut SliceCursor,
+        size: qiniu_sdk::upload::PartSize,
+        total_size: Option<u64>,
+    ) {
+        let requested_size = size.as_u64();
+        let size = total_size
+            .map(|total_size| requested_size.min(total_size.saturating_sub(cursor.offset)));
+        let (_, offset) = cursor.advance(size.unwrap_or(requested_size));
+        let is_last = matches!(
+            (size, total_size),
+            (Some(size), Some(total_size)) if offset + size >= total_size
+        );
+        self.lock().insert(
+            PartKey::current(),
+            UploadingPart {
+                offset,
+                size,
+                retried: 0,
+                is_last,
+            },
+        );
+    }
+
+    fn current(&self) -> Option<UploadingPart> {
+        self.lock().get(&PartKey::current()).copied()
+    }
+
+    /// 记录当前分片失败一次，返回失败前的分片信息
+    fn retry(&self) -> Option<UploadingPart> {
+        self.lock().get_mut(&PartKey::current()).map(|part| {
+            let current = *part;
+            part.retried += 1;
+            current
+        })
+    }
+
+    fn finish(&self) {
+        self.lock().remove(&PartKey::current());
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct UploadingPart {
+    offset: u64,
+    size: Option<u64>,
+    retried: usize,
+    is_last: bool,
+}
+
+/// 记录每个切片在数据源中的位置，供 `part_upload_error` 和 `part_uploaded` 回调使用
+#[derive(Debug, Clone)]
+struct PartTrackingDataSource {
+    source: DataSource,
+    tracker: PartTracker,
+    cursor: Arc<Mutex<SliceCursor>>,
+}
+
+impl PartTrackingDataSource {
+    fn new(source: DataSource, tracker: PartTracker) -> Self {
+        Self {
+            source,
+            tracker,
+            cursor: Default::default(),
+        }
+    }
+}
+
+impl qiniu_sdk::upload::DataSource<Sha1> for PartTrackingDataSource {
+    fn slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> IoResult<Option<qiniu_sdk::upload::DataSourceReader>> {
+        let mut cursor = self.cursor.lock().unwrap();
+        let reader = qiniu_sdk::upload::DataSource::slice(&self.source, size)?;
+        if reader.is_some() {
+            let total_size = qiniu_sdk::upload::DataSource::total_size(&self.source)?;
+            self.tracker.track(&mut cursor, size, total_size);
+        } else {
+            self.tracker.finish();
+        }
+        Ok(reader)
+    }
+
+    fn reset(&self) -> IoResult<()> {
+        *self.cursor.lock().unwrap() = Default::default();
+        qiniu_sdk::upload::DataSource::reset(&self.source)
+    }
+
+    fn source_key(&self) -> IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
+        qiniu_sdk::upload::DataSource::source_key(&self.source)
+    }
+
+    fn total_size(&self) -> IoResult<Option<u64>> {
+        qiniu_sdk::upload::DataSource::total_size(&self.source)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AsyncPartTrackingDataSource {
+    source: AsyncDataSource,
+    tracker: PartTracker,
+    cursor: Arc<AsyncMutex<SliceCursor>>,
+}
+
+impl AsyncPartTrackingDataSource {
+    fn new(source: AsyncDataSource, tracker: PartTracker) -> Self {
+        Self {
+            source,
+            tracker,
+            cursor: Default::default(),
+        }
+    }
+}
+
+impl qiniu_sdk::upload::AsyncDataSource<Sha1> for AsyncPartTrackingDataSource {
+    fn slice(
+        &self,
+        size: qiniu_sdk::upload::PartSize,
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::AsyncDataSourceReader>>>
+    {
+        Box::pin(async move {
+            let mut cursor = self.cursor.lock().await;
+            let reader = qiniu_sdk::upload::AsyncDataSource::slice(&self.source, size).await?;
+            if reader.is_some() {
+                let total_size =
+                    qiniu_sdk::upload::AsyncDataSource::total_size(&self.source).await?;
+                self.tracker.track(&mut cursor, size, total_size);
+            } else {
+                self.tracker.finish();
+            }
+            Ok(reader)
+        })
+    }
+
+    fn reset(&self) -> futures::future::BoxFuture<IoResult<()>> {
+        Box::pin(async move {
+            *self.cursor.lock().await = Default::default();
+            qiniu_sdk::upload::AsyncDataSource::reset(&self.source).await
+        })
+    }
+
+    fn source_key(
+        &self,
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>>> {
+        qiniu_sdk::upload::AsyncDataSource::source_key(&self.source)
+    }
+
+    fn total_size(&self) -> futures::future::BoxFuture<IoResult<Option<u64>>> {
+        qiniu_sdk::upload::AsyncDataSource::total_size(&self.source)
+    }
+}
+
 /// 期望的分片上传调度器
 #[pyclass]
 #[derive(Debug, Clone)]
@@ -2722,21 +6720,391 @@ impl From<MultiPartsUploaderPrefer> for qiniu_sdk::upload::MultiPartsUploaderPre
             MultiPartsUploaderPrefer::V2 => qiniu_sdk::upload::MultiPartsUploaderPrefer::V2,
         }
     }
-}
+}
+
+/// 自动上传器
+///
+/// 使用设置的各种提供者，将文件或是二进制流数据上传。
+///
+/// 上传时如果传入 `verify_etag=True`，将在上传完成后计算本地数据的 Etag，并与上传结果中的 `hash` 字段比对，不一致时抛出 `QiniuEtagMismatchError`。
+/// 如果上传结果中的 `hash` 为 Etag V2，将使用实际上传的分片大小计算本地数据的 Etag V2，否则计算 Etag V1。
+/// 如果数据流无法 seek，或是上传结果中没有 `hash` 字段，则跳过校验。
+///
+/// 上传时如果传入 `operation_timeout_secs`，整个上传操作超过该时长后将中止剩余分片的上传并抛出 `QiniuTimeoutError`，已经上传的分片仍会被记录，之后可以断点续传。
+///
+/// 上传时如果传入 `typed=True`，将返回 `UploadResult` 而不是字典。
+///
+/// 通过 `upload_manager.auto_uploader()` 创建自动上传器
+#[pyclass]
+#[derive(Debug, Clone)]
+struct AutoUploader(
+    qiniu_sdk::upload::AutoUploader,
+    LastServer,
+    SourcePermits,
+    Option<RegionsProvider>,
+);
+
+impl_last_server!(AutoUploader);
+
+#[pymethods]
+impl AutoUploader {
+    #[pyo3(
+        text_signature = "($self, path, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None, guess_content_type=False, verify_etag=False, operation_timeout_secs=None, typed=False, params=None)"
+    )]
+    #[args(
+        region_provider = "None",
+        object_name = "None",
+        file_name = "None",
+        content_type = "None",
+        metadata = "None",
+        custom_vars = "None",
+        multi_parts_uploader_scheduler_prefer = "None",
+        single_part_uploader_prefer = "None",
+        multi_parts_uploader_prefer = "None",
+        guess_content_type = "false",
+        verify_etag = "false",
+        operation_timeout_secs = "None",
+        typed = "false",
+        params = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_path(
+        &self,
+        path: &str,
+        region_provider: Option<&PyAny>,
+        object_name: Option<&str>,
+        file_name: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        custom_vars: Option<HashMap<String, String>>,
+        multi_parts_uploader_scheduler_prefer: Option<MultiPartsUploaderSchedulerPrefer>,
+        single_part_uploader_prefer: Option<SinglePartUploaderPrefer>,
+        multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
+        guess_content_type: bool,
+        verify_etag: bool,
+        operation_timeout_secs: Option<u64>,
+        typed: bool,
+        params: Option<AutoUploaderObjectParams>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let object_params = match params {
+            Some(params) => params.0,
+            None => make_auto_uploader_object_params(
+                region_provider,
+                self.3.as_ref(),
+                object_name,
+                file_name,
+                content_type,
+                metadata,
+                custom_vars,
+                guess_content_type,
+                multi_parts_uploader_scheduler_prefer,
+                single_part_uploader_prefer,
+                multi_parts_uploader_prefer,
+                None,
+            )?,
+        };
+        let _registration = UploadCallbackRegistration::new(&object_params)?;
+        let deadline = OperationDeadline::new(operation_timeout_secs);
+        py.allow_threads(|| {
+            let _permit = self.2.acquire();
+            let mut uploader = deadline.make_uploader(&self.0);
+            let part_sizes = verify_etag.then(|| {
+                let part_sizes = UploadedPartSizes::default();
+                part_sizes.record(&mut uploader);
+                part_sizes
+            });
+            let value = uploader
+                .upload_path(path, object_params)
+                .map_err(|err| deadline.convert_error(err))?;
+            if let Some(part_sizes) = part_sizes {
+                let parts = part_sizes.etag_parts(&value);
+                let local_etag = File::open(path)
+                    .and_then(|file| local_etag_of(file, parts.as_deref()))
+                    .map_err(QiniuIoError::from_err)?;
+                check_uploaded_etag(&local_etag, &value)?;
+            }
+            convert_upload_response(value, typed)
+        })
+    }
+
+    #[pyo3(
+        text_signature = "($self, reader, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None, guess_content_type=False, verify_etag=False, operation_timeout_secs=None, typed=False, params=None)"
+    )]
+    #[args(
+        region_provider = "None",
+        object_name = "None",
+        file_name = "None",
+        content_type = "None",
+        metadata = "None",
+        custom_vars = "None",
+        multi_parts_uploader_scheduler_prefer = "None",
+        single_part_uploader_prefer = "None",
+        multi_parts_uploader_prefer = "None",
+        guess_content_type = "false",
+        verify_etag = "false",
+        operation_timeout_secs = "None",
+        typed = "false",
+        params = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_reader(
+        &self,
+        reader: PyObject,
+        region_provider: Option<&PyAny>,
+        object_name: Option<&str>,
+        file_name: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        custom_vars: Option<HashMap<String, String>>,
+        multi_parts_uploader_scheduler_prefer: Option<MultiPartsUploaderSchedulerPrefer>,
+        single_part_uploader_prefer: Option<SinglePartUploaderPrefer>,
+        multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
+        guess_content_type: bool,
+        verify_etag: bool,
+        operation_timeout_secs: Option<u64>,
+        typed: bool,
+        params: Option<AutoUploaderObjectParams>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let object_params = match params {
+            Some(params) => params.0,
+            None => make_auto_uploader_object_params(
+                region_provider,
+                self.3.as_ref(),
+                object_name,
+                file_name,
+                content_type,
+                metadata,
+                custom_vars,
+                guess_content_type,
+                multi_parts_uploader_scheduler_prefer,
+                single_part_uploader_prefer,
+                multi_parts_uploader_prefer,
+                None,
+            )?,
+        };
+        let _registration = UploadCallbackRegistration::new(&object_params)?;
+        let etag_reader = if verify_etag {
+            let mut etag_reader = PythonIoBase::new(reader.clone_ref(py));
+            etag_reader
+                .stream_position()
+                .ok()
+                .map(|position| (etag_reader, position))
+        } else {
+            None
+        };
+        let deadline = OperationDeadline::new(operation_timeout_secs);
+        py.allow_threads(|| {
+            let mut uploader = deadline.make_uploader(&self.0);
+            let part_sizes = UploadedPartSizes::default();
+            if etag_reader.is_some() {
+                part_sizes.record(&mut uploader);
+            }
+            let value = uploader
+                .upload_reader(PythonIoBase::new(reader), object_params)
+                .map_err(|err| deadline.convert_error(err))?;
+            if let Some((mut etag_reader, position)) = etag_reader {
+                let parts = part_sizes.etag_parts(&value);
+                let local_etag = etag_reader
+                    .seek(SeekFrom::Start(position))
+                    .and_then(|_| local_etag_of(etag_reader, parts.as_deref()))
+                    .map_err(QiniuIoError::from_err)?;
+                check_uploaded_etag(&local_etag, &value)?;
+            }
+            convert_upload_response(value, typed)
+        })
+    }
+
+    #[pyo3(
+        text_signature = "($self, path, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None, guess_content_type=False, verify_etag=False, operation_timeout_secs=None, typed=False, params=None)"
+    )]
+    #[args(
+        region_provider = "None",
+        object_name = "None",
+        file_name = "None",
+        content_type = "None",
+        metadata = "None",
+        custom_vars = "None",
+        multi_parts_uploader_scheduler_prefer = "None",
+        single_part_uploader_prefer = "None",
+        multi_parts_uploader_prefer = "None",
+        guess_content_type = "false",
+        verify_etag = "false",
+        operation_timeout_secs = "None",
+        typed = "false",
+        params = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn async_upload_path<'p>(
+        &self,
+        path: String,
+        region_provider: Option<&PyAny>,
+        object_name: Option<&str>,
+        file_name: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        custom_vars: Option<HashMap<String, String>>,
+        multi_parts_uploader_scheduler_prefer: Option<MultiPartsUploaderSchedulerPrefer>,
+        single_part_uploader_prefer: Option<SinglePartUploaderPrefer>,
+        multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
+        guess_content_type: bool,
+        verify_etag: bool,
+        operation_timeout_secs: Option<u64>,
+        typed: bool,
+        params: Option<AutoUploaderObjectParams>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let object_params = match params {
+            Some(params) => params.0,
+            None => make_auto_uploader_object_params(
+                region_provider,
+                self.3.as_ref(),
+                object_name,
+                file_name,
+                content_type,
+                metadata,
+                custom_vars,
+                guess_content_type,
+                multi_parts_uploader_scheduler_prefer,
+                single_part_uploader_prefer,
+                multi_parts_uploader_prefer,
+                None,
+            )?,
+        };
+        let registration = UploadCallbackRegistration::new(&object_params)?;
+        let uploader = self.0.to_owned();
+        let source_permits = self.2.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let _registration = registration;
+            let deadline = OperationDeadline::new(operation_timeout_secs);
+            let mut uploader = deadline.make_uploader(&uploader);
+            let part_sizes = verify_etag.then(|| {
+                let part_sizes = UploadedPartSizes::default();
+                part_sizes.record(&mut uploader);
+                part_sizes
+            });
+            let _permit = source_permits.async_acquire().await;
+            let value = deadline
+                .race(uploader.async_upload_path(&path, object_params))
+                .await?;
+            if let Some(part_sizes) = part_sizes {
+                let parts = part_sizes.etag_parts(&value);
+                let file = async_std::fs::File::open(&path)
+                    .await
+                    .map_err(QiniuIoError::from_err)?;
+                let local_etag = async_local_etag_of(file, parts.as_deref())
+                    .await
+                    .map_err(QiniuIoError::from_err)?;
+                check_uploaded_etag(&local_etag, &value)?;
+            }
+            convert_upload_response(value, typed)
+        })
+    }
 
-/// 自动上传器
-///
-/// 使用设置的各种提供者，将文件或是二进制流数据上传。
-///
-/// 通过 `upload_manager.auto_uploader()` 创建自动上传器
-#[pyclass]
-#[derive(Debug, Clone)]
-struct AutoUploader(qiniu_sdk::upload::AutoUploader);
+    #[pyo3(
+        text_signature = "($self, reader, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None, guess_content_type=False, verify_etag=False, operation_timeout_secs=None, typed=False, params=None)"
+    )]
+    #[args(
+        region_provider = "None",
+        object_name = "None",
+        file_name = "None",
+        content_type = "None",
+        metadata = "None",
+        custom_vars = "None",
+        multi_parts_uploader_scheduler_prefer = "None",
+        single_part_uploader_prefer = "None",
+        multi_parts_uploader_prefer = "None",
+        guess_content_type = "false",
+        verify_etag = "false",
+        operation_timeout_secs = "None",
+        typed = "false",
+        params = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn async_upload_reader<'p>(
+        &self,
+        reader: PyObject,
+        region_provider: Option<&PyAny>,
+        object_name: Option<&str>,
+        file_name: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        custom_vars: Option<HashMap<String, String>>,
+        multi_parts_uploader_scheduler_prefer: Option<MultiPartsUploaderSchedulerPrefer>,
+        single_part_uploader_prefer: Option<SinglePartUploaderPrefer>,
+        multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
+        guess_content_type: bool,
+        verify_etag: bool,
+        operation_timeout_secs: Option<u64>,
+        typed: bool,
+        params: Option<AutoUploaderObjectParams>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let object_params = match params {
+            Some(params) => params.0,
+            None => make_auto_uploader_object_params(
+                region_provider,
+                self.3.as_ref(),
+                object_name,
+                file_name,
+                content_type,
+                metadata,
+                custom_vars,
+                guess_content_type,
+                multi_parts_uploader_scheduler_prefer,
+                single_part_uploader_prefer,
+                multi_parts_uploader_prefer,
+                None,
+            )?,
+        };
+        let registration = UploadCallbackRegistration::new(&object_params)?;
+        let uploader = self.0.to_owned();
+        let etag_reader = verify_etag.then(|| reader.clone_ref(py));
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let _registration = registration;
+            let deadline = OperationDeadline::new(operation_timeout_secs);
+            let mut uploader = deadline.make_uploader(&uploader);
+            let etag_reader = if let Some(etag_reader) = etag_reader {
+                let mut etag_reader = PythonIoBase::new(etag_reader).into_async_read();
+                etag_reader
+                    .seek(SeekFrom::Current(0))
+                    .await
+                    .ok()
+                    .map(|position| (etag_reader, position))
+            } else {
+                None
+            };
+            let part_sizes = UploadedPartSizes::default();
+            if etag_reader.is_some() {
+                part_sizes.record(&mut uploader);
+            }
+            let value = deadline
+                .race(uploader.async_upload_reader(
+                    PythonIoBase::new(reader).into_async_read(),
+                    object_params,
+                ))
+                .await?;
+            if let Some((mut etag_reader, position)) = etag_reader {
+                let parts = part_sizes.etag_parts(&value);
+                etag_reader
+                    .seek(SeekFrom::Start(position))
+                    .await
+                    .map_err(QiniuIoError::from_err)?;
+                let local_etag = async_local_etag_of(etag_reader, parts.as_deref())
+                    .await
+                    .map_err(QiniuIoError::from_err)?;
+                check_uploaded_etag(&local_etag, &value)?;
+            }
+            convert_upload_response(value, typed)
+        })
+    }
 
-#[pymethods]
-impl AutoUploader {
+    /// 上传文件，失败时不抛出异常
+    ///
+    /// 参数与 `upload_path()` 相同，返回 `TryUploadResult`，失败原因记录在其 `error` 属性中
     #[pyo3(
-        text_signature = "($self, path, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None)"
+        text_signature = "($self, path, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None, guess_content_type=False, verify_etag=False, operation_timeout_secs=None, params=None)"
     )]
     #[args(
         region_provider = "None",
@@ -2747,13 +7115,17 @@ impl AutoUploader {
         custom_vars = "None",
         multi_parts_uploader_scheduler_prefer = "None",
         single_part_uploader_prefer = "None",
-        multi_parts_uploader_prefer = "None"
+        multi_parts_uploader_prefer = "None",
+        guess_content_type = "false",
+        verify_etag = "false",
+        operation_timeout_secs = "None",
+        params = "None"
     )]
     #[allow(clippy::too_many_arguments)]
-    fn upload_path(
+    fn try_upload_path(
         &self,
         path: &str,
-        region_provider: Option<RegionsProvider>,
+        region_provider: Option<&PyAny>,
         object_name: Option<&str>,
         file_name: Option<&str>,
         content_type: Option<&str>,
@@ -2762,9 +7134,14 @@ impl AutoUploader {
         multi_parts_uploader_scheduler_prefer: Option<MultiPartsUploaderSchedulerPrefer>,
         single_part_uploader_prefer: Option<SinglePartUploaderPrefer>,
         multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
+        guess_content_type: bool,
+        verify_etag: bool,
+        operation_timeout_secs: Option<u64>,
+        params: Option<AutoUploaderObjectParams>,
         py: Python<'_>,
-    ) -> PyResult<PyObject> {
-        let object_params = make_auto_uploader_object_params(
+    ) -> TryUploadResult {
+        TryUploadResult::from_result(self.upload_path(
+            path,
             region_provider,
             object_name,
             file_name,
@@ -2774,17 +7151,20 @@ impl AutoUploader {
             multi_parts_uploader_scheduler_prefer,
             single_part_uploader_prefer,
             multi_parts_uploader_prefer,
-        )?;
-        py.allow_threads(|| {
-            self.0
-                .upload_path(path, object_params)
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                .and_then(|v| convert_json_value_to_py_object(&v))
-        })
+            guess_content_type,
+            verify_etag,
+            operation_timeout_secs,
+            false,
+            params,
+            py,
+        ))
     }
 
+    /// 上传数据流，失败时不抛出异常
+    ///
+    /// 参数与 `upload_reader()` 相同，返回 `TryUploadResult`，失败原因记录在其 `error` 属性中
     #[pyo3(
-        text_signature = "($self, reader, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None)"
+        text_signature = "($self, reader, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None, guess_content_type=False, verify_etag=False, operation_timeout_secs=None, params=None)"
     )]
     #[args(
         region_provider = "None",
@@ -2795,13 +7175,17 @@ impl AutoUploader {
         custom_vars = "None",
         multi_parts_uploader_scheduler_prefer = "None",
         single_part_uploader_prefer = "None",
-        multi_parts_uploader_prefer = "None"
+        multi_parts_uploader_prefer = "None",
+        guess_content_type = "false",
+        verify_etag = "false",
+        operation_timeout_secs = "None",
+        params = "None"
     )]
     #[allow(clippy::too_many_arguments)]
-    fn upload_reader(
+    fn try_upload_reader(
         &self,
         reader: PyObject,
-        region_provider: Option<RegionsProvider>,
+        region_provider: Option<&PyAny>,
         object_name: Option<&str>,
         file_name: Option<&str>,
         content_type: Option<&str>,
@@ -2810,9 +7194,14 @@ impl AutoUploader {
         multi_parts_uploader_scheduler_prefer: Option<MultiPartsUploaderSchedulerPrefer>,
         single_part_uploader_prefer: Option<SinglePartUploaderPrefer>,
         multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
+        guess_content_type: bool,
+        verify_etag: bool,
+        operation_timeout_secs: Option<u64>,
+        params: Option<AutoUploaderObjectParams>,
         py: Python<'_>,
-    ) -> PyResult<PyObject> {
-        let object_params = make_auto_uploader_object_params(
+    ) -> TryUploadResult {
+        TryUploadResult::from_result(self.upload_reader(
+            reader,
             region_provider,
             object_name,
             file_name,
@@ -2822,17 +7211,20 @@ impl AutoUploader {
             multi_parts_uploader_scheduler_prefer,
             single_part_uploader_prefer,
             multi_parts_uploader_prefer,
-        )?;
-        py.allow_threads(|| {
-            self.0
-                .upload_reader(PythonIoBase::new(reader), object_params)
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                .and_then(|v| convert_json_value_to_py_object(&v))
-        })
+            guess_content_type,
+            verify_etag,
+            operation_timeout_secs,
+            false,
+            params,
+            py,
+        ))
     }
 
+    /// 异步上传文件，失败时不抛出异常
+    ///
+    /// 参数与 `async_upload_path()` 相同，返回 `TryUploadResult`，失败原因记录在其 `error` 属性中
     #[pyo3(
-        text_signature = "($self, path, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None)"
+        text_signature = "($self, path, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None, guess_content_type=False, verify_etag=False, operation_timeout_secs=None, params=None)"
     )]
     #[args(
         region_provider = "None",
@@ -2843,13 +7235,17 @@ impl AutoUploader {
         custom_vars = "None",
         multi_parts_uploader_scheduler_prefer = "None",
         single_part_uploader_prefer = "None",
-        multi_parts_uploader_prefer = "None"
+        multi_parts_uploader_prefer = "None",
+        guess_content_type = "false",
+        verify_etag = "false",
+        operation_timeout_secs = "None",
+        params = "None"
     )]
     #[allow(clippy::too_many_arguments)]
-    fn async_upload_path<'p>(
+    fn async_try_upload_path<'p>(
         &self,
         path: String,
-        region_provider: Option<RegionsProvider>,
+        region_provider: Option<&PyAny>,
         object_name: Option<&str>,
         file_name: Option<&str>,
         content_type: Option<&str>,
@@ -2858,9 +7254,14 @@ impl AutoUploader {
         multi_parts_uploader_scheduler_prefer: Option<MultiPartsUploaderSchedulerPrefer>,
         single_part_uploader_prefer: Option<SinglePartUploaderPrefer>,
         multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
+        guess_content_type: bool,
+        verify_etag: bool,
+        operation_timeout_secs: Option<u64>,
+        params: Option<AutoUploaderObjectParams>,
         py: Python<'p>,
     ) -> PyResult<&'p PyAny> {
-        let object_params = make_auto_uploader_object_params(
+        let awaitable = self.async_upload_path(
+            path,
             region_provider,
             object_name,
             file_name,
@@ -2870,19 +7271,24 @@ impl AutoUploader {
             multi_parts_uploader_scheduler_prefer,
             single_part_uploader_prefer,
             multi_parts_uploader_prefer,
+            guess_content_type,
+            verify_etag,
+            operation_timeout_secs,
+            false,
+            params,
+            py,
         )?;
-        let uploader = self.0.to_owned();
+        let fut = pyo3_asyncio::async_std::into_future(awaitable)?;
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            uploader
-                .async_upload_path(&path, object_params)
-                .await
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                .and_then(|v| convert_json_value_to_py_object(&v))
+            Ok(TryUploadResult::from_result(fut.await))
         })
     }
 
+    /// 异步上传数据流，失败时不抛出异常
+    ///
+    /// 参数与 `async_upload_reader()` 相同，返回 `TryUploadResult`，失败原因记录在其 `error` 属性中
     #[pyo3(
-        text_signature = "($self, reader, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None)"
+        text_signature = "($self, reader, /, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None, guess_content_type=False, verify_etag=False, operation_timeout_secs=None, params=None)"
     )]
     #[args(
         region_provider = "None",
@@ -2893,13 +7299,17 @@ impl AutoUploader {
         custom_vars = "None",
         multi_parts_uploader_scheduler_prefer = "None",
         single_part_uploader_prefer = "None",
-        multi_parts_uploader_prefer = "None"
+        multi_parts_uploader_prefer = "None",
+        guess_content_type = "false",
+        verify_etag = "false",
+        operation_timeout_secs = "None",
+        params = "None"
     )]
     #[allow(clippy::too_many_arguments)]
-    fn async_upload_reader<'p>(
+    fn async_try_upload_reader<'p>(
         &self,
         reader: PyObject,
-        region_provider: Option<RegionsProvider>,
+        region_provider: Option<&PyAny>,
         object_name: Option<&str>,
         file_name: Option<&str>,
         content_type: Option<&str>,
@@ -2908,9 +7318,14 @@ impl AutoUploader {
         multi_parts_uploader_scheduler_prefer: Option<MultiPartsUploaderSchedulerPrefer>,
         single_part_uploader_prefer: Option<SinglePartUploaderPrefer>,
         multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
+        guess_content_type: bool,
+        verify_etag: bool,
+        operation_timeout_secs: Option<u64>,
+        params: Option<AutoUploaderObjectParams>,
         py: Python<'p>,
     ) -> PyResult<&'p PyAny> {
-        let object_params = make_auto_uploader_object_params(
+        let awaitable = self.async_upload_reader(
+            reader,
             region_provider,
             object_name,
             file_name,
@@ -2920,15 +7335,505 @@ impl AutoUploader {
             multi_parts_uploader_scheduler_prefer,
             single_part_uploader_prefer,
             multi_parts_uploader_prefer,
+            guess_content_type,
+            verify_etag,
+            operation_timeout_secs,
+            false,
+            params,
+            py,
         )?;
+        let fut = pyo3_asyncio::async_std::into_future(awaitable)?;
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            Ok(TryUploadResult::from_result(fut.await))
+        })
+    }
+
+    /// 上传目录
+    ///
+    /// 递归遍历目录中的所有文件并逐一上传，对象名称为 `key_prefix` 与文件相对路径（以 `/` 分隔）的拼接。指向目录的符号链接不会被遍历。
+    ///
+    /// 如果 `content_type_by_extension` 为 `True`，将根据文件扩展名猜测 MIME 类型。
+    ///
+    /// 未传入 `region_provider` 时与 `upload_path()` 相同，优先使用上传管理器的加速域名区域信息。
+    ///
+    /// 如果传入 `upload_progress`，则每个文件的上传进度都会以 `(relative_path, progress)` 的形式回调。
+    ///
+    /// 返回一个列表，每一项为文件相对路径与上传结果组成的元组，如果该文件上传失败，上传结果为对应的 `QiniuApiCallError` 异常。
+    #[pyo3(
+        text_signature = "($self, local_dir, /, key_prefix = '', concurrency = None, content_type_by_extension = True, region_provider = None, upload_progress = None)"
+    )]
+    #[args(
+        key_prefix = "\"\"",
+        concurrency = "None",
+        content_type_by_extension = "true",
+        region_provider = "None",
+        upload_progress = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_directory(
+        &self,
+        local_dir: &str,
+        key_prefix: &str,
+        concurrency: Option<usize>,
+        content_type_by_extension: bool,
+        region_provider: Option<&PyAny>,
+        upload_progress: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<(String, PyObject)>> {
+        let region_provider = region_provider
+            .map(extract_regions_provider)
+            .transpose()?
+            .or_else(|| self.3.to_owned());
+        let entries = py
+            .allow_threads(|| list_directory_files(Path::new(local_dir)))
+            .map_err(QiniuIoError::from_err)?;
+        let concurrency = get_concurrency(concurrency)?;
+        let results = py.allow_threads(|| {
+            let entries = Arc::new(entries);
+            let next = Arc::new(AtomicUsize::new(0));
+            let results = Arc::new(Mutex::new(Vec::with_capacity(entries.len())));
+            let handles = (0..concurrency.min(entries.len()))
+                .map(|_| {
+                    let entries = entries.to_owned();
+                    let next = next.to_owned();
+                    let results = results.to_owned();
+                    let uploader = self.0.to_owned();
+                    let source_permits = self.2.to_owned();
+                    let key_prefix = key_prefix.to_owned();
+                    let region_provider = region_provider.to_owned();
+                    let upload_progress = upload_progress.to_owned();
+                    thread::spawn(move || loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let entry = if let Some(entry) = entries.get(index) {
+                            entry
+                        } else {
+                            break;
+                        };
+                        let uploader = make_directory_entry_uploader(
+                            &uploader,
+                            entry,
+                            upload_progress.as_ref(),
+                        );
+                        let object_params = make_directory_entry_object_params(
+                            entry,
+                            &key_prefix,
+                            content_type_by_extension,
+                            region_provider.as_ref(),
+                        );
+                        let result = match UploadCallbackRegistration::new(&object_params) {
+                            Ok(_registration) => {
+                                let _permit = source_permits.acquire();
+                                uploader.upload_path(&entry.path, object_params)
+                            }
+                            Err(err) => Err(convert_registration_error(err)),
+                        };
+                        results
+                            .lock()
+                            .unwrap_or_else(|err| err.into_inner())
+                            .push((index, result));
+                    })
+                })
+                .collect::<Vec<_>>();
+            for handle in handles {
+                join_thread(handle)?;
+            }
+            let mut results = take(&mut *results.lock().unwrap_or_else(|err| err.into_inner()));
+            results.sort_by_key(|(index, _)| *index);
+            Ok::<_, PyErr>(
+                results
+                    .into_iter()
+                    .map(|(index, result)| (entries[index].relative_path.to_owned(), result))
+                    .collect::<Vec<_>>(),
+            )
+        })?;
+        convert_directory_uploading_results(results)
+    }
+
+    /// 异步上传目录
+    ///
+    /// 递归遍历目录中的所有文件并逐一上传，对象名称为 `key_prefix` 与文件相对路径（以 `/` 分隔）的拼接。指向目录的符号链接不会被遍历。
+    ///
+    /// 如果 `content_type_by_extension` 为 `True`，将根据文件扩展名猜测 MIME 类型。
+    ///
+    /// 未传入 `region_provider` 时与 `upload_path()` 相同，优先使用上传管理器的加速域名区域信息。
+    ///
+    /// 如果传入 `upload_progress`，则每个文件的上传进度都会以 `(relative_path, progress)` 的形式回调。
+    ///
+    /// 返回一个列表，每一项为文件相对路径与上传结果组成的元组，如果该文件上传失败，上传结果为对应的 `QiniuApiCallError` 异常。
+    #[pyo3(
+        text_signature = "($self, local_dir, /, key_prefix = '', concurrency = None, content_type_by_extension = True, region_provider = None, upload_progress = None)"
+    )]
+    #[args(
+        key_prefix = "String::new()",
+        concurrency = "None",
+        content_type_by_extension = "true",
+        region_provider = "None",
+        upload_progress = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn async_upload_directory<'p>(
+        &self,
+        local_dir: String,
+        key_prefix: String,
+        concurrency: Option<usize>,
+        content_type_by_extension: bool,
+        region_provider: Option<&PyAny>,
+        upload_progress: Option<PyObject>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let region_provider = region_provider
+            .map(extract_regions_provider)
+            .transpose()?
+            .or_else(|| self.3.to_owned());
+        let concurrency = get_concurrency(concurrency)?;
         let uploader = self.0.to_owned();
+        let source_permits = self.2.to_owned();
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            uploader
-                .async_upload_reader(PythonIoBase::new(reader).into_async_read(), object_params)
-                .await
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                .and_then(|v| convert_json_value_to_py_object(&v))
+            let entries = async_std::task::spawn_blocking(move || {
+                list_directory_files(Path::new(&local_dir))
+            })
+            .await
+            .map_err(QiniuIoError::from_err)?;
+            let results = futures::stream::iter(entries.into_iter().map(|entry| {
+                let uploader =
+                    make_directory_entry_uploader(&uploader, &entry, upload_progress.as_ref());
+                let object_params = make_directory_entry_object_params(
+                    &entry,
+                    &key_prefix,
+                    content_type_by_extension,
+                    region_provider.as_ref(),
+                );
+                let source_permits = source_permits.to_owned();
+                async move {
+                    let result = match UploadCallbackRegistration::new(&object_params) {
+                        Ok(_registration) => {
+                            let _permit = source_permits.async_acquire().await;
+                            uploader.async_upload_path(&entry.path, object_params).await
+                        }
+                        Err(err) => Err(convert_registration_error(err)),
+                    };
+                    (entry.relative_path, result)
+                }
+            }))
+            .buffered(concurrency)
+            .collect::<Vec<_>>()
+            .await;
+            convert_directory_uploading_results(results)
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+/// 上传结果
+///
+/// 通过 `auto_uploader.try_upload_path()` 或 `auto_uploader.try_upload_reader()` 获取，上传失败时不会抛出异常，而是将失败原因记录在 `error` 属性中
+#[pyclass]
+#[derive(Debug)]
+struct TryUploadResult {
+    response: Option<PyObject>,
+    error: Option<String>,
+}
+
+impl TryUploadResult {
+    fn from_result(result: PyResult<PyObject>) -> Self {
+        match result {
+            Ok(response) => Self {
+                response: Some(response),
+                error: None,
+            },
+            Err(err) => Self {
+                response: None,
+                error: Some(err.to_string()),
+            },
+        }
+    }
+}
+
+#[pymethods]
+impl TryUploadResult {
+    /// 是否上传成功
+    #[getter]
+    fn get_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// 获取上传响应，上传失败时为 `None`
+    #[getter]
+    fn get_response(&self) -> Option<PyObject> {
+        self.response.to_owned()
+    }
+
+    /// 获取失败原因，上传成功时为 `None`
+    #[getter]
+    fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn __bool__(&self) -> bool {
+        self.get_ok()
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(match (&self.response, &self.error) {
+            (_, Some(error)) => format!("TryUploadResult(ok=False, error={:?})", error),
+            (Some(response), _) => format!(
+                "TryUploadResult(ok=True, response={})",
+                response.as_ref(py).repr()?
+            ),
+            (None, None) => "TryUploadResult(ok=True)".to_owned(),
+        })
+    }
+
+    fn __str__(&self, py: Python<'_>) -> PyResult<String> {
+        self.__repr__(py)
+    }
+}
+
+/// 上传响应
+///
+/// 上传时传入 `typed=True` 将返回上传响应，而不是字典
+#[pyclass]
+#[derive(Clone, Debug)]
+struct UploadResult(serde_json::Value);
+
+#[pymethods]
+impl UploadResult {
+    /// 获取对象名称
+    #[getter]
+    fn get_key(&self) -> Option<&str> {
+        self.0.get("key").and_then(|v| v.as_str())
+    }
+
+    /// 获取对象哈希值
+    #[getter]
+    fn get_hash(&self) -> Option<&str> {
+        self.0.get("hash").and_then(|v| v.as_str())
+    }
+
+    /// 获取原始的上传响应
+    #[getter]
+    fn get_raw(&self) -> PyResult<PyObject> {
+        convert_json_value_to_py_object(&self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+fn convert_upload_response(value: serde_json::Value, typed: bool) -> PyResult<PyObject> {
+    if typed {
+        Ok(Python::with_gil(|py| UploadResult(value).into_py(py)))
+    } else {
+        convert_json_value_to_py_object(&value)
+    }
+}
+
+#[derive(Debug)]
+struct DirectoryEntry {
+    path: PathBuf,
+    relative_path: String,
+}
+
+fn list_directory_files(local_dir: &Path) -> IoResult<Vec<DirectoryEntry>> {
+    let mut entries = Vec::new();
+    let mut dirs = vec![local_dir.to_owned()];
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            // 不跟随指向目录的符号链接，避免符号链接成环时无限递归，指向文件的符号链接仍然会被上传
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                dirs.push(path);
+            } else if file_type.is_file() || file_type.is_symlink() && path.is_file() {
+                let relative_path = path
+                    .strip_prefix(local_dir)
+                    .map_err(|err| IoError::new(IoErrorKind::Other, err))?
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                entries.push(DirectoryEntry {
+                    path,
+                    relative_path,
+                });
+            }
+        }
+    }
+    entries.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    Ok(entries)
+}
+
+fn make_directory_entry_uploader(
+    uploader: &qiniu_sdk::upload::AutoUploader,
+    entry: &DirectoryEntry,
+    upload_progress: Option<&PyObject>,
+) -> qiniu_sdk::upload::AutoUploader {
+    let mut uploader = uploader.to_owned();
+    if let Some(callback) = upload_progress {
+        let callback = callback.to_owned();
+        let relative_path = entry.relative_path.to_owned();
+        uploader.on_upload_progress(move |progress| {
+            Python::with_gil(|py| {
+                callback.call1(
+                    py,
+                    (
+                        relative_path.as_str(),
+                        UploadingProgressInfo::new(
+                            progress.transferred_bytes(),
+                            progress.total_bytes(),
+                        ),
+                    ),
+                )
+            })?;
+            Ok(())
+        });
+    }
+    uploader
+}
+
+fn make_directory_entry_object_params(
+    entry: &DirectoryEntry,
+    key_prefix: &str,
+    content_type_by_extension: bool,
+    region_provider: Option<&RegionsProvider>,
+) -> qiniu_sdk::upload::AutoUploaderObjectParams {
+    let mut builder = qiniu_sdk::upload::AutoUploaderObjectParams::builder();
+    builder.object_name(format!("{}{}", key_prefix, entry.relative_path));
+    if let Some(file_name) = entry.path.file_name() {
+        builder.file_name(file_name.to_string_lossy().into_owned());
+    }
+    if content_type_by_extension {
+        if let Some(content_type) = mime_guess::from_path(&entry.path).first() {
+            builder.content_type(content_type);
+        }
+    }
+    if let Some(region_provider) = region_provider {
+        builder.region_provider(region_provider.to_owned());
+    }
+    builder.build()
+}
+
+fn convert_registration_error(err: PyErr) -> qiniu_sdk::http_client::ResponseError {
+    qiniu_sdk::http_client::ResponseError::new(
+        qiniu_sdk::http::ResponseErrorKind::UnknownError.into(),
+        err,
+    )
+}
+
+fn convert_directory_uploading_results(
+    results: Vec<(String, qiniu_sdk::http_client::ApiResult<serde_json::Value>)>,
+) -> PyResult<Vec<(String, PyObject)>> {
+    results
+        .into_iter()
+        .map(|(relative_path, result)| {
+            let result = match result {
+                Ok(value) => convert_json_value_to_py_object(&value)?,
+                Err(err) => Python::with_gil(|py| {
+                    QiniuApiCallError::from_err(MaybeOwned::Owned(err))
+                        .value(py)
+                        .into_py(py)
+                }),
+            };
+            Ok((relative_path, result))
         })
+        .collect()
+}
+
+/// 自动上传器的对象上传参数
+///
+/// 通过 `AutoUploaderObjectParams(region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None, guess_content_type=False, callback_urls=None, callback_host=None, callback_body=None, callback_body_type=None)` 创建自动上传器的对象上传参数
+///
+/// 创建后可以通过 `params` 参数传给自动上传器的各个上传方法重复使用，此时这些方法的其他对象参数将被忽略
+///
+/// 上传回调设置与 `ObjectParams` 相同
+#[pyclass]
+#[derive(Debug, Clone)]
+#[pyo3(
+    text_signature = "(/, region_provider=None, object_name=None, file_name=None, content_type=None, metadata=None, custom_vars=None, multi_parts_uploader_scheduler_prefer=None, single_part_uploader_prefer=None, multi_parts_uploader_prefer=None, guess_content_type=False, callback_urls=None, callback_host=None, callback_body=None, callback_body_type=None)"
+)]
+struct AutoUploaderObjectParams(qiniu_sdk::upload::AutoUploaderObjectParams);
+
+#[pymethods]
+impl AutoUploaderObjectParams {
+    #[new]
+    #[args(
+        region_provider = "None",
+        object_name = "None",
+        file_name = "None",
+        content_type = "None",
+        metadata = "None",
+        custom_vars = "None",
+        multi_parts_uploader_scheduler_prefer = "None",
+        single_part_uploader_prefer = "None",
+        multi_parts_uploader_prefer = "None",
+        guess_content_type = "false",
+        callback_urls = "None",
+        callback_host = "None",
+        callback_body = "None",
+        callback_body_type = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        region_provider: Option<&PyAny>,
+        object_name: Option<&str>,
+        file_name: Option<&str>,
+        content_type: Option<&str>,
+        metadata: Option<HashMap<String, String>>,
+        custom_vars: Option<HashMap<String, String>>,
+        multi_parts_uploader_scheduler_prefer: Option<MultiPartsUploaderSchedulerPrefer>,
+        single_part_uploader_prefer: Option<SinglePartUploaderPrefer>,
+        multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
+        guess_content_type: bool,
+        callback_urls: Option<Vec<String>>,
+        callback_host: Option<String>,
+        callback_body: Option<String>,
+        callback_body_type: Option<String>,
+    ) -> PyResult<Self> {
+        make_auto_uploader_object_params(
+            region_provider,
+            None,
+            object_name,
+            file_name,
+            content_type,
+            metadata,
+            custom_vars,
+            guess_content_type,
+            multi_parts_uploader_scheduler_prefer,
+            single_part_uploader_prefer,
+            multi_parts_uploader_prefer,
+            UploadCallback::new(
+                callback_urls,
+                callback_host,
+                callback_body,
+                callback_body_type,
+            )?,
+        )
+        .map(Self)
+    }
+
+    /// 对象名称
+    #[getter]
+    fn get_object_name(&self) -> Option<&str> {
+        self.0.object_name()
+    }
+
+    /// 文件名称
+    #[getter]
+    fn get_file_name(&self) -> Option<&str> {
+        self.0.file_name()
     }
 
     fn __repr__(&self) -> String {
@@ -2942,19 +7847,24 @@ impl AutoUploader {
 
 #[allow(clippy::too_many_arguments)]
 fn make_auto_uploader_object_params(
-    region_provider: Option<RegionsProvider>,
+    region_provider: Option<&PyAny>,
+    default_region_provider: Option<&RegionsProvider>,
     object_name: Option<&str>,
     file_name: Option<&str>,
     content_type: Option<&str>,
     metadata: Option<HashMap<String, String>>,
     custom_vars: Option<HashMap<String, String>>,
+    guess_content_type: bool,
     multi_parts_uploader_scheduler_prefer: Option<MultiPartsUploaderSchedulerPrefer>,
     single_part_uploader_prefer: Option<SinglePartUploaderPrefer>,
     multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
+    callback: Option<UploadCallback>,
 ) -> PyResult<qiniu_sdk::upload::AutoUploaderObjectParams> {
     let mut builder = qiniu_sdk::upload::AutoUploaderObjectParams::builder();
     if let Some(region_provider) = region_provider {
-        builder.region_provider(region_provider);
+        builder.region_provider(extract_regions_provider(region_provider)?);
+    } else if let Some(default_region_provider) = default_region_provider {
+        builder.region_provider(default_region_provider.to_owned());
     }
     if let Some(object_name) = object_name {
         builder.object_name(object_name);
@@ -2964,6 +7874,10 @@ fn make_auto_uploader_object_params(
     }
     if let Some(content_type) = content_type {
         builder.content_type(parse_mime(content_type)?);
+    } else if guess_content_type {
+        if let Some(content_type) = guess_mime(&[file_name, object_name]) {
+            builder.content_type(content_type);
+        }
     }
     if let Some(metadata) = metadata {
         builder.metadata(metadata);
@@ -2980,6 +7894,9 @@ fn make_auto_uploader_object_params(
     if let Some(multi_parts_uploader_prefer) = multi_parts_uploader_prefer {
         builder.multi_parts_uploader_prefer(multi_parts_uploader_prefer.into());
     }
+    if let Some(callback) = callback {
+        builder.insert_extension(callback);
+    }
     Ok(builder.build())
 }
 
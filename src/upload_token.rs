@@ -30,6 +30,7 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<UploadPolicyBuilder>()?;
     m.add_class::<UploadPolicyBuilderRef>()?;
     m.add_class::<UploadTokenProvider>()?;
+    m.add_class::<UploadToken>()?;
     m.add_class::<GetAccessKeyOptions>()?;
     m.add_class::<GetPolicyOptions>()?;
     m.add_class::<ToStringOptions>()?;
@@ -368,6 +369,48 @@ macro_rules! impl_upload_policy_builder {
                 self.0.callback(urls, host, body, body_type);
             }
 
+            /// 设置上传成功后，七牛云向业务服务器发送回调通知的 URL 列表
+            ///
+            /// 七牛云将依次尝试列表中的 URL，直到回调成功为止
+            #[setter]
+            fn set_callback_urls(&mut self, urls: Vec<String>) {
+                self.0.set("callbackUrl".to_owned(), urls.join(";").into());
+            }
+
+            /// 设置上传成功后，七牛云向业务服务器发送回调通知时的 Host
+            #[setter]
+            fn set_callback_host(&mut self, host: &str) {
+                self.0.set("callbackHost".to_owned(), host.into());
+            }
+
+            /// 设置上传成功后，七牛云向业务服务器发送回调通知的请求体
+            ///
+            /// 支持[魔法变量](https://developer.qiniu.com/kodo/manual/1235/vars#magicvar)和[自定义变量](https://developer.qiniu.com/kodo/manual/1235/vars#xvar)
+            #[setter]
+            fn set_callback_body(&mut self, body: &str) {
+                self.0.set("callbackBody".to_owned(), body.into());
+            }
+
+            /// 设置上传成功后，七牛云向业务服务器发送回调通知的请求体的 MIME 类型
+            #[setter]
+            fn set_callback_body_type(&mut self, body_type: &str) {
+                self.0.set("callbackBodyType".to_owned(), body_type.into());
+            }
+
+            /// 设置自定义对象名称
+            ///
+            /// 仅当上传时没有主动指定对象名称时起作用，除非设置了 `force_save_key`
+            #[setter]
+            fn set_save_key(&mut self, save_key: &str) {
+                self.0.set("saveKey".to_owned(), save_key.into());
+            }
+
+            /// 设置是否强制使用 `save_key` 作为对象名称
+            #[setter]
+            fn set_force_save_key(&mut self, force: bool) {
+                self.0.set("forceSaveKey".to_owned(), force.into());
+            }
+
             /// 自定义对象名称
             ///
             /// 支持[魔法变量](https://developer.qiniu.com/kodo/manual/1235/vars#magicvar)和[自定义变量](https://developer.qiniu.com/kodo/manual/1235/vars#xvar)。
@@ -754,11 +797,104 @@ fn convert_to_string_error_to_py_err(err: ToStringError) -> PyErr {
     }
 }
 
+/// 上传凭证
+///
+/// 通过 `UploadToken.parse(upload_token)` 解析已有的上传凭证，可以获取其中的 AccessKey 和上传策略
+#[pyclass]
+#[derive(Clone, Debug)]
+struct UploadToken {
+    token: String,
+    access_key: String,
+    policy: qiniu_sdk::upload_token::UploadPolicy,
+}
+
+#[pymethods]
+impl UploadToken {
+    /// 解析上传凭证
+    ///
+    /// 如果上传凭证格式错误，将抛出 `QiniuUploadTokenFormatError` 异常
+    #[staticmethod]
+    #[pyo3(text_signature = "(upload_token)")]
+    fn parse(upload_token: &str) -> PyResult<Self> {
+        use qiniu_sdk::upload_token::UploadTokenProvider;
+
+        let provider = qiniu_sdk::upload_token::StaticUploadTokenProvider::new(upload_token);
+        let access_key = provider
+            .access_key(Default::default())
+            .map_err(QiniuUploadTokenFormatError::from_err)?
+            .into_access_key()
+            .to_string();
+        let policy = provider
+            .policy(Default::default())
+            .map_err(QiniuUploadTokenFormatError::from_err)?
+            .into_upload_policy();
+        Ok(Self {
+            token: upload_token.to_owned(),
+            access_key,
+            policy,
+        })
+    }
+
+    /// 获取上传凭证中的 AccessKey
+    #[getter]
+    fn get_access_key(&self) -> &str {
+        &self.access_key
+    }
+
+    /// 获取上传凭证中的存储空间名称
+    #[getter]
+    fn get_bucket_name(&self) -> Option<&str> {
+        self.policy.bucket()
+    }
+
+    /// 获取上传凭证中的上传策略，以字典形式返回
+    #[getter]
+    fn get_policy(&self) -> PyResult<PyObject> {
+        let policy = self
+            .policy
+            .keys()
+            .zip(self.policy.values())
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect();
+        convert_json_value_to_py_object(&serde_json::Value::Object(policy))
+    }
+
+    /// 获取上传凭证中的上传策略
+    #[getter]
+    fn get_upload_policy(&self) -> UploadPolicy {
+        UploadPolicy(self.policy.to_owned())
+    }
+
+    /// 获取上传凭证过期时间戳
+    #[getter]
+    fn get_deadline(&self) -> PyResult<Option<u64>> {
+        self.get_upload_policy().get_token_deadline()
+    }
+
+    /// 上传凭证是否已经过期
+    #[pyo3(text_signature = "($self)")]
+    fn is_expired(&self) -> bool {
+        self.policy
+            .token_deadline()
+            .map_or(false, |deadline| deadline <= SystemTime::now())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.token.to_owned()
+    }
+}
+
 /// 静态上传凭证提供者
 ///
 /// 根据已经被生成好的上传凭证字符串生成上传凭证获取接口的实例，可以将上传凭证解析为 Access Token 和上传策略
 ///
-/// 通过 `StaticUploadTokenProvider(upload_token)` 创建静态上传凭证提供者
+/// 适用于从业务服务器获取上传凭证的场景，无需持有 SecretKey 即可上传
+///
+/// 通过 `StaticUploadTokenProvider(upload_token)` 创建静态上传凭证提供者，如果上传凭证格式错误，将抛出 `QiniuUploadTokenFormatError` 等异常
 #[pyclass(extends = UploadTokenProvider)]
 #[pyo3(text_signature = "(upload_token)")]
 struct StaticUploadTokenProvider;
@@ -766,13 +902,18 @@ struct StaticUploadTokenProvider;
 #[pymethods]
 impl StaticUploadTokenProvider {
     #[new]
-    fn new(upload_token: &str) -> (Self, UploadTokenProvider) {
-        (
-            Self,
-            UploadTokenProvider(Box::new(
-                qiniu_sdk::upload_token::StaticUploadTokenProvider::new(upload_token),
-            )),
-        )
+    fn new(upload_token: &str) -> PyResult<(Self, UploadTokenProvider)> {
+        let provider = qiniu_sdk::upload_token::StaticUploadTokenProvider::new(upload_token);
+        {
+            use qiniu_sdk::upload_token::UploadTokenProvider;
+            provider
+                .access_key(Default::default())
+                .map_err(convert_parse_error_to_py_err)?;
+            provider
+                .policy(Default::default())
+                .map_err(convert_parse_error_to_py_err)?;
+        }
+        Ok((Self, UploadTokenProvider(Box::new(provider))))
     }
 }
 
@@ -1,13 +1,16 @@
 use super::{
     exceptions::{
         QiniuApiCallErrorInfo, QiniuBodySizeMissingError, QiniuHeaderValueEncodingError,
-        QiniuInvalidDomainWithPortError, QiniuInvalidEndpointError, QiniuInvalidHeaderNameError,
+        QiniuInvalidChunkSize, QiniuInvalidConcurrency, QiniuInvalidDomainWithPortError,
+        QiniuInvalidEncryptionKey, QiniuInvalidEndpointError, QiniuInvalidHeaderNameError,
         QiniuInvalidHeaderValueError, QiniuInvalidIpAddrError, QiniuInvalidIpAddrWithPortError,
         QiniuInvalidMethodError, QiniuInvalidPortError, QiniuInvalidStatusCodeError,
-        QiniuInvalidURLError, QiniuMimeParseError, QiniuUnsupportedTypeError,
+        QiniuInvalidURLError, QiniuIoError, QiniuMimeParseError, QiniuResponseTooLargeError,
+        QiniuUnpicklableError, QiniuUnsupportedTypeError,
     },
-    http_client::{Endpoint, EndpointsProvider, RegionsProvider},
+    http_client::{Endpoint, EndpointsProvider, MultipartForm, RegionsProvider},
 };
+use ctr::cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
 use futures::{
     channel::{
         mpsc::{
@@ -19,9 +22,11 @@ use futures::{
     future::{select, Either},
     io::Cursor,
     lock::Mutex as AsyncMutex,
-    pin_mut, ready, AsyncRead, AsyncSeek, AsyncWrite, FutureExt, SinkExt, StreamExt,
+    pin_mut, ready, AsyncRead, AsyncReadExt, AsyncSeek, AsyncWrite, AsyncWriteExt, FutureExt,
+    SinkExt, StreamExt,
 };
 use pyo3::{
+    exceptions::{PyIOError, PyRuntimeError, PyStopAsyncIteration},
     prelude::*,
     types::{PyBytes, PyDict, PyTuple},
 };
@@ -44,8 +49,10 @@ use std::{
     net::IpAddr,
     num::NonZeroU16,
     pin::Pin,
-    sync::Arc,
+    sync::{Arc, Mutex},
     task::{Context, Poll},
+    thread::JoinHandle,
+    time::{Duration, Instant},
 };
 
 const READ: &str = "read";
@@ -53,6 +60,13 @@ const SEEK: &str = "seek";
 const WRITE: &str = "write";
 const FLUSH: &str = "flush";
 
+pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "utils")?;
+    m.add_function(wrap_pyfunction!(copy_stream, m)?)?;
+    m.add_function(wrap_pyfunction!(async_copy_stream, m)?)?;
+    Ok(m)
+}
+
 #[derive(Debug)]
 pub(super) struct PythonIoBase {
     io_base: PyObject,
@@ -410,6 +424,95 @@ impl Debug for AsyncSeekStep {
     }
 }
 
+/// 基于 Python 异步生成器的异步阅读器
+///
+/// 依次读取异步生成器产生的 `bytes`，异步生成器结束时读取结束
+#[derive(Debug)]
+pub(super) struct PythonAsyncGeneratorRead {
+    agen: PyObject,
+    step: AsyncGeneratorReadStep,
+}
+
+#[derive(SmartDefault)]
+enum AsyncGeneratorReadStep {
+    Waiting(SyncBoxFuture<'static, IoResult<Option<Vec<u8>>>>),
+
+    #[default]
+    Buffered(Cursor<Vec<u8>>),
+
+    Done,
+}
+
+impl PythonAsyncGeneratorRead {
+    pub(super) fn new(agen: PyObject) -> Self {
+        Self {
+            agen,
+            step: Default::default(),
+        }
+    }
+
+    fn next_chunk(&self) -> SyncBoxFuture<'static, IoResult<Option<Vec<u8>>>> {
+        let agen = Python::with_gil(|py| self.agen.clone_ref(py));
+        Box::pin(async move {
+            let retval =
+                Python::with_gil(|py| DirectPyCall.call_python_method(agen, "__anext__", None, py))
+                    .map_err(make_io_error_from_py_err)?
+                    .await;
+            Python::with_gil(|py| match retval {
+                Ok(retval) => extract_bytes_from_py_object(py, retval).map(Some),
+                Err(err) if err.is_instance_of::<PyStopAsyncIteration>(py) => Ok(None),
+                Err(err) => Err(err),
+            })
+            .map_err(make_io_error_from_py_err)
+        })
+    }
+}
+
+impl AsyncRead for PythonAsyncGeneratorRead {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<IoResult<usize>> {
+        match &mut self.step {
+            AsyncGeneratorReadStep::Waiting(fut) => match ready!(fut.poll_unpin(cx)) {
+                Ok(Some(chunk)) => {
+                    self.step = AsyncGeneratorReadStep::Buffered(Cursor::new(chunk));
+                    self.poll_read(cx, buf)
+                }
+                Ok(None) => {
+                    self.step = AsyncGeneratorReadStep::Done;
+                    Poll::Ready(Ok(0))
+                }
+                Err(err) => {
+                    self.step = AsyncGeneratorReadStep::Done;
+                    Poll::Ready(Err(err))
+                }
+            },
+            AsyncGeneratorReadStep::Buffered(buffered) => {
+                match ready!(Pin::new(buffered).poll_read(cx, buf)) {
+                    Ok(0) if !buf.is_empty() => {
+                        self.step = AsyncGeneratorReadStep::Waiting(self.next_chunk());
+                        self.poll_read(cx, buf)
+                    }
+                    result => Poll::Ready(result),
+                }
+            }
+            AsyncGeneratorReadStep::Done => Poll::Ready(Ok(0)),
+        }
+    }
+}
+
+impl Debug for AsyncGeneratorReadStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Waiting { .. } => f.debug_tuple("Waiting").finish(),
+            Self::Buffered(cursor) => f.debug_tuple("Buffered").field(cursor).finish(),
+            Self::Done => f.debug_tuple("Done").finish(),
+        }
+    }
+}
+
 fn extract_bytes_from_py_object(py: Python<'_>, obj: PyObject) -> PyResult<Vec<u8>> {
     let bytes = if let Ok(str) = obj.extract::<String>(py) {
         str.into_bytes()
@@ -584,6 +687,31 @@ pub(super) fn parse_query_pairs(
     })
 }
 
+/// 按顺序解析查询参数，`pairs` 可以是字典或二元组列表
+pub(super) fn parse_ordered_query_pairs(pairs: &PyAny) -> PyResult<Vec<(String, String)>> {
+    if let Ok(pairs) = pairs.downcast::<PyDict>() {
+        pairs
+            .iter()
+            .map(|(key, value)| Ok((key.extract()?, value.extract()?)))
+            .collect()
+    } else {
+        pairs.extract()
+    }
+}
+
+/// 对查询参数的键或值进行 URL 编码，仅保留非保留字符
+pub(super) fn encode_query_component(component: &str) -> String {
+    let mut encoded = String::with_capacity(component.len());
+    for &b in component.as_bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(b as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", b));
+        }
+    }
+    encoded
+}
+
 pub(super) fn parse_headers(headers: HashMap<String, String>) -> PyResult<HeaderMap> {
     headers
         .into_iter()
@@ -639,9 +767,13 @@ pub(super) fn parse_port(port: u16) -> PyResult<NonZeroU16> {
 }
 
 pub(super) fn extract_sync_multipart(
-    parts: HashMap<String, PyObject>,
+    parts: PyObject,
 ) -> PyResult<qiniu_sdk::http_client::SyncMultipart<'static>> {
     Python::with_gil(|py| {
+        if let Ok(form) = parts.extract::<PyRef<MultipartForm>>(py) {
+            return form.to_sync_multipart(py);
+        }
+        let parts = parts.extract::<HashMap<String, PyObject>>(py)?;
         let mut multipart = qiniu_sdk::http_client::SyncMultipart::new();
         for (field_name, part) in parts {
             let part = if let Ok((body, metadata)) = part.extract::<(PyObject, &PyDict)>(py) {
@@ -674,10 +806,15 @@ fn extract_sync_part<'a>(
     Ok(part)
 }
 
-pub(super) fn extract_async_multipart(
-    parts: HashMap<String, PyObject>,
+pub(super) async fn extract_async_multipart(
+    parts: PyObject,
 ) -> PyResult<qiniu_sdk::http_client::AsyncMultipart<'static>> {
+    let form = Python::with_gil(|py| parts.extract::<MultipartForm>(py).ok());
+    if let Some(form) = form {
+        return form.to_async_multipart().await;
+    }
     Python::with_gil(|py| {
+        let parts = parts.extract::<HashMap<String, PyObject>>(py)?;
         let mut multipart = qiniu_sdk::http_client::AsyncMultipart::new();
         for (field_name, part) in parts {
             let part = if let Ok((body, metadata)) = part.extract::<(PyObject, &PyDict)>(py) {
@@ -727,6 +864,33 @@ fn extract_multipart_metadata(dict: &PyDict) -> PyResult<qiniu_sdk::http_client:
     Ok(metadata)
 }
 
+/// 对于可以 seek 的数据流，通过 seek 到末尾再 seek 回原位置的方式计算剩余的数据长度
+///
+/// 如果数据流不支持 seek，则返回 `None`
+fn guess_body_len(body: &PyAny) -> PyResult<Option<u64>> {
+    match body.call_method0("seekable") {
+        Ok(seekable) if matches!(seekable.extract::<bool>(), Ok(true)) => {}
+        _ => return Ok(None),
+    }
+    let current = body.call_method0("tell")?.extract::<u64>()?;
+    body.call_method1("seek", (0, 2))?;
+    let end = body.call_method0("tell")?.extract::<u64>()?;
+    body.call_method1("seek", (current, 0))?;
+    Ok(Some(end.saturating_sub(current)))
+}
+
+pub(super) fn get_body_len(
+    body: &PyObject,
+    body_len: Option<u64>,
+    py: Python<'_>,
+) -> PyResult<Option<u64>> {
+    if let Some(body_len) = body_len {
+        Ok(Some(body_len))
+    } else {
+        guess_body_len(body.as_ref(py))
+    }
+}
+
 pub(super) fn extract_sync_request_body(
     body: PyObject,
     body_len: Option<u64>,
@@ -736,7 +900,7 @@ pub(super) fn extract_sync_request_body(
         Ok(SyncRequestBody::from(body))
     } else if let Ok(body) = body.extract::<Vec<u8>>(py) {
         Ok(SyncRequestBody::from(body))
-    } else if let Some(body_len) = body_len {
+    } else if let Some(body_len) = get_body_len(&body, body_len, py)? {
         Ok(SyncRequestBody::from_reader(
             PythonIoBase::new(body),
             body_len,
@@ -757,7 +921,7 @@ pub(super) fn extract_async_request_body(
         Ok((AsyncRequestBody::from(body), None))
     } else if let Ok(body) = body.extract::<Vec<u8>>(py) {
         Ok((AsyncRequestBody::from(body), None))
-    } else if let Some(body_len) = body_len {
+    } else if let Some(body_len) = get_body_len(&body, body_len, py)? {
         let (body, agent) = PythonIoBase::new(body).into_async_read_with_local_agent();
         Ok((AsyncRequestBody::from_reader(body, body_len), Some(agent)))
     } else {
@@ -803,13 +967,6 @@ pub(super) fn parse_domain_with_port(ip_addr: &str) -> PyResult<DomainWithPort>
         .map_err(QiniuInvalidDomainWithPortError::from_err)
 }
 
-pub(super) fn extract_ip_addrs_with_port(ip_addrs: &[&str]) -> PyResult<Vec<IpAddrWithPort>> {
-    ip_addrs
-        .iter()
-        .map(|&s| parse_ip_addr_with_port(s))
-        .collect()
-}
-
 pub(super) fn parse_ip_addr_with_port(ip_addr: &str) -> PyResult<IpAddrWithPort> {
     ip_addr
         .parse::<IpAddrWithPort>()
@@ -849,6 +1006,113 @@ pub(super) fn extract_endpoints_provider(
     }
 }
 
+pub(super) fn extract_regions_provider(provider: &PyAny) -> PyResult<RegionsProvider> {
+    if let Ok(regions) = provider.extract::<RegionsProvider>() {
+        Ok(regions)
+    } else {
+        let endpoints = provider.extract::<EndpointsProvider>()?;
+        Ok(RegionsProvider::from_endpoints_provider(endpoints))
+    }
+}
+
+/// 为无法通过 `pickle` 序列化的对象生成错误，这类对象通常持有 HTTP 客户端、缓存或 Python 回调函数等运行时资源
+pub(super) fn unpicklable_error(obj: &PyAny) -> PyErr {
+    let type_name = obj.get_type().name().unwrap_or("object");
+    QiniuUnpicklableError::new_err(format!(
+        "cannot pickle '{}' object: only value types such as Credential, Endpoints and Region can be pickled, create it again in each process instead",
+        type_name
+    ))
+}
+
+/// AES-CTR 加解密器
+///
+/// 支持从任意偏移量开始加解密，因此同一段数据无论被如何切分，加密结果都是确定的
+#[derive(Clone)]
+pub(super) struct AesCtrCipher {
+    key: Vec<u8>,
+    nonce: [u8; 16],
+}
+
+impl AesCtrCipher {
+    /// 创建 AES-CTR 加解密器，`key` 必须为 16、24 或 32 个字节，`nonce` 必须为 16 个字节
+    pub(super) fn new(key: &[u8], nonce: &[u8]) -> PyResult<Self> {
+        if !matches!(key.len(), 16 | 24 | 32) {
+            return Err(QiniuInvalidEncryptionKey::new_err(
+                "Invalid key length, expected 16, 24 or 32",
+            ));
+        }
+        let nonce = <[u8; 16]>::try_from(nonce)
+            .map_err(|_| QiniuInvalidEncryptionKey::new_err("Invalid nonce length, expected 16"))?;
+        Ok(Self {
+            key: key.to_owned(),
+            nonce,
+        })
+    }
+
+    /// 对从 `offset` 开始的数据进行原地加解密
+    pub(super) fn apply_keystream(&self, offset: u64, buf: &mut [u8]) {
+        macro_rules! apply_keystream {
+            ($aes:ty) => {{
+                let mut cipher = ctr::Ctr128BE::<$aes>::new_from_slices(&self.key, &self.nonce)
+                    .expect("key and nonce lengths have been checked");
+                cipher.seek(offset);
+                cipher.apply_keystream(buf);
+            }};
+        }
+        match self.key.len() {
+            16 => apply_keystream!(aes::Aes128),
+            24 => apply_keystream!(aes::Aes192),
+            _ => apply_keystream!(aes::Aes256),
+        }
+    }
+
+    pub(super) fn nonce(&self) -> &[u8] {
+        &self.nonce
+    }
+}
+
+impl Debug for AesCtrCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AesCtrCipher")
+            .field("key_size", &self.key.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// 进度回调节流器
+///
+/// 保证在指定间隔内最多放行一次进度事件，但最终完成的进度事件总是会被放行
+#[derive(Debug)]
+pub(super) struct ProgressThrottle {
+    interval: Duration,
+    last_emitted_at: Mutex<Option<Instant>>,
+}
+
+impl ProgressThrottle {
+    pub(super) fn new(interval_ms: u64) -> Self {
+        Self {
+            interval: Duration::from_millis(interval_ms),
+            last_emitted_at: Default::default(),
+        }
+    }
+
+    /// 判断当前进度事件是否应该被放行
+    pub(super) fn should_emit(&self, transferred_bytes: u64, total_bytes: Option<u64>) -> bool {
+        if total_bytes.map_or(false, |total_bytes| transferred_bytes >= total_bytes) {
+            return true;
+        }
+        let now = Instant::now();
+        let mut last_emitted_at = self.last_emitted_at.lock().unwrap();
+        match *last_emitted_at {
+            Some(last) if now.duration_since(last) < self.interval => false,
+            _ => {
+                *last_emitted_at = Some(now);
+                true
+            }
+        }
+    }
+}
+
 pub(super) fn parse_mime(mime: &str) -> PyResult<qiniu_sdk::http_client::mime::Mime> {
     mime.parse::<qiniu_sdk::http_client::mime::Mime>()
         .map_err(QiniuMimeParseError::from_err)
@@ -927,6 +1191,203 @@ fn split_seek_from(seek_from: SeekFrom) -> (i64, i64) {
     }
 }
 
+/// 获取 Python 内置的 `UserWarning` 警告类型
+pub(super) fn user_warning(py: Python<'_>) -> PyResult<&PyAny> {
+    py.import("builtins")?.getattr("UserWarning")
+}
+
+/// 计算从现在起经过指定时长后的时刻，时长过大导致溢出时饱和为一百年后
+pub(super) fn instant_after(duration: Duration) -> Instant {
+    const FAR_FUTURE: Duration = Duration::from_secs(100 * 365 * 24 * 60 * 60);
+    let now = Instant::now();
+    now.checked_add(duration)
+        .or_else(|| now.checked_add(FAR_FUTURE))
+        .unwrap_or(now)
+}
+
+/// 获取并发数，未指定时使用系统可用的并行度，为 0 时抛出 `QiniuInvalidConcurrency`
+pub(super) fn get_concurrency(concurrency: Option<usize>) -> PyResult<usize> {
+    match concurrency {
+        Some(0) => Err(QiniuInvalidConcurrency::new_err("Invalid concurrency")),
+        Some(concurrency) => Ok(concurrency),
+        None => Ok(std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)),
+    }
+}
+
+/// 等待线程结束，如果线程发生 panic，则转换为 `RuntimeError` 而不是继续 panic
+pub(super) fn join_thread<T>(handle: JoinHandle<T>) -> PyResult<T> {
+    handle.join().map_err(|err| {
+        let message = err
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| err.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        PyRuntimeError::new_err(format!("thread panicked: {}", message))
+    })
+}
+
 pub(super) fn convert_api_call_error(error: &PyErr) -> PyResult<QiniuApiCallErrorInfo> {
     Python::with_gil(|py| error.value(py).getattr("args")?.get_item(0i32)?.extract())
 }
+
+/// 将 reader 中的数据复制到 writer 中
+///
+/// 每次最多读取 `chunk_size` 个字节，复制过程中释放 GIL，每写入一块数据后以已复制的字节数调用 `progress` 回调函数。
+///
+/// 返回复制的总字节数
+#[pyfunction(chunk_size = "65536", progress = "None")]
+#[pyo3(text_signature = "(reader, writer, /, chunk_size = 65536, progress = None)")]
+fn copy_stream(
+    reader: PyObject,
+    writer: PyObject,
+    chunk_size: usize,
+    progress: Option<PyObject>,
+    py: Python<'_>,
+) -> PyResult<u64> {
+    let mut buf = make_copy_buffer(chunk_size)?;
+    let mut reader = PythonIoBase::new(reader);
+    let mut writer = PythonIoBase::new(writer);
+    py.allow_threads(|| {
+        let mut copied = 0u64;
+        loop {
+            let have_read = match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(have_read) => have_read,
+                Err(err) if err.kind() == IoErrorKind::Interrupted => continue,
+                Err(err) => return Err(QiniuIoError::from_err(err)),
+            };
+            writer
+                .write_all(&buf[..have_read])
+                .map_err(QiniuIoError::from_err)?;
+            copied += have_read as u64;
+            if let Some(progress) = &progress {
+                Python::with_gil(|py| progress.call1(py, (copied,)))?;
+            }
+        }
+        writer.flush().map_err(QiniuIoError::from_err)?;
+        Ok(copied)
+    })
+}
+
+/// 将异步 reader 中的数据异步复制到异步 writer 中
+///
+/// 每次最多读取 `chunk_size` 个字节，每写入一块数据后以已复制的字节数调用 `progress` 回调函数。
+///
+/// 返回复制的总字节数
+#[pyfunction(chunk_size = "65536", progress = "None")]
+#[pyo3(text_signature = "(reader, writer, /, chunk_size = 65536, progress = None)")]
+fn async_copy_stream(
+    reader: PyObject,
+    writer: PyObject,
+    chunk_size: usize,
+    progress: Option<PyObject>,
+    py: Python<'_>,
+) -> PyResult<&PyAny> {
+    let mut buf = make_copy_buffer(chunk_size)?;
+    let mut reader = PythonIoBase::new(reader).into_async_read();
+    let mut writer = PythonIoBase::new(writer).into_async_write();
+    pyo3_asyncio::async_std::future_into_py(py, async move {
+        let mut copied = 0u64;
+        loop {
+            let have_read = match reader.read(&mut buf).await {
+                Ok(0) => break,
+                Ok(have_read) => have_read,
+                Err(err) if err.kind() == IoErrorKind::Interrupted => continue,
+                Err(err) => return Err(QiniuIoError::from_err(err)),
+            };
+            writer
+                .write_all(&buf[..have_read])
+                .await
+                .map_err(QiniuIoError::from_err)?;
+            copied += have_read as u64;
+            if let Some(progress) = &progress {
+                Python::with_gil(|py| progress.call1(py, (copied,)))?;
+            }
+        }
+        writer.flush().await.map_err(QiniuIoError::from_err)?;
+        Ok(copied)
+    })
+}
+
+/// 响应体超过最大限制时的错误
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ResponseBodyTooLarge {
+    pub(super) max_body_size: u64,
+}
+
+impl fmt::Display for ResponseBodyTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "response body exceeds max body size: {} bytes",
+            self.max_body_size
+        )
+    }
+}
+
+impl std::error::Error for ResponseBodyTooLarge {}
+
+/// 将读取响应体时发生的 IO 错误转换为 Python 异常
+pub(super) fn convert_body_read_error(err: IoError) -> PyErr {
+    match err
+        .get_ref()
+        .and_then(|err| err.downcast_ref::<ResponseBodyTooLarge>())
+    {
+        Some(err) => QiniuResponseTooLargeError::new_err(err.to_string()),
+        None => PyIOError::new_err(err),
+    }
+}
+
+const READ_WITH_PROGRESS_CHUNK_SIZE: usize = 1 << 16;
+
+/// 读取 reader 中的所有数据，每读取一块数据后以已读取的字节数调用 `progress` 回调函数
+///
+/// 调用回调函数时会自动获取 GIL
+pub(super) fn read_to_end_with_progress(
+    mut reader: impl Read,
+    buf: &mut Vec<u8>,
+    progress: &PyObject,
+) -> PyResult<()> {
+    let mut chunk = vec![0u8; READ_WITH_PROGRESS_CHUNK_SIZE];
+    loop {
+        let have_read = match reader.read(&mut chunk) {
+            Ok(0) => return Ok(()),
+            Ok(have_read) => have_read,
+            Err(err) if err.kind() == IoErrorKind::Interrupted => continue,
+            Err(err) => return Err(convert_body_read_error(err)),
+        };
+        buf.extend_from_slice(&chunk[..have_read]);
+        Python::with_gil(|py| progress.call1(py, (buf.len() as u64,)))?;
+    }
+}
+
+/// 异步读取 reader 中的所有数据，每读取一块数据后以已读取的字节数调用 `progress` 回调函数
+pub(super) async fn async_read_to_end_with_progress(
+    mut reader: impl AsyncRead + Unpin,
+    buf: &mut Vec<u8>,
+    progress: &PyObject,
+) -> PyResult<()> {
+    let mut chunk = vec![0u8; READ_WITH_PROGRESS_CHUNK_SIZE];
+    loop {
+        let have_read = match reader.read(&mut chunk).await {
+            Ok(0) => return Ok(()),
+            Ok(have_read) => have_read,
+            Err(err) if err.kind() == IoErrorKind::Interrupted => continue,
+            Err(err) => return Err(convert_body_read_error(err)),
+        };
+        buf.extend_from_slice(&chunk[..have_read]);
+        Python::with_gil(|py| progress.call1(py, (buf.len() as u64,)))?;
+    }
+}
+
+fn make_copy_buffer(chunk_size: usize) -> PyResult<Vec<u8>> {
+    if chunk_size == 0 {
+        Err(QiniuInvalidChunkSize::new_err(
+            "chunk_size must not be zero",
+        ))
+    } else {
+        Ok(vec![0u8; chunk_size])
+    }
+}
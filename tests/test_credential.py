@@ -1,8 +1,10 @@
-from qiniu_bindings import credential, upload_token
+from qiniu_bindings import credential, upload_token, QiniuUnpicklableError
 import unittest
 import aiofiles
 import asyncio
 import io
+import os
+import pickle
 
 
 class TestCredential(unittest.TestCase):
@@ -11,6 +13,16 @@ class TestCredential(unittest.TestCase):
         self.assertEqual(c.sign(b'hello'),
                          'abcdefghklmnopq:b84KVc-LroDiz0ebUANfdzSRxa0=')
 
+    def test_credential_pickle(self):
+        c = pickle.loads(pickle.dumps(get_credential()))
+        self.assertIsInstance(c, credential.Credential)
+        self.assertEqual(c.access_key, ACCESS_KEY)
+        self.assertEqual(c.secret_key, SECRET_KEY)
+        self.assertEqual(c.sign(b'hello'),
+                         'abcdefghklmnopq:b84KVc-LroDiz0ebUANfdzSRxa0=')
+        with self.assertRaises(QiniuUnpicklableError):
+            pickle.dumps(credential.EnvCredentialProvider())
+
     def test_credential_sign_reader(self):
         c = get_credential()
         reader = io.BytesIO(b'hello')
@@ -25,6 +37,31 @@ class TestCredential(unittest.TestCase):
         url = c.sign_download_url('http://www.qiniu.com/?go=1', 3600)
         self.assertTrue(url.startswith('http://www.qiniu.com/?go=1&e='))
         self.assertTrue('&token=abcdefghklmnopq' in url)
+        url = c.sign_download_url('http://www.qiniu.com/?go=1', 2 ** 64 - 1)
+        self.assertTrue(url.startswith(
+            'http://www.qiniu.com/?go=1&e=%d&token=' % (2 ** 64 - 1)))
+
+    def test_credential_sign_download_url_with_deadline(self):
+        c = get_credential()
+        self.assertEqual(c.sign_download_url_with_deadline('http://www.qiniu.com/file.txt', 1893456000),
+                         'http://www.qiniu.com/file.txt?e=1893456000&token=abcdefghklmnopq:edqGoV8S2iLj8jwWZofstxWHeRM=')
+        self.assertEqual(c.sign_download_url_with_deadline('http://www.qiniu.com/file.txt?go=1', 1893456000),
+                         'http://www.qiniu.com/file.txt?go=1&e=1893456000&token=abcdefghklmnopq:pKu9HlTaV6upNcjXyZY4z11WaqM=')
+        self.assertEqual(c.sign_download_url_with_deadline('http://www.qiniu.com/file.txt?go=1#top', 1893456000),
+                         'http://www.qiniu.com/file.txt?go=1&e=1893456000&token=abcdefghklmnopq:pKu9HlTaV6upNcjXyZY4z11WaqM=#top')
+
+    def test_credential_sign_download_url_with_extra_query(self):
+        c = get_credential()
+        url = c.sign_download_url_with_deadline('http://www.qiniu.com/file.txt', 1893456000,
+                                                extra_query={'attname': '测试 文件.txt'})
+        unsigned_url = 'http://www.qiniu.com/file.txt?attname=%E6%B5%8B%E8%AF%95%20%E6%96%87%E4%BB%B6.txt&e=1893456000'
+        self.assertEqual(url, unsigned_url + '&token=' +
+                         c.sign(unsigned_url.encode()))
+        url = c.sign_download_url('http://www.qiniu.com/file.txt?go=1#top', 3600,
+                                  extra_query=[('attname', 'a&b.txt')])
+        self.assertTrue(url.startswith(
+            'http://www.qiniu.com/file.txt?go=1&attname=a%26b.txt&e='))
+        self.assertTrue(url.endswith('#top'))
 
     def test_credential_authorization_v1_for_request(self):
         c = get_credential()
@@ -74,6 +111,38 @@ class TestCredentialProvider(unittest.TestCase):
         self.assertEqual(ec.secret_key, SECRET_KEY)
         credential.EnvCredentialProvider.clear()
 
+    def test_env_credential_rotation(self):
+        credential.EnvCredentialProvider.clear()
+        provider = credential.EnvCredentialProvider()
+        with self.assertRaises(OSError):
+            provider.get()
+        os.environ[credential.QINIU_ACCESS_KEY_ENV_KEY] = 'ak1'
+        with self.assertRaises(OSError):
+            provider.get()
+        os.environ[credential.QINIU_SECRET_KEY_ENV_KEY] = 'sk1'
+        self.assertEqual(provider.get().access_key, 'ak1')
+        self.assertEqual(provider.get().secret_key, 'sk1')
+        os.environ[credential.QINIU_ACCESS_KEY_ENV_KEY] = 'ak2'
+        os.environ[credential.QINIU_SECRET_KEY_ENV_KEY] = 'sk2'
+        self.assertEqual(provider.get().access_key, 'ak2')
+        self.assertEqual(provider.get().secret_key, 'sk2')
+        credential.EnvCredentialProvider.clear()
+
+    def test_mutable_credential(self):
+        provider = credential.MutableCredentialProvider(
+            credential.Credential('ak1', 'sk1'))
+        token_provider = upload_token.BucketUploadTokenProvider(
+            'test-bucket', 3600, provider)
+        self.assertEqual(provider.get().access_key, 'ak1')
+        self.assertTrue(token_provider.to_token_string().startswith('ak1:'))
+        signature = provider.get().sign(b'hello')
+        provider.set_credential(credential.Credential('ak2', 'sk2'))
+        self.assertEqual(provider.get().access_key, 'ak2')
+        self.assertEqual(provider.get().secret_key, 'sk2')
+        self.assertTrue(token_provider.to_token_string().startswith('ak2:'))
+        self.assertNotEqual(provider.get().sign(
+            b'hello').split(':')[1], signature.split(':')[1])
+
     def test_chain_credential(self):
         credential.GlobalCredentialProvider.clear()
         credential.EnvCredentialProvider.clear()
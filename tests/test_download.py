@@ -1,6 +1,7 @@
-from qiniu_bindings import credential, download, http_client
+from qiniu_bindings import credential, download, http_client, QiniuApiCallError, QiniuDownloadError, QiniuChunkedDownloadError, QiniuIoError
 from aiohttp import web
 import io
+import os
 import unittest
 import secrets
 import aiofiles
@@ -31,6 +32,27 @@ class TestDownloadUrlsGenerator(unittest.TestCase):
             'https://192.168.2.1:8080/fakekey?'))
 
 
+    def test_download_urls_builder(self):
+        builder = download.DownloadUrlsBuilder(
+            ['cdn.domain.com', 'source.domain.com:8080'], 'fakekey', use_https=True)
+        self.assertEqual(builder.object_name, 'fakekey')
+        self.assertEqual(builder.build(), [
+                         'https://cdn.domain.com/fakekey', 'https://source.domain.com:8080/fakekey'])
+
+        builder = download.DownloadUrlsBuilder(
+            ['cdn.domain.com', 'source.domain.com:8080'], 'fakekey', use_https=False, credential=credential.Credential('ak', 'sk'), ttl_secs=3600)
+        urls = builder.build()
+        self.assertTrue(urls[0].startswith('http://cdn.domain.com/fakekey?'))
+        self.assertTrue(urls[1].startswith(
+            'http://source.domain.com:8080/fakekey?'))
+        self.assertTrue(all('token=ak:' in url for url in urls))
+        urls = builder.generate('fakekey')
+        self.assertTrue(urls[0].startswith('http://cdn.domain.com/fakekey?'))
+        self.assertTrue(all('token=ak:' in url for url in urls))
+        with self.assertRaises(QiniuApiCallError):
+            builder.generate('otherkey')
+
+
 class TestDownloadManager(unittest.IsolatedAsyncioTestCase):
     async def test_download_manager(self):
         case = self
@@ -52,11 +74,13 @@ class TestDownloadManager(unittest.IsolatedAsyncioTestCase):
                 credential.Credential('ak', 'sk'), download.StaticDomainsUrlsGenerator(
                     ['127.0.0.1:8089'], use_https=False))
             download_manager = download.DownloadManager(generator)
-            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
-                await download_manager.async_download_to_path('fakeobjectname', f.name)
-                await f.seek(0, io.SEEK_SET)
-                content = await f.read(-1)
+            async with aiofiles.tempfile.TemporaryDirectory() as d:
+                path = os.path.join(d, 'fakeobjectname')
+                await download_manager.async_download_to_path('fakeobjectname', path)
+                async with aiofiles.open(path, 'rb') as f:
+                    content = await f.read(-1)
                 self.assertEqual(content, rand_bytes)
+                self.assertFalse(os.path.exists(path + '.downloading'))
             async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
                 await download_manager.download_to_async_writer('fakeobjectname', f)
                 await f.seek(0, io.SEEK_SET)
@@ -67,3 +91,245 @@ class TestDownloadManager(unittest.IsolatedAsyncioTestCase):
             self.assertEqual(content, rand_bytes)
         finally:
             await runner.cleanup()
+
+    async def test_range_download(self):
+        case = self
+
+        rand_bytes = secrets.token_bytes(1 << 16)
+
+        async def getrange(request):
+            (start, end) = request.headers['Range'][len('bytes='):].split('-')
+            start = int(start)
+            end = int(end) if end else len(rand_bytes) - 1
+            return web.Response(status=206, body=rand_bytes[start:end+1], headers={
+                'Content-Range': 'bytes %d-%d/%d' % (start, end, len(rand_bytes)),
+                'Etag': 'fakeetag',
+                'X-Reqid': 'fakereqid'})
+
+        async def getfile(request):
+            return web.Response(body=rand_bytes, headers={'Etag': 'fakeetag', 'X-Reqid': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/rangedobject', getrange)])
+        app.add_routes([web.get('/fakeobjectname', getfile)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            generator = download.StaticDomainsUrlsGenerator(
+                ['127.0.0.1:8089'], use_https=False)
+            download_manager = download.DownloadManager(generator)
+            content_ranges = []
+
+            def response_ok(parts):
+                case.assertEqual(parts.status_code, 206)
+                content_ranges.append(
+                    (parts.content_range, parts.content_length))
+            reader = download_manager.async_reader(
+                'rangedobject', range_from=1024, range_to=2048, response_ok=response_ok)
+            content = await reader.readall()
+            self.assertEqual(len(content_ranges), 1)
+            (content_range, content_length) = content_ranges[0]
+            self.assertTrue(content_range.startswith('bytes 1024-'))
+            self.assertEqual(content_length, len(content))
+            self.assertEqual(content, rand_bytes[1024:1024+len(content)])
+
+            async with aiofiles.tempfile.TemporaryDirectory() as d:
+                with self.assertRaises(QiniuDownloadError):
+                    await download_manager.async_download_to_path(
+                        'fakeobjectname', os.path.join(d, 'fakeobjectname'), range_from=1024, range_to=2048, retrier=download.NeverRetrier())
+                with self.assertRaises(QiniuDownloadError):
+                    await download_manager.async_download_to_path(
+                        'fakeobjectname', os.path.join(d, 'fakeobjectname'), range_from=0, range_to=0, retrier=download.NeverRetrier())
+        finally:
+            await runner.cleanup()
+
+    async def test_resumable_download(self):
+        rand_bytes = secrets.token_bytes(1 << 20)
+
+        async def getfile(request):
+            range = request.headers.get('Range')
+            if range is None:
+                return web.Response(body=rand_bytes, headers={'Etag': 'fakeetag', 'X-Reqid': 'fakereqid'})
+            (start, end) = range[len('bytes='):].split('-')
+            start = int(start)
+            if start >= len(rand_bytes):
+                return web.Response(status=416, headers={
+                    'Content-Range': 'bytes */%d' % len(rand_bytes),
+                    'X-Reqid': 'fakereqid'})
+            end = int(end) if end else len(rand_bytes) - 1
+            return web.Response(status=206, body=rand_bytes[start:end+1], headers={
+                'Content-Range': 'bytes %d-%d/%d' % (start, end, len(rand_bytes)),
+                'Etag': 'fakeetag',
+                'X-Reqid': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/fakeobjectname', getfile)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            generator = download.StaticDomainsUrlsGenerator(
+                ['127.0.0.1:8089'], use_https=False)
+            download_manager = download.DownloadManager(generator)
+            async with aiofiles.tempfile.TemporaryDirectory() as d:
+                path = os.path.join(d, 'fakeobjectname')
+                async with aiofiles.open(path + '.downloading', 'wb') as f:
+                    await f.write(rand_bytes[:1 << 19])
+                async with aiofiles.open(path + '.downloading.etag', 'w') as f:
+                    await f.write('fakeetag')
+                last_progress = []
+
+                def download_progress(progress):
+                    last_progress[:] = [progress]
+                await download_manager.async_download_to_path('fakeobjectname', path, download_progress=download_progress, resume=True)
+                async with aiofiles.open(path, 'rb') as f:
+                    content = await f.read(-1)
+                self.assertEqual(content, rand_bytes)
+                self.assertFalse(os.path.exists(path + '.downloading'))
+                self.assertFalse(os.path.exists(path + '.downloading.etag'))
+                self.assertEqual(
+                    last_progress[0].transferred_bytes, len(rand_bytes))
+
+                os.remove(path)
+                async with aiofiles.open(path + '.downloading', 'wb') as f:
+                    await f.write(rand_bytes)
+                async with aiofiles.open(path + '.downloading.etag', 'w') as f:
+                    await f.write('fakeetag')
+                await download_manager.async_download_to_path('fakeobjectname', path, resume=True)
+                async with aiofiles.open(path, 'rb') as f:
+                    content = await f.read(-1)
+                self.assertEqual(content, rand_bytes)
+                self.assertFalse(os.path.exists(path + '.downloading'))
+
+                async with aiofiles.open(path + '.downloading', 'wb') as f:
+                    await f.write(rand_bytes[:1 << 19])
+                async with aiofiles.open(path + '.downloading.etag', 'w') as f:
+                    await f.write('staleetag')
+                with self.assertRaises(QiniuIoError):
+                    await download_manager.async_download_to_path('fakeobjectname', path, resume=True)
+                self.assertFalse(os.path.exists(path + '.downloading'))
+                self.assertFalse(os.path.exists(path + '.downloading.etag'))
+        finally:
+            await runner.cleanup()
+
+    async def test_failed_download_leaves_no_temp_files(self):
+        rand_bytes = secrets.token_bytes(1 << 16)
+
+        async def getfile(request):
+            return web.Response(body=rand_bytes, headers={'Etag': 'fakeetag', 'X-Reqid': 'fakereqid'})
+
+        async def getbrokenfile(request):
+            return web.Response(status=500, headers={'X-Reqid': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/fakeobjectname', getfile),
+                       web.get('/brokenobjectname', getbrokenfile)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            generator = download.StaticDomainsUrlsGenerator(
+                ['127.0.0.1:8089'], use_https=False)
+            download_manager = download.DownloadManager(generator)
+            async with aiofiles.tempfile.TemporaryDirectory() as d:
+                path = os.path.join(d, 'brokenobjectname')
+                with self.assertRaises(QiniuDownloadError):
+                    await download_manager.async_download_to_path(
+                        'brokenobjectname', path, retrier=download.NeverRetrier(), resume=False)
+                self.assertEqual(os.listdir(d), [])
+                with self.assertRaises(QiniuDownloadError):
+                    await download_manager.async_download_to_path(
+                        'brokenobjectname', path, retrier=download.NeverRetrier())
+                self.assertEqual(os.listdir(d), [])
+
+                path = os.path.join(d, 'fakeobjectname')
+                with self.assertRaises(QiniuDownloadError):
+                    await download_manager.async_download_to_path(
+                        'fakeobjectname', path, range_from=0, range_to=1023, retrier=download.NeverRetrier())
+                self.assertEqual(os.listdir(d), [])
+        finally:
+            await runner.cleanup()
+
+    async def test_chunked_download(self):
+        rand_bytes = secrets.token_bytes(1 << 20)
+
+        def parse_range(request):
+            (start, end) = request.headers['Range'][len('bytes='):].split('-')
+            return (int(start), int(end))
+
+        async def getrange(request):
+            (start, end) = parse_range(request)
+            return web.Response(status=206, body=rand_bytes[start:end+1], headers={
+                'Content-Range': 'bytes %d-%d/%d' % (start, end, len(rand_bytes)),
+                'Etag': 'fakeetag',
+                'X-Reqid': 'fakereqid'})
+
+        async def getfile(request):
+            return web.Response(body=rand_bytes, headers={'Etag': 'fakeetag', 'X-Reqid': 'fakereqid'})
+
+        async def getbroken(request):
+            (start, _) = parse_range(request)
+            if start == 1 << 18:
+                return web.Response(status=599, headers={'X-Reqid': 'fakereqid'})
+            return await getrange(request)
+
+        async def getunknownsize(request):
+            (start, end) = parse_range(request)
+            return web.Response(status=206, body=rand_bytes[start:end+1], headers={
+                'Content-Range': 'bytes %d-%d/*' % (start, end),
+                'X-Reqid': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/rangedobject', getrange)])
+        app.add_routes([web.get('/fakeobjectname', getfile)])
+        app.add_routes([web.get('/brokenobject', getbroken)])
+        app.add_routes([web.get('/unknownsizeobject', getunknownsize)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            generator = download.StaticDomainsUrlsGenerator(
+                ['127.0.0.1:8089'], use_https=False)
+            download_manager = download.DownloadManager(generator)
+            async with aiofiles.tempfile.TemporaryDirectory() as d:
+                last_progress = []
+
+                def download_progress(progress):
+                    last_progress[:] = [progress]
+                path = os.path.join(d, 'rangedobject')
+                await download_manager.async_download_chunked('rangedobject', path, chunk_size=1 << 17, concurrency=4, download_progress=download_progress)
+                async with aiofiles.open(path, 'rb') as f:
+                    self.assertEqual(await f.read(-1), rand_bytes)
+                self.assertEqual(
+                    last_progress[0].transferred_bytes, len(rand_bytes))
+                self.assertEqual(
+                    last_progress[0].total_bytes, len(rand_bytes))
+
+                path = os.path.join(d, 'fakeobjectname')
+                await download_manager.async_download_chunked('fakeobjectname', path, chunk_size=1 << 17, concurrency=4)
+                async with aiofiles.open(path, 'rb') as f:
+                    self.assertEqual(await f.read(-1), rand_bytes)
+
+                path = os.path.join(d, 'brokenobject')
+                with self.assertRaises(QiniuChunkedDownloadError) as cm:
+                    await download_manager.async_download_chunked('brokenobject', path, chunk_size=1 << 17, concurrency=4, retrier=download.NeverRetrier())
+                self.assertIn('262144-393215', str(cm.exception))
+                self.assertFalse(os.path.exists(path))
+                self.assertFalse(os.path.exists(path + '.downloading'))
+
+                path = os.path.join(d, 'unknownsizeobject')
+                with self.assertRaises(QiniuChunkedDownloadError):
+                    await download_manager.async_download_chunked('unknownsizeobject', path, chunk_size=1 << 17, concurrency=4, retrier=download.NeverRetrier())
+                self.assertFalse(os.path.exists(path))
+                self.assertFalse(os.path.exists(path + '.downloading'))
+        finally:
+            await runner.cleanup()
@@ -1,8 +1,10 @@
-from qiniu_bindings import etag
+from qiniu_bindings import etag, QiniuInvalidPartSize
 import unittest
 import aiofiles
 import asyncio
 import io
+import os
+import tempfile
 
 
 class TestEtag(unittest.TestCase):
@@ -22,6 +24,25 @@ class TestEtag(unittest.TestCase):
         stream = io.BytesIO(_data_of_size(5 * (1 << 20)))
         self.assertEqual(etag.etag_of(stream), 'lg-Eb5KFCuZn-cUfj_oS2PPOU9xy')
 
+    def test_etag_of_with_progress(self):
+        progresses = []
+        stream = io.BytesIO(_data_of_size(5 * (1 << 20)))
+        self.assertEqual(etag.etag_of(stream, progress=progresses.append), 'lg-Eb5KFCuZn-cUfj_oS2PPOU9xy')
+        self.assertEqual(progresses, sorted(progresses))
+        self.assertEqual(progresses[-1], 5 * (1 << 20))
+
+
+class TestEtagOfBytes(unittest.TestCase):
+    def test_etag_of_bytes(self):
+        self.assertEqual(etag.etag_of_bytes(b''), 'Fto5o-5ea0sNMlW_75VgGJCv2AcJ')
+        self.assertEqual(etag.etag_of_bytes(b'etag'), 'FpLiADEaVoALPkdb8tJEJyRTXoe_')
+        self.assertEqual(etag.etag_of_bytes(bytes(_data_of_size(5 * (1 << 20)))), 'lg-Eb5KFCuZn-cUfj_oS2PPOU9xy')
+
+    def test_etag_v2_of_bytes(self):
+        data = bytes(_data_of_size(5 * (1 << 20)))
+        self.assertEqual(etag.etag_v2_of_bytes(data, 1 << 22), 'lg-Eb5KFCuZn-cUfj_oS2PPOU9xy')
+        self.assertTrue(etag.etag_v2_of_bytes(data, 1 << 20).startswith('n'))
+
 
 class TestEtagV1(unittest.TestCase):
     def test_large_size_etag_v1(self):
@@ -30,6 +51,43 @@ class TestEtagV1(unittest.TestCase):
         self.assertEqual(e.finalize(), 'lg-Eb5KFCuZn-cUfj_oS2PPOU9xy')
 
 
+class TestEtagOfPaths(unittest.IsolatedAsyncioTestCase):
+    async def test_etag_of_paths(self):
+        with tempfile.TemporaryDirectory() as d:
+            expected = {}
+            for (name, data, etag_value) in [('empty', b'', 'Fto5o-5ea0sNMlW_75VgGJCv2AcJ'),
+                                             ('simple', b'etag', 'FpLiADEaVoALPkdb8tJEJyRTXoe_'),
+                                             ('large', _data_of_size(5 * (1 << 20)), 'lg-Eb5KFCuZn-cUfj_oS2PPOU9xy')]:
+                path = os.path.join(d, name)
+                with open(path, 'wb') as f:
+                    f.write(data)
+                expected[path] = etag_value
+            self.assertEqual(etag.etag_of_paths(list(expected.keys()), concurrency=2), expected)
+            self.assertEqual(await etag.async_etag_of_paths(list(expected.keys()), concurrency=2), expected)
+
+            progresses = []
+            self.assertEqual(etag.etag_of_paths(list(expected.keys()), concurrency=2,
+                             progress=progresses.append, progress_interval_ms=1000), expected)
+            self.assertEqual(progresses[-1], 5 * (1 << 20) + 4)
+
+
+class TestVerifyEtag(unittest.IsolatedAsyncioTestCase):
+    async def test_verify_etag(self):
+        self.assertTrue(etag.verify_etag(io.BytesIO(b'etag'), 'FpLiADEaVoALPkdb8tJEJyRTXoe_'))
+        self.assertFalse(etag.verify_etag(io.BytesIO(b'etag!'), 'FpLiADEaVoALPkdb8tJEJyRTXoe_'))
+        with tempfile.TemporaryDirectory() as d:
+            path = os.path.join(d, 'large')
+            with open(path, 'wb') as f:
+                f.write(_data_of_size(5 * (1 << 20)))
+            self.assertTrue(etag.verify_etag(path, 'lg-Eb5KFCuZn-cUfj_oS2PPOU9xy'))
+            self.assertTrue(await etag.async_verify_etag(path, 'lg-Eb5KFCuZn-cUfj_oS2PPOU9xy'))
+            self.assertFalse(await etag.async_verify_etag(path, 'Fto5o-5ea0sNMlW_75VgGJCv2AcJ'))
+            with self.assertRaises(QiniuInvalidPartSize):
+                etag.verify_etag(path, 'nfakeetagv2')
+            with self.assertRaises(QiniuInvalidPartSize):
+                await etag.async_verify_etag(path, 'nfakeetagv2')
+
+
 class TestAsyncEtag(unittest.IsolatedAsyncioTestCase):
     async def test_empty_etag_of(self):
         async with aiofiles.tempfile.TemporaryFile('wb+') as f:
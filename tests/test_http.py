@@ -1,11 +1,27 @@
 from threading import Thread
-from qiniu_bindings import http
+from qiniu_bindings import http, QiniuBodySizeMissingError
 from http.server import ThreadingHTTPServer, BaseHTTPRequestHandler
 from aiohttp import web
 import unittest
 import os
 import io
 import aiofiles
+import gzip
+import zlib
+
+
+class TestVersion(unittest.TestCase):
+    def test_version_ordering(self):
+        self.assertTrue(http.Version.HTTP_2 >= http.Version.HTTP_11)
+        self.assertTrue(http.Version.HTTP_10 < http.Version.HTTP_11)
+        self.assertTrue(http.Version.HTTP_3 > http.Version.HTTP_2)
+        self.assertEqual(http.Version.HTTP_11, http.Version.HTTP_11)
+        self.assertNotEqual(http.Version.HTTP_11, http.Version.HTTP_2)
+        self.assertEqual(max(http.Version.HTTP_09, http.Version.HTTP_3,
+                         http.Version.HTTP_11), http.Version.HTTP_3)
+        counter = {http.Version.HTTP_11: 1, http.Version.HTTP_2: 2}
+        self.assertEqual(counter[http.Version.HTTP_11], 1)
+        self.assertEqual(counter[http.Version.HTTP_2], 2)
 
 
 class TestSyncHttpRequest(unittest.TestCase):
@@ -22,6 +38,42 @@ class TestSyncHttpRequest(unittest.TestCase):
         self.assertEqual(req.resolved_ip_addrs, [
                          '127.0.0.1', '127.0.0.2'])
 
+    def test_sync_http_request_with_seekable_body(self):
+        body = io.BytesIO(b'hello world')
+        body.seek(6)
+        http.SyncHttpRequest(
+            url='http://www.qiniu.com/robots.txt', method='PUT', body=body)
+        self.assertEqual(body.tell(), 6)
+
+        class Unseekable(io.RawIOBase):
+            def readable(self):
+                return True
+
+            def readinto(self, b):
+                return 0
+
+        with self.assertRaises(QiniuBodySizeMissingError):
+            http.SyncHttpRequest(
+                url='http://www.qiniu.com/robots.txt', method='PUT', body=Unseekable())
+
+
+    def test_sync_http_request_to_curl(self):
+        req = http.SyncHttpRequest(url='http://www.qiniu.com/robots.txt', method='POST',
+                                   headers={'authorization': 'Qiniu ak:sign', 'x-reqid': "it's"}, body=b'hello', resolved_ip_addrs=['127.0.0.1'])
+        curl = req.to_curl()
+        self.assertTrue(curl.startswith(
+            "curl -X POST 'http://www.qiniu.com/robots.txt' "))
+        self.assertIn("-H 'authorization: <redacted>'", curl)
+        self.assertIn("-H 'x-reqid: it'\\''s'", curl)
+        self.assertIn("--resolve 'www.qiniu.com:80:127.0.0.1'", curl)
+        self.assertIn('5 bytes', curl)
+        self.assertIn("-H 'authorization: Qiniu ak:sign'",
+                      req.to_curl(redact=False))
+
+        parts = http.HttpRequestParts(
+            url='http://www.qiniu.com/robots.txt', method='GET')
+        self.assertNotIn('bytes', parts.to_curl())
+
 
 class TestAsyncHttpRequest(unittest.TestCase):
     def test_new_async_http_request(self):
@@ -38,6 +90,13 @@ class TestAsyncHttpRequest(unittest.TestCase):
                          '127.0.0.1', '127.0.0.2'])
 
 
+class TestTransferProgressInfo(unittest.TestCase):
+    def test_percentage(self):
+        self.assertEqual(http.TransferProgressInfo(
+            512, 2048).percentage, 25.0)
+        self.assertIsNone(http.TransferProgressInfo(0, 0).percentage)
+
+
 class TestMetrics(unittest.TestCase):
     def test_metrics(self):
         metrics = http.Metrics(total_duration_ns=1234567890)
@@ -45,6 +104,24 @@ class TestMetrics(unittest.TestCase):
         metrics.total_duration = 9876543210
         self.assertEqual(metrics.total_duration, 9876543210)
 
+    def test_metrics_to_dict_and_equality(self):
+        metrics = http.Metrics(total_duration_ns=1000,
+                               connect_duration_ns=200)
+        self.assertEqual(metrics.to_dict(), {
+            'total_duration': 1000,
+            'name_lookup_duration': None,
+            'connect_duration': 200,
+            'secure_connect_duration': None,
+            'redirect_duration': None,
+            'transfer_duration': None,
+        })
+        other = http.Metrics(total_duration_ns=1000, connect_duration_ns=200)
+        self.assertEqual(metrics, other)
+        self.assertEqual(hash(metrics), hash(other))
+        self.assertEqual(len({metrics, other}), 1)
+        other.transfer_duration = 300
+        self.assertNotEqual(metrics, other)
+
 
 class TestSyncHttpResponse(unittest.TestCase):
     def test_new_sync_http_response(self):
@@ -62,6 +139,70 @@ class TestSyncHttpResponse(unittest.TestCase):
         self.assertEqual(response.read(2), b'he')
         self.assertEqual(response.readall(), b'llo')
 
+    def test_sync_http_response_decode_content(self):
+        response = http.SyncHttpResponse(
+            headers={'content-encoding': 'gzip'}, body=gzip.compress(b'hello world'))
+        self.assertEqual(response.read(5), b'hello')
+        self.assertEqual(response.headers, {})
+        self.assertEqual(response.readall(), b' world')
+        response = http.SyncHttpResponse(
+            headers={'content-encoding': 'deflate'}, body=zlib.compress(b'hello world'))
+        self.assertEqual(response.readall(), b'hello world')
+        compressed = gzip.compress(b'hello world')
+        response = http.SyncHttpResponse(
+            headers={'content-encoding': 'gzip'}, body=compressed)
+        self.assertEqual(response.readall(decode_content=False), compressed)
+        self.assertEqual(response.headers, {'content-encoding': 'gzip'})
+        response = http.SyncHttpResponse(
+            headers={'content-encoding': 'gzip'}, body=compressed)
+        response.read(5, decode_content=False)
+        with self.assertRaises(ValueError):
+            response.read()
+
+    def test_sync_http_response_seek(self):
+        response = http.SyncHttpResponse(body=b'hello world')
+        self.assertTrue(response.seekable())
+        self.assertEqual(response.read(5), b'hello')
+        self.assertEqual(response.tell(), 5)
+        self.assertEqual(response.seek(0), 0)
+        self.assertEqual(response.readall(), b'hello world')
+        self.assertEqual(response.seek(-5, io.SEEK_END), 6)
+        self.assertEqual(response.read(), b'world')
+
+        response = http.SyncHttpResponse(body=io.BytesIO(b'hello world'))
+        self.assertTrue(response.seekable())
+        self.assertEqual(response.seek(6), 6)
+        self.assertEqual(response.read(), b'world')
+
+        response = http.SyncHttpResponse(
+            headers={'content-encoding': 'gzip'}, body=gzip.compress(b'hello world'))
+        self.assertEqual(response.read(5), b'hello')
+        self.assertFalse(response.seekable())
+        with self.assertRaises(NotImplementedError):
+            response.seek(0)
+
+    def test_sync_http_response_readall_with_progress(self):
+        data = os.urandom(1 << 18)
+        response = http.SyncHttpResponse(body=data)
+        progresses = []
+        self.assertEqual(response.readall(
+            progress=lambda have_read: progresses.append(have_read)), data)
+        self.assertTrue(len(progresses) > 1)
+        self.assertEqual(progresses, sorted(progresses))
+        self.assertEqual(progresses[-1], len(data))
+
+    def test_sync_http_response_text(self):
+        response = http.SyncHttpResponse(
+            headers={'content-type': 'text/plain; charset=gbk'}, body='七牛'.encode('gbk'))
+        self.assertEqual(response.text(), '七牛')
+        response = http.SyncHttpResponse(body='七牛'.encode('utf-8'))
+        self.assertEqual(response.text(), '七牛')
+        response = http.SyncHttpResponse(body='七牛'.encode('gbk'))
+        self.assertEqual(response.text('gbk'), '七牛')
+        response = http.SyncHttpResponse(body=b'\xff\xfe\xfd')
+        with self.assertRaises(UnicodeDecodeError):
+            response.text()
+
 
 class TestAsyncHttpResponse(unittest.IsolatedAsyncioTestCase):
     async def test_new_async_http_response(self):
@@ -79,6 +220,35 @@ class TestAsyncHttpResponse(unittest.IsolatedAsyncioTestCase):
         self.assertEqual(await response.read(2), b'he')
         self.assertEqual(await response.readall(), b'llo')
 
+    async def test_async_http_response_decode_content(self):
+        response = http.AsyncHttpResponse(
+            headers={'content-encoding': 'gzip'}, body=gzip.compress(b'hello world'))
+        self.assertEqual(await response.read(5), b'hello')
+        self.assertEqual(response.headers, {})
+        self.assertEqual(await response.readall(), b' world')
+        response = http.AsyncHttpResponse(
+            headers={'content-encoding': 'deflate'}, body=zlib.compress(b'hello world'))
+        self.assertEqual(await response.text(), 'hello world')
+
+    async def test_async_http_response_readall_with_progress(self):
+        data = os.urandom(1 << 18)
+        response = http.AsyncHttpResponse(body=data)
+        progresses = []
+        self.assertEqual(await response.readall(
+            progress=lambda have_read: progresses.append(have_read)), data)
+        self.assertEqual(progresses, sorted(progresses))
+        self.assertEqual(progresses[-1], len(data))
+
+    async def test_async_http_response_text(self):
+        response = http.AsyncHttpResponse(
+            headers={'content-type': 'text/plain; charset=gbk'}, body='七牛'.encode('gbk'))
+        self.assertEqual(await response.text(), '七牛')
+        response = http.AsyncHttpResponse(body='七牛'.encode('gbk'))
+        self.assertEqual(await response.text('gbk'), '七牛')
+        response = http.AsyncHttpResponse(body=b'\xff\xfe\xfd')
+        with self.assertRaises(UnicodeDecodeError):
+            await response.text()
+
 
 class TestSyncIsahcHttpCaller(unittest.TestCase):
     def test_sync_isahc_http_caller(self):
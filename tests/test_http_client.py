@@ -1,10 +1,17 @@
-from qiniu_bindings import credential, http, http_client, QiniuInvalidDomainWithPortError, QiniuInvalidIpAddrWithPortError, QiniuEmptyRegionsProvider, QiniuApiCallError
+from qiniu_bindings import apis, credential, http, http_client, QiniuInvalidDomainWithPortError, QiniuInvalidEndpointError, QiniuInvalidIpAddrWithPortError, QiniuEmptyRegionsProvider, QiniuApiCallError, QiniuErrorKind, QiniuInvalidRateLimit, QiniuJsonError, QiniuInvalidBackoffJitter, QiniuInvalidRatio, QiniuResponseTooLargeError, QiniuTimeoutError, QiniuUnpicklableError, QiniuInvalidConcurrency
 from aiohttp import web
 import os
 import io
 import aiofiles
 import unittest
 import fractions
+import json
+import gzip
+import tempfile
+import pickle
+import pathlib
+import time
+import asyncio
 
 
 class TestDomainWithPort(unittest.TestCase):
@@ -78,6 +85,29 @@ class TestEndpoint(unittest.TestCase):
         self.assertEqual(d.ip_addr, '127.0.0.1')
         self.assertEqual(d.port, None)
 
+    def test_endpoint_with_scheme(self):
+        d = http_client.Endpoint('http://up.qiniup.com')
+        self.assertEqual(d.domain, 'up.qiniup.com')
+        self.assertEqual(d.port, 80)
+        self.assertEqual(d.use_https, False)
+        d = http_client.Endpoint.from_url('https://up.qiniup.com:8080/path')
+        self.assertEqual(d.domain, 'up.qiniup.com')
+        self.assertEqual(d.port, 8080)
+        self.assertEqual(d.use_https, True)
+        d = http_client.Endpoint('https://127.0.0.1')
+        self.assertEqual(d.ip_addr, '127.0.0.1')
+        self.assertEqual(d.port, 443)
+        self.assertEqual(d.use_https, True)
+        d = http_client.Endpoint('up.qiniup.com')
+        self.assertEqual(d.domain, 'up.qiniup.com')
+        self.assertEqual(d.port, None)
+        self.assertIsNone(d.use_https)
+        with self.assertRaises(QiniuInvalidEndpointError):
+            http_client.Endpoint.from_url('up.qiniup.com')
+        with self.assertRaises(QiniuInvalidEndpointError) as ctx:
+            http_client.Endpoint('ftp://up.qiniup.com')
+        self.assertEqual(ctx.exception.to_dict()['type'], 'QiniuInvalidEndpointError')
+
 
 class TestEndpoints(unittest.TestCase):
     def test_endpoints(self):
@@ -118,6 +148,62 @@ class TestEndpoints(unittest.TestCase):
             http_client.Endpoint('192.168.2.3', 8080),
         ])
 
+    def test_accelerated_endpoints(self):
+        e = http_client.Endpoints(['192.168.1.1:8080'], ['192.168.2.1:8080'])
+        accelerated = e.with_accelerated_endpoints(
+            ['upload-acc.example.com'], ['upload-acc2.example.com'])
+        self.assertEqual(accelerated.preferred, [
+                         http_client.Endpoint('upload-acc.example.com')])
+        self.assertEqual(accelerated.alternative, [
+            http_client.Endpoint('upload-acc2.example.com'),
+            http_client.Endpoint('192.168.1.1', 8080),
+            http_client.Endpoint('192.168.2.1', 8080),
+        ])
+        self.assertEqual(e.preferred, [
+                         http_client.Endpoint('192.168.1.1', 8080)])
+
+
+    def test_endpoints_from_list(self):
+        e = http_client.Endpoints.from_list(
+            ['192.168.1.1:8080', ('192.168.1.2', 8080)], ['192.168.2.1'])
+        self.assertEqual(e.preferred, [
+            http_client.Endpoint('192.168.1.1', 8080),
+            http_client.Endpoint('192.168.1.2', 8080),
+        ])
+        self.assertEqual(e.alternative, [
+                         http_client.Endpoint('192.168.2.1')])
+        self.assertEqual(e, http_client.Endpoints(
+            ['192.168.1.1:8080', '192.168.1.2:8080'], ['192.168.2.1']))
+        self.assertEqual(http_client.Endpoints.from_list(
+            ['192.168.1.1:8080']).alternative, [])
+
+
+    def test_endpoints_json_and_hash(self):
+        e = http_client.Endpoints(
+            ['192.168.1.2:8080', 'domain.com'], ['192.168.2.1', 'domain2.com:8080'])
+        e2 = http_client.Endpoints.from_json(e.to_json())
+        self.assertEqual(e, e2)
+        self.assertEqual(hash(e), hash(e2))
+        self.assertEqual(e2.preferred, [http_client.Endpoint(
+            '192.168.1.2', 8080), http_client.Endpoint('domain.com')])
+        self.assertEqual(e2.alternative, [http_client.Endpoint(
+            '192.168.2.1'), http_client.Endpoint('domain2.com', 8080)])
+        self.assertEqual(len({e, e2, http_client.Endpoints(['domain.com'])}), 2)
+        self.assertEqual(json.loads(e.to_json()), {
+            'preferred': ['192.168.1.2:8080', 'domain.com'],
+            'alternative': ['192.168.2.1', 'domain2.com:8080']})
+        with self.assertRaises(QiniuJsonError):
+            http_client.Endpoints.from_json('not json')
+
+    def test_endpoints_pickle(self):
+        e = http_client.Endpoints(
+            ['192.168.1.2:8080', 'domain.com'], ['192.168.2.1', 'domain2.com:8080'])
+        e2 = pickle.loads(pickle.dumps(e))
+        self.assertIsInstance(e2, http_client.Endpoints)
+        self.assertEqual(e, e2)
+        with self.assertRaises(QiniuUnpicklableError):
+            pickle.dumps(http_client.HttpClient())
+
 
 class TestRegion(unittest.TestCase):
     def test_region(self):
@@ -212,6 +298,47 @@ class TestRegion(unittest.TestCase):
         ]))
 
 
+    def test_region_with_service_endpoints(self):
+        r = http_client.Region('z0',
+                               up=http_client.Endpoints(
+                                   ['192.168.1.1:8080'], ['192.168.2.1:8080']),
+                               uc=['192.168.3.1:8080'],
+                               uc_alternative_endpoints=['192.168.4.1:8080'],
+                               api=[('192.168.5.1', 8080)])
+        self.assertEqual(r.up, http_client.Endpoints(
+            ['192.168.1.1:8080'], ['192.168.2.1:8080']))
+        self.assertEqual(r.uc, http_client.Endpoints(
+            ['192.168.3.1:8080'], ['192.168.4.1:8080']))
+        self.assertEqual(r.api, http_client.Endpoints(['192.168.5.1:8080']))
+        self.assertEqual(r.io, http_client.Endpoints([]))
+        self.assertEqual(r, http_client.Region('z0',
+                                               up_preferred_endpoints=['192.168.1.1:8080'],
+                                               up_alternative_endpoints=['192.168.2.1:8080'],
+                                               uc_preferred_endpoints=['192.168.3.1:8080'],
+                                               uc_alternative_endpoints=['192.168.4.1:8080'],
+                                               api_preferred_endpoints=['192.168.5.1:8080']))
+        self.assertNotEqual(r, http_client.Region('z1',
+                                                  up=http_client.Endpoints(
+                                                      ['192.168.1.1:8080'], ['192.168.2.1:8080']),
+                                                  uc=['192.168.3.1:8080'],
+                                                  uc_alternative_endpoints=['192.168.4.1:8080'],
+                                                  api=[('192.168.5.1', 8080)]))
+
+    def test_region_pickle(self):
+        r = http_client.Region('z0',
+                               s3_region_id='cn-east-1',
+                               up=http_client.Endpoints(
+                                   ['192.168.1.1:8080'], ['192.168.2.1:8080']),
+                               io=['192.168.3.1'],
+                               api=[('192.168.5.1', 8080)])
+        r2 = pickle.loads(pickle.dumps(r))
+        self.assertIsInstance(r2, http_client.Region)
+        self.assertEqual(r, r2)
+        self.assertEqual(r2.s3_region_id, 'cn-east-1')
+        with self.assertRaises(QiniuUnpicklableError):
+            pickle.dumps(http_client.RegionsProvider([r]))
+
+
 class TestRegionsProvider(unittest.TestCase):
     def test_regions_provider(self):
         r1 = http_client.Region('z0',
@@ -243,6 +370,27 @@ class TestRegionsProvider(unittest.TestCase):
         with self.assertRaises(QiniuEmptyRegionsProvider):
             http_client.RegionsProvider([])
 
+    def test_accelerated_regions_provider(self):
+        r1 = http_client.Region('z0',
+                                s3_region_id='cn-east-1',
+                                up_preferred_endpoints=['192.168.1.1:8080'],
+                                up_alternative_endpoints=['192.168.2.1:8080'],
+                                io_preferred_endpoints=['192.168.3.1:8080'])
+        r2 = http_client.Region('z1',
+                                up_preferred_endpoints=['192.168.4.1:8080'])
+        provider = http_client.AcceleratedRegionsProvider(
+            http_client.RegionsProvider([r1, r2]), ['upload-acc.example.com'], ['upload-acc2.example.com'])
+        r = provider.get()
+        self.assertEqual(r.region_id, 'z0')
+        self.assertEqual(r.s3_region_id, 'cn-east-1')
+        self.assertEqual(r.up, http_client.Endpoints(['upload-acc.example.com'], [
+                         'upload-acc2.example.com', '192.168.1.1:8080', '192.168.2.1:8080']))
+        self.assertEqual(r.io, r1.io)
+        regions = provider.get_all()
+        self.assertEqual(len(regions), 2)
+        self.assertEqual(regions[1].up, http_client.Endpoints(['upload-acc.example.com'], [
+                         'upload-acc2.example.com', '192.168.4.1:8080']))
+
 
 class TestEndpointsProvider(unittest.TestCase):
     def test_endpoints_provider(self):
@@ -315,6 +463,72 @@ class TestAllRegionsProvider(unittest.IsolatedAsyncioTestCase):
             await runner.cleanup()
 
 
+    async def test_all_regions_provider_refresh(self):
+        requests = 0
+        fail = False
+
+        async def handler(request):
+            nonlocal requests
+            requests += 1
+            if fail:
+                return web.json_response({'error': 'bad request'}, status=400, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response(regions_response_body(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/regions', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            with tempfile.TemporaryDirectory() as dir:
+                provider = http_client.AllRegionsProvider.load_or_create_from(credential.Credential(
+                    'ak', 'sk'), os.path.join(dir, 'regions-cache'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']), cache_lifetime_secs=3600)
+                regions = await provider.async_get_all()
+                self.assertEqual(len(regions), 5)
+                await provider.async_get_all()
+                self.assertEqual(requests, 1)
+
+                regions = await provider.async_refresh()
+                self.assertEqual(len(regions), 5)
+                self.assertEqual(regions[0].region_id, 'z0')
+                self.assertEqual(requests, 2)
+                await provider.async_get_all()
+                self.assertEqual(requests, 2)
+
+                fail = True
+                with self.assertRaises(QiniuApiCallError):
+                    await provider.async_refresh()
+                regions = await provider.async_get_all()
+                self.assertEqual(len(regions), 5)
+        finally:
+            await runner.cleanup()
+
+    async def test_all_regions_provider_refresh_empty_regions(self):
+        async def handler(request):
+            return web.json_response({'regions': []}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/regions', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            with tempfile.TemporaryDirectory() as dir:
+                provider = http_client.AllRegionsProvider(credential.Credential('ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(
+                    ['127.0.0.1:8089']), cache_lifetime_secs=3600, cache_path=os.path.join(dir, 'regions-cache'))
+                self.assertEqual(await provider.async_refresh(), [])
+                with self.assertRaises(QiniuApiCallError):
+                    await provider.async_get()
+                with self.assertRaises(QiniuApiCallError):
+                    provider.get()
+        finally:
+            await runner.cleanup()
+
+
 class TestBucketRegionsQueryer(unittest.IsolatedAsyncioTestCase):
     async def test_bucket_regions_queryer(self):
         async def handler(request):
@@ -340,6 +554,34 @@ class TestBucketRegionsQueryer(unittest.IsolatedAsyncioTestCase):
         finally:
             await runner.cleanup()
 
+    async def test_bucket_regions_queryer_warm(self):
+        buckets = []
+
+        async def handler(request):
+            buckets.append(request.query['bucket'])
+            return web.json_response(query_response_body(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']), cache_lifetime_secs=3600)
+            with self.assertRaises(QiniuInvalidConcurrency):
+                await queryer.async_warm('ak', ['bucket1'], concurrency=0)
+            await queryer.async_warm('ak', ['bucket1', 'bucket2'], concurrency=1)
+            self.assertEqual(sorted(buckets), ['bucket1', 'bucket2'])
+            for bucket in ['bucket1', 'bucket2']:
+                regions = await queryer.query('ak', bucket).async_get_all()
+                self.assertEqual(len(regions), 2)
+            self.assertEqual(len(buckets), 2)
+        finally:
+            await runner.cleanup()
+
 
 class TestBucketDomainsQueryer(unittest.IsolatedAsyncioTestCase):
     async def test_bucket_domains_queryer(self):
@@ -721,6 +963,25 @@ class TestAuthorization(unittest.IsolatedAsyncioTestCase):
         self.assertTrue(req.url.startswith(
             'http://127.0.0.1:8080/robots.txt?e='))
 
+    async def test_authorization_header_for(self):
+        cred = credential.Credential('ak', 'sk')
+        auth = http_client.Authorization.v1(cred)
+        self.assertEqual(auth.authorization_header_for('POST', 'http://127.0.0.1:8080/robots.txt', body=b'hello world'),
+                         'QBox ak:OM5YrCaVA6t1nWsDpqPOdIZ2ufA=')
+        self.assertEqual(await auth.async_authorization_header_for('POST', 'http://127.0.0.1:8080/robots.txt'),
+                         'QBox ak:OM5YrCaVA6t1nWsDpqPOdIZ2ufA=')
+        auth = http_client.Authorization.v2(cred)
+        header = auth.authorization_header_for('POST', 'http://127.0.0.1:8080/robots.txt',
+                                               headers={'Content-Type': 'application/json'}, body=b'{}')
+        self.assertTrue(header.startswith('Qiniu ak:'))
+        self.assertNotEqual(header, auth.authorization_header_for(
+            'POST', 'http://127.0.0.1:8080/robots.txt', headers={'Content-Type': 'application/json'}, body=b'{"a":1}'))
+        self.assertEqual(header, await auth.async_authorization_header_for('POST', 'http://127.0.0.1:8080/robots.txt',
+                                                                           headers={'Content-Type': 'application/json'}, body=b'{}'))
+        auth = http_client.Authorization.download(cred)
+        self.assertIsNone(auth.authorization_header_for(
+            'GET', 'http://127.0.0.1:8080/robots.txt'))
+
 
 class TestResolver(unittest.IsolatedAsyncioTestCase):
     async def test_simple_resolver(self):
@@ -733,6 +994,75 @@ class TestResolver(unittest.IsolatedAsyncioTestCase):
         domains = await resolver.async_resolve('upload.qiniup.com')
         self.assertTrue(len(domains) > 0)
 
+    async def test_cached_resolver(self):
+        with tempfile.TemporaryDirectory() as dir:
+            path = os.path.join(dir, 'resolver-cache.json')
+            resolver = http_client.CachedResolver.load_or_create_from(
+                http_client.SimpleResolver(), path, auto_persistent=False)
+            self.assertEqual(len(resolver), 0)
+            ips = await resolver.async_resolve('localhost')
+            self.assertTrue(len(ips) > 0)
+            self.assertEqual(len(resolver), 1)
+            self.assertFalse(os.path.exists(path))
+            resolver.persist()
+            with open(path) as f:
+                self.assertIn('localhost', f.read())
+
+            resolver = http_client.CachedResolver.load_or_create_from(
+                http_client.SimpleResolver(), path)
+            self.assertEqual(len(resolver), 1)
+            self.assertEqual(await resolver.async_resolve('localhost'), ips)
+            self.assertEqual(len(resolver), 1)
+            resolver.clear()
+            self.assertEqual(len(resolver), 0)
+            self.assertFalse(os.path.exists(path))
+
+            resolver = http_client.CachedResolver.load_or_create_from(
+                http_client.SimpleResolver(), path, auto_persistent=False)
+            self.assertEqual(len(resolver), 0)
+
+    async def test_doh_resolver(self):
+        async def handler(request):
+            self.assertEqual(request.query['name'], 'upload.qiniup.com')
+            self.assertEqual(request.headers['Accept'], 'application/dns-json')
+            if request.query['type'] == '1':
+                answers = [{'name': 'upload.qiniup.com', 'type': 5, 'data': 'upload.qiniup.com.cdn.'},
+                           {'name': 'upload.qiniup.com', 'type': 1, 'data': '10.0.0.1'},
+                           {'name': 'upload.qiniup.com', 'type': 1, 'data': '10.0.0.2'}]
+            else:
+                answers = [{'name': 'upload.qiniup.com',
+                            'type': 28, 'data': '::1'}]
+            return web.json_response({'Status': 0, 'Answer': answers})
+
+        async def failed_handler(request):
+            return web.json_response({'error': 'internal error'}, status=500)
+
+        app = web.Application()
+        app.add_routes([web.get('/dns-query', handler),
+                       web.get('/failed-dns-query', failed_handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            resolver = http_client.DohResolver(
+                'http://127.0.0.1:8089/dns-query', timeout_ms=3000)
+            ips = await resolver.async_resolve('upload.qiniup.com')
+            self.assertEqual(ips, ['10.0.0.1', '10.0.0.2', '::1'])
+
+            resolver = http_client.DohResolver('http://127.0.0.1:8089/failed-dns-query',
+                                               fallback=http_client.SimpleResolver())
+            ips = await resolver.async_resolve('localhost')
+            self.assertTrue(len(ips) > 0)
+
+            resolver = http_client.DohResolver(
+                'http://127.0.0.1:8089/failed-dns-query')
+            with self.assertRaises(QiniuApiCallError):
+                await resolver.async_resolve('upload.qiniup.com')
+        finally:
+            await runner.cleanup()
+
 
 class TestChoose(unittest.IsolatedAsyncioTestCase):
     async def test_chooser(self):
@@ -741,6 +1071,18 @@ class TestChoose(unittest.IsolatedAsyncioTestCase):
         self.assertEqual(
             chosen, ['127.0.0.1:8000', '127.0.0.1:8001', '127.0.0.1:8002'])
 
+    async def test_choose_detailed(self):
+        chooser = http_client.DirectChooser()
+        chosen = chooser.choose_detailed(
+            [http_client.IpAddrWithPort('127.0.0.1', 8000), '127.0.0.1:8001'])
+        self.assertEqual(chosen, [http_client.IpAddrWithPort(
+            '127.0.0.1', 8000), http_client.IpAddrWithPort('127.0.0.1', 8001)])
+        self.assertEqual(chosen[1].ip_addr, '127.0.0.1')
+        self.assertEqual(chosen[1].port, 8001)
+        chosen = await chooser.async_choose_detailed(['127.0.0.2'])
+        self.assertEqual(chosen[0].ip_addr, '127.0.0.2')
+        self.assertIsNone(chosen[0].port)
+
     async def test_ip_chooser(self):
         chooser = http_client.IpChooser()
         chosen = await chooser.async_choose(['127.0.0.1', '127.0.0.2', '127.0.1.1'])
@@ -756,6 +1098,13 @@ class TestChoose(unittest.IsolatedAsyncioTestCase):
             await chooser.async_feedback(['127.0.0.1'], error=e)
             chosen = await chooser.async_choose(['127.0.0.1', '127.0.0.2', '127.0.1.1'])
             self.assertEqual(chosen, ['127.0.0.2', '127.0.1.1'])
+            self.assertEqual(chooser.blocked_ips(), ['127.0.0.1'])
+            chooser.feedback([http_client.IpAddrWithPort('127.0.0.2')], error=e)
+            chosen = chooser.choose_detailed(['127.0.0.1', '127.0.0.2', '127.0.1.1'])
+            self.assertEqual(chosen, [http_client.IpAddrWithPort('127.0.1.1')])
+            self.assertEqual(chooser.blocked_ips(), ['127.0.0.1', '127.0.0.2'])
+            chooser.feedback(['127.0.0.1'])
+            self.assertEqual(chooser.blocked_ips(), ['127.0.0.2'])
 
     async def test_subnet_chooser(self):
         chooser = http_client.SubnetChooser()
@@ -771,6 +1120,25 @@ class TestChoose(unittest.IsolatedAsyncioTestCase):
             chosen = await chooser.async_choose(['127.0.0.1', '127.0.0.2', '127.0.1.1'])
             self.assertEqual(chosen, ['127.0.1.1'])
 
+    async def test_manually_block_ips(self):
+        chooser = http_client.IpChooser()
+        chooser.mark_unavailable('127.0.0.2', 60)
+        chooser.mark_unavailable('127.0.1.1', 60)
+        self.assertEqual(chooser.blocked_ips(), ['127.0.0.2', '127.0.1.1'])
+        chosen = await chooser.async_choose(['127.0.0.1:8000', '127.0.0.2:8000', '127.0.1.1'])
+        self.assertEqual(chosen, ['127.0.0.1:8000'])
+        chooser.mark_available('127.0.1.1')
+        self.assertEqual(chooser.blocked_ips(), ['127.0.0.2'])
+        chosen = chooser.choose(['127.0.0.1', '127.0.0.2', '127.0.1.1'])
+        self.assertEqual(chosen, ['127.0.0.1', '127.0.1.1'])
+
+        chooser = http_client.SubnetChooser()
+        chooser.mark_unavailable('127.0.0.1', 0)
+        self.assertEqual(chooser.blocked_ips(), [])
+        chooser.mark_unavailable('127.0.0.1', 60)
+        chosen = await chooser.async_choose(['127.0.0.1', '127.0.0.2'])
+        self.assertEqual(chosen, ['127.0.0.2'])
+
     async def test_never_empty_handed_chooser(self):
         chooser = http_client.NeverEmptyHandedChooser(
             http_client.IpChooser(), fractions.Fraction(1, 2))
@@ -786,6 +1154,13 @@ class TestChoose(unittest.IsolatedAsyncioTestCase):
             chosen = await chooser.async_choose(['127.0.0.1', '127.0.0.2', '127.0.1.1'])
             self.assertEqual(len(chosen), 2)
 
+    def test_never_empty_handed_chooser_with_float(self):
+        http_client.NeverEmptyHandedChooser(http_client.IpChooser(), 0.5)
+        with self.assertRaises(QiniuInvalidRatio):
+            http_client.NeverEmptyHandedChooser(http_client.IpChooser(), 1.5)
+        with self.assertRaises(QiniuInvalidRatio):
+            http_client.NeverEmptyHandedChooser(http_client.IpChooser(), 0.0)
+
 
 class TestRetrier(unittest.IsolatedAsyncioTestCase):
     async def test_error_retrier(self):
@@ -840,6 +1215,21 @@ class TestRetrier(unittest.IsolatedAsyncioTestCase):
             await runner.cleanup()
 
 
+    def test_retried_stats_info_timestamps(self):
+        retried_stats = http_client.RetriedStatsInfo()
+        first_attempt_at = retried_stats.first_attempt_at_ns
+        self.assertEqual(retried_stats.last_attempt_at_ns, first_attempt_at)
+        self.assertEqual(retried_stats.elapsed_ns, 0)
+
+        time.sleep(0.01)
+        retried_stats.increase_current_endpoint()
+        self.assertEqual(retried_stats.first_attempt_at_ns, first_attempt_at)
+        self.assertGreater(retried_stats.last_attempt_at_ns, first_attempt_at)
+        self.assertEqual(retried_stats.elapsed_ns,
+                         retried_stats.last_attempt_at_ns - first_attempt_at)
+        self.assertGreaterEqual(retried_stats.elapsed_ns, 10000000)
+
+
 class TestBackoff(unittest.IsolatedAsyncioTestCase):
     async def test_backoff(self):
         async def handler(request):
@@ -884,6 +1274,18 @@ class TestBackoff(unittest.IsolatedAsyncioTestCase):
             self.assertTrue(time_ns <= 1500000)
             self.assertTrue(time_ns >= 500000)
 
+            backoff = http_client.RandomizedBackoff(
+                http_client.FixedBackoff(1000000), 0.5, 1.5)
+            self.assertEqual(backoff.minification, fractions.Fraction(1, 2))
+            self.assertEqual(backoff.magnification, fractions.Fraction(3, 2))
+            time_ns = backoff.time_ns(request, e, retried=retried_stats)
+            self.assertTrue(time_ns <= 1500000)
+            self.assertTrue(time_ns >= 500000)
+
+            with self.assertRaises(QiniuInvalidRatio):
+                http_client.RandomizedBackoff(
+                    http_client.FixedBackoff(1000000), 1.5, 1.5)
+
             backoff = http_client.LimitedBackoff(backoff, 900000, 1100000)
             self.assertEqual(backoff.min_backoff, 900000)
             self.assertEqual(backoff.max_backoff, 1100000)
@@ -891,6 +1293,27 @@ class TestBackoff(unittest.IsolatedAsyncioTestCase):
             self.assertTrue(time_ns <= 1100000)
             self.assertTrue(time_ns >= 900000)
 
+            backoff = http_client.JitteredExponentialBackoff(2, 1000000, jitter=0.5)
+            self.assertEqual(backoff.base_number, 2)
+            self.assertEqual(backoff.base_delay, 1000000)
+            self.assertEqual(backoff.jitter, 0.5)
+            time_ns = backoff.time_ns(request, e, retried=retried_stats)
+            self.assertTrue(time_ns <= 6000000)
+            self.assertTrue(time_ns >= 2000000)
+
+            with self.assertRaises(QiniuInvalidBackoffJitter):
+                http_client.JitteredExponentialBackoff(2, 1000000, jitter=1.5)
+
+            self.assertEqual(http_client.FixedBackoff.from_millis(1).delay, 1000000)
+            self.assertEqual(http_client.FixedBackoff.from_secs(5).delay, 5000000000)
+            self.assertEqual(http_client.ExponentialBackoff.from_millis(2, 1).base_delay, 1000000)
+            backoff = http_client.LimitedBackoff.from_secs(backoff, 1, 2)
+            self.assertEqual(backoff.min_backoff, 1000000000)
+            self.assertEqual(backoff.max_backoff, 2000000000)
+            backoff = http_client.JitteredExponentialBackoff.from_millis(2, 1, jitter=0.1)
+            self.assertEqual(backoff.base_delay, 1000000)
+            self.assertEqual(backoff.jitter, 0.1)
+
         finally:
             await runner.cleanup()
 
@@ -927,6 +1350,100 @@ class TestHttpClient(unittest.IsolatedAsyncioTestCase):
         finally:
             await runner.cleanup()
 
+    async def test_rate_limited_get(self):
+        async def handler(request):
+            return web.json_response({}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/getfile', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            with self.assertRaises(QiniuInvalidRateLimit):
+                http_client.HttpClient(max_requests_per_sec=0)
+
+            client = http_client.HttpClient(max_requests_per_sec=2)
+            begin_at = time.monotonic()
+            for _ in range(4):
+                resp = await client.async_call(
+                    'GET', http_client.Endpoints(['127.0.0.1:8089']),
+                    use_https=False,
+                    path='/getfile')
+                self.assertEqual(resp.status_code, 200)
+            self.assertGreaterEqual(time.monotonic() - begin_at, 0.9)
+        finally:
+            await runner.cleanup()
+
+    async def test_get_with_body_complete_callback(self):
+        async def handler(request):
+            return web.json_response({'hello': 'world'}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/getfile', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            completed = []
+            client = http_client.HttpClient(use_https=False)
+            resp = await client.async_call(
+                'GET', http_client.Endpoints(['127.0.0.1:8089']),
+                path='/getfile',
+                on_body_complete=lambda parts: completed.append(parts))
+            self.assertEqual(completed, [])
+            self.assertEqual(await resp.parse_json(), {'hello': 'world'})
+            self.assertEqual(len(completed), 1)
+            self.assertEqual(completed[0].status_code, 200)
+        finally:
+            await runner.cleanup()
+
+    async def test_get_with_max_body_size(self):
+        async def large_handler(request):
+            return web.Response(body=b'x' * 4096, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        async def stream_handler(request):
+            resp = web.StreamResponse(status=200, headers={'X-ReqId': 'fakereqid'})
+            resp.enable_chunked_encoding()
+            await resp.prepare(request)
+            for _ in range(4):
+                await resp.write(b'x' * 1024)
+            await resp.write_eof()
+            return resp
+
+        app = web.Application()
+        app.add_routes([web.get('/large', large_handler),
+                       web.get('/stream', stream_handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.HttpClient(use_https=False)
+            with self.assertRaises(QiniuResponseTooLargeError):
+                await client.async_call(
+                    'GET', http_client.Endpoints(['127.0.0.1:8089']),
+                    path='/large',
+                    max_body_size=1024)
+            resp = await client.async_call(
+                'GET', http_client.Endpoints(['127.0.0.1:8089']),
+                path='/stream',
+                max_body_size=1024)
+            with self.assertRaises(QiniuResponseTooLargeError):
+                await resp.readall()
+            resp = await client.async_call(
+                'GET', http_client.Endpoints(['127.0.0.1:8089']),
+                path='/stream',
+                max_body_size=4096)
+            self.assertEqual(await resp.readall(), b'x' * 4096)
+        finally:
+            await runner.cleanup()
+
     async def test_post_bytes(self):
         async def handler(request):
             self.assertTrue(
@@ -956,6 +1473,438 @@ class TestHttpClient(unittest.IsolatedAsyncioTestCase):
         finally:
             await runner.cleanup()
 
+    def test_default_shared_http_client(self):
+        client = http_client.HttpClient(use_https=False)
+        http_client.set_default_shared_http_client(client)
+        self.assertEqual(
+            str(http_client.default_shared_http_client()), str(client))
+        http_client.set_default_shared_http_client()
+        self.assertIsInstance(
+            http_client.default_shared_http_client(), http_client.HttpClient)
+
+    async def test_get_and_post_json(self):
+        async def get_handler(request):
+            self.assertEqual(request.query['key'], 'val')
+            return web.json_response({'method': 'get'}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        async def post_handler(request):
+            self.assertEqual(await request.json(), {'hello': 'world'})
+            return web.json_response({'method': 'post'}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/get', get_handler),
+                       web.post('/post', post_handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.HttpClient(use_https=False)
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            resp = await client.async_get(endpoints, '/get', query={'key': 'val'})
+            self.assertEqual(resp.status_code, 200)
+            self.assertEqual(resp['method'], 'get')
+            resp = await client.async_post_json(endpoints, '/post', {'hello': 'world'}, query='key=val')
+            self.assertEqual(resp.body, {'method': 'post'})
+        finally:
+            await runner.cleanup()
+
+    async def test_request_and_response_log(self):
+        async def get_handler(request):
+            return web.json_response({'method': 'get'}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/get', get_handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            requests = []
+            responses = []
+            client = http_client.HttpClient(
+                use_https=False, on_request=requests.append, on_response_log=responses.append)
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            resp = await client.async_get(endpoints, '/get')
+            self.assertEqual(resp['method'], 'get')
+            self.assertEqual(len(requests), 1)
+            self.assertEqual(requests[0]['method'], 'GET')
+            self.assertEqual(requests[0]['url'], 'http://127.0.0.1:8089/get')
+            self.assertEqual(requests[0]['attempt'], 1)
+            self.assertEqual(len(responses), 1)
+            self.assertEqual(responses[0]['status'], 200)
+            self.assertEqual(responses[0]['attempt'], 1)
+            self.assertGreaterEqual(responses[0]['duration_ms'], 0)
+        finally:
+            await runner.cleanup()
+
+    async def test_on_retry(self):
+        calls = 0
+
+        async def handler(request):
+            nonlocal calls
+            calls += 1
+            if calls == 1:
+                return web.json_response({"error": "concurrency limit exceeded"}, status=573, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response({'method': 'get'}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/get', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            retries = []
+            client = http_client.HttpClient(
+                use_https=False,
+                backoff=http_client.FixedBackoff(1000),
+                on_retry=lambda context, retried, backoff_ns: retries.append((context.url, retried, backoff_ns)))
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            resp = await client.async_get(endpoints, '/get')
+            self.assertEqual(resp['method'], 'get')
+            self.assertEqual(len(retries), 1)
+            self.assertEqual(retries[0][0], 'http://127.0.0.1:8089/get')
+            self.assertIsInstance(retries[0][1], http_client.RetriedStatsInfo)
+            self.assertIsNotNone(retries[0][1].first_attempt_at_ns)
+            self.assertEqual(retries[0][1].elapsed_ns, 0)
+            self.assertEqual(retries[0][2], 1000)
+        finally:
+            await runner.cleanup()
+
+    async def test_call_with_max_retries(self):
+        calls = 0
+
+        async def handler(request):
+            nonlocal calls
+            calls += 1
+            if calls % 3 != 0:
+                return web.json_response({"error": "concurrency limit exceeded"}, status=573, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response({'method': 'get'}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/get', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.HttpClient(
+                use_https=False,
+                request_retrier=http_client.LimitedRetrier.limit_total(
+                    http_client.ErrorRetrier(), 0),
+                backoff=http_client.FixedBackoff(1000))
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            with self.assertRaises(QiniuApiCallError):
+                await client.async_call('GET', endpoints, path='/get')
+            self.assertEqual(calls, 1)
+
+            calls = 0
+            resp = await client.async_call('GET', endpoints, path='/get', max_retries=2)
+            self.assertEqual(resp.status_code, 200)
+            self.assertEqual(calls, 3)
+        finally:
+            await runner.cleanup()
+
+    async def test_api_call_error_attributes(self):
+        async def handler(request):
+            return web.json_response({"error": "service unavailable"}, status=503, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/get', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.HttpClient(
+                use_https=False,
+                request_retrier=http_client.LimitedRetrier.limit_total(
+                    http_client.ErrorRetrier(), 0))
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            with self.assertRaises(QiniuApiCallError) as ctx:
+                await client.async_call('GET', endpoints, path='/get')
+            e = ctx.exception
+            self.assertEqual(e.status_code, 503)
+            self.assertEqual(e.error_kind, QiniuErrorKind.Status)
+            self.assertTrue(e.is_retryable)
+            self.assertEqual(e.response_headers['X-ReqId'], 'fakereqid')
+            self.assertIn(b'service unavailable', e.response_body)
+
+            d = e.to_dict()
+            json.dumps(d)
+            self.assertEqual(d['type'], 'QiniuApiCallError')
+            self.assertEqual(d['kind'], 'Status')
+            self.assertEqual(d['status'], 503)
+            self.assertEqual(d['x_reqid'], 'fakereqid')
+            self.assertTrue(d['is_retryable'])
+        finally:
+            await runner.cleanup()
+
+    async def test_call_with_extra_headers(self):
+        async def handler(request):
+            return web.json_response({'tenant': request.headers.get('X-Tenant-Id')}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/get', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.HttpClient(use_https=False, extra_headers={'X-Tenant-Id': 'tenant1'})
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            resp = await client.async_call('GET', endpoints, path='/get')
+            self.assertEqual(await resp.parse_json(), {'tenant': 'tenant1'})
+            resp = await client.async_call('GET', endpoints, path='/get', headers={'x-tenant-id': 'tenant2'})
+            self.assertEqual(await resp.parse_json(), {'tenant': 'tenant2'})
+        finally:
+            await runner.cleanup()
+
+    async def test_response_last_resolved_ips(self):
+        async def handler(request):
+            return web.json_response({}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/get', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.HttpClient(use_https=False)
+            resp = await client.async_call('GET', http_client.Endpoints(['localhost:8089']), path='/get')
+            self.assertIn('127.0.0.1', resp.last_resolved_ips)
+            resp = await client.async_call('GET', http_client.Endpoints(['127.0.0.1:8089']), path='/get')
+            self.assertEqual(resp.last_resolved_ips, [])
+        finally:
+            await runner.cleanup()
+
+    async def test_call_with_deadline(self):
+        calls = 0
+
+        async def handler(request):
+            nonlocal calls
+            calls += 1
+            return web.json_response({"error": "concurrency limit exceeded"}, status=573, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/get', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.HttpClient(
+                use_https=False,
+                backoff=http_client.FixedBackoff(100_000_000))
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            with self.assertRaises(QiniuTimeoutError):
+                await client.async_call('GET', endpoints, path='/get', max_retries=100, deadline_ms=500)
+            self.assertGreater(calls, 1)
+            self.assertLess(calls, 100)
+        finally:
+            await runner.cleanup()
+
+    async def test_call_with_deadline_cuts_off_slow_attempt(self):
+        async def handler(request):
+            await asyncio.sleep(10)
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/get', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.HttpClient(use_https=False)
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            started_at = time.monotonic()
+            with self.assertRaises(QiniuTimeoutError):
+                await asyncio.get_running_loop().run_in_executor(
+                    None, lambda: client.call('GET', endpoints, path='/get', deadline_ms=500))
+            self.assertLess(time.monotonic() - started_at, 5)
+        finally:
+            await runner.cleanup()
+
+    async def test_call_with_accept_encoding(self):
+        accept_encodings = []
+
+        async def handler(request):
+            accept_encoding = request.headers.get('Accept-Encoding')
+            accept_encodings.append(accept_encoding)
+            body = json.dumps({'method': 'get'}).encode('utf-8')
+            if accept_encoding is not None and 'gzip' in accept_encoding:
+                return web.Response(body=gzip.compress(body), status=200, headers={'X-ReqId': 'fakereqid', 'Content-Type': 'application/json', 'Content-Encoding': 'gzip'})
+            return web.Response(body=body, status=200, headers={'X-ReqId': 'fakereqid', 'Content-Type': 'application/json'})
+
+        app = web.Application()
+        app.add_routes([web.get('/get', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.HttpClient(use_https=False)
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            resp = await client.async_call('GET', endpoints, path='/get')
+            self.assertEqual(await resp.parse_json(), {'method': 'get'})
+            self.assertIsNone(resp.headers.get('content-encoding'))
+            self.assertEqual(accept_encodings[-1], 'gzip, deflate')
+
+            resp = await client.async_call('GET', endpoints, path='/get', accept_encoding=False)
+            self.assertEqual(await resp.parse_json(), {'method': 'get'})
+            self.assertIsNone(accept_encodings[-1])
+
+            client = http_client.HttpClient(use_https=False, accept_encoding=False)
+            resp = await client.async_call('GET', endpoints, path='/get')
+            self.assertEqual(await resp.parse_json(), {'method': 'get'})
+            self.assertIsNone(accept_encodings[-1])
+
+            resp = await client.async_call('GET', endpoints, path='/get', accept_encoding=True)
+            self.assertEqual(await resp.parse_json(), {'method': 'get'})
+            self.assertEqual(accept_encodings[-1], 'gzip, deflate')
+        finally:
+            await runner.cleanup()
+
+    async def test_call_with_follow_redirects(self):
+        async def redirect_handler(request):
+            return web.Response(status=302, headers={'X-ReqId': 'fakereqid', 'Location': '/final'})
+
+        async def final_handler(request):
+            return web.json_response({'method': 'get'}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/redirect', redirect_handler),
+                       web.get('/final', final_handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.HttpClient(use_https=False)
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            resp = await client.async_call('GET', endpoints, path='/redirect', follow_redirects=True)
+            self.assertEqual(resp.status_code, 200)
+            self.assertEqual(await resp.parse_json(), {'method': 'get'})
+            self.assertEqual(resp.redirect_history, ['http://127.0.0.1:8089/final'])
+
+            resp = await client.async_call('GET', endpoints, path='/final')
+            self.assertEqual(resp.redirect_history, [])
+        finally:
+            await runner.cleanup()
+
+    async def test_call_with_cross_origin_redirects(self):
+        authorizations = []
+
+        async def redirect_handler(request):
+            authorizations.append(request.headers.get('Authorization'))
+            return web.Response(status=302, headers={'X-ReqId': 'fakereqid', 'Location': 'http://127.0.0.1:8090/final'})
+
+        async def final_handler(request):
+            authorizations.append(request.headers.get('Authorization'))
+            self.assertIsNone(request.headers.get('Cookie'))
+            return web.json_response({'method': 'get'}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/redirect', redirect_handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        other_app = web.Application()
+        other_app.add_routes([web.get('/final', final_handler)])
+        other_runner = web.AppRunner(other_app)
+        await other_runner.setup()
+        other_site = web.TCPSite(other_runner, '127.0.0.1', 8090)
+        await other_site.start()
+
+        try:
+            client = http_client.HttpClient(use_https=False)
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            auth = http_client.Authorization.v2(credential.Credential('ak', 'sk'))
+            resp = await client.async_call('GET', endpoints, path='/redirect', headers={'Cookie': 'session=fake'},
+                                           authorization=auth, follow_redirects=True)
+            self.assertEqual(resp.status_code, 200)
+            self.assertEqual(await resp.parse_json(), {'method': 'get'})
+            self.assertEqual(resp.redirect_history, ['http://127.0.0.1:8090/final'])
+            self.assertEqual(len(authorizations), 2)
+            self.assertTrue(authorizations[0].startswith('Qiniu '))
+            self.assertIsNone(authorizations[1])
+        finally:
+            await runner.cleanup()
+            await other_runner.cleanup()
+
+    async def test_api_call_keep_raw(self):
+        async def handler(request):
+            return web.json_response({'fsize': 1024}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/stat/{entry}', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = apis.storage.stat_object.Client(use_https=False)
+            endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+            cred = credential.Credential('ak', 'sk')
+            resp = await client.async_call(endpoints, cred, entry='fakebucket:fakekey')
+            self.assertEqual(resp['fsize'], 1024)
+            self.assertIsNone(resp.raw_bytes)
+            resp = await client.async_call(endpoints, cred, entry='fakebucket:fakekey', keep_raw=True)
+            self.assertEqual(resp['fsize'], 1024)
+            self.assertEqual(json.loads(resp.raw_bytes), {'fsize': 1024})
+        finally:
+            await runner.cleanup()
+
+    async def test_post_body_path(self):
+        async def handler(request):
+            self.assertEqual(request.headers['Content-Length'], str(1 << 20))
+            body = await request.read()
+            self.assertEqual(body, data)
+            return web.json_response({}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application(client_max_size=1 << 30)
+        app.add_routes([web.post('/postbinary', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        data = os.urandom(1 << 20)
+        try:
+            with tempfile.TemporaryDirectory() as dir:
+                path = pathlib.Path(dir, 'body')
+                path.write_bytes(data)
+                client = http_client.HttpClient(use_https=False)
+                resp = await client.async_call(
+                    'POST', http_client.Endpoints(['127.0.0.1:8089']),
+                    path='/postbinary',
+                    body_path=path)
+                self.assertEqual(resp.status_code, 200)
+                resp = await client.async_call(
+                    'POST', http_client.Endpoints(['127.0.0.1:8089']),
+                    path='/postbinary',
+                    body_path=str(path))
+                self.assertEqual(resp.status_code, 200)
+        finally:
+            await runner.cleanup()
+
     async def test_post_reader(self):
         async def handler(request):
             self.assertTrue(
@@ -1102,6 +2051,52 @@ class TestHttpClient(unittest.IsolatedAsyncioTestCase):
         finally:
             await runner.cleanup()
 
+    async def test_post_multipart_form(self):
+        parts = []
+
+        async def handler(request):
+            multipart = await request.multipart()
+            while True:
+                part = await multipart.next()
+                if part is None:
+                    break
+                parts.append((part.name, part.filename,
+                             part.headers.get('content-type'), await part.read()))
+            return web.json_response({}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/postmultipart', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            with tempfile.NamedTemporaryFile(suffix='.txt') as f:
+                f.write(b'file content')
+                f.flush()
+                form = http_client.MultipartForm() \
+                    .add_text('key', 'val') \
+                    .add_file('file', f.name, filename='upload.txt', content_type='text/plain') \
+                    .add_stream('stream', io.BytesIO(b'hello world'), filename='stream.bin',
+                                content_type='application/octet-stream', length=5)
+                self.assertEqual(len(form), 3)
+                client = http_client.HttpClient(use_https=False)
+                resp = await client.async_call(
+                    'POST', http_client.Endpoints(['127.0.0.1:8089']),
+                    path='/postmultipart',
+                    multipart=form)
+                self.assertEqual(resp.status_code, 200)
+            self.assertEqual(parts[0][0], 'key')
+            self.assertEqual(parts[0][3], b'val')
+            self.assertEqual(parts[1][:2], ('file', 'upload.txt'))
+            self.assertTrue(parts[1][2].startswith('text/plain'))
+            self.assertEqual(parts[1][3], b'file content')
+            self.assertEqual(parts[2], ('stream', 'stream.bin',
+                             'application/octet-stream', b'hello'))
+        finally:
+            await runner.cleanup()
+
     async def test_post_json(self):
         async def handler(request):
             self.assertTrue(
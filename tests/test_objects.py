@@ -1,4 +1,4 @@
-from qiniu_bindings import objects, credential, http_client
+from qiniu_bindings import objects, credential, http_client, QiniuInvalidStorageType
 from aiohttp import web
 import unittest
 import base64
@@ -16,7 +16,7 @@ class TestObjectsOperation(unittest.IsolatedAsyncioTestCase):
             case.assertEqual(
                 bytes(self.match_info['entry'], 'utf-8'),
                 base64.urlsafe_b64encode(b'fakebucket:fakekey'))
-            return web.json_response({"fsize": 1024, "hash": 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response({"fsize": 1024, "hash": 'fakehash', "mimeType": 'text/plain', "putTime": 16000000000000000, "type": 1, "x-qn-meta": {"author": "qiniu"}}, headers={'X-ReqId': 'fakereqid'})
 
         async def copy(self):
             case.assertEqual(
@@ -99,6 +99,13 @@ class TestObjectsOperation(unittest.IsolatedAsyncioTestCase):
             self.assertEqual(resp.status_code, 200)
             self.assertEqual(resp.body['fsize'], 1024)
             self.assertEqual(resp.body['hash'], 'fakehash')
+            stat = await bucket.async_stat('fakekey')
+            self.assertEqual(stat.size, 1024)
+            self.assertEqual(stat.hash, 'fakehash')
+            self.assertEqual(stat.mime_type, 'text/plain')
+            self.assertEqual(stat.put_time, 16000000000000000)
+            self.assertEqual(stat.storage_type, 1)
+            self.assertEqual(stat.metadata, {'author': 'qiniu'})
             resp = await bucket.copy_object_to('fakekey', 'fakebucket2', 'fakekey2').async_call()
             self.assertEqual(resp.status_code, 200)
             self.assertEqual(resp.body, {})
@@ -120,6 +127,49 @@ class TestObjectsOperation(unittest.IsolatedAsyncioTestCase):
         finally:
             await runner.cleanup()
 
+    async def test_set_storage_type_and_lifecycle(self):
+        case = self
+
+        async def chtype(self):
+            case.assertEqual(
+                bytes(self.match_info['entry'], 'utf-8'),
+                base64.urlsafe_b64encode(b'fakebucket:fakekey'))
+            case.assertEqual(self.match_info['type'], '2')
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        async def lifecycle(self):
+            case.assertEqual(
+                bytes(self.match_info['entry'], 'utf-8'),
+                base64.urlsafe_b64encode(b'fakebucket:fakekey'))
+            params = self.match_info['params'].split('/')
+            case.assertEqual(dict(zip(params[::2], params[1::2])), {
+                'toIAAfterDays': '30', 'deleteAfterDays': '365'})
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes(
+            [web.post('/chtype/{entry}/type/{type}', chtype)])
+        app.add_routes(
+            [web.post('/lifecycle/{entry}/{params:.*}', lifecycle)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False)
+            bucket = objects_manager.bucket(
+                'fakebucket', regions=http_client.Endpoints.from_list(['127.0.0.1:8089']))
+            await bucket.async_set_storage_type('fakekey', 2)
+            with self.assertRaises(QiniuInvalidStorageType):
+                await bucket.async_set_storage_type('fakekey', 9)
+            with self.assertRaises(QiniuInvalidStorageType):
+                bucket.set_object_type('fakekey', 9)
+            await bucket.async_set_lifecycle('fakekey', delete_after_days=365, to_ia_after_days=30)
+        finally:
+            await runner.cleanup()
+
     async def test_objects_list(self):
         case = self
 
@@ -189,6 +239,163 @@ class TestObjectsOperation(unittest.IsolatedAsyncioTestCase):
         finally:
             await runner.cleanup()
 
+    async def test_objects_list_with_delimiter(self):
+        case = self
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def list(self):
+            case.assertEqual(self.query.get('bucket'), 'fakebucket')
+            case.assertEqual(self.query.get('limit'), '1000')
+            case.assertEqual(self.query.get('delimiter'), '/')
+            if self.query.get('marker') == 'fakemarker':
+                return web.json_response({
+                    "marker": "",
+                    "commonPrefixes": ["fakedir2/"],
+                    "items": [{
+                        "key": "fakeobj3",
+                        "put_time": generate_put_time(),
+                        "hash": "fakeobj3hash",
+                        "fsize": 3,
+                        "mime_type": "text/plain",
+                    }, {
+                        "key": "fakeobj4",
+                        "put_time": generate_put_time(),
+                        "hash": "fakeobj4hash",
+                        "fsize": 4,
+                        "mime_type": "text/plain",
+                    }]
+                }, headers={'X-ReqId': 'fakereqid'})
+            else:
+                return web.json_response({
+                    "marker": "fakemarker",
+                    "commonPrefixes": ["fakedir1/"],
+                    "items": [{
+                        "key": "fakeobj1",
+                        "put_time": generate_put_time(),
+                        "hash": "fakeobj1hash",
+                        "fsize": 1,
+                        "mime_type": "text/plain",
+                    }, {
+                        "key": "fakeobj2",
+                        "put_time": generate_put_time(),
+                        "hash": "fakeobj2hash",
+                        "fsize": 2,
+                        "mime_type": "text/plain",
+                    }]
+                }, headers={'X-ReqId': 'fakereqid'})
+
+        def generate_put_time():
+            return int(time.time_ns()/100)
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.get('/list', list)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            idx = 0
+            async for object in bucket.list(None, None, None, objects.ListVersion.V1, delimiter='/'):
+                idx += 1
+                self.assertEqual(object['fsize'], idx)
+                self.assertEqual(object['size'], idx)
+                self.assertEqual(object['key'], 'fakeobj%d' % idx)
+            self.assertEqual(idx, 4)
+            iterator = bucket.list(delimiter='/').__aiter__()
+            while True:
+                try:
+                    await iterator.__anext__()
+                except StopAsyncIteration:
+                    break
+            self.assertEqual(await iterator.common_prefixes(), ['fakedir1/', 'fakedir2/'])
+        finally:
+            await runner.cleanup()
+
+    async def test_objects_list_with_static_endpoints(self):
+        case = self
+
+        async def list(self):
+            case.assertEqual(self.query.get('bucket'), 'fakebucket')
+            return web.json_response({
+                "marker": "",
+                "items": [{
+                    "key": "fakeobj1",
+                    "put_time": int(time.time_ns()/100),
+                    "hash": "fakeobj1hash",
+                    "fsize": 1,
+                    "mime_type": "text/plain",
+                }]
+            }, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/list', list)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False)
+            bucket = objects_manager.bucket(
+                'fakebucket', regions=http_client.Endpoints.from_list(['127.0.0.1:8089']))
+            keys = [object['key'] async for object in bucket.list(version=objects.ListVersion.V1)]
+            self.assertEqual(keys, ['fakeobj1'])
+        finally:
+            await runner.cleanup()
+
+    async def test_fetch_and_prefetch(self):
+        case = self
+
+        async def fetch(request):
+            case.assertEqual(base64.urlsafe_b64decode(
+                request.match_info['from_url']), b'http://www.example.com/fakeobj')
+            case.assertEqual(base64.urlsafe_b64decode(
+                request.match_info['to_entry']), b'fakebucket:fakeobj')
+            case.assertTrue(
+                request.headers['Authorization'].startswith('Qiniu ak:'))
+            return web.json_response({'key': 'fakeobj', 'hash': 'fakehash', 'fsize': 1024, 'mimeType': 'text/plain'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def prefetch(request):
+            case.assertEqual(base64.urlsafe_b64decode(
+                request.match_info['entry']), b'fakebucket:fakeobj')
+            return web.json_response({'key': 'fakeobj', 'hash': 'fakehash2', 'fsize': 2048, 'mimeType': 'text/html'}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes(
+            [web.post('/fetch/{from_url}/to/{to_entry}', fetch),
+             web.post('/prefetch/{entry}', prefetch)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False)
+            bucket = objects_manager.bucket(
+                'fakebucket', regions=http_client.Endpoints.from_list(['127.0.0.1:8089']))
+            fetched = await bucket.async_fetch('http://www.example.com/fakeobj', 'fakeobj')
+            self.assertEqual(fetched.key, 'fakeobj')
+            self.assertEqual(fetched.hash, 'fakehash')
+            self.assertEqual(fetched.size, 1024)
+            self.assertEqual(fetched.mime_type, 'text/plain')
+            fetched = await bucket.async_prefetch('fakeobj')
+            self.assertEqual(fetched.hash, 'fakehash2')
+            self.assertEqual(fetched.size, 2048)
+            self.assertEqual(fetched.mime_type, 'text/html')
+        finally:
+            await runner.cleanup()
+
     async def test_objects_operation(self):
         case = self
 
@@ -236,6 +443,18 @@ class TestObjectsOperation(unittest.IsolatedAsyncioTestCase):
                     result.data, {'fsize': 1024, 'hash': 'fakehash'})
                 count += 1
             self.assertEqual(count, 3)
+
+            ops = bucket.batch_ops()
+            ops.stat('object_1')
+            ops.stat('object_2')
+            ops.stat('object_3')
+            results = await ops.async_execute()
+            self.assertEqual(len(results), 3)
+            for result in results:
+                self.assertEqual(result.status_code, 200)
+                self.assertIsNone(result.error)
+                self.assertEqual(
+                    result.data, {'fsize': 1024, 'hash': 'fakehash'})
         finally:
             await runner.cleanup()
 
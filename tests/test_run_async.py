@@ -0,0 +1,20 @@
+from qiniu_bindings import etag, run_async, QiniuRunningEventLoopError
+import unittest
+import io
+
+
+class TestRunAsync(unittest.TestCase):
+    def test_run_async_with_callable(self):
+        self.assertEqual(run_async(lambda: etag.async_etag_of(io.BytesIO(b'etag'))),
+                         'FpLiADEaVoALPkdb8tJEJyRTXoe_')
+
+    def test_run_async_with_coroutine(self):
+        async def etag_of():
+            return await etag.async_etag_of(io.BytesIO(b'etag'))
+        self.assertEqual(run_async(etag_of()), 'FpLiADEaVoALPkdb8tJEJyRTXoe_')
+
+
+class TestRunAsyncInEventLoop(unittest.IsolatedAsyncioTestCase):
+    async def test_run_async_in_running_event_loop(self):
+        with self.assertRaises(QiniuRunningEventLoopError):
+            run_async(lambda: etag.async_etag_of(io.BytesIO(b'etag')))
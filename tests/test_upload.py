@@ -1,4 +1,4 @@
-from qiniu_bindings import upload, credential, http_client, QiniuIoError
+from qiniu_bindings import upload, download, credential, http, http_client, etag, QiniuIoError, QiniuInvalidSourceKeyLengthError, QiniuEtagMismatchError, QiniuInvalidCompressionLevel, QiniuInvalidEncryptionKey, QiniuTimeoutError, QiniuApiCallError, QiniuUploadManagerClosedError, QiniuInvalidSaveKey, QiniuInvalidCallback, QiniuUnsupportedRecorderOperation, QiniuInvalidConcurrency
 from aiohttp import web
 import unittest
 import io
@@ -8,13 +8,39 @@ import hashlib
 import aiofiles
 import time
 import base64
+import json
+import tempfile
+import re
+import gzip
 import threading
+import asyncio
+import warnings
 
 
 class TestConcurrencyProvider(unittest.TestCase):
     def test_concurrency_provider(self):
         self.assertEqual(upload.FixedConcurrencyProvider(5).concurrency, 5)
 
+    def test_adaptive_concurrency_provider(self):
+        provider = upload.AdaptiveConcurrencyProvider(4, 1, 5)
+        self.assertEqual(provider.concurrency, 4)
+        provider.feedback(4, 1 << 20, 1000*1000*1000)
+        self.assertEqual(provider.concurrency, 5)
+        provider.feedback(5, 1 << 20, 1000*1000*1000)
+        self.assertEqual(provider.concurrency, 5)
+        provider.feedback(5, 1 << 20, 60*1000*1000*1000)
+        self.assertEqual(provider.concurrency, 2)
+        provider.feedback(2, 1 << 20, 60*1000*1000*1000)
+        self.assertEqual(provider.concurrency, 1)
+
+
+class TestUploadingProgressInfo(unittest.TestCase):
+    def test_percentage(self):
+        self.assertEqual(upload.UploadingProgressInfo(
+            512, 2048).percentage, 25.0)
+        self.assertIsNone(upload.UploadingProgressInfo(512).percentage)
+        self.assertIsNone(upload.UploadingProgressInfo(0, 0).percentage)
+
 
 class TestDataPartitionProvider(unittest.TestCase):
     def test_data_partition_provider(self):
@@ -27,6 +53,15 @@ class TestDataPartitionProvider(unittest.TestCase):
         self.assertEqual(upload.LimitedDataPartitionProvider(
             upload.FixedDataPartitionProvider(3*1024*1024), 4*1024*1024, 8*1024*1024).part_size, 4*1024*1024)
 
+    def test_throttled_data_partition_provider(self):
+        provider = upload.ThrottledDataPartitionProvider(
+            upload.FixedDataPartitionProvider(4*1024*1024), 512*1024)
+        self.assertEqual(provider.part_size, 4*1024*1024)
+        provider.feedback(4*1024*1024, 4*1000*1000*1000)
+        self.assertEqual(provider.part_size, 2*1024*1024)
+        provider.feedback(2*1024*1024, 8*1000*1000*1000)
+        self.assertEqual(provider.part_size, 4*1024*1024)
+
 
 class TestResumablePolicyProvider(unittest.TestCase):
     def test_resumable_policy_provider(self):
@@ -61,6 +96,19 @@ class TestResumablePolicyProvider(unittest.TestCase):
             17*1024*1024), upload.ResumablePolicy.MultiPartsUploading)
 
 
+class TestSourceKey(unittest.TestCase):
+    def test_source_key_hex(self):
+        digest = hashlib.sha1(b"key").digest()
+        key = upload.SourceKey(digest)
+        self.assertEqual(key.to_bytes(), digest)
+        self.assertEqual(str(key), digest.hex())
+        self.assertEqual(upload.SourceKey.from_hex(str(key)), key)
+        self.assertEqual(hash(upload.SourceKey.from_hex(str(key))), hash(key))
+        self.assertEqual(len({key, upload.SourceKey(digest)}), 1)
+        with self.assertRaises(QiniuInvalidSourceKeyLengthError):
+            upload.SourceKey.from_hex('abcd')
+
+
 class TestResumableRecorder(unittest.IsolatedAsyncioTestCase):
     async def test_resumable_recorder(self):
         sha1 = hashlib.sha1()
@@ -71,51 +119,834 @@ class TestResumableRecorder(unittest.IsolatedAsyncioTestCase):
         async with aiofiles.tempfile.TemporaryDirectory() as d:
             recorder = upload.FileSystemResumableRecorder(d)
 
-            try:
-                medium = await recorder.open_for_async_create_new(key)
-                await medium.write(b"hello world\n")
-                await medium.flush()
+            try:
+                medium = await recorder.open_for_async_create_new(key)
+                await medium.write(b"hello world\n")
+                await medium.flush()
+
+                medium = await recorder.open_for_async_append(key)
+                await medium.write(b"hello world\n")
+                await medium.flush()
+
+                medium = await recorder.open_for_async_read(key)
+                self.assertEqual(await medium.readall(), b"hello world\nhello world\n")
+            finally:
+                await recorder.async_delete(key)
+
+    async def test_warning_resumable_recorder(self):
+        sha1 = hashlib.sha1()
+        sha1.update(b"key")
+        key = upload.SourceKey(sha1.digest())
+        recorder = upload.WarningResumableRecorder()
+        with warnings.catch_warnings(record=True) as caught:
+            warnings.simplefilter('always')
+            with self.assertRaises(QiniuIoError):
+                recorder.open_for_read(key)
+            with self.assertRaises(QiniuIoError):
+                await recorder.open_for_async_read(key)
+        self.assertEqual(len(caught), 1)
+        self.assertTrue(issubclass(caught[0].category, UserWarning))
+
+    async def test_fsync_file_system_resumable_recorder(self):
+        sha1 = hashlib.sha1()
+        sha1.update(b"key")
+        key = upload.SourceKey(sha1.digest())
+        async with aiofiles.tempfile.TemporaryDirectory() as d:
+            recorder = upload.FileSystemResumableRecorder(d, fsync=True)
+            with self.assertRaises(QiniuIoError):
+                recorder.open_for_append(key)
+
+            medium = recorder.open_for_create_new(key)
+            medium.write(b"hello world\n")
+            medium.flush()
+            self.assertTrue(os.path.exists(os.path.join(d, str(key))))
+
+            medium = await recorder.open_for_async_append(key)
+            await medium.write(b"hello world\n")
+            await medium.flush()
+
+            self.assertEqual(recorder.open_for_read(key).readall(),
+                             b"hello world\nhello world\n")
+            medium = await upload.FileSystemResumableRecorder(d).open_for_async_read(key)
+            self.assertEqual(await medium.readall(), b"hello world\nhello world\n")
+            await recorder.async_delete(key)
+            with self.assertRaises(QiniuIoError):
+                await recorder.open_for_async_read(key)
+
+    async def test_sqlite_resumable_recorder(self):
+        sha1 = hashlib.sha1()
+        sha1.update(b"key")
+        key = upload.SourceKey(sha1.digest())
+        async with aiofiles.tempfile.TemporaryDirectory() as d:
+            recorder = upload.SqliteResumableRecorder(
+                os.path.join(d, 'records.db'))
+            with self.assertRaises(QiniuIoError):
+                recorder.open_for_read(key)
+            with self.assertRaises(QiniuIoError):
+                recorder.open_for_append(key)
+
+            medium = recorder.open_for_create_new(key)
+            medium.write(b"hello world\n")
+            medium.flush()
+
+            medium = await recorder.open_for_async_append(key)
+            await medium.write(b"hello world\n")
+            await medium.flush()
+
+            self.assertEqual(recorder.open_for_read(key).readall(),
+                             b"hello world\nhello world\n")
+            medium = await recorder.open_for_async_read(key)
+            self.assertEqual(await medium.readall(), b"hello world\nhello world\n")
+
+            await recorder.async_delete(key)
+            with self.assertRaises(QiniuIoError):
+                await recorder.open_for_async_read(key)
+
+    async def test_list_and_prune_resumable_records(self):
+        sha1 = hashlib.sha1()
+        sha1.update(b"key")
+        key = upload.SourceKey(sha1.digest())
+        async with aiofiles.tempfile.TemporaryDirectory() as d:
+            for recorder in [upload.FileSystemResumableRecorder(d),
+                             upload.SqliteResumableRecorder(os.path.join(d, 'records.db'))]:
+                medium = recorder.open_for_create_new(key)
+                medium.write(b"hello world\n")
+                medium.flush()
+                del medium
+
+                records = recorder.list_records()
+                self.assertEqual(len(records), 1)
+                self.assertEqual(records[0].source_key, key)
+                self.assertEqual(records[0].size, 12)
+                self.assertLessEqual(
+                    abs(records[0].modified_at - time.time()), 60)
+
+                self.assertEqual(await recorder.async_prune(3600), 0)
+                self.assertEqual(len(await recorder.async_list_records()), 1)
+
+            old = time.time() - 7200
+            os.utime(os.path.join(d, str(key)), (old, old))
+            recorder = upload.FileSystemResumableRecorder(d)
+            self.assertEqual(recorder.prune(3600), 1)
+            self.assertEqual(recorder.list_records(), [])
+        with self.assertRaises(QiniuUnsupportedRecorderOperation):
+            upload.MemoryResumableRecorder().list_records()
+
+
+class TestDataSource(unittest.IsolatedAsyncioTestCase):
+    async def test_data_source(self):
+        async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+            slices = []
+            for _ in range(1 << 10):
+                bytes = os.urandom(1 << 10)
+                slices.append(bytes)
+                await f.write(bytes)
+            await f.seek(0, io.SEEK_SET)
+            data_source = upload.AsyncFileDataSource(f.name)
+            for i in range(1 << 10):
+                reader = await data_source.slice(1 << 10)
+                self.assertEqual(reader.offset, i << 10)
+                self.assertEqual(reader.size, 1 << 10)
+                self.assertEqual(await reader.readall(), slices[i])
+
+            data_source = upload.FileDataSource(f.name)
+            reader = data_source.slice(1000)
+            self.assertEqual((reader.offset, reader.size), (0, 1000))
+            reader = data_source.slice((1 << 20) - 500)
+            self.assertEqual((reader.offset, reader.size), (1000, (1 << 20) - 1000))
+            self.assertIsNone(data_source.slice(1000))
+
+    async def test_iterate_data_source_reader(self):
+        async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+            data = os.urandom(1 << 12)
+            await f.write(data)
+            await f.flush()
+
+            reader = upload.FileDataSource(f.name).slice(1 << 12)
+            reader.chunk_size = 1 << 10
+            chunks = [chunk for chunk in reader]
+            self.assertEqual(len(chunks), 4)
+            self.assertEqual(b''.join(chunks), data)
+
+            reader = await upload.AsyncFileDataSource(f.name).slice(1 << 12)
+            reader.chunk_size = 3 << 10
+            chunks = [chunk async for chunk in reader]
+            self.assertEqual([len(chunk) for chunk in chunks], [3 << 10, 1 << 10])
+            self.assertEqual(b''.join(chunks), data)
+
+    async def test_precompute_source_key(self):
+        async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+            await f.write(os.urandom(1 << 12))
+            await f.flush()
+
+            data_source = upload.FileDataSource(f.name)
+            self.assertIsNone(data_source.precompute_source_key())
+            source_key = data_source.source_key()
+            self.assertIsNotNone(source_key)
+            await f.write(os.urandom(1 << 12))
+            await f.flush()
+            self.assertEqual(data_source.source_key(), source_key)
+
+            async_data_source = upload.AsyncFileDataSource(f.name)
+            self.assertIsNone(await async_data_source.precompute_source_key())
+            async_source_key = await async_data_source.source_key()
+            self.assertIsNotNone(async_source_key)
+            await f.write(os.urandom(1 << 12))
+            await f.flush()
+            self.assertEqual(await async_data_source.source_key(), async_source_key)
+
+    async def test_file_data_source_window(self):
+        async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+            data = os.urandom(1 << 12)
+            await f.write(data)
+            await f.flush()
+
+            data_source = upload.FileDataSource(f.name, offset=1 << 10, length=3 << 10)
+            self.assertEqual(data_source.total_size(), 3 << 10)
+            self.assertEqual(data_source.slice(1 << 11).readall(), data[1 << 10:3 << 10])
+            self.assertEqual(data_source.slice(1 << 11).readall(), data[3 << 10:])
+            self.assertIsNone(data_source.slice(1 << 11))
+            self.assertNotEqual(data_source.source_key(),
+                                upload.FileDataSource(f.name, offset=1 << 10).source_key())
+            self.assertNotEqual(data_source.source_key(),
+                                upload.FileDataSource(f.name).source_key())
+
+            async_data_source = upload.AsyncFileDataSource(f.name, offset=1 << 11)
+            self.assertEqual(await async_data_source.total_size(), 1 << 11)
+            reader = await async_data_source.slice(1 << 12)
+            self.assertEqual(await reader.readall(), data[1 << 11:])
+            self.assertIsNone(await async_data_source.slice(1 << 12))
+            self.assertNotEqual(await async_data_source.source_key(),
+                                await upload.AsyncFileDataSource(f.name).source_key())
+
+
+    async def test_unseekable_data_source_with_total_size(self):
+        data = os.urandom(1 << 12)
+        data_source = upload.UnseekableDataSource(io.BytesIO(data))
+        self.assertIsNone(data_source.total_size())
+        data_source = upload.UnseekableDataSource(
+            io.BytesIO(data), total_size=len(data))
+        self.assertEqual(data_source.total_size(), len(data))
+        self.assertEqual(data_source.slice(1 << 13).readall(), data)
+
+        async with aiofiles.tempfile.TemporaryFile('wb+') as f:
+            await f.write(data)
+            await f.seek(0, io.SEEK_SET)
+            data_source = upload.AsyncUnseekableDataSource(
+                f, total_size=len(data))
+            self.assertEqual(await data_source.total_size(), len(data))
+            reader = await data_source.slice(1 << 13)
+            self.assertEqual(await reader.readall(), data)
+
+    async def test_async_generator_data_source(self):
+        chunks = [os.urandom(1000) for _ in range(5)]
+
+        async def agen():
+            for chunk in chunks:
+                await asyncio.sleep(0)
+                yield chunk
+
+        data_source = upload.AsyncGeneratorDataSource(agen())
+        self.assertIsNone(await data_source.total_size())
+        self.assertIsNone(await data_source.source_key())
+        reader = await data_source.slice(1 << 11)
+        self.assertEqual(await reader.readall(), b''.join(chunks)[:1 << 11])
+        reader = await data_source.slice(1 << 13)
+        self.assertEqual(await reader.readall(), b''.join(chunks)[1 << 11:])
+        self.assertIsNone(await data_source.slice(1 << 13))
+
+    async def test_gzip_data_source(self):
+        async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+            data = b'qiniu' * (1 << 16)
+            await f.write(data)
+            await f.flush()
+
+            data_source = upload.GzipDataSource(upload.FileDataSource(f.name))
+            self.assertIsNone(data_source.total_size())
+            compressed = b''
+            while True:
+                reader = data_source.slice(1 << 10)
+                if reader is None:
+                    break
+                compressed += reader.readall()
+            self.assertLess(len(compressed), len(data))
+            self.assertEqual(gzip.decompress(compressed), data)
+
+            data_source = upload.AsyncGzipDataSource(
+                upload.AsyncFileDataSource(f.name), level=9)
+            self.assertIsNone(await data_source.total_size())
+            compressed = b''
+            while True:
+                reader = await data_source.slice(1 << 10)
+                if reader is None:
+                    break
+                compressed += await reader.readall()
+            self.assertEqual(gzip.decompress(compressed), data)
+
+            with self.assertRaises(QiniuInvalidCompressionLevel):
+                upload.GzipDataSource(upload.FileDataSource(f.name), level=10)
+
+
+    async def test_encrypting_data_source(self):
+        key = os.urandom(32)
+        nonce = os.urandom(16)
+        async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+            data = os.urandom(5 << 10)
+            await f.write(data)
+            await f.flush()
+
+            data_source = upload.EncryptingDataSource(
+                upload.FileDataSource(f.name), key, nonce)
+            self.assertEqual(data_source.total_size(), len(data))
+            self.assertNotEqual(data_source.source_key(),
+                                upload.FileDataSource(f.name).source_key())
+            encrypted = b''
+            while True:
+                reader = data_source.slice(1 << 12)
+                if reader is None:
+                    break
+                encrypted += reader.readall()
+            self.assertEqual(len(encrypted), len(data))
+            self.assertNotEqual(encrypted, data)
+
+            data_source = upload.AsyncEncryptingDataSource(
+                upload.AsyncFileDataSource(f.name), key, nonce)
+            async_encrypted = b''
+            while True:
+                reader = await data_source.slice(3 << 10)
+                if reader is None:
+                    break
+                async_encrypted += await reader.readall()
+            self.assertEqual(async_encrypted, encrypted)
+
+            reader = download.DecryptingReader(
+                io.BytesIO(encrypted), key, nonce)
+            self.assertEqual(reader.read(100) + reader.readall(), data)
+            reader = download.DecryptingReader(
+                io.BytesIO(encrypted[1000:]), key, nonce, offset=1000)
+            self.assertEqual(reader.readall(), data[1000:])
+
+            with self.assertRaises(QiniuInvalidEncryptionKey):
+                upload.EncryptingDataSource(
+                    upload.FileDataSource(f.name), os.urandom(20), nonce)
+
+
+class TestUploadManager(unittest.IsolatedAsyncioTestCase):
+    async def test_upload_manager_as_context_manager(self):
+        signer = upload.UploadTokenSigner.new_credential_provider(
+            credential.Credential('ak', 'sk'), 'fakebucket', 3600)
+        with upload.UploadManager(signer, use_https=False) as upload_manager:
+            upload_manager.form_uploader()
+        with self.assertRaises(QiniuUploadManagerClosedError):
+            upload_manager.form_uploader()
+
+        async with upload.UploadManager(signer, use_https=False) as upload_manager:
+            upload_manager.auto_uploader()
+        with self.assertRaises(QiniuUploadManagerClosedError):
+            upload_manager.auto_uploader()
+
+
+class TestFormUploader(unittest.IsolatedAsyncioTestCase):
+    async def test_form_uploader(self):
+        case = self
+
+        async def form_upload(request):
+            data = await request.post()
+            case.assertTrue(data['token'].startswith('ak:'))
+            case.assertEqual(data['key'], 'fakeobjectname')
+            case.assertEqual(data['file'].filename, 'fakefilename')
+            case.assertEqual(data['file'].content_type,
+                             'application/octet-stream')
+            case.assertEqual(len(data['file'].file.read()), 1 << 20)
+            data['file'].file.close()
+
+            return web.json_response({'key': 'fakekey', 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer).form_uploader()
+            self.assertIsNone(uploader.last_server_ip)
+            self.assertIsNone(uploader.last_endpoint)
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                for _ in range(1 << 10):
+                    await f.write(os.urandom(1 << 10))
+                await f.seek(0, io.SEEK_SET)
+                result = await uploader.async_upload_reader(
+                    f, object_name='fakeobjectname', file_name='fakefilename')
+                self.assertEqual(result['key'], 'fakekey')
+                self.assertEqual(result['hash'], 'fakehash')
+            self.assertEqual(uploader.last_server_ip, '127.0.0.1')
+            self.assertEqual(uploader.last_server_port, 8089)
+            self.assertEqual(uploader.last_endpoint, '127.0.0.1:8089')
+        finally:
+            await runner.cleanup()
+
+
+    async def test_form_uploader_with_save_key(self):
+        policies = []
+
+        async def form_upload(request):
+            data = await request.post()
+            encoded_policy = data['token'].split(':')[-1]
+            policies.append(json.loads(base64.urlsafe_b64decode(
+                encoded_policy + '=' * (-len(encoded_policy) % 4))))
+            data['file'].file.close()
+            return web.json_response({'key': 'fakekey', 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            with self.assertRaises(QiniuInvalidSaveKey):
+                upload.UploadTokenSigner.new_credential_provider(
+                    credential.Credential('ak', 'sk'), 'fakebucket', 3600, force_save_key=True)
+
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600,
+                save_key='$(etag)$(ext)', force_save_key=True),
+                use_https=False,
+                queryer=queryer).form_uploader()
+            await uploader.async_upload_reader(io.BytesIO(b'hello world'), object_name='fakeobjectname')
+            self.assertEqual(policies[0]['saveKey'], '$(etag)$(ext)')
+            self.assertTrue(policies[0]['forceSaveKey'])
+        finally:
+            await runner.cleanup()
+
+    def test_form_uploader_with_callback(self):
+        policies = []
+
+        def call(request):
+            token = re.search(rb'name="token"\r\n\r\n([^\r]+)', request.body).group(1).decode()
+            encoded_policy = token.split(':')[-1]
+            policies.append(json.loads(base64.urlsafe_b64decode(
+                encoded_policy + '=' * (-len(encoded_policy) % 4))))
+            return http.SyncHttpResponse(
+                status_code=200,
+                headers={'Content-Type': 'application/json', 'X-ReqId': 'fakereqid'},
+                body=b'{"callback":"ok"}')
+
+        with self.assertRaises(QiniuInvalidCallback):
+            upload.ObjectParams(callback_body='key=$(key)')
+        with self.assertRaises(QiniuInvalidCallback):
+            upload.AutoUploaderObjectParams(callback_urls=[])
+
+        upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+            credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+            http_client=http_client.HttpClient(http_caller=http.CallbackHttpCaller(call)),
+            use_https=False)
+        region_provider = http_client.Endpoints(['127.0.0.1:8089'])
+
+        params = upload.ObjectParams(
+            region_provider=region_provider,
+            object_name='fakeobjectname',
+            callback_urls=['https://callback1.example.com', 'https://callback2.example.com'],
+            callback_host='callback.example.com',
+            callback_body='{"key":"$(key)"}',
+            callback_body_type='application/json')
+        result = upload_manager.form_uploader().upload_reader(io.BytesIO(b'hello world'), params=params)
+        self.assertEqual(result, {'callback': 'ok'})
+        self.assertEqual(
+            policies[0]['callbackUrl'], 'https://callback1.example.com;https://callback2.example.com')
+        self.assertEqual(policies[0]['callbackHost'], 'callback.example.com')
+        self.assertEqual(policies[0]['callbackBody'], '{"key":"$(key)"}')
+        self.assertEqual(policies[0]['callbackBodyType'], 'application/json')
+
+        upload_manager.form_uploader().upload_reader(
+            io.BytesIO(b'hello world'), region_provider=region_provider, object_name='fakeobjectname')
+        self.assertNotIn('callbackUrl', policies[1])
+
+        params = upload.AutoUploaderObjectParams(
+            region_provider=region_provider,
+            object_name='fakeobjectname',
+            callback_urls=['https://callback.example.com'],
+            callback_body='key=$(key)')
+        result = upload_manager.auto_uploader().upload_reader(io.BytesIO(b'hello world'), params=params)
+        self.assertEqual(result, {'callback': 'ok'})
+        self.assertEqual(policies[2]['callbackUrl'], 'https://callback.example.com')
+        self.assertEqual(policies[2]['callbackBody'], 'key=$(key)')
+
+    async def test_form_uploader_with_unsized_reader(self):
+        case = self
+
+        class AsyncUnsizedReader:
+            def __init__(self, data):
+                self.inner = io.BytesIO(data)
+
+            async def seekable(self):
+                return False
+
+            async def read(self, size=-1):
+                return self.inner.read(size)
+
+        async def form_upload(request):
+            data = await request.post()
+            case.assertEqual(data['file'].file.read(), b'hello world')
+            data['file'].file.close()
+            return web.json_response({'key': data['key'], 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False, queryer=queryer).form_uploader()
+            with warnings.catch_warnings(record=True) as caught:
+                warnings.simplefilter('always')
+                result = await uploader.async_upload_reader(AsyncUnsizedReader(b'hello world'), object_name='fakeobjectname')
+            self.assertEqual(result['key'], 'fakeobjectname')
+            self.assertEqual(len(caught), 1)
+            self.assertTrue(issubclass(caught[0].category, UserWarning))
+        finally:
+            await runner.cleanup()
+
+    async def test_form_uploader_with_reused_params(self):
+        keys = []
+
+        async def form_upload(request):
+            data = await request.post()
+            keys.append((data['key'], data['file'].filename))
+            data['file'].file.close()
+            return web.json_response({'key': data['key'], 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer).form_uploader()
+            params = upload.ObjectParams(
+                object_name='fakeobjectname', file_name='fakefilename')
+            self.assertEqual(params.object_name, 'fakeobjectname')
+            self.assertEqual(params.file_name, 'fakefilename')
+            for _ in range(2):
+                result = await uploader.async_upload_reader(
+                    io.BytesIO(os.urandom(1 << 10)), object_name='ignoredname', params=params)
+                self.assertEqual(result['key'], 'fakeobjectname')
+            self.assertEqual(
+                keys, [('fakeobjectname', 'fakefilename'), ('fakeobjectname', 'fakefilename')])
+
+            auto_params = upload.AutoUploaderObjectParams(
+                object_name='fakeobjectname', single_part_uploader_prefer=upload.SinglePartUploaderPrefer.Form)
+            self.assertEqual(auto_params.object_name, 'fakeobjectname')
+            self.assertIsNone(auto_params.file_name)
+        finally:
+            await runner.cleanup()
+
+    async def test_form_uploader_with_guessed_content_type(self):
+        case = self
+        content_types = []
+
+        async def form_upload(request):
+            data = await request.post()
+            content_types.append(data['file'].content_type)
+            data['file'].file.close()
+            return web.json_response({'key': 'fakekey', 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer).form_uploader()
+            await uploader.async_upload_reader(
+                io.BytesIO(b'{}'), object_name='fakeobject.json', guess_content_type=True)
+            await uploader.async_upload_reader(
+                io.BytesIO(b'{}'), object_name='fakeobject.json', file_name='fakefile.png', guess_content_type=True)
+            await uploader.async_upload_reader(
+                io.BytesIO(b'{}'), object_name='fakeobject.json', content_type='text/plain', guess_content_type=True)
+            await uploader.async_upload_reader(
+                io.BytesIO(b'{}'), object_name='fakeobject.json')
+            case.assertEqual(content_types, [
+                'application/json', 'image/png', 'text/plain', 'application/octet-stream'])
+
+            result = await uploader.async_upload_reader(
+                io.BytesIO(b'{}'), object_name='fakeobject.json', typed=True)
+            case.assertEqual(result.key, 'fakekey')
+            case.assertEqual(result.hash, 'fakehash')
+            case.assertEqual(result.raw, {'key': 'fakekey', 'hash': 'fakehash'})
+        finally:
+            await runner.cleanup()
+
+    async def test_form_uploader_with_progress_interval(self):
+        progresses = []
+
+        async def form_upload(request):
+            data = await request.post()
+            data['file'].file.close()
+            return web.json_response({'key': 'fakekey', 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application(client_max_size=1 << 30)
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer).form_uploader(upload_progress=lambda progress: progresses.append(progress), progress_interval_ms=60000)
+            await uploader.async_upload_reader(
+                io.BytesIO(os.urandom(1 << 22)), object_name='fakeobjectname')
+            self.assertGreater(len(progresses), 0)
+            self.assertLessEqual(len(progresses), 2)
+            if progresses[-1].total_bytes is not None:
+                self.assertEqual(
+                    progresses[-1].transferred_bytes, progresses[-1].total_bytes)
+        finally:
+            await runner.cleanup()
+
+
+class TestAutoUploader(unittest.IsolatedAsyncioTestCase):
+    async def test_upload_directory(self):
+        uploaded = {}
+
+        async def form_upload(request):
+            data = await request.post()
+            uploaded[data['key']] = (data['file'].content_type, data['file'].file.read())
+            data['file'].file.close()
+            return web.json_response({'key': data['key'], 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer).auto_uploader()
+            progresses = set()
+            async with aiofiles.tempfile.TemporaryDirectory() as d:
+                os.makedirs(os.path.join(d, 'sub', 'dir'))
+                with open(os.path.join(d, 'a.txt'), 'wb') as f:
+                    f.write(b'a')
+                with open(os.path.join(d, 'sub', 'dir', 'b.json'), 'wb') as f:
+                    f.write(b'{}')
+                results = await uploader.async_upload_directory(
+                    d, key_prefix='prefix/', concurrency=2,
+                    upload_progress=lambda path, _: progresses.add(path))
+            self.assertEqual([path for (path, _) in results], [
+                             'a.txt', 'sub/dir/b.json'])
+            self.assertEqual(results[0][1]['key'], 'prefix/a.txt')
+            self.assertEqual(results[1][1]['key'], 'prefix/sub/dir/b.json')
+            self.assertEqual(uploaded['prefix/a.txt'], ('text/plain', b'a'))
+            self.assertEqual(
+                uploaded['prefix/sub/dir/b.json'], ('application/json', b'{}'))
+            self.assertEqual(progresses, {'a.txt', 'sub/dir/b.json'})
+        finally:
+            await runner.cleanup()
+
+
+    async def test_upload_with_etag_verification(self):
+        data = os.urandom(1 << 10)
+        etag_v2 = base64.urlsafe_b64encode(b'\x9e' + os.urandom(20)).decode()
+        hashes = [etag.etag_of(io.BytesIO(data)), 'fakehash', etag_v2]
+
+        async def form_upload(request):
+            form = await request.post()
+            form['file'].file.close()
+            return web.json_response({'key': form['key'], 'hash': hashes.pop(0)}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer).auto_uploader()
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(data)
+                await f.flush()
+                result = await uploader.async_upload_path(
+                    f.name, object_name='fakeobjectname', verify_etag=True)
+                self.assertEqual(result['hash'], etag.etag_of(io.BytesIO(data)))
+                with self.assertRaises(QiniuEtagMismatchError):
+                    await uploader.async_upload_path(
+                        f.name, object_name='fakeobjectname', verify_etag=True)
+                with self.assertRaises(QiniuEtagMismatchError):
+                    await uploader.async_upload_path(
+                        f.name, object_name='fakeobjectname', verify_etag=True)
+        finally:
+            await runner.cleanup()
+
+    async def test_multi_parts_v2_upload_with_etag_verification(self):
+        data = os.urandom((4 << 20) + (1 << 19))
+        hashes = [etag.etag_v2_of_bytes(data, 1 << 20), etag.etag_v2_of_bytes(data, 1 << 21)]
+
+        async def init_parts(request):
+            return web.json_response({'uploadId': 'fakeUploadId', 'expireAt': int(time.time()) + 3600}, headers={'X-ReqId': 'fakereqid'})
+
+        async def upload_part(request):
+            await request.read()
+            return web.json_response({'etag': 'fakeEtag-%s' % request.match_info['part_number'], 'md5': 'fakemd5'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def complete_parts(request):
+            await request.json()
+            return web.json_response({'key': 'fakeobjectname', 'hash': hashes.pop(0)}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application(client_max_size=1 << 30)
+        app.add_routes(
+            [web.post('/buckets/{bucket_name}/objects/{encoded_key}/uploads', init_parts)])
+        app.add_routes(
+            [web.put('/buckets/{bucket_name}/objects/{encoded_key}/uploads/{upload_id}/{part_number}', upload_part)])
+        app.add_routes(
+            [web.post('/buckets/{bucket_name}/objects/{encoded_key}/uploads/{upload_id}', complete_parts)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
 
-                medium = await recorder.open_for_async_append(key)
-                await medium.write(b"hello world\n")
-                await medium.flush()
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer).auto_uploader(
+                    resumable_recorder=upload.DummyResumableRecorder(),
+                    data_partition_provider=upload.FixedDataPartitionProvider(1 << 20))
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(data)
+                await f.flush()
+                result = await uploader.async_upload_path(
+                    f.name, object_name='fakeobjectname', verify_etag=True,
+                    multi_parts_uploader_prefer=upload.MultiPartsUploaderPrefer.V2)
+                self.assertEqual(result['hash'], etag.etag_v2_of_bytes(data, 1 << 20))
+                with self.assertRaises(QiniuEtagMismatchError):
+                    await uploader.async_upload_path(
+                        f.name, object_name='fakeobjectname', verify_etag=True,
+                        multi_parts_uploader_prefer=upload.MultiPartsUploaderPrefer.V2)
+        finally:
+            await runner.cleanup()
 
-                medium = await recorder.open_for_async_read(key)
-                self.assertEqual(await medium.readall(), b"hello world\nhello world\n")
-            finally:
-                await recorder.async_delete(key)
 
+    async def test_upload_with_operation_timeout(self):
+        async def form_upload(request):
+            await asyncio.sleep(3)
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
 
-class TestDataSource(unittest.IsolatedAsyncioTestCase):
-    async def test_data_source(self):
-        async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
-            slices = []
-            for _ in range(1 << 10):
-                bytes = os.urandom(1 << 10)
-                slices.append(bytes)
-                await f.write(bytes)
-            await f.seek(0, io.SEEK_SET)
-            data_source = upload.AsyncFileDataSource(f.name)
-            for i in range(1 << 10):
-                reader = await data_source.slice(1 << 10)
-                self.assertEqual(await reader.readall(), slices[i])
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
 
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
 
-class TestFormUploader(unittest.IsolatedAsyncioTestCase):
-    async def test_form_uploader(self):
-        case = self
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer).auto_uploader()
+            with self.assertRaises(QiniuTimeoutError):
+                await uploader.async_upload_reader(
+                    io.BytesIO(os.urandom(1 << 10)), object_name='fakeobjectname', operation_timeout_secs=1)
+        finally:
+            await runner.cleanup()
 
+    async def test_try_upload(self):
         async def form_upload(request):
             data = await request.post()
-            case.assertTrue(data['token'].startswith('ak:'))
-            case.assertEqual(data['key'], 'fakeobjectname')
-            case.assertEqual(data['file'].filename, 'fakefilename')
-            case.assertEqual(data['file'].content_type,
-                             'application/octet-stream')
-            case.assertEqual(len(data['file'].file.read()), 1 << 20)
-            data['file'].file.close()
-
-            return web.json_response({'key': 'fakekey', 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+            if data['key'] == 'badobjectname':
+                return web.json_response({'error': 'file exists'}, status=614, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response({'key': data['key']}, headers={'X-ReqId': 'fakereqid'})
 
         async def query(self):
             return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
@@ -134,15 +965,18 @@ class TestFormUploader(unittest.IsolatedAsyncioTestCase):
             uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
                 credential.Credential('ak', 'sk'), 'fakebucket', 3600),
                 use_https=False,
-                queryer=queryer).form_uploader()
-            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
-                for _ in range(1 << 10):
-                    await f.write(os.urandom(1 << 10))
-                await f.seek(0, io.SEEK_SET)
-                result = await uploader.async_upload_reader(
-                    f, object_name='fakeobjectname', file_name='fakefilename')
-                self.assertEqual(result['key'], 'fakekey')
-                self.assertEqual(result['hash'], 'fakehash')
+                queryer=queryer).auto_uploader()
+            result = await uploader.async_try_upload_reader(
+                io.BytesIO(os.urandom(1 << 10)), object_name='goodobjectname')
+            self.assertTrue(result.ok)
+            self.assertEqual(result.response, {'key': 'goodobjectname'})
+            self.assertIsNone(result.error)
+
+            result = await uploader.async_try_upload_reader(
+                io.BytesIO(os.urandom(1 << 10)), object_name='badobjectname')
+            self.assertFalse(result.ok)
+            self.assertIsNone(result.response)
+            self.assertIn('file exists', result.error)
         finally:
             await runner.cleanup()
 
@@ -200,14 +1034,161 @@ class TestMultiPartsUploader(unittest.IsolatedAsyncioTestCase):
                 while True:
                     part = await uploader.async_upload_part(inited, data_partitioner)
                     if part != None:
+                        self.assertFalse(part.is_last)
                         parts.append(part)
                     else:
                         break
+                self.assertEqual([part.is_last for part in parts], [
+                                 False, False, False, True])
                 response = await uploader.async_complete_part(inited, parts)
                 self.assertEqual(response['body'], 'done')
         finally:
             await runner.cleanup()
 
+    async def test_multi_parts_uploader_with_memory_recorder(self):
+        blocks = 0
+
+        async def mkblk(request):
+            await request.read()
+            nonlocal blocks
+            blocks += 1
+            return web.json_response({'ctx': '===ctx-%d===' % blocks, 'expired_at': int(time.time()) + 3600}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application(client_max_size=1 << 30)
+        app.add_routes([web.post('/mkblk/{block_size}', mkblk)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            recorder = upload.MemoryResumableRecorder()
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False, queryer=queryer).multi_parts_v1_uploader(recorder)
+            data_partitioner = upload.FixedDataPartitionProvider(1 << 22)
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                for _ in range(1 << 11):
+                    await f.write(os.urandom(1 << 12))
+                await f.flush()
+                inited = await uploader.async_initialize_parts(upload.AsyncFileDataSource(f.name), object_name='fakeobjectname', file_name='fakefilename')
+                self.assertEqual(inited.resumed_parts(), [])
+                for _ in range(2):
+                    part = await uploader.async_upload_part(inited, data_partitioner)
+                    self.assertFalse(part.resumed)
+                self.assertEqual(blocks, 2)
+
+                inited = await uploader.async_initialize_parts(upload.AsyncFileDataSource(f.name), object_name='fakeobjectname', file_name='fakefilename')
+                resumed_parts = inited.resumed_parts()
+                self.assertEqual([(p.offset, p.size) for p in resumed_parts], [(0, 1 << 22), (1 << 22, 1 << 22)])
+                self.assertTrue(all(p.resumed for p in resumed_parts))
+                part = await uploader.async_upload_part(inited, data_partitioner)
+                self.assertTrue(part.resumed)
+                self.assertEqual(blocks, 2)
+        finally:
+            await runner.cleanup()
+
+    async def test_multi_parts_v1_uploader_resumed_parts_skip_expired_records(self):
+        blocks = 0
+
+        async def mkblk(request):
+            await request.read()
+            nonlocal blocks
+            blocks += 1
+            expired_at = int(time.time()) + (-1 if blocks == 1 else 3600)
+            return web.json_response({'ctx': '===ctx-%d===' % blocks, 'expired_at': expired_at}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application(client_max_size=1 << 30)
+        app.add_routes([web.post('/mkblk/{block_size}', mkblk)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False, queryer=queryer).multi_parts_v1_uploader(upload.MemoryResumableRecorder())
+            data_partitioner = upload.FixedDataPartitionProvider(1 << 22)
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(os.urandom(1 << 23))
+                await f.flush()
+                inited = await uploader.async_initialize_parts(upload.AsyncFileDataSource(f.name), object_name='fakeobjectname')
+                for _ in range(2):
+                    await uploader.async_upload_part(inited, data_partitioner)
+
+                inited = await uploader.async_initialize_parts(upload.AsyncFileDataSource(f.name), object_name='fakeobjectname')
+                self.assertEqual([(p.offset, p.size) for p in inited.resumed_parts()], [(1 << 22, 1 << 22)])
+                part = await uploader.async_upload_part(inited, data_partitioner)
+                self.assertFalse(part.resumed)
+                self.assertEqual(blocks, 3)
+        finally:
+            await runner.cleanup()
+
+    def test_multi_parts_v2_uploader_resumed_parts(self):
+        expire_after = 3600
+        uploaded_parts = 0
+
+        def call(request):
+            nonlocal uploaded_parts
+            if request.method == 'PUT':
+                request.body
+                uploaded_parts += 1
+                body = {'etag': 'fakeEtag-%d' % uploaded_parts, 'md5': 'fakemd5'}
+            else:
+                body = {'uploadId': 'fakeUploadId', 'expireAt': int(time.time()) + expire_after}
+            return http.SyncHttpResponse(
+                status_code=200,
+                headers={'Content-Type': 'application/json', 'X-ReqId': 'fakereqid'},
+                body=json.dumps(body).encode())
+
+        uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+            credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+            http_client=http_client.HttpClient(http_caller=http.CallbackHttpCaller(call)),
+            use_https=False).multi_parts_v2_uploader(upload.MemoryResumableRecorder())
+        region_provider = http_client.Endpoints(['127.0.0.1:8089'])
+        data_partitioner = upload.FixedDataPartitionProvider(1 << 20)
+        with tempfile.NamedTemporaryFile('wb+') as f:
+            f.write(os.urandom(1 << 22))
+            f.flush()
+            inited = uploader.initialize_parts(
+                upload.FileDataSource(f.name), region_provider=region_provider, object_name='fakeobjectname')
+            self.assertEqual(inited.resumed_parts(), [])
+            for _ in range(2):
+                uploader.upload_part(inited, data_partitioner)
+
+            inited = uploader.initialize_parts(
+                upload.FileDataSource(f.name), region_provider=region_provider, object_name='fakeobjectname')
+            resumed_parts = inited.resumed_parts()
+            self.assertEqual([(p.offset, p.size) for p in resumed_parts], [(0, 1 << 20), (1 << 20, 1 << 20)])
+            self.assertTrue(all(p.resumed for p in resumed_parts))
+            self.assertTrue(uploader.upload_part(inited, data_partitioner).resumed)
+            self.assertEqual(uploaded_parts, 2)
+
+            expire_after = 1
+            f.write(b'changed')
+            f.flush()
+            inited = uploader.initialize_parts(
+                upload.FileDataSource(f.name), region_provider=region_provider, object_name='fakeobjectname')
+            uploader.upload_part(inited, data_partitioner)
+            time.sleep(2)
+            inited = uploader.initialize_parts(
+                upload.FileDataSource(f.name), region_provider=region_provider, object_name='fakeobjectname')
+            self.assertEqual(inited.resumed_parts(), [])
+            self.assertFalse(uploader.upload_part(inited, data_partitioner).resumed)
+
     async def test_multi_parts_v2_uploader(self):
         case = self
 
@@ -317,6 +1298,196 @@ class TestMultiPartsUploader(unittest.IsolatedAsyncioTestCase):
         finally:
             await runner.cleanup()
 
+    async def test_upload_with_max_open_sources(self):
+        uploaded_parts = []
+
+        async def init_parts(request):
+            return web.json_response({'uploadId': 'fakeUploadId-%s' % base64.urlsafe_b64decode(request.match_info['encoded_key']).decode(), 'expireAt': int(time.time()) + 3600}, headers={'X-ReqId': 'fakereqid'})
+
+        async def upload_part(request):
+            data = await request.read()
+            uploaded_parts.append((request.match_info['upload_id'], len(data)))
+            return web.json_response({'etag': 'fakeEtag-%s' % request.match_info['part_number'], 'md5': 'fakemd5'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def complete_parts(request):
+            data = await request.json()
+            return web.json_response({'parts': len(data['parts'])}, headers={'X-ReqId': 'fakereqid'})
+
+        async def form_upload(request):
+            data = await request.post()
+            data['file'].file.close()
+            return web.json_response({'key': data['key'], 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application(client_max_size=1 << 30)
+        app.add_routes(
+            [web.post('/buckets/{bucket_name}/objects/{encoded_key}/uploads', init_parts)])
+        app.add_routes(
+            [web.put('/buckets/{bucket_name}/objects/{encoded_key}/uploads/{upload_id}/{part_number}', upload_part)])
+        app.add_routes(
+            [web.post('/buckets/{bucket_name}/objects/{encoded_key}/uploads/{upload_id}', complete_parts)])
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            signer = upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600)
+            with self.assertRaises(QiniuInvalidConcurrency):
+                upload.UploadManager(signer, use_https=False, max_open_sources=0)
+
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            upload_manager = upload.UploadManager(
+                signer, use_https=False, queryer=queryer, max_open_sources=1)
+            scheduler = upload.ConcurrentMultiPartsUploaderScheduler(
+                upload_manager.multi_parts_v2_uploader(upload.DummyResumableRecorder()))
+            scheduler.data_partition_provider = upload.FixedDataPartitionProvider(1 << 20)
+            async with aiofiles.tempfile.TemporaryDirectory() as d:
+                paths = [os.path.join(d, str(i)) for i in range(4)]
+                for path in paths:
+                    with open(path, 'wb') as f:
+                        f.write(os.urandom(3 << 20))
+                responses = await asyncio.gather(*[
+                    scheduler.async_upload(upload.AsyncFileDataSource(path), object_name=str(i))
+                    for (i, path) in enumerate(paths)])
+                self.assertEqual([response['parts'] for response in responses], [3] * 4)
+                self.assertEqual(len(uploaded_parts), 12)
+                self.assertTrue(all(size == 1 << 20 for (_, size) in uploaded_parts))
+
+                uploader = upload_manager.auto_uploader()
+                results = await uploader.async_upload_directory(d, concurrency=4)
+                self.assertEqual(len(results), 4)
+                self.assertEqual(sorted(result['key'] for (_, result) in results), ['0', '1', '2', '3'])
+        finally:
+            await runner.cleanup()
+
+    async def test_upload_with_accelerated_endpoints(self):
+        uploaded_by = []
+
+        def make_form_upload(name):
+            async def form_upload(request):
+                data = await request.post()
+                data['file'].file.close()
+                uploaded_by.append(name)
+                return web.json_response({'key': data['key'], 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+            return form_upload
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', make_form_upload('up'))])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+        accelerated_app = web.Application()
+        accelerated_app.add_routes([web.post('/', make_form_upload('accelerated'))])
+        accelerated_runner = web.AppRunner(accelerated_app)
+        await accelerated_runner.setup()
+        accelerated_site = web.TCPSite(accelerated_runner, '127.0.0.1', 8090)
+        await accelerated_site.start()
+
+        try:
+            signer = upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600)
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(
+                signer, use_https=False, queryer=queryer,
+                accelerated_endpoints=http_client.Endpoints(['127.0.0.1:8090'])).auto_uploader()
+            async with aiofiles.tempfile.NamedTemporaryFile('wb') as f:
+                await f.write(b'hello')
+                await f.flush()
+                response = await uploader.async_upload_path(f.name, object_name='accelerated')
+                self.assertEqual(response['key'], 'accelerated')
+                self.assertEqual(uploaded_by, ['accelerated'])
+
+                async with aiofiles.tempfile.TemporaryDirectory() as d:
+                    async with aiofiles.open(os.path.join(d, 'file'), 'wb') as file:
+                        await file.write(b'hello')
+                    results = await uploader.async_upload_directory(d, key_prefix='dir/')
+                    self.assertEqual(results[0][0], 'file')
+                    self.assertEqual(results[0][1]['key'], 'dir/file')
+                self.assertEqual(uploaded_by, ['accelerated', 'accelerated'])
+
+                await accelerated_runner.cleanup()
+                response = await uploader.async_upload_path(f.name, object_name='fallback')
+                self.assertEqual(response['key'], 'fallback')
+                self.assertEqual(uploaded_by, ['accelerated', 'accelerated', 'up'])
+        finally:
+            await accelerated_runner.cleanup()
+            await runner.cleanup()
+
+    async def test_multi_parts_uploader_with_part_upload_error(self):
+        failed = False
+
+        async def init_parts(request):
+            return web.json_response({'uploadId': 'fakeUploadId', 'expireAt': int(time.time()) + 3600}, headers={'X-ReqId': 'fakereqid'})
+
+        async def upload_part(request):
+            await request.read()
+            nonlocal failed
+            if request.match_info['part_number'] == '2' and not failed:
+                failed = True
+                return web.json_response({'error': 'internal error'}, status=599, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response({'etag': 'fakeEtag-%s' % request.match_info['part_number'], 'md5': 'fakemd5'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def complete_parts(request):
+            await request.json()
+            return web.json_response({'body': 'done'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application(client_max_size=1 << 30)
+        app.add_routes(
+            [web.post('/buckets/{bucket_name}/objects/{encoded_key}/uploads', init_parts)])
+        app.add_routes(
+            [web.put('/buckets/{bucket_name}/objects/{encoded_key}/uploads/{upload_id}/{part_number}', upload_part)])
+        app.add_routes(
+            [web.post('/buckets/{bucket_name}/objects/{encoded_key}/uploads/{upload_id}', complete_parts)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            errors = []
+
+            def part_upload_error(part_offset, part_size, error, retried):
+                errors.append((part_offset, part_size, retried))
+
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False, queryer=queryer).multi_parts_v2_uploader(
+                upload.DummyResumableRecorder(), part_upload_error=part_upload_error)
+            scheduler = upload.SerialMultiPartsUploaderScheduler(uploader)
+            scheduler.data_partition_provider = upload.FixedDataPartitionProvider(
+                1 << 22)
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                for _ in range(3 << 10):
+                    await f.write(os.urandom(1 << 12))
+                await f.flush()
+                try:
+                    await asyncio.get_running_loop().run_in_executor(None, lambda: scheduler.upload(
+                        upload.FileDataSource(f.name), object_name='fakeobjectname', file_name='fakefilename'))
+                except QiniuApiCallError:
+                    pass
+            self.assertEqual(errors[0], (1 << 22, 1 << 22, 0))
+        finally:
+            await runner.cleanup()
+
 
 def regions_info():
     return {
@@ -1,4 +1,4 @@
-from qiniu_bindings import upload_token, credential
+from qiniu_bindings import upload_token, credential, QiniuUploadTokenFormatError, QiniuBase64Error, QiniuJsonError
 import unittest
 
 
@@ -135,6 +135,27 @@ class TestUploadPolicy(unittest.TestCase):
         self.assertSetEqual(set(policy.keys), {
                             'deadline', 'forceSaveKey', 'saveKey', 'scope'})
 
+    def test_policy_builder_fields(self):
+        builder = upload_token.UploadPolicyBuilder.new_for_bucket(
+            'test-bucket', 3600)
+        builder.callback_urls = ['https://a.com/cb', 'https://b.com/cb']
+        builder.callback_host = 'a.com'
+        builder.callback_body = 'key=$(key)'
+        builder.callback_body_type = 'application/x-www-form-urlencoded'
+        builder.save_key = '$(etag)'
+        builder.force_save_key = True
+        builder.token_deadline = 4102444800
+        policy = builder.build()
+        self.assertEqual(policy.callback_urls, [
+                         'https://a.com/cb', 'https://b.com/cb'])
+        self.assertEqual(policy.callback_host, 'a.com')
+        self.assertEqual(policy.callback_body, 'key=$(key)')
+        self.assertEqual(policy.callback_body_type,
+                         'application/x-www-form-urlencoded')
+        self.assertEqual(policy.save_key, '$(etag)')
+        self.assertTrue(policy.is_save_key_forced)
+        self.assertEqual(policy.token_deadline, 4102444800)
+
     def test_file_size_limitation(self):
         builder = upload_token.UploadPolicy.new_for_object(
             'test-bucket', 'test-object', 3600)
@@ -172,6 +193,31 @@ class TestUploadTokenProvider(unittest.TestCase):
         self.assertEqual(provider.access_key(), 'test-ak')
         self.assertEqual(provider.bucket_name(), 'test-bucket')
         self.assertEqual(provider.policy().key, 'test-object')
+        with self.assertRaises(QiniuUploadTokenFormatError):
+            upload_token.StaticUploadTokenProvider('invalid-token')
+        with self.assertRaises((QiniuBase64Error, QiniuJsonError)):
+            upload_token.StaticUploadTokenProvider('test-ak:sign:!!!')
+
+    def test_parse_upload_token(self):
+        cred = credential.Credential('test-ak', 'test-sk')
+        token = upload_token.UploadPolicy.new_for_object(
+            'test-bucket', 'test-object', 3600).build().to_upload_token_provider(cred).to_token_string()
+        parsed = upload_token.UploadToken.parse(token)
+        self.assertEqual(parsed.access_key, 'test-ak')
+        self.assertEqual(parsed.bucket_name, 'test-bucket')
+        self.assertEqual(parsed.policy['scope'], 'test-bucket:test-object')
+        self.assertFalse(parsed.is_expired())
+        self.assertEqual(str(parsed), token)
+
+        builder = upload_token.UploadPolicy.new_for_bucket('test-bucket', 3600)
+        builder.token_deadline = 1
+        token = builder.build().to_upload_token_provider(cred).to_token_string()
+        self.assertTrue(upload_token.UploadToken.parse(token).is_expired())
+
+        with self.assertRaises(QiniuUploadTokenFormatError):
+            upload_token.UploadToken.parse('invalid-token')
+        with self.assertRaises(QiniuUploadTokenFormatError):
+            upload_token.UploadToken.parse('test-ak:sign:!!!')
 
     def test_bucket_upload_token_provider(self):
         cred = credential.Credential('test-ak', 'test-sk')
@@ -0,0 +1,32 @@
+from qiniu_bindings import utils, QiniuInvalidChunkSize
+import unittest
+import aiofiles
+import io
+import os
+
+
+class TestCopyStream(unittest.TestCase):
+    def test_copy_stream(self):
+        data = os.urandom((1 << 20) + 1)
+        writer = io.BytesIO()
+        progresses = []
+        copied = utils.copy_stream(io.BytesIO(data), writer, chunk_size=1 << 16,
+                                   progress=lambda copied: progresses.append(copied))
+        self.assertEqual(copied, len(data))
+        self.assertEqual(writer.getvalue(), data)
+        self.assertEqual(len(progresses), 17)
+        self.assertEqual(progresses[-1], len(data))
+
+    def test_copy_stream_with_zero_chunk_size(self):
+        with self.assertRaises(QiniuInvalidChunkSize):
+            utils.copy_stream(io.BytesIO(b'data'), io.BytesIO(), chunk_size=0)
+
+
+class TestAsyncCopyStream(unittest.IsolatedAsyncioTestCase):
+    async def test_async_copy_stream(self):
+        data = os.urandom((1 << 20) + 1)
+        async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+            copied = await utils.async_copy_stream(io.BytesIO(data), f)
+            self.assertEqual(copied, len(data))
+            await f.seek(0, io.SEEK_SET)
+            self.assertEqual(await f.read(), data)